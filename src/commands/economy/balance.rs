@@ -0,0 +1,32 @@
+use poise::{
+    serenity_prelude::{CreateEmbed, User},
+    CreateReply,
+};
+
+use super::format_amount;
+use crate::{commands::EMBED_COLOR, database, Error};
+
+/// See how many bones you or someone else has
+#[poise::command(slash_command)]
+pub async fn balance(
+    ctx: poise::Context<'_, (), Error>,
+    #[description = "Whose balance to see."] user: Option<User>,
+) -> Result<(), Error> {
+    ctx.defer().await?;
+
+    let user = user.as_ref().unwrap_or_else(|| ctx.author());
+    let balance = database::get_balance(user.id)?;
+
+    ctx.send(
+        CreateReply::default().embed(
+            CreateEmbed::new()
+                .title(format!("{}'s balance", user.name))
+                .description(format!("{} has {}.", user.name, format_amount(balance)))
+                .thumbnail(user.face())
+                .color(EMBED_COLOR),
+        ),
+    )
+    .await?;
+
+    Ok(())
+}
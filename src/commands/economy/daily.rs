@@ -0,0 +1,48 @@
+use poise::{serenity_prelude::CreateEmbed, CreateReply};
+
+use super::{
+    format_amount, DailyClaim, Transaction, TransactionKind, DAILY_AMOUNT, DAILY_COOLDOWN,
+};
+use crate::{
+    commands::{CONFIRM_EMBED_COLOR, CONFIRM_EMOJI, ERROR_EMBED_COLOR, ERROR_EMOJI},
+    database, Error,
+};
+
+/// Claim your daily bones
+#[poise::command(slash_command)]
+pub async fn daily(ctx: poise::Context<'_, (), Error>) -> Result<(), Error> {
+    ctx.defer().await?;
+
+    let transaction = Transaction::new(
+        None,
+        Some(ctx.author().id),
+        DAILY_AMOUNT,
+        TransactionKind::Daily,
+    );
+
+    let embed = match database::claim_daily(&transaction, DAILY_COOLDOWN.as_secs())? {
+        DailyClaim::Claimed => {
+            let balance = database::get_balance(ctx.author().id)?;
+
+            CreateEmbed::new()
+                .title("Daily reward claimed")
+                .description(format!(
+                    "{CONFIRM_EMOJI} You got {}! You now have {}.",
+                    format_amount(DAILY_AMOUNT),
+                    format_amount(balance)
+                ))
+                .color(CONFIRM_EMBED_COLOR)
+        }
+        DailyClaim::OnCooldown(last_claim) => CreateEmbed::new()
+            .title("Error")
+            .description(format!(
+                "{ERROR_EMOJI} You already claimed your daily reward, come back <t:{}:R>.",
+                last_claim + DAILY_COOLDOWN.as_secs()
+            ))
+            .color(ERROR_EMBED_COLOR),
+    };
+
+    ctx.send(CreateReply::default().embed(embed)).await?;
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use poise::{serenity_prelude::CreateEmbed, CreateReply};
+
+use super::format_amount;
+use crate::{commands::EMBED_COLOR, database, Error};
+
+/// How many users the leaderboard shows.
+const LEADERBOARD_SIZE: u64 = 10;
+
+/// See who has the most bones
+#[poise::command(slash_command)]
+pub async fn leaderboard(ctx: poise::Context<'_, (), Error>) -> Result<(), Error> {
+    ctx.defer().await?;
+
+    let balances = database::get_balance_leaderboard(LEADERBOARD_SIZE)?;
+
+    let leaderboard = if balances.is_empty() {
+        "Nobody has any bones yet, use `/daily` to be the first!".to_string()
+    } else {
+        balances
+            .iter()
+            .enumerate()
+            .map(|(i, (user_id, balance))| {
+                format!("{}. <@{user_id}>: {}", i + 1, format_amount(*balance))
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+
+    // Footers don't support markdown, so the balance goes with the rest.
+    let description = format!(
+        "{leaderboard}\n\nYou have {}.",
+        format_amount(database::get_balance(ctx.author().id)?)
+    );
+
+    ctx.send(
+        CreateReply::default().embed(
+            CreateEmbed::new()
+                .title("Richest users")
+                .description(description)
+                .color(EMBED_COLOR),
+        ),
+    )
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,94 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use poise::serenity_prelude::UserId;
+
+pub mod balance;
+pub mod daily;
+pub mod leaderboard;
+pub mod pay;
+
+/// What the currency is shown as.
+pub const CURRENCY_EMOJI: &str = "🦴";
+
+/// How much `/daily` gives.
+const DAILY_AMOUNT: u64 = 250;
+
+/// How long users have to wait between `/daily` claims.
+const DAILY_COOLDOWN: Duration = Duration::from_hours(20);
+
+/// Why money moved between users, or in or out of the economy.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub enum TransactionKind {
+    #[default]
+    Daily,
+    Payment,
+    Wager,
+    Winnings,
+    /// A wager given back because it couldn't be settled.
+    Refund,
+}
+
+impl TransactionKind {
+    /// The identifier used to store this kind in the database.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Daily => "daily",
+            Self::Payment => "payment",
+            Self::Wager => "wager",
+            Self::Winnings => "winnings",
+            Self::Refund => "refund",
+        }
+    }
+}
+
+/// An entry in the ledger.
+///
+/// Money without a sender is created by the bot, like daily rewards, and
+/// money without a recipient is taken out of the economy, like lost wagers.
+#[derive(Debug, Clone, Eq, PartialEq, Default)]
+pub struct Transaction {
+    pub(crate) from_user_id: Option<UserId>,
+    pub(crate) to_user_id: Option<UserId>,
+    pub(crate) amount: u64,
+    pub(crate) kind: TransactionKind,
+    pub(crate) timestamp: u64,
+}
+
+impl Transaction {
+    #[must_use]
+    pub fn new(
+        from_user_id: Option<UserId>,
+        to_user_id: Option<UserId>,
+        amount: u64,
+        kind: TransactionKind,
+    ) -> Self {
+        Self {
+            from_user_id,
+            to_user_id,
+            amount,
+            kind,
+            timestamp: now(),
+        }
+    }
+}
+
+/// The result of trying to claim the daily reward.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum DailyClaim {
+    Claimed,
+    /// The reward was already claimed at this timestamp.
+    OnCooldown(u64),
+}
+
+/// Formats an amount of money to be shown in an embed.
+#[must_use]
+pub fn format_amount(amount: u64) -> String {
+    format!("**{amount}** {CURRENCY_EMOJI}")
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs())
+}
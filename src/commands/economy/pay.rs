@@ -0,0 +1,67 @@
+use poise::{
+    serenity_prelude::{CreateEmbed, User},
+    CreateReply,
+};
+
+use super::{format_amount, Transaction, TransactionKind};
+use crate::{
+    commands::{CONFIRM_EMBED_COLOR, CONFIRM_EMOJI, ERROR_EMBED_COLOR, ERROR_EMOJI},
+    database, Error,
+};
+
+/// Give some of your bones to someone else
+#[poise::command(slash_command)]
+pub async fn pay(
+    ctx: poise::Context<'_, (), Error>,
+    #[description = "Who to pay."] user: User,
+    #[description = "How many bones to give them."]
+    #[min = 1]
+    amount: u64,
+) -> Result<(), Error> {
+    ctx.defer().await?;
+
+    let error = if user.id == ctx.author().id {
+        Some("You can't pay yourself.")
+    } else if user.bot {
+        Some("Bots don't need bones.")
+    } else {
+        None
+    };
+
+    let embed = if let Some(error) = error {
+        CreateEmbed::new()
+            .title("Error")
+            .description(format!("{ERROR_EMOJI} {error}"))
+            .color(ERROR_EMBED_COLOR)
+    } else {
+        let transaction = Transaction::new(
+            Some(ctx.author().id),
+            Some(user.id),
+            amount,
+            TransactionKind::Payment,
+        );
+
+        if database::apply_transaction(&transaction)? {
+            CreateEmbed::new()
+                .title("Payment sent")
+                .description(format!(
+                    "{CONFIRM_EMOJI} You gave {} to {}.",
+                    format_amount(amount),
+                    user.name
+                ))
+                .color(CONFIRM_EMBED_COLOR)
+        } else {
+            CreateEmbed::new()
+                .title("Error")
+                .description(format!(
+                    "{ERROR_EMOJI} You don't have enough bones, you only have {}.",
+                    format_amount(database::get_balance(ctx.author().id)?)
+                ))
+                .color(ERROR_EMBED_COLOR)
+        }
+    };
+
+    ctx.send(CreateReply::default().embed(embed)).await?;
+
+    Ok(())
+}
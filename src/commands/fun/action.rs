@@ -1,257 +1,857 @@
-use std::vec;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::RwLock;
+use std::time::Duration;
 
-use crate::{database, Error, ACTION_COUNTS};
+use crate::{database, media_cache, Error};
 
-use super::super::{EMBED_COLOR, ERROR_EMBED_COLOR, ERROR_EMOJI};
-use poise::serenity_prelude::{CreateEmbed, CreateEmbedFooter, Mentionable};
+use super::super::{
+    CONFIRM_EMBED_COLOR, CONFIRM_EMOJI, EMBED_COLOR, ERROR_EMBED_COLOR, ERROR_EMOJI,
+};
+use log::{info, warn};
+use once_cell::sync::Lazy;
+use poise::serenity_prelude::{CreateEmbed, CreateEmbedFooter, GuildId, Mentionable, User, UserId};
 use poise::CreateReply;
 use rand::Rng;
-use serde::{Deserialize, Serialize};
+use serde::Deserialize;
 
 const BASE_URL: &str = "https://shiba-gifs.pages.dev";
 
-const ACTIONS: [&str; 11] = [
-    "bite", "cuddle", "highfive", "hug", "kiss", "lick", "pat", "poke", "punch", "shoot", "slap",
+/// The wording of the actions that were around before the manifest could
+/// define it, for when it only lists their GIF counts:
+/// `(name, verb, past tense, performers, emoji)`.
+const BUILT_IN_ACTIONS: [(&str, &str, &str, &str, Option<&str>); 11] = [
+    ("bite", "bites", "bitten", "biters", None),
+    ("cuddle", "cuddles", "cuddled", "cuddlers", None),
+    ("highfive", "highfives", "highfived", "highfivers", None),
+    ("hug", "hugs", "hugged", "huggers", None),
+    ("kiss", "kisses", "kissed", "kissers", None),
+    ("lick", "licks", "licked", "lickers", None),
+    ("pat", "pats", "patted", "patters", None),
+    ("poke", "pokes", "poked", "pokers", None),
+    ("punch", "punches", "punched", "punchers", None),
+    (
+        "shoot",
+        "shoots",
+        "shot",
+        "shooters",
+        Some("<:shibaGun:1200035732794912798>"),
+    ),
+    ("slap", "slaps", "slapped", "slappers", None),
 ];
 
-#[derive(Serialize, Deserialize, Debug, Copy, Clone)]
-pub struct Counts {
-    bite: usize,
-    cuddle: usize,
-    highfive: usize,
-    hug: usize,
-    kiss: usize,
-    lick: usize,
-    pat: usize,
-    poke: usize,
-    punch: usize,
-    shoot: usize,
-    slap: usize,
+/// The actions that can be performed, keyed by name.
+///
+/// They're empty until [`load_actions`] is called at startup.
+static ACTIONS: Lazy<RwLock<Counts>> = Lazy::new(|| RwLock::new(BTreeMap::new()));
+
+/// Every action that can be performed, keyed by name.
+pub type Counts = BTreeMap<String, Action>;
+
+/// An action that can be performed on someone, and how to word it.
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize)]
+pub struct Action {
+    /// The third person present tense, e.g. "hugs".
+    pub verb: String,
+    /// The past participle, e.g. "hugged".
+    pub past_tense: String,
+    /// What the people performing it are called, e.g. "huggers".
+    #[serde(default)]
+    pub performers: Option<String>,
+    /// Shown before the title when the action is performed.
+    #[serde(default)]
+    pub emoji: Option<String>,
+    /// Where the GIFs are, relative to [`BASE_URL`]. Defaults to the action's name.
+    #[serde(default)]
+    pub directory: Option<String>,
+    /// How many GIFs there are.
+    pub count: usize,
+}
+
+/// An entry of the `api.json` manifest.
+///
+/// Older manifests only have the GIF count of each action, newer ones can
+/// define the whole action.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ManifestEntry {
+    Count(usize),
+    Action(Action),
+}
+
+impl Action {
+    /// Builds an action from just its GIF count, using the built-in wording if
+    /// there is some or regular conjugation otherwise.
+    fn from_count(name: &str, count: usize) -> Self {
+        BUILT_IN_ACTIONS
+            .iter()
+            .find(|(built_in, ..)| *built_in == name)
+            .map_or_else(
+                || Self {
+                    verb: format!("{name}s"),
+                    past_tense: format!("{name}ed"),
+                    performers: None,
+                    emoji: None,
+                    directory: None,
+                    count,
+                },
+                |(_, verb, past_tense, performers, emoji)| Self {
+                    verb: (*verb).to_string(),
+                    past_tense: (*past_tense).to_string(),
+                    performers: Some((*performers).to_string()),
+                    emoji: emoji.map(str::to_string),
+                    directory: None,
+                    count,
+                },
+            )
+    }
+}
+
+/// How many GIFs of each action are cached at startup, when the media cache is
+/// enabled.
+const PREFETCHED_GIFS: usize = 5;
+
+/// How long an action waits for its GIF to be cached before using another
+/// cached one, well within the 3 seconds Discord gives to respond.
+const CACHE_WAIT: Duration = Duration::from_millis(1500);
+
+/// How many users each section of the leaderboards shows.
+const LEADERBOARD_SIZE: u64 = 5;
+
+/// How many actions `/action stats` shows, since embeds can't have more than
+/// 25 fields.
+const MAX_STATS_FIELDS: usize = 24;
+
+/// Which side of an action users are ranked by.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ActionRole {
+    Performer,
+    Receiver,
+}
+
+impl ActionRole {
+    /// The column holding the users on this side of the action.
+    #[must_use]
+    pub const fn column(self) -> &'static str {
+        match self {
+            Self::Performer => "from_user_id",
+            Self::Receiver => "to_user_id",
+        }
+    }
+}
+
+/// How many times a user performed and received an action.
+#[derive(Debug, Clone, Eq, PartialEq, Default)]
+pub struct ActionStats {
+    pub(crate) action: String,
+    pub(crate) given: u64,
+    pub(crate) received: u64,
+}
+
+impl ActionStats {
+    /// Combines the per-action totals a user gave and received, sorted by action.
+    #[must_use]
+    pub fn merge(given: Vec<(String, u64)>, received: Vec<(String, u64)>) -> Vec<Self> {
+        let mut stats = BTreeMap::<String, Self>::new();
+
+        for (action, amount) in given {
+            stats
+                .entry(action.clone())
+                .or_insert_with(|| Self {
+                    action,
+                    ..Default::default()
+                })
+                .given += amount;
+        }
+
+        for (action, amount) in received {
+            stats
+                .entry(action.clone())
+                .or_insert_with(|| Self {
+                    action,
+                    ..Default::default()
+                })
+                .received += amount;
+        }
+
+        stats.into_values().collect()
+    }
+}
+
+/// Two users who both performed an action on each other.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ActionPair {
+    pub(crate) first: UserId,
+    pub(crate) second: UserId,
+    pub(crate) first_to_second: u64,
+    pub(crate) second_to_first: u64,
+}
+
+/// What a user lets others do to them with actions.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ActionPreferences {
+    pub(crate) user_id: UserId,
+    /// Whether they opted out of every action.
+    pub(crate) opted_out_all: bool,
+    /// The actions they opted out of.
+    pub(crate) opted_out: Vec<String>,
+    /// The users who can't perform actions on them.
+    pub(crate) blocked: Vec<UserId>,
+    /// Whether they get pinged when an action is performed on them.
+    pub(crate) ping: bool,
+}
+
+impl ActionPreferences {
+    /// The preferences of someone who never changed them.
+    #[must_use]
+    pub const fn new(user_id: UserId) -> Self {
+        Self {
+            user_id,
+            opted_out_all: false,
+            opted_out: Vec::new(),
+            blocked: Vec::new(),
+            ping: true,
+        }
+    }
+
+    /// Whether `from` can perform `action` on this user.
+    #[must_use]
+    pub fn allows(&self, action: &str, from: UserId) -> bool {
+        !self.opted_out_all
+            && !self.opted_out.iter().any(|opted_out| opted_out == action)
+            && !self.blocked.contains(&from)
+    }
+}
+
+/// Roleplay actions and see who does them the most
+#[poise::command(
+    slash_command,
+    subcommands(
+        "perform",
+        "stats",
+        "leaderboard",
+        "optout",
+        "optin",
+        "block",
+        "unblock",
+        "pings",
+        "preferences"
+    ),
+    subcommand_required
+)]
+// This function needs to be async for poise to use it.
+#[allow(clippy::unused_async)]
+pub async fn action(_: poise::Context<'_, (), Error>) -> Result<(), Error> {
+    Ok(())
 }
 
 /// Roleplay an action to someone
 #[poise::command(slash_command, broadcast_typing)]
-pub async fn action(
+pub async fn perform(
     ctx: poise::Context<'_, (), Error>,
     #[description = "The action you want to perform."]
     #[autocomplete = "actions_autocomplete"]
     action: String,
-    #[description = "The user you'd like to perform this action on."]
-    user: poise::serenity_prelude::User,
+    #[description = "The user you'd like to perform this action on."] user: User,
+) -> Result<(), Error> {
+    perform_action(ctx, &action, &user).await
+}
+
+/// Hug someone
+#[poise::command(context_menu_command = "Hug")]
+pub async fn hug_user(
+    ctx: poise::Context<'_, (), Error>,
+    #[description = "The user to hug."] user: User,
+) -> Result<(), Error> {
+    perform_action(ctx, "hug", &user).await
+}
+
+/// Pat someone
+#[poise::command(context_menu_command = "Pat")]
+pub async fn pat_user(
+    ctx: poise::Context<'_, (), Error>,
+    #[description = "The user to pat."] user: User,
+) -> Result<(), Error> {
+    perform_action(ctx, "pat", &user).await
+}
+
+/// Performs an action on someone, if they accept it from the author.
+async fn perform_action(
+    ctx: poise::Context<'_, (), Error>,
+    action: &str,
+    user: &User,
 ) -> Result<(), Error> {
     // Can't use defer since it makes poise edit the message with the content
     // instead of sending the message directly. This makes it not ping when it
     // mentions users.
     // ctx.defer().await?;
 
-    // It IS used
-    #[allow(unused)]
-    let mut title = String::new();
-    #[allow(unused)]
-    let mut footer = String::new();
-    #[allow(unused)]
-    let mut gif_count = 0;
-    let mut new_count = 0;
-    if ACTIONS.contains(&action.as_str()) {
-        let action_count = database::get_action_count(&action, ctx.author().id, user.id)?;
-        new_count = action_count + 1;
-        database::update_action_count(&action, ctx.author().id, user.id, new_count)?;
+    let Some(definition) = get_action(action) else {
+        ctx.send(
+            CreateReply::default().embed(
+                CreateEmbed::default()
+                    .title("Error")
+                    .description(format!("{ERROR_EMOJI} Action not found."))
+                    .color(ERROR_EMBED_COLOR),
+            ),
+        )
+        .await?;
+        return Ok(());
+    };
+
+    let target_preferences = get_preferences(user.id)?;
+    if !target_preferences.allows(action, ctx.author().id) {
+        // Doesn't say why, so people can't tell whether they were blocked.
+        send_error(
+            ctx,
+            &format!("{} isn't accepting that action from you.", user.name),
+        )
+        .await?;
+        return Ok(());
     }
 
-    if unsafe { ACTION_COUNTS.is_none() } {
-        return Err("Action counts not loaded.".into());
+    let new_count = database::increment_action_count(action, ctx.author().id, user.id)?;
+
+    if let Some(guild_id) = ctx.guild_id() {
+        database::add_guild_action(guild_id.get(), action, ctx.author().id, user.id)?;
     }
 
-    match action.as_str() {
-        "bite" => {
-            title = format!("{} bites {}!", ctx.author().name, user.name);
-            footer = format!(
-                "{} has bitten {} {} times.",
-                ctx.author().name,
-                user.name,
-                new_count
-            );
-            gif_count = get_counts().bite;
-        }
+    let title = format!(
+        "{}{} {} {}!",
+        definition
+            .emoji
+            .as_ref()
+            .map_or_else(String::new, |emoji| format!("{emoji} ")),
+        ctx.author().name,
+        definition.verb,
+        user.name
+    );
+    let footer = format!(
+        "{} has {} {} {new_count} {}.",
+        ctx.author().name,
+        definition.past_tense,
+        user.name,
+        if new_count == 1 { "time" } else { "times" }
+    );
 
-        "cuddle" => {
-            title = format!("{} {}s {}!", ctx.author().name, action, user.name);
-            footer = format!(
-                "{} has {}d {} {} times.",
-                ctx.author().name,
-                action,
-                user.name,
-                new_count
-            );
-            gif_count = get_counts().cuddle;
-        }
+    let mut rng = <rand::rngs::SmallRng as rand::SeedableRng>::from_entropy();
+    let random_number: usize = rng.gen_range(1..definition.count);
+    let directory = definition.directory.as_deref().unwrap_or(action);
+    let url = format!("{BASE_URL}/{directory}/{random_number}.gif");
 
-        "highfive" => {
-            title = format!("{} {}s {}!", ctx.author().name, action, user.name);
-            footer = format!(
-                "{} has {}d {} {} times.",
-                ctx.author().name,
-                action,
-                user.name,
-                new_count
-            );
-            gif_count = get_counts().highfive;
-        }
+    let mut reply = CreateReply::default();
+    if target_preferences.ping {
+        reply = reply.content(user.mention().to_string());
+    }
 
-        "hug" => {
-            title = format!("{} hugs {}!", ctx.author().name, user.name);
-            footer = format!(
-                "{} has hugged {} {} times.",
-                ctx.author().name,
-                user.name,
-                new_count
-            );
-            gif_count = get_counts().hug;
-        }
+    let mut embed = CreateEmbed::default()
+        .title(title)
+        .footer(CreateEmbedFooter::new(footer))
+        .color(EMBED_COLOR);
 
-        "kiss" => {
-            title = format!("{} kisses {}!", ctx.author().name, user.name);
-            footer = format!(
-                "{} has kissed {} {} times.",
-                ctx.author().name,
-                user.name,
-                new_count
-            );
-            gif_count = get_counts().kiss;
-        }
+    // Uploads the GIF when it's cached, so it still shows if the GIF host is down.
+    // The command isn't deferred, so slow downloads can't be waited on.
+    if let Some(media) =
+        media_cache::fetch_or_fallback_within(&format!("action_{action}"), &url, CACHE_WAIT).await
+    {
+        embed = embed.image(media.attachment_url());
+        reply = reply.attachment(media.attachment());
+    } else {
+        embed = embed.image(url);
+    }
 
-        "lick" => {
-            title = format!("{} {}s {}!", ctx.author().name, action, user.name);
-            footer = format!(
-                "{} has {}ed {} {} times.",
-                ctx.author().name,
-                action,
-                user.name,
-                new_count
-            );
-            gif_count = get_counts().lick;
-        }
+    ctx.send(reply.embed(embed)).await?;
 
-        "pat" => {
-            title = format!("{} {}s {}!", ctx.author().name, action, user.name);
-            footer = format!(
-                "{} has {}ted {} {} times.",
-                ctx.author().name,
-                action,
-                user.name,
-                new_count
-            );
-            gif_count = get_counts().pat;
-        }
+    Ok(())
+}
 
-        "poke" => {
-            title = format!("{} {}s {}!", ctx.author().name, action, user.name);
-            footer = format!(
-                "{} has {}ed {} {} times.",
-                ctx.author().name,
-                action,
-                user.name,
-                new_count
-            );
-            gif_count = get_counts().poke;
-        }
+/// See how many actions someone has given and received
+#[poise::command(slash_command, broadcast_typing)]
+pub async fn stats(
+    ctx: poise::Context<'_, (), Error>,
+    #[description = "Whose actions to show, yours if empty."] user: Option<User>,
+    #[description = "Whether to count this server's actions or everyone's."]
+    #[autocomplete = "scope_autocomplete"]
+    scope: Option<String>,
+) -> Result<(), Error> {
+    let user = user.as_ref().unwrap_or_else(|| ctx.author());
+    let guild_id = scope_guild_id(ctx, scope.as_deref());
 
-        "punch" => {
-            title = format!("{} punches {}!", ctx.author().name, user.name);
-            footer = format!(
-                "{} has punched {} {} times.",
-                ctx.author().name,
-                user.name,
-                new_count
-            );
-            gif_count = get_counts().punch;
-        }
+    let mut stats = database::get_action_stats(guild_id, user.id)?;
+    stats.sort_by_key(|stat| std::cmp::Reverse(stat.given + stat.received));
 
-        "shoot" => {
-            title = format!(
-                "<:shibaGun:1200035732794912798> {} shoots {}!",
-                ctx.author().name,
-                user.name
-            );
-            footer = format!(
-                "{} has shot {} {} times.",
-                ctx.author().name,
-                user.name,
-                new_count
-            );
-            gif_count = get_counts().shoot;
-        }
+    let mut embed = CreateEmbed::default()
+        .title(if guild_id.is_some() {
+            format!("{}'s actions in this server", user.name)
+        } else {
+            format!("{}'s actions", user.name)
+        })
+        .color(EMBED_COLOR);
 
-        "slap" => {
-            title = format!("{} slaps {}!", ctx.author().name, user.name);
-            footer = format!(
-                "{} has slapped {} {} times.",
-                ctx.author().name,
-                user.name,
-                new_count
-            );
-            gif_count = get_counts().slap;
-        }
+    if stats.is_empty() {
+        embed = embed.description(format!(
+            "{} hasn't given or received any actions yet.",
+            user.mention()
+        ));
+    } else {
+        let given = stats.iter().map(|stat| stat.given).sum::<u64>();
+        let received = stats.iter().map(|stat| stat.received).sum::<u64>();
 
-        _ => {
-            ctx.send(
-                CreateReply::default().embed(
-                    CreateEmbed::default()
-                        .title("Error")
-                        .description(format!("{ERROR_EMOJI} Action not found.",))
-                        .color(ERROR_EMBED_COLOR),
-                ),
+        let description = if stats.len() > MAX_STATS_FIELDS {
+            format!(
+                "**{given}** given, **{received}** received.\nShowing the top {MAX_STATS_FIELDS}, +{} more.",
+                stats.len() - MAX_STATS_FIELDS
             )
-            .await?;
-            return Ok(());
+        } else {
+            format!("**{given}** given, **{received}** received.")
+        };
+
+        embed = embed
+            .description(description)
+            .fields(stats.iter().take(MAX_STATS_FIELDS).map(|stat| {
+                (
+                    stat.action.clone(),
+                    format!("Given: {}\nReceived: {}", stat.given, stat.received),
+                    true,
+                )
+            }));
+    }
+
+    ctx.send(CreateReply::default().embed(embed)).await?;
+
+    Ok(())
+}
+
+/// See who performs and receives an action the most
+#[poise::command(slash_command, broadcast_typing)]
+pub async fn leaderboard(
+    ctx: poise::Context<'_, (), Error>,
+    #[description = "The action to rank users by."]
+    #[autocomplete = "actions_autocomplete"]
+    action: String,
+    #[description = "Whether to count this server's actions or everyone's."]
+    #[autocomplete = "scope_autocomplete"]
+    scope: Option<String>,
+) -> Result<(), Error> {
+    let Some(definition) = get_action(&action) else {
+        ctx.send(
+            CreateReply::default().embed(
+                CreateEmbed::default()
+                    .title("Error")
+                    .description(format!("{ERROR_EMOJI} Action not found."))
+                    .color(ERROR_EMBED_COLOR),
+            ),
+        )
+        .await?;
+        return Ok(());
+    };
+
+    let guild_id = scope_guild_id(ctx, scope.as_deref());
+
+    let receivers = database::get_action_leaderboard(
+        guild_id,
+        &action,
+        ActionRole::Receiver,
+        LEADERBOARD_SIZE,
+    )?;
+    let givers = database::get_action_leaderboard(
+        guild_id,
+        &action,
+        ActionRole::Performer,
+        LEADERBOARD_SIZE,
+    )?;
+    let pairs = database::get_mutual_action_pairs(guild_id, &action, LEADERBOARD_SIZE)?;
+
+    let ranking = |users: &[(UserId, u64)]| {
+        if users.is_empty() {
+            return "Nobody yet!".to_string();
         }
+
+        users
+            .iter()
+            .enumerate()
+            .map(|(i, (user_id, amount))| format!("{}. <@{user_id}>: **{amount}**", i + 1))
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+
+    let pairs = if pairs.is_empty() {
+        "Nobody yet!".to_string()
+    } else {
+        pairs
+            .iter()
+            .enumerate()
+            .map(|(i, pair)| {
+                format!(
+                    "{}. <@{}> ({}) and <@{}> ({})",
+                    i + 1,
+                    pair.first,
+                    pair.first_to_second,
+                    pair.second,
+                    pair.second_to_first
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+
+    ctx.send(
+        CreateReply::default().embed(
+            CreateEmbed::default()
+                .title(if guild_id.is_some() {
+                    format!("{action} leaderboard of this server")
+                } else {
+                    format!("Global {action} leaderboard")
+                })
+                .field(
+                    format!("Most {}", definition.past_tense),
+                    ranking(&receivers),
+                    false,
+                )
+                .field(
+                    definition.performers.as_ref().map_or_else(
+                        || format!("{} others the most", capitalize(&definition.past_tense)),
+                        |performers| format!("Top {performers}"),
+                    ),
+                    ranking(&givers),
+                    false,
+                )
+                .field("Mutual pairs", pairs, false)
+                .color(EMBED_COLOR),
+        ),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Stop people from performing an action, or every action, on you
+#[poise::command(slash_command)]
+pub async fn optout(
+    ctx: poise::Context<'_, (), Error>,
+    #[description = "The action to opt out of, or \"all\" for every action."]
+    #[autocomplete = "preference_autocomplete"]
+    action: String,
+) -> Result<(), Error> {
+    let action = action.trim().to_lowercase();
+    let mut preferences = get_preferences(ctx.author().id)?;
+
+    if action == "all" {
+        preferences.opted_out_all = true;
+    } else if get_action(&action).is_none() {
+        send_error(ctx, "Action not found.").await?;
+        return Ok(());
+    } else if !preferences.opted_out.contains(&action) {
+        preferences.opted_out.push(action.clone());
+    }
+
+    database::set_action_preferences(&preferences)?;
+
+    send_confirmation(
+        ctx,
+        &if action == "all" {
+            "Nobody can perform actions on you anymore.".to_string()
+        } else {
+            format!("Nobody can {action} you anymore.")
+        },
+    )
+    .await
+}
+
+/// Let people perform an action, or every action, on you again
+#[poise::command(slash_command)]
+pub async fn optin(
+    ctx: poise::Context<'_, (), Error>,
+    #[description = "The action to opt back into, or \"all\" for every action."]
+    #[autocomplete = "preference_autocomplete"]
+    action: String,
+) -> Result<(), Error> {
+    let action = action.trim().to_lowercase();
+    let mut preferences = get_preferences(ctx.author().id)?;
+
+    if action == "all" {
+        preferences.opted_out_all = false;
+        preferences.opted_out.clear();
+    } else {
+        preferences
+            .opted_out
+            .retain(|opted_out| *opted_out != action);
     }
 
-    if new_count == 1 {
-        // kinda dirty but it works
-        footer = footer.replace("times", "time");
+    database::set_action_preferences(&preferences)?;
+
+    send_confirmation(
+        ctx,
+        &if action == "all" {
+            "People can perform every action on you again.".to_string()
+        } else if preferences.opted_out_all {
+            format!("You opted back into {action}, but you're still opted out of every action. Use `/action optin all` to undo that.")
+        } else {
+            format!("People can {action} you again.")
+        },
+    )
+    .await
+}
+
+/// Stop someone from performing any action on you
+#[poise::command(slash_command)]
+pub async fn block(
+    ctx: poise::Context<'_, (), Error>,
+    #[description = "The user to block."] user: User,
+) -> Result<(), Error> {
+    if user.id == ctx.author().id {
+        send_error(ctx, "You can't block yourself.").await?;
+        return Ok(());
     }
 
-    let mut rng = <rand::rngs::SmallRng as rand::SeedableRng>::from_entropy();
-    let random_number: usize = rng.gen_range(1..gif_count);
+    let mut preferences = get_preferences(ctx.author().id)?;
+    if !preferences.blocked.contains(&user.id) {
+        preferences.blocked.push(user.id);
+    }
+
+    database::set_action_preferences(&preferences)?;
+
+    send_confirmation(
+        ctx,
+        &format!("{} can't perform actions on you anymore.", user.name),
+    )
+    .await
+}
+
+/// Let someone you blocked perform actions on you again
+#[poise::command(slash_command)]
+pub async fn unblock(
+    ctx: poise::Context<'_, (), Error>,
+    #[description = "The user to unblock."] user: User,
+) -> Result<(), Error> {
+    let mut preferences = get_preferences(ctx.author().id)?;
+    preferences.blocked.retain(|blocked| *blocked != user.id);
+
+    database::set_action_preferences(&preferences)?;
+
+    send_confirmation(
+        ctx,
+        &format!("{} can perform actions on you again.", user.name),
+    )
+    .await
+}
+
+/// Choose whether you get pinged when someone performs an action on you
+#[poise::command(slash_command)]
+pub async fn pings(
+    ctx: poise::Context<'_, (), Error>,
+    #[description = "Whether to ping you."] enabled: bool,
+) -> Result<(), Error> {
+    let mut preferences = get_preferences(ctx.author().id)?;
+    preferences.ping = enabled;
+
+    database::set_action_preferences(&preferences)?;
+
+    send_confirmation(
+        ctx,
+        if enabled {
+            "You'll be pinged when someone performs an action on you."
+        } else {
+            "You won't be pinged when someone performs an action on you anymore."
+        },
+    )
+    .await
+}
+
+/// See who and what you've allowed to perform actions on you
+#[poise::command(slash_command)]
+pub async fn preferences(ctx: poise::Context<'_, (), Error>) -> Result<(), Error> {
+    let preferences = get_preferences(ctx.author().id)?;
+
+    let opted_out = if preferences.opted_out_all {
+        "Every action".to_string()
+    } else if preferences.opted_out.is_empty() {
+        "None".to_string()
+    } else {
+        preferences.opted_out.join(", ")
+    };
+
+    let blocked = if preferences.blocked.is_empty() {
+        "Nobody".to_string()
+    } else {
+        preferences
+            .blocked
+            .iter()
+            .map(|user_id| format!("<@{user_id}>"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
 
     ctx.send(
         CreateReply::default()
-            .content(user.mention().to_string())
             .embed(
                 CreateEmbed::default()
-                    .title(title)
-                    .image(format!("{BASE_URL}/{action}/{random_number}.gif"))
-                    .footer(CreateEmbedFooter::new(footer))
+                    .title("Your action preferences")
+                    .field("Opted out of", opted_out, false)
+                    .field("Blocked users", blocked, false)
+                    .field("Pings", if preferences.ping { "On" } else { "Off" }, false)
                     .color(EMBED_COLOR),
-            ),
+            )
+            .ephemeral(true),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Gets a user's action preferences, or the default ones if they never set any.
+fn get_preferences(user_id: UserId) -> Result<ActionPreferences, Error> {
+    Ok(database::get_action_preferences(user_id)?
+        .unwrap_or_else(|| ActionPreferences::new(user_id)))
+}
+
+async fn send_error(ctx: poise::Context<'_, (), Error>, message: &str) -> Result<(), Error> {
+    ctx.send(
+        CreateReply::default()
+            .embed(
+                CreateEmbed::default()
+                    .title("Error")
+                    .description(format!("{ERROR_EMOJI} {message}"))
+                    .color(ERROR_EMBED_COLOR),
+            )
+            .ephemeral(true),
+    )
+    .await?;
+
+    Ok(())
+}
+
+async fn send_confirmation(ctx: poise::Context<'_, (), Error>, message: &str) -> Result<(), Error> {
+    ctx.send(
+        CreateReply::default()
+            .embed(
+                CreateEmbed::default()
+                    .description(format!("{CONFIRM_EMOJI} {message}"))
+                    .color(CONFIRM_EMBED_COLOR),
+            )
+            .ephemeral(true),
     )
     .await?;
 
     Ok(())
 }
 
+/// Gets the guild whose actions should be counted, or `None` to count everyone's.
+///
+/// Outside of servers there's only the global scope.
+fn scope_guild_id(ctx: poise::Context<'_, (), Error>, scope: Option<&str>) -> Option<u64> {
+    match scope {
+        Some("global") => None,
+        _ => ctx.guild_id().map(GuildId::get),
+    }
+}
+
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    chars.next().map_or_else(String::new, |first| {
+        first.to_uppercase().chain(chars).collect()
+    })
+}
+
+// This function needs to be async for poise to use it.
+#[allow(clippy::unused_async)]
+async fn scope_autocomplete(_: poise::Context<'_, (), Error>, _partial: &str) -> Vec<String> {
+    vec!["server".to_string(), "global".to_string()]
+}
+
+async fn preference_autocomplete(ctx: poise::Context<'_, (), Error>, partial: &str) -> Vec<String> {
+    let mut options = actions_autocomplete(ctx, partial).await;
+    if "all".starts_with(&partial.to_lowercase()) {
+        options.insert(0, "all".to_string());
+    }
+
+    options
+}
+
 // This function needs to be async for poise to use it.
 #[allow(clippy::unused_async)]
 async fn actions_autocomplete(_: poise::Context<'_, (), Error>, partial: &str) -> Vec<String> {
     let partial = partial.to_lowercase();
-    let options = ACTIONS
-        .iter()
-        .filter(move |action| action.starts_with(&partial))
-        .collect::<Vec<_>>();
 
-    options.iter().map(|&s| (*s).to_string()).collect()
+    ACTIONS
+        .read()
+        .expect("Actions lock was poisoned")
+        .keys()
+        .filter(|action| action.starts_with(&partial))
+        .cloned()
+        .collect()
 }
 
+/// Gets an action by name.
+#[must_use]
+pub fn get_action(name: &str) -> Option<Action> {
+    ACTIONS
+        .read()
+        .expect("Actions lock was poisoned")
+        .get(name)
+        .cloned()
+}
+
+/// Caches a few random GIFs of every action in the background, so there's
+/// something to fall back to if the GIF host goes down.
+///
+/// Does nothing if the media cache is disabled.
+pub fn prefetch_gifs() {
+    let actions = ACTIONS.read().expect("Actions lock was poisoned").clone();
+    let mut rng = <rand::rngs::SmallRng as rand::SeedableRng>::from_entropy();
+
+    for (name, action) in actions {
+        let directory = action.directory.unwrap_or_else(|| name.clone());
+        let urls = (0..PREFETCHED_GIFS)
+            .map(|_| rng.gen_range(1..action.count))
+            .collect::<std::collections::BTreeSet<_>>()
+            .into_iter()
+            .map(|number| format!("{BASE_URL}/{directory}/{number}.gif"))
+            .collect();
+
+        media_cache::prefetch(format!("action_{name}"), urls);
+    }
+}
+
+/// Fetches the actions from the `api.json` manifest and swaps them in.
+///
+/// Actions without enough GIFs to pick from are skipped.
+///
+/// Returns how many actions were loaded.
 #[allow(clippy::missing_errors_doc)]
-pub async fn initialize_action_count() -> Result<Counts, Error> {
-    Ok(reqwest::get(format!("{BASE_URL}/api.json"))
+pub async fn load_actions() -> Result<usize, Error> {
+    let manifest: HashMap<String, ManifestEntry> = reqwest::get(format!("{BASE_URL}/api.json"))
         .await?
         .json()
-        .await?)
-}
+        .await?;
+
+    let mut actions = Counts::new();
+    for (name, entry) in manifest {
+        let name = name.trim().to_lowercase();
+        let action = match entry {
+            ManifestEntry::Count(count) => Action::from_count(&name, count),
+            ManifestEntry::Action(action) => action,
+        };
+
+        // GIFs are picked from 1 up to, but not including, the count.
+        if action.count < 2 || action.verb.trim().is_empty() || action.past_tense.trim().is_empty()
+        {
+            warn!("Skipping invalid action in the manifest: {name}");
+            continue;
+        }
+
+        actions.insert(name, action);
+    }
+
+    if actions.is_empty() {
+        return Err("The action manifest has no valid actions".into());
+    }
+
+    let loaded = actions.len();
+    *ACTIONS
+        .write()
+        .map_err(|_| Error::from("Actions lock was poisoned"))? = actions;
+
+    info!("Loaded {loaded} actions");
 
-#[inline]
-fn get_counts() -> Counts {
-    unsafe { ACTION_COUNTS.expect("Unreachable") }
+    Ok(loaded)
 }
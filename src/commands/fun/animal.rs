@@ -1,284 +1,558 @@
-use std::vec;
+use std::time::Duration;
 
-use crate::Error;
+use crate::{media_cache, Error};
 
 use super::super::{API_THANKS_EMOJI, EMBED_COLOR, ERROR_EMBED_COLOR, ERROR_EMOJI};
+use log::warn;
+use once_cell::sync::Lazy;
 use poise::serenity_prelude::{CreateEmbed, CreateEmbedFooter};
-use poise::CreateReply;
+use poise::{BoxFuture, CreateReply};
+
+/// How long a provider gets to answer before the next one is tried.
+const PROVIDER_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// The extensions an image URL can have, since Discord embeds can't show
+/// videos.
+const IMAGE_EXTENSIONS: [&str; 5] = ["gif", "png", "jpg", "jpeg", "webp"];
+
+static CLIENT: Lazy<reqwest::Client> = Lazy::new(reqwest::Client::new);
+
+/// Every animal that can be asked for, with its providers in the order
+/// they're tried.
+static ANIMALS: Lazy<Vec<Animal>> = Lazy::new(|| {
+    vec![
+        Animal::new("cat")
+            .provider(JsonProvider::new(
+                "thecatapi.com",
+                "https://api.thecatapi.com/v1/images/search",
+                "/0/url",
+            ))
+            .provider(JsonProvider::new(
+                "some-random-api.com",
+                "https://some-random-api.com/animal/cat",
+                "/image",
+            )),
+        Animal::new("dog")
+            .provider(JsonProvider::new(
+                "random.dog",
+                "https://random.dog/woof.json",
+                "/url",
+            ))
+            .provider(JsonProvider::new(
+                "dog.ceo",
+                "https://dog.ceo/api/breeds/image/random",
+                "/message",
+            )),
+        Animal::new("shiba")
+            .provider(JsonProvider::new(
+                "Shibe.online",
+                "https://shibe.online/api/shibes",
+                "/0",
+            ))
+            .provider(JsonProvider::new(
+                "dog.ceo",
+                "https://dog.ceo/api/breed/shiba/images/random",
+                "/message",
+            )),
+        Animal::new("fox")
+            .provider(JsonProvider::new(
+                "randomfox.ca",
+                "https://randomfox.ca/floof",
+                "/image",
+            ))
+            .provider(JsonProvider::new(
+                "some-random-api.com",
+                "https://some-random-api.com/animal/fox",
+                "/image",
+            )),
+        Animal::new("duck").provider(JsonProvider::new(
+            "random-d.uk",
+            "https://random-d.uk/api/v2/random",
+            "/url",
+        )),
+        Animal::new("bunny").provider(JsonProvider::new(
+            "bunnies.io",
+            "https://api.bunnies.io/v2/loop/random/?media=gif",
+            "/media/gif",
+        )),
+        Animal::new("panda").provider(JsonProvider::new(
+            "some-random-api.com",
+            "https://some-random-api.com/animal/panda",
+            "/image",
+        )),
+        Animal::new("bird").provider(JsonProvider::new(
+            "some-random-api.com",
+            "https://some-random-api.com/animal/bird",
+            "/image",
+        )),
+    ]
+});
+
+/// Somewhere random images of an animal can be gotten from.
+pub trait AnimalProvider: Send + Sync {
+    /// The name shown in the footer to thank the provider.
+    fn credit(&self) -> &str;
+
+    /// Gets the URL of a random image.
+    fn fetch<'a>(&'a self, client: &'a reqwest::Client) -> BoxFuture<'a, Result<String, Error>>;
+}
 
-/// Get an image of a random animal
-#[poise::command(slash_command, broadcast_typing)]
-pub async fn animal(
-    ctx: poise::Context<'_, (), Error>,
-    #[description = "The name of the animal."]
-    #[autocomplete = "animal_autocomplete"]
-    animal: String,
-) -> Result<(), Error> {
-    ctx.defer().await?;
+/// A provider whose API answers with JSON that has the image URL in it.
+pub struct JsonProvider {
+    credit: String,
+    url: String,
+    /// Where the image URL is in the response, as a JSON pointer.
+    pointer: String,
+}
 
-    match animal.as_str() {
-        "cat" => send_cat(ctx).await?,
-        "dog" => send_dog(ctx).await?,
-        "shiba" => send_shiba(ctx).await?,
-        "fox" => send_fox(ctx).await?,
-        "duck" => send_duck(ctx).await?,
-        _ => {
-            ctx.send(
-                CreateReply::default().embed(
-                    CreateEmbed::default()
-                        .title("Error")
-                        .description(format!("{ERROR_EMOJI} Animal not found.",))
-                        .color(ERROR_EMBED_COLOR),
-                ),
-            )
-            .await?;
+impl JsonProvider {
+    #[must_use]
+    pub fn new(credit: &str, url: &str, pointer: &str) -> Self {
+        Self {
+            credit: credit.to_string(),
+            url: url.to_string(),
+            pointer: pointer.to_string(),
         }
     }
+}
 
-    Ok(())
+impl AnimalProvider for JsonProvider {
+    fn credit(&self) -> &str {
+        &self.credit
+    }
+
+    fn fetch<'a>(&'a self, client: &'a reqwest::Client) -> BoxFuture<'a, Result<String, Error>> {
+        Box::pin(async move {
+            let response: serde_json::Value = client
+                .get(&self.url)
+                .send()
+                .await?
+                .error_for_status()?
+                .json()
+                .await?;
+
+            response
+                .pointer(&self.pointer)
+                .and_then(serde_json::Value::as_str)
+                .map(ToString::to_string)
+                .ok_or_else(|| format!("{} didn't send an image URL", self.credit).into())
+        })
+    }
 }
 
-#[derive(serde::Deserialize)]
-struct Shiba {
-    #[serde(rename = "0")]
-    url: String,
+pub struct Animal {
+    pub name: String,
+    pub providers: Vec<Box<dyn AnimalProvider>>,
 }
 
-async fn send_shiba(ctx: poise::Context<'_, (), Error>) -> Result<(), Error> {
-    let response = match reqwest::get("https://shibe.online/api/shibes").await {
-        Ok(response) => response,
-        Err(error) => {
-            ctx.send(
-                CreateReply::default().embed(
-                    CreateEmbed::default()
-                        .title("Error")
-                        .footer(CreateEmbedFooter::new(
-                            "Please contact our developers about this in the support server!",
-                        ))
-                        .description(format!("{ERROR_EMOJI} {error}"))
-                        .color(ERROR_EMBED_COLOR),
-                ),
-            )
-            .await?;
-            return Err(error.into());
+impl Animal {
+    #[must_use]
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            providers: Vec::new(),
         }
-    };
+    }
 
-    let response: Shiba = response.json().await?;
+    /// Adds a provider to try after the ones that were already added.
+    #[must_use]
+    pub fn provider(mut self, provider: impl AnimalProvider + 'static) -> Self {
+        self.providers.push(Box::new(provider));
+        self
+    }
 
-    ctx.send(
-        CreateReply::default().embed(
-            CreateEmbed::default()
-                .title("Have a look at this shiba!")
-                .image(response.url)
-                .color(EMBED_COLOR)
-                .footer(
-                    CreateEmbedFooter::new("Powered by Shibe.online").icon_url(API_THANKS_EMOJI),
+    /// Tries every provider in order until one gives a valid image URL.
+    ///
+    /// Returns the URL and who to credit for it.
+    ///
+    /// # Errors
+    ///
+    /// Fails if every provider failed, timed out or gave an invalid URL.
+    pub async fn fetch(
+        &self,
+        client: &reqwest::Client,
+        timeout: Duration,
+    ) -> Result<(String, &str), Error> {
+        for provider in &self.providers {
+            let result = match tokio::time::timeout(timeout, provider.fetch(client)).await {
+                Ok(Ok(url)) => validate(&url).map(|()| url),
+                Ok(Err(e)) => Err(e),
+                Err(_) => Err(format!("{} timed out", provider.credit()).into()),
+            };
+
+            match result {
+                Ok(url) => return Ok((url, provider.credit())),
+                Err(e) => warn!(
+                    "Failed to get a {} from {}: {e}",
+                    self.name,
+                    provider.credit()
                 ),
-        ),
-    )
-    .await?;
+            }
+        }
 
-    Ok(())
+        Err(format!("Couldn't get a {} from any of our sources.", self.name).into())
+    }
 }
 
-#[derive(serde::Deserialize)]
-struct DogResponse {
-    #[serde(rename = "fileSizeBytes")]
-    #[allow(unused)]
-    file_size_bytes: u64,
-    url: String,
-}
+/// Makes sure a URL is one an embed can show as an image.
+fn validate(url: &str) -> Result<(), Error> {
+    let parsed = reqwest::Url::parse(url).map_err(|e| format!("Invalid image URL {url}: {e}"))?;
 
-async fn send_dog(ctx: poise::Context<'_, (), Error>) -> Result<(), Error> {
-    let response = match reqwest::get("https://random.dog/woof.json").await {
-        Ok(response) => response,
-        Err(error) => {
-            ctx.send(
-                CreateReply::default().embed(
-                    CreateEmbed::default()
-                        .title("Error")
-                        .footer(CreateEmbedFooter::new(
-                            "Please contact our developers about this in the support server!",
-                        ))
-                        .description(format!("{ERROR_EMOJI} {error}"))
-                        .color(ERROR_EMBED_COLOR),
-                ),
-            )
-            .await?;
-            return Err(error.into());
-        }
-    };
+    if !matches!(parsed.scheme(), "http" | "https") {
+        return Err(format!("{url} isn't an HTTP URL").into());
+    }
 
-    let response: DogResponse = response.json().await?;
+    // URLs without an extension are let through, since some APIs serve images
+    // that way.
+    let extension = parsed
+        .path_segments()
+        .and_then(|mut segments| segments.next_back())
+        .and_then(|file_name| file_name.rsplit_once('.'))
+        .map(|(_, extension)| extension.to_lowercase());
+
+    match extension {
+        Some(extension) if !IMAGE_EXTENSIONS.contains(&extension.as_str()) => {
+            Err(format!("{url} isn't an image").into())
+        }
+        _ => Ok(()),
+    }
+}
 
-    ctx.send(
-        CreateReply::default().embed(
-            CreateEmbed::default()
-                .title("Have a look at this dog!")
-                .image(response.url)
-                .color(EMBED_COLOR)
-                .footer(CreateEmbedFooter::new("Powered by random.dog").icon_url(API_THANKS_EMOJI)),
-        ),
-    )
-    .await?;
+/// Get an image of a random animal
+#[poise::command(slash_command, broadcast_typing)]
+pub async fn animal(
+    ctx: poise::Context<'_, (), Error>,
+    #[description = "The name of the animal."]
+    #[autocomplete = "animal_autocomplete"]
+    animal: String,
+) -> Result<(), Error> {
+    ctx.defer().await?;
 
-    Ok(())
-}
+    let animal = animal.to_lowercase();
+    let Some(animal) = ANIMALS.iter().find(|a| a.name == animal) else {
+        ctx.send(
+            CreateReply::default().embed(
+                CreateEmbed::default()
+                    .title("Error")
+                    .description(format!("{ERROR_EMOJI} Animal not found.",))
+                    .color(ERROR_EMBED_COLOR),
+            ),
+        )
+        .await?;
+        return Ok(());
+    };
 
-#[derive(serde::Deserialize)]
-struct CatResponse {
-    #[serde(rename = "0")]
-    inner: CatInner,
+    send_animal(ctx, animal, animal.fetch(&CLIENT, PROVIDER_TIMEOUT).await).await
 }
 
-#[derive(serde::Deserialize)]
-#[allow(unused)]
-struct CatInner {
-    id: String,
-    url: String,
-    width: i32,
-    height: i32,
-}
+/// Sends the image a provider gave for an animal.
+///
+/// If every provider failed, a cached image of the same animal is sent instead
+/// when there is one.
+async fn send_animal(
+    ctx: poise::Context<'_, (), Error>,
+    animal: &Animal,
+    result: Result<(String, &str), Error>,
+) -> Result<(), Error> {
+    let namespace = format!("animal_{}", animal.name);
 
-async fn send_cat(ctx: poise::Context<'_, (), Error>) -> Result<(), Error> {
-    let response = match reqwest::get("https://api.thecatapi.com/v1/images/search").await {
-        Ok(response) => response,
+    let (media, url, footer) = match result {
+        Ok((url, credit)) => (
+            media_cache::fetch_or_fallback(&namespace, &url).await,
+            Some(url),
+            format!("Powered by {credit}"),
+        ),
         Err(error) => {
-            ctx.send(
-                CreateReply::default().embed(
-                    CreateEmbed::default()
-                        .title("Error")
-                        .footer(CreateEmbedFooter::new(
-                            "Please contact our developers about this in the support server!",
-                        ))
-                        .description(format!("{ERROR_EMOJI} {error}"))
-                        .color(ERROR_EMBED_COLOR),
-                ),
+            let Some(media) = media_cache::random(&namespace).await else {
+                ctx.send(
+                    CreateReply::default().embed(
+                        CreateEmbed::default()
+                            .title("Error")
+                            .footer(CreateEmbedFooter::new(
+                                "Please contact our developers about this in the support server!",
+                            ))
+                            .description(format!("{ERROR_EMOJI} {error}"))
+                            .color(ERROR_EMBED_COLOR),
+                    ),
+                )
+                .await?;
+                return Err(error);
+            };
+
+            (
+                Some(media),
+                None,
+                "Our sources are unavailable, so this one is from our cache".to_string(),
             )
-            .await?;
-            return Err(error.into());
         }
     };
 
-    let response: CatResponse = response.json().await?;
+    let mut reply = CreateReply::default();
+    let mut embed = CreateEmbed::default()
+        .title(format!("Have a look at this {}!", animal.name))
+        .color(EMBED_COLOR)
+        .footer(CreateEmbedFooter::new(footer).icon_url(API_THANKS_EMOJI));
+
+    if let Some(media) = media {
+        embed = embed.image(media.attachment_url());
+        reply = reply.attachment(media.attachment());
+    } else if let Some(url) = url {
+        embed = embed.image(url);
+    }
 
-    ctx.send(
-        CreateReply::default().embed(
-            CreateEmbed::default()
-                .title("Have a look at this cat!")
-                .image(response.inner.url)
-                .color(EMBED_COLOR)
-                .footer(
-                    CreateEmbedFooter::new("Powered by thecatapi.com").icon_url(API_THANKS_EMOJI),
-                ),
-        ),
-    )
-    .await?;
+    ctx.send(reply.embed(embed)).await?;
 
     Ok(())
 }
 
-#[derive(serde::Deserialize)]
-struct FoxResponse {
-    image: String,
+// This function needs to be async for poise to use it.
+#[allow(clippy::unused_async)]
+async fn animal_autocomplete(_: poise::Context<'_, (), Error>, partial: &str) -> Vec<String> {
+    let partial = partial.to_lowercase();
+
+    ANIMALS
+        .iter()
+        .filter(|animal| animal.name.starts_with(&partial))
+        .map(|animal| animal.name.clone())
+        .collect()
 }
 
-async fn send_fox(ctx: poise::Context<'_, (), Error>) -> Result<(), Error> {
-    let response = match reqwest::get("https://randomfox.ca/floof").await {
-        Ok(response) => response,
-        Err(error) => {
-            ctx.send(
-                CreateReply::default().embed(
-                    CreateEmbed::default()
-                        .title("Error")
-                        .footer(CreateEmbedFooter::new(
-                            "Please contact our developers about this in the support server!",
-                        ))
-                        .description(format!("{ERROR_EMOJI} {error}"))
-                        .color(ERROR_EMBED_COLOR),
-                ),
-            )
-            .await?;
-            return Err(error.into());
-        }
-    };
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    /// What the mock server answers with for a path.
+    #[derive(Clone)]
+    enum Route {
+        Json(&'static str),
+        Status(u16),
+        /// Waits this long before answering with the JSON.
+        Slow(Duration, &'static str),
+    }
 
-    let response: FoxResponse = response.json().await?;
+    /// Starts an HTTP server on a random local port that answers every path
+    /// in `routes` and 404s everything else. Returns its base URL.
+    async fn mock_server(routes: Vec<(&'static str, Route)>) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+
+        tokio::spawn(async move {
+            loop {
+                let Ok((mut stream, _)) = listener.accept().await else {
+                    return;
+                };
+                let routes = routes.clone();
+
+                tokio::spawn(async move {
+                    let mut buffer = vec![0; 4096];
+                    let read = stream.read(&mut buffer).await.unwrap_or(0);
+                    let request = String::from_utf8_lossy(&buffer[..read]);
+                    let path = request.split_whitespace().nth(1).unwrap_or("/");
+
+                    let route = routes
+                        .iter()
+                        .find(|(route_path, _)| *route_path == path)
+                        .map_or(Route::Status(404), |(_, route)| route.clone());
+
+                    let (status, body) = match route {
+                        Route::Json(body) => (200, body),
+                        Route::Status(status) => (status, ""),
+                        Route::Slow(delay, body) => {
+                            tokio::time::sleep(delay).await;
+                            (200, body)
+                        }
+                    };
+
+                    let response = format!(
+                        "HTTP/1.1 {status} Mock\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                        body.len()
+                    );
+                    let _ = stream.write_all(response.as_bytes()).await;
+                });
+            }
+        });
+
+        format!("http://{address}")
+    }
 
-    ctx.send(
-        CreateReply::default().embed(
-            CreateEmbed::default()
-                .title("Have a look at this fox!")
-                .image(response.image)
-                .color(EMBED_COLOR)
-                .footer(
-                    CreateEmbedFooter::new("Powered by randomfox.ca").icon_url(API_THANKS_EMOJI),
-                ),
-        ),
-    )
-    .await?;
+    const TIMEOUT: Duration = Duration::from_millis(500);
+
+    #[tokio::test]
+    async fn reads_the_url_from_the_pointer() {
+        let base = mock_server(vec![(
+            "/cats",
+            Route::Json(r#"[{"id":"a","url":"https://example.com/cat.jpg"}]"#),
+        )])
+        .await;
+
+        let animal = Animal::new("cat").provider(JsonProvider::new(
+            "cats",
+            &format!("{base}/cats"),
+            "/0/url",
+        ));
+
+        let (url, credit) = animal
+            .fetch(&reqwest::Client::new(), TIMEOUT)
+            .await
+            .unwrap();
+        assert_eq!(url, "https://example.com/cat.jpg");
+        assert_eq!(credit, "cats");
+    }
 
-    Ok(())
-}
+    #[tokio::test]
+    async fn tries_providers_in_order() {
+        let base = mock_server(vec![
+            (
+                "/first",
+                Route::Json(r#"{"image":"https://example.com/first.png"}"#),
+            ),
+            (
+                "/second",
+                Route::Json(r#"{"image":"https://example.com/second.png"}"#),
+            ),
+        ])
+        .await;
+
+        let animal = Animal::new("fox")
+            .provider(JsonProvider::new(
+                "first",
+                &format!("{base}/first"),
+                "/image",
+            ))
+            .provider(JsonProvider::new(
+                "second",
+                &format!("{base}/second"),
+                "/image",
+            ));
+
+        let (url, credit) = animal
+            .fetch(&reqwest::Client::new(), TIMEOUT)
+            .await
+            .unwrap();
+        assert_eq!(url, "https://example.com/first.png");
+        assert_eq!(credit, "first");
+    }
 
-#[derive(serde::Deserialize)]
-struct DuckResponse {
-    url: String,
-}
+    #[tokio::test]
+    async fn falls_back_on_errors() {
+        let base = mock_server(vec![
+            ("/down", Route::Status(500)),
+            ("/malformed", Route::Json("not json")),
+            ("/missing", Route::Json(r#"{"something":"else"}"#)),
+            (
+                "/up",
+                Route::Json(r#"{"url":"https://example.com/duck.gif"}"#),
+            ),
+        ])
+        .await;
+
+        let animal = Animal::new("duck")
+            .provider(JsonProvider::new("down", &format!("{base}/down"), "/url"))
+            .provider(JsonProvider::new(
+                "malformed",
+                &format!("{base}/malformed"),
+                "/url",
+            ))
+            .provider(JsonProvider::new(
+                "missing",
+                &format!("{base}/missing"),
+                "/url",
+            ))
+            .provider(JsonProvider::new("up", &format!("{base}/up"), "/url"));
+
+        let (url, credit) = animal
+            .fetch(&reqwest::Client::new(), TIMEOUT)
+            .await
+            .unwrap();
+        assert_eq!(url, "https://example.com/duck.gif");
+        assert_eq!(credit, "up");
+    }
 
-async fn send_duck(ctx: poise::Context<'_, (), Error>) -> Result<(), Error> {
-    let response = match reqwest::get("https://random-d.uk/api/v2/random").await {
-        Ok(response) => response,
-        Err(error) => {
-            ctx.send(
-                CreateReply::default().embed(
-                    CreateEmbed::default()
-                        .title("Error")
-                        .footer(CreateEmbedFooter::new(
-                            "Please contact our developers about this in the support server!",
-                        ))
-                        .description(format!("{ERROR_EMOJI} {error}"))
-                        .color(ERROR_EMBED_COLOR),
+    #[tokio::test]
+    async fn falls_back_on_timeouts() {
+        let base = mock_server(vec![
+            (
+                "/slow",
+                Route::Slow(
+                    Duration::from_secs(5),
+                    r#"{"url":"https://example.com/slow.png"}"#,
                 ),
-            )
-            .await?;
-            return Err(error.into());
-        }
-    };
+            ),
+            (
+                "/fast",
+                Route::Json(r#"{"url":"https://example.com/fast.png"}"#),
+            ),
+        ])
+        .await;
+
+        let animal = Animal::new("dog")
+            .provider(JsonProvider::new("slow", &format!("{base}/slow"), "/url"))
+            .provider(JsonProvider::new("fast", &format!("{base}/fast"), "/url"));
+
+        let (url, _) = animal
+            .fetch(&reqwest::Client::new(), Duration::from_millis(200))
+            .await
+            .unwrap();
+        assert_eq!(url, "https://example.com/fast.png");
+    }
 
-    let response: DuckResponse = response.json().await?;
-    ctx.send(
-        CreateReply::default().embed(
-            CreateEmbed::default()
-                .title("Have a look at this duck!")
-                .image(response.url)
-                .color(EMBED_COLOR)
-                .footer(
-                    CreateEmbedFooter::new("Powered by random-d.uk").icon_url(API_THANKS_EMOJI),
-                ),
-        ),
-    )
-    .await?;
+    #[tokio::test]
+    async fn skips_invalid_urls() {
+        let base = mock_server(vec![
+            (
+                "/video",
+                Route::Json(r#"{"url":"https://example.com/dog.mp4"}"#),
+            ),
+            ("/relative", Route::Json(r#"{"url":"/dog.png"}"#)),
+            (
+                "/ftp",
+                Route::Json(r#"{"url":"ftp://example.com/dog.png"}"#),
+            ),
+            (
+                "/image",
+                Route::Json(r#"{"url":"https://example.com/dog.JPG?size=big"}"#),
+            ),
+        ])
+        .await;
+
+        let animal = Animal::new("dog")
+            .provider(JsonProvider::new("video", &format!("{base}/video"), "/url"))
+            .provider(JsonProvider::new(
+                "relative",
+                &format!("{base}/relative"),
+                "/url",
+            ))
+            .provider(JsonProvider::new("ftp", &format!("{base}/ftp"), "/url"))
+            .provider(JsonProvider::new("image", &format!("{base}/image"), "/url"));
+
+        let (url, credit) = animal
+            .fetch(&reqwest::Client::new(), TIMEOUT)
+            .await
+            .unwrap();
+        assert_eq!(url, "https://example.com/dog.JPG?size=big");
+        assert_eq!(credit, "image");
+    }
 
-    Ok(())
-}
+    #[tokio::test]
+    async fn fails_when_every_provider_fails() {
+        let base = mock_server(vec![("/down", Route::Status(503))]).await;
 
-// This function needs to be async for poise to use it.
-#[allow(clippy::unused_async)]
-async fn animal_autocomplete(_: poise::Context<'_, (), Error>, partial: &str) -> Vec<String> {
-    let options = [
-        "cat".to_string(),
-        "dog".to_string(),
-        "shiba".to_string(),
-        "fox".to_string(),
-        "duck".to_string(),
-    ];
-    let partial = partial.to_lowercase();
-    let options = options
-        .iter()
-        .filter(move |action| action.starts_with(&partial))
-        .collect::<Vec<_>>();
+        let animal = Animal::new("panda")
+            .provider(JsonProvider::new("down", &format!("{base}/down"), "/image"))
+            .provider(JsonProvider::new("gone", &format!("{base}/gone"), "/image"));
 
-    options.iter().map(|&s| (*s).to_string()).collect()
+        assert!(animal
+            .fetch(&reqwest::Client::new(), TIMEOUT)
+            .await
+            .is_err());
+    }
+
+    #[test]
+    fn every_animal_has_a_provider() {
+        assert!(ANIMALS.iter().all(|animal| !animal.providers.is_empty()));
+    }
 }
@@ -1,42 +1,141 @@
 use std::vec;
 
-use crate::Error;
+use crate::{
+    commands::economy::{format_amount, Transaction, TransactionKind},
+    database, Error,
+};
 
-use super::super::EMBED_COLOR;
+use super::super::{EMBED_COLOR, ERROR_EMBED_COLOR, ERROR_EMOJI};
+use log::error;
 use poise::serenity_prelude::CreateEmbed;
 use poise::CreateReply;
 use rand::Rng;
 
 /// Flip a coin!
 #[poise::command(slash_command, broadcast_typing)]
-pub async fn coinflip(ctx: poise::Context<'_, (), Error>) -> Result<(), Error> {
+pub async fn coinflip(
+    ctx: poise::Context<'_, (), Error>,
+    #[description = "Bet some of your bones on the result."]
+    #[min = 1]
+    wager: Option<u64>,
+    #[description = "The side you're betting on. Defaults to heads."]
+    #[autocomplete = "side_autocomplete"]
+    call: Option<String>,
+) -> Result<(), Error> {
     ctx.defer().await?;
 
+    let called = match call.as_deref().map(str::trim) {
+        None => "heads",
+        Some(call) if call.eq_ignore_ascii_case("heads") => "heads",
+        Some(call) if call.eq_ignore_ascii_case("tails") => "tails",
+        Some(_) => {
+            ctx.send(
+                CreateReply::default().embed(
+                    CreateEmbed::new()
+                        .title("Error")
+                        .description(format!(
+                            "{ERROR_EMOJI} You can only bet on `heads` or `tails`."
+                        ))
+                        .color(ERROR_EMBED_COLOR),
+                ),
+            )
+            .await?;
+            return Ok(());
+        }
+    };
+
     let mut rng = <rand::rngs::SmallRng as rand::SeedableRng>::from_entropy();
     let random_number: f64 = rng.gen();
 
     let img_url;
+    let side;
     let ans = if random_number < 0.48 {
         img_url = "https://i.imgur.com/BpuCUPH.png";
+        side = "heads";
         "landed on heads"
     } else if random_number < 0.96 {
         img_url = "https://i.imgur.com/tpnNRIR.png";
+        side = "tails";
         "landed on tails"
     } else {
         img_url = "https://i.imgur.com/RKcIpar.png";
+        side = "side";
         "landed on the side"
     };
 
-    ctx.send(
-        CreateReply::default().embed(
-            CreateEmbed::default()
-                .title("Coinflip")
-                .field(format!("The coin {ans}!"), "", false)
-                .color(EMBED_COLOR)
-                .thumbnail(img_url),
-        ),
-    )
-    .await?;
+    // The wager is taken before paying out, so the same bones can't be bet on
+    // several flips at once.
+    if let Some(wager) = wager {
+        let stake = Transaction::new(Some(ctx.author().id), None, wager, TransactionKind::Wager);
+
+        if !database::apply_transaction(&stake)? {
+            ctx.send(
+                CreateReply::default().embed(
+                    CreateEmbed::new()
+                        .title("Error")
+                        .description(format!(
+                            "{ERROR_EMOJI} You can't bet more bones than you have, you only have {}.",
+                            format_amount(database::get_balance(ctx.author().id)?)
+                        ))
+                        .color(ERROR_EMBED_COLOR),
+                ),
+            )
+            .await?;
+            return Ok(());
+        }
+    }
+
+    let mut embed = CreateEmbed::default()
+        .title("Coinflip")
+        .field(format!("The coin {ans}!"), "", false)
+        .color(EMBED_COLOR)
+        .thumbnail(img_url);
+
+    if let Some(wager) = wager {
+        // Winning pays back the wager plus as much again.
+        let result = if side == called {
+            let winnings = Transaction::new(
+                None,
+                Some(ctx.author().id),
+                wager.saturating_mul(2),
+                TransactionKind::Winnings,
+            );
+            if let Err(e) = database::apply_transaction(&winnings) {
+                // The stake was already taken, so it's given back rather than
+                // lost along with the winnings.
+                let refund =
+                    Transaction::new(None, Some(ctx.author().id), wager, TransactionKind::Refund);
+                if let Err(refund_error) = database::apply_transaction(&refund) {
+                    error!(
+                        "Failed to refund a wager of {wager} to {}: {refund_error}",
+                        ctx.author().id
+                    );
+                }
+                return Err(e);
+            }
+
+            format!("You bet on {called} and won {}!", format_amount(wager))
+        } else {
+            format!("You bet on {called} and lost {}.", format_amount(wager))
+        };
+
+        embed = embed.field(
+            "Wager",
+            format!(
+                "{result}\nYou now have {}.",
+                format_amount(database::get_balance(ctx.author().id)?)
+            ),
+            false,
+        );
+    }
+
+    ctx.send(CreateReply::default().embed(embed)).await?;
 
     Ok(())
 }
+
+// This function needs to be async for poise to use it.
+#[allow(clippy::unused_async)]
+async fn side_autocomplete(_: poise::Context<'_, (), Error>, _partial: &str) -> Vec<String> {
+    vec!["heads".to_string(), "tails".to_string()]
+}
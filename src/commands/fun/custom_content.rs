@@ -0,0 +1,377 @@
+use crate::commands::{
+    CONFIRM_EMBED_COLOR, CONFIRM_EMOJI, EMBED_COLOR, ERROR_EMBED_COLOR, ERROR_EMOJI,
+};
+use crate::content::{self, Pool};
+use crate::{database, Error};
+
+use log::error;
+use poise::serenity_prelude::{CreateEmbed, CreateEmbedFooter, Permissions, UserId};
+use poise::CreateReply;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// The percentage of daily posts taken from a guild's custom pool when the
+/// guild hasn't configured one.
+pub const DEFAULT_RATIO: u8 = 50;
+
+/// Daily posts are sent as embed fields, which can't be longer than this.
+const MAX_CONTENT_LENGTH: usize = 1024;
+
+/// The most pending submissions a guild can have queued up at once.
+const MAX_PENDING: usize = 25;
+
+/// How many pending submissions `pending` lists, so their previews fit in an
+/// embed description.
+const MAX_LISTED_PENDING: usize = 20;
+
+/// Which daily pool a piece of custom content belongs to.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub enum ContentKind {
+    #[default]
+    Qotd,
+    Fotd,
+}
+
+impl ContentKind {
+    /// The identifier used to store this kind in the database.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Qotd => "qotd",
+            Self::Fotd => "fotd",
+        }
+    }
+
+    #[must_use]
+    pub fn from_db(kind: &str) -> Self {
+        match kind {
+            "fotd" => Self::Fotd,
+            _ => Self::Qotd,
+        }
+    }
+
+    const fn pool(self) -> Pool {
+        match self {
+            Self::Qotd => Pool::Questions,
+            Self::Fotd => Pool::Facts,
+        }
+    }
+
+    const fn noun(self) -> &'static str {
+        match self {
+            Self::Qotd => "question",
+            Self::Fotd => "fact",
+        }
+    }
+}
+
+/// A question or fact submitted by a guild member.
+#[derive(Debug, Clone, Eq, PartialEq, Default)]
+pub struct CustomContent {
+    pub(crate) id: u64,
+    pub(crate) guild_id: u64,
+    pub(crate) kind: ContentKind,
+    pub(crate) content: String,
+    pub(crate) author_id: UserId,
+    pub(crate) approved: bool,
+}
+
+impl CustomContent {
+    #[must_use]
+    pub fn new_random(
+        kind: ContentKind,
+        guild_id: u64,
+        content: String,
+        author_id: UserId,
+    ) -> Self {
+        let mut rng = rand::thread_rng();
+        Self {
+            id: rng.gen::<u64>(),
+            guild_id,
+            kind,
+            content,
+            author_id,
+            approved: false,
+        }
+    }
+}
+
+/// Picks the next daily post for a guild, mixing the guild's approved custom
+/// content with the built-in pool according to its configured ratio.
+///
+/// Falls back to the built-in pool if there's no guild or the database can't
+/// be reached.
+#[must_use]
+pub fn pick(kind: ContentKind, guild_id: Option<u64>) -> String {
+    let mut rng = rand::thread_rng();
+
+    if let Some(guild_id) = guild_id {
+        let ratio = database::get_custom_content_ratio(guild_id, kind)
+            .map_err(|e| error!("Failed to get custom content ratio: {e}"))
+            .ok()
+            .flatten()
+            .unwrap_or(DEFAULT_RATIO);
+
+        if rng.gen_range(0..100) < ratio {
+            let custom = database::get_custom_content(guild_id, kind, true)
+                .map_err(|e| error!("Failed to get custom content: {e}"))
+                .unwrap_or_default();
+
+            if let Some(content) = custom.choose(&mut rng) {
+                return content.content.clone();
+            }
+        }
+    }
+
+    content::random(kind.pool())
+}
+
+/// Handles the options of `/qotd` and `/fotd` that deal with custom content.
+///
+/// Returns `Ok(false)` if `option` isn't one of them, so the caller can keep
+/// handling it.
+#[allow(clippy::missing_errors_doc)]
+pub async fn handle_option(
+    ctx: poise::Context<'_, (), Error>,
+    kind: ContentKind,
+    option: &str,
+    text: Option<String>,
+    submission: Option<String>,
+    ratio: Option<u8>,
+) -> Result<bool, Error> {
+    if !["submit", "pending", "approve", "reject", "ratio"].contains(&option) {
+        return Ok(false);
+    }
+
+    let Some(guild_id) = ctx.guild_id() else {
+        send_error(ctx, "This option can only be used in a server.").await?;
+        return Ok(true);
+    };
+    let guild_id = guild_id.get();
+    let noun = kind.noun();
+
+    if option != "submit" && !has_manage_guild(ctx).await {
+        send_error(
+            ctx,
+            "You don't have permission to use this command.\nPermission needed: `MANAGE_GUILD`.",
+        )
+        .await?;
+        return Ok(true);
+    }
+
+    match option {
+        "submit" => {
+            let Some(text) = text.map(|t| t.trim().to_string()).filter(|t| !t.is_empty()) else {
+                send_error(
+                    ctx,
+                    &format!("Please provide the {noun} you want to submit."),
+                )
+                .await?;
+                return Ok(true);
+            };
+
+            if text.chars().count() > MAX_CONTENT_LENGTH {
+                send_error(
+                    ctx,
+                    &format!("Your {noun} can't be longer than {MAX_CONTENT_LENGTH} characters."),
+                )
+                .await?;
+                return Ok(true);
+            }
+
+            let pending = database::get_custom_content(guild_id, kind, false)?;
+            if pending.len() >= MAX_PENDING {
+                send_error(
+                    ctx,
+                    "There are too many submissions waiting for approval, please try again later.",
+                )
+                .await?;
+                return Ok(true);
+            }
+
+            let content = CustomContent::new_random(kind, guild_id, text, ctx.author().id);
+            database::add_custom_content(&content)?;
+
+            ctx.send(
+                CreateReply::default()
+                    .embed(
+                        CreateEmbed::default()
+                            .title("Submission received")
+                            .description(format!(
+                                "{CONFIRM_EMOJI} Your {noun} was sent to the server's staff for approval."
+                            ))
+                            .footer(CreateEmbedFooter::new(format!("Submission ID: {}", content.id)))
+                            .color(CONFIRM_EMBED_COLOR),
+                    )
+                    .ephemeral(true),
+            )
+            .await?;
+        }
+
+        "pending" => {
+            let pending = database::get_custom_content(guild_id, kind, false)?;
+
+            let description = if pending.is_empty() {
+                format!("There are no {noun}s waiting for approval.")
+            } else {
+                let mut entries = pending
+                    .iter()
+                    .take(MAX_LISTED_PENDING)
+                    .map(|c| {
+                        // Keeps the whole list within the embed description limit.
+                        let preview: String = c.content.chars().take(120).collect();
+                        format!("`{}` by <@{}>\n{preview}", c.id, c.author_id)
+                    })
+                    .collect::<Vec<_>>();
+                if pending.len() > MAX_LISTED_PENDING {
+                    entries.push(format!("and {} more", pending.len() - MAX_LISTED_PENDING));
+                }
+                entries.join("\n\n")
+            };
+
+            ctx.send(
+                CreateReply::default().embed(
+                    CreateEmbed::default()
+                        .title(format!("Pending {noun}s"))
+                        .description(description)
+                        .footer(CreateEmbedFooter::new(format!(
+                            "Use `/{} approve` or `/{} reject` to review them.",
+                            kind.as_str(),
+                            kind.as_str()
+                        )))
+                        .color(EMBED_COLOR),
+                ),
+            )
+            .await?;
+        }
+
+        "approve" | "reject" => {
+            let Some(id) = submission.as_deref().and_then(parse_submission_id) else {
+                send_error(ctx, "Please select the submission you want to review.").await?;
+                return Ok(true);
+            };
+
+            let found = if option == "approve" {
+                database::approve_custom_content(id, guild_id)?
+            } else {
+                database::remove_custom_content(id, guild_id)?
+            };
+
+            if !found {
+                send_error(ctx, "That submission doesn't exist.").await?;
+                return Ok(true);
+            }
+
+            let description = if option == "approve" {
+                format!("{CONFIRM_EMOJI} The {noun} will now be part of this server's daily posts.")
+            } else {
+                format!("{CONFIRM_EMOJI} The {noun} was rejected and removed.")
+            };
+
+            ctx.send(
+                CreateReply::default().embed(
+                    CreateEmbed::default()
+                        .title(if option == "approve" {
+                            "Submission approved"
+                        } else {
+                            "Submission rejected"
+                        })
+                        .description(description)
+                        .color(CONFIRM_EMBED_COLOR),
+                ),
+            )
+            .await?;
+        }
+
+        "ratio" => {
+            let Some(ratio) = ratio.filter(|r| *r <= 100) else {
+                let current =
+                    database::get_custom_content_ratio(guild_id, kind)?.unwrap_or(DEFAULT_RATIO);
+                send_error(
+                    ctx,
+                    &format!(
+                        "Please provide a ratio between 0 and 100.\nCurrently {current}% of daily {noun}s come from this server's submissions."
+                    ),
+                )
+                .await?;
+                return Ok(true);
+            };
+
+            database::set_custom_content_ratio(guild_id, kind, ratio)?;
+
+            ctx.send(
+                CreateReply::default().embed(
+                    CreateEmbed::default()
+                        .title("Ratio updated")
+                        .description(format!(
+                            "{CONFIRM_EMOJI} {ratio}% of daily {noun}s will now come from this server's submissions."
+                        ))
+                        .color(CONFIRM_EMBED_COLOR),
+                ),
+            )
+            .await?;
+        }
+
+        _ => {
+            send_error(ctx, "That option doesn't exist.").await?;
+        }
+    }
+
+    Ok(true)
+}
+
+/// Lists the pending submissions of the current guild, formatted so
+/// [`parse_submission_id`] can get the ID back.
+#[must_use]
+pub fn pending_autocomplete(
+    guild_id: Option<u64>,
+    kind: ContentKind,
+    partial: &str,
+) -> Vec<String> {
+    let Some(guild_id) = guild_id else {
+        return Vec::new();
+    };
+
+    let partial = partial.to_lowercase();
+    database::get_custom_content(guild_id, kind, false)
+        .map_err(|e| error!("Failed to get pending custom content: {e}"))
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|c| c.content.to_lowercase().contains(&partial))
+        .map(|c| {
+            // Autocomplete choices can't be longer than 100 characters.
+            let preview: String = c.content.chars().take(70).collect();
+            format!("{preview} (ID: {})", c.id)
+        })
+        .collect()
+}
+
+fn parse_submission_id(submission: &str) -> Option<u64> {
+    submission
+        .rsplit_once("(ID: ")
+        .map_or(submission, |(_, id)| id.trim_end_matches(')'))
+        .trim()
+        .parse::<u64>()
+        .ok()
+}
+
+async fn has_manage_guild(ctx: poise::Context<'_, (), Error>) -> bool {
+    ctx.author_member()
+        .await
+        .and_then(|member| member.permissions)
+        .is_some_and(|perms| perms.contains(Permissions::MANAGE_GUILD))
+}
+
+async fn send_error(ctx: poise::Context<'_, (), Error>, message: &str) -> Result<(), Error> {
+    ctx.send(
+        CreateReply::default().embed(
+            CreateEmbed::default()
+                .title("Error")
+                .description(format!("{ERROR_EMOJI} {message}"))
+                .color(ERROR_EMBED_COLOR),
+        ),
+    )
+    .await?;
+
+    Ok(())
+}
@@ -4,17 +4,18 @@ use crate::commands::{
     CONFIRM_EMBED_COLOR, CONFIRM_EMOJI, DOWNSCALED_SHIBA_MAIN_IMAGE_URL, ERROR_EMBED_COLOR,
     ERROR_EMOJI,
 };
+use crate::content::{self, Filter, Pool, Tag};
 use crate::{database, Error};
 
 use super::super::EMBED_COLOR;
+use super::custom_content::{self, ContentKind};
 use base64::engine::general_purpose::STANDARD;
 use base64::Engine;
 use chrono::Timelike;
 use log::info;
-use poise::serenity_prelude::Permissions;
 use poise::serenity_prelude::{json::json, CreateEmbed};
+use poise::serenity_prelude::{GuildId, Permissions};
 use poise::CreateReply;
-use rand::seq::SliceRandom;
 use webhook::client::WebhookClient;
 
 /// The amount of seconds [`fotd_loop`] will wait before starting.
@@ -30,21 +31,49 @@ pub async fn fotd(
     #[description = "The channel to send the fact to."] channel: Option<
         poise::serenity_prelude::Channel,
     >,
+    #[description = "The fact you want to submit to this server's pool."] text: Option<String>,
+    #[description = "The submission you want to approve or reject."]
+    #[autocomplete = "submission_autocomplete"]
+    submission: Option<String>,
+    #[description = "How many daily facts, in percent, should come from this server's submissions."]
+    ratio: Option<u8>,
+    #[description = "Only get facts about this topic."]
+    #[autocomplete = "category_autocomplete"]
+    category: Option<String>,
 ) -> Result<(), Error> {
     ctx.defer().await?;
 
     if option.is_none() {
-        // It's safe to use .expect() since `FACTS_OF_THE_DAY` will never be empty.
-        // .choose() will only return `None` if it is empty.
-        let fotd: &&str = FACTS_OF_THE_DAY
-            .choose(&mut rand::thread_rng())
-            .expect("Unreachable.");
+        // Server submissions aren't tagged, so they're left out when filtering.
+        let fotd = if category.is_some() {
+            let filter = Filter {
+                category,
+                ..Default::default()
+            };
+
+            let Some(fotd) = content::random_matching(Pool::Facts, &filter) else {
+                ctx.send(
+                    CreateReply::default().embed(
+                        CreateEmbed::default()
+                            .title("Error")
+                            .description(format!("{ERROR_EMOJI} No facts match that category."))
+                            .color(ERROR_EMBED_COLOR),
+                    ),
+                )
+                .await?;
+                return Ok(());
+            };
+
+            fotd
+        } else {
+            custom_content::pick(ContentKind::Fotd, ctx.guild_id().map(GuildId::get))
+        };
 
         ctx.send(
             CreateReply::default().embed(
                 CreateEmbed::default()
                     .title("Here's your fact of the day:")
-                    .description((*fotd).to_string())
+                    .description(fotd)
                     .color(EMBED_COLOR),
             ),
         )
@@ -52,6 +81,15 @@ pub async fn fotd(
         return Ok(());
     }
 
+    // It's safe to use .expect() because we already checked if the option is `None` above.
+    let option = option.expect("Unreachable");
+
+    if custom_content::handle_option(ctx, ContentKind::Fotd, &option, text, submission, ratio)
+        .await?
+    {
+        return Ok(());
+    }
+
     let perms = ctx
         .author_member()
         .await
@@ -74,9 +112,6 @@ pub async fn fotd(
         return Ok(());
     }
 
-    // It's safe to use .expect() because we already checked if the option is `None` above.
-    let option = option.expect("Unreachable");
-
     if channel.is_none() {
         ctx.send(
             CreateReply::default().embed(
@@ -155,11 +190,7 @@ pub async fn fotd(
             )
             .await?;
 
-            // It's safe to use .expect() since `FACTS_OF_THE_DAY` will never be empty.
-            // .choose() will only return `None` if it is empty.
-            let qotd = FACTS_OF_THE_DAY
-                .choose(&mut rand::thread_rng())
-                .expect("Unreachable");
+            let fotd = custom_content::pick(ContentKind::Fotd, ctx.guild_id().map(GuildId::get));
             let webhook_url = database::get_webhook_by_id_fotd(channel.id().get())?;
             let webhook = WebhookClient::new(&webhook_url);
             webhook
@@ -168,7 +199,7 @@ pub async fn fotd(
                         .username("Shiba FOTD")
                         .embed(|e| {
                             e.title("Fun fact of the day is here!")
-                                .field("", qotd, true)
+                                .field("", &fotd, true)
                                 .color(&EMBED_COLOR.0.to_string())
                         })
                 })
@@ -234,7 +265,31 @@ async fn fotd_autocomplete(_: poise::Context<'_, (), Error>, _partial: &str) ->
     //     .collect::<Vec<_>>();
 
     // options.iter().map(|&s| (*s).to_string()).collect()
-    vec!["add".to_string(), "remove".to_string()]
+    vec![
+        "add".to_string(),
+        "remove".to_string(),
+        "submit".to_string(),
+        "pending".to_string(),
+        "approve".to_string(),
+        "reject".to_string(),
+        "ratio".to_string(),
+    ]
+}
+
+// This function needs to be async for poise to use it.
+#[allow(clippy::unused_async)]
+async fn submission_autocomplete(ctx: poise::Context<'_, (), Error>, partial: &str) -> Vec<String> {
+    custom_content::pending_autocomplete(
+        ctx.guild_id().map(GuildId::get),
+        ContentKind::Fotd,
+        partial,
+    )
+}
+
+// This function needs to be async for poise to use it.
+#[allow(clippy::unused_async)]
+async fn category_autocomplete(_: poise::Context<'_, (), Error>, partial: &str) -> Vec<String> {
+    content::tag_values(Pool::Facts, Tag::Category, partial)
 }
 
 #[derive(serde::Deserialize)]
@@ -244,36 +299,43 @@ struct InvalidWebhookResponse {
     code: u16,
 }
 
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+#[allow(unused)]
+enum WebhookResponse {
+    Invalid(InvalidWebhookResponse),
+    Valid { guild_id: Option<String> },
+}
+
 pub(crate) async fn run_loop() -> Result<(), crate::Error> {
     loop {
         let timestamp = chrono::Local::now();
         if timestamp.hour() == 12 && timestamp.minute() == 0 {
             tokio::time::sleep(std::time::Duration::from_secs(LOOP_OFFSET)).await;
 
-            let mut webhooks = database::get_all_webhooks_fotd()?;
-            let mut invalid_webhooks = Vec::new();
+            let webhooks = database::get_all_webhooks_fotd()?;
+            // The guild each valid webhook belongs to, so their custom content can be mixed in.
+            let mut valid_webhooks = Vec::with_capacity(webhooks.len());
 
-            for webhook_url in &webhooks {
-                let response: Result<InvalidWebhookResponse, reqwest::Error> =
-                    reqwest::get(webhook_url).await?.json().await;
-
-                if response.is_ok() {
-                    info!("Removing invalid webhook (fotd): {}", webhook_url);
-                    database::remove_webhook_fotd(webhook_url)?;
-                    invalid_webhooks.push(webhook_url.to_string());
+            for webhook_url in webhooks {
+                let response: Result<WebhookResponse, reqwest::Error> =
+                    reqwest::get(&webhook_url).await?.json().await;
+
+                match response {
+                    Ok(WebhookResponse::Invalid(_)) => {
+                        info!("Removing invalid webhook (fotd): {webhook_url}");
+                        database::remove_webhook_fotd(&webhook_url)?;
+                    }
+                    Ok(WebhookResponse::Valid { guild_id }) => {
+                        let guild_id = guild_id.and_then(|id| id.parse::<u64>().ok());
+                        valid_webhooks.push((webhook_url, guild_id));
+                    }
+                    Err(_) => valid_webhooks.push((webhook_url, None)),
                 }
             }
 
-            for webhook_url in &mut invalid_webhooks {
-                webhooks.retain(|x| x != webhook_url);
-            }
-
-            for webhook_url in webhooks {
-                // It's safe to use .expect() since `FACTS_OF_THE_DAY` will never be empty.
-                // .choose() will only return `None` if it is empty.
-                let fotd = FACTS_OF_THE_DAY
-                    .choose(&mut rand::thread_rng())
-                    .expect("Unreachable");
+            for (webhook_url, guild_id) in valid_webhooks {
+                let fotd = custom_content::pick(ContentKind::Fotd, guild_id);
 
                 let webhook = WebhookClient::new(&webhook_url);
 
@@ -283,7 +345,7 @@ pub(crate) async fn run_loop() -> Result<(), crate::Error> {
                             .username("Shiba FOTD")
                             .embed(|e| {
                                 e.title("Fun fact of the day is here!")
-                                    .field("", fotd, true)
+                                    .field("", &fotd, true)
                                     .color(&EMBED_COLOR.0.to_string())
                             })
                     })
@@ -294,1429 +356,3 @@ pub(crate) async fn run_loop() -> Result<(), crate::Error> {
         tokio::time::sleep(std::time::Duration::from_secs(60)).await;
     }
 }
-
-pub const FACTS_OF_THE_DAY: [&str; 1423] = [
-    "Burger King restaurants serve over 400 million ounces of orange juice annually.",
-    "The six official languages of the United Nations are Arabic, Chinese, English, French, Russian, and Spanish.",
-    "People from North America prefer pickles with warts, where as Europeans prefer pickles with no warts.",
-    "A fetus starts to develop fingerprints at the age of eight weeks.",
-    "The Sears Tower in Chicago contains enough steel to build 50,000 automobiles.",
-    "The average person falls asleep in about 12 to 14 minutes.",
-    "In the wild, the poinsettia flower can reach a height of 12 feet, and have leaves that are eight inches across.",
-    "Just by recycling one aluminum can, enough energy would be saved to have a TV run for three hours.",
-    "The skeleton of a spider is located on the outside of the body. The name for this is exoskeleton.",
-    "Before toilet paper was invented, French royalty wiped their bottoms with fine linen.",
-    "Pumpkins contain potassium and vitamin A.",
-    "During the Easter season, 600 million Marshmallow Peeps are bought my Americans. The Marshmallow Peep is the most popular Easter candy besides chocolate.",
-    "The most popular gift that teachers receive in the United States from their students is chocolate.",
-    "Steve Fletcher holds the record for the largest gum wrapper collection. His collection has 5300 gum wrappers from all across the world.",
-    "In New York City, approximately 1,600 people are bitten by other humans annually.",
-    "A ripe cranberry will bounce. Another name for a cranberry is bounceberry.",
-    "Reptiles do not perspire, and do not have any oil glands.",
-    "There have been over fifty million Mr. Potato Heads sold since it came out in 1952.",
-    "The most popular name for a pet in the United States is Max.",
-    "In 1657, the first chocolate house was opened in London, England. The cost of chocolate was about 13 shillings per pound and was a drink that only the elite enjoyed.",
-    "In Australia, the average person uses 876 gallons of water daily. In Switzerland they use only 77 gallons of water per person daily.",
-    "The greatest snowfall ever in a single storm was 189 inches at the Mount Shasta Ski Bowl in February, 1959.",
-    "The green ring that is formed around the yolk of eggs that have been cooked too long is formed by the chemical reaction from the iron in the yolk and the sulphur in the white part of the egg.",
-    "The music band UB40 got its name from an unemployment form in England.",
-    "Charlie Chaplin once lost a contest for a Charlie Chaplin look a like.",
-    "The country with the highest consumption of chocolate per capita is Switzerland, with 22 pounds per person, per year.",
-    "Clans of long ago that wanted to get rid of their unwanted people without killing them would burn their houses down - hence the expression \"to get fired.\".",
-    "There is a law in the state of Idaho that does not permit one citizen to give another citizen a box of candy that is heavier than 50 pounds.",
-    "The most popular recipient of Valentine cards are school teachers.",
-    "Paper money is not made from wood pulp but from cotton. This means that it will not disintegrate as fast if it is put in the laundry.",
-    "7-Eleven was the first convenience store to have television advertising. The animated commercial ran in 1949 and had a singing rooster and owl.",
-    "The only animal, besides humans that can get leprosy is the Armadillo.",
-    "Totally Hair Barbie is the best selling Barbie of all time. It sold over ten million units.",
-    "The phrase \"Often a bridesmaid, but never a bride,\" actually originates from an advertisement for Listerine mouthwash from 1924.",
-    "Americans did not commonly use forks until after the Civil War.",
-    "In England, the Speaker of the House is not allowed to speak.",
-    "Every year, an igloo hotel is built in Sweden that has the capacity to sleep 100 people.",
-    "Corals take a long time to grow. Some corals only grow one centimeter in one year.",
-    "Giant flying foxes, which are a type of bat, that live in Indonesia have wingspans of nearly six feet.",
-    "For the blockbuster movie \"The Terminator,\" O.J. Simpson was considered to play the role of the Terminator, but producers did not choose him as they thought he would not be taken seriously.",
-    "The highest point in France is Mont Blanc, located in the Alps.",
-    "In a year, about 90 million jars of Skippy Peanut Butter are sold. This works out to three jars sold every second.",
-    "Trees that are near street lights do not shed their leaves as fast as a tree that is in the country.",
-    "The first lighthouse was in Alexandria in 290 B.C.",
-    "Wendel Clark holds the record for the longest span between NHL All-Star appearances, with 13 years (1986-1999).",
-    "Air is passed through the nose at a speed of 100 miles per hour when a person sneezes.",
-    "Researches have discovered that eating five or more apples a week is linked to better functioning of the lungs.",
-    "The Beatles have sold more records than anyone else with over a billion worldwide.",
-    "In a lifetime, the average house cat spends approximately 10,950 hours purring.",
-    "The real name of Toto the dog in \"The Wizard Of Oz\" was Terry.",
-    "On average, Americans move to a new place eleven times in their lifetime.",
-    "The cartoon character Popeye was actually based on a real person named Frank \"Rocky\" Fiegel who was a tough guy who was quite similar to Popeye physically.",
-    "The deepest underwater penguin dive is 1,772 feet by an Emperor Penguin.",
-    "The national sport of Japan is sumo wrestling.",
-    "The temperature of milk when it is coming out of a cow is about 36 degrees celsius.",
-    "In 1893, the first mosque in the United States was built.",
-    "Rice is thrown at weddings as a symbol of fertility.",
-    "The only married couple to fly together in space were Jan Davis and Mark Lee, who flew aboard the Endeavor space shuttle from Sept 12-20, 1992.",
-    "The first TONKA truck was made in 1947.",
-    "In Canada, the most productive day of the workweek is Tuesday.",
-    "Between 1997-2002, there was an increase of 228% in cosmetic procedures in the United States.",
-    "People in low-income homes spend 50% more time playing video games than people in high-income homes.",
-    "The state of California has more 7-Eleven stores than any other state. There are approximately 1,200 stores.",
-    "In Ontario, an average household income of a golfer is greater than $60,000.",
-    "There are 54 bones in your hands including the wrists.",
-    "Uranus is the only planet that rotates on its side.",
-    "The household wrench was invented by boxing heavyweight champion Jack Johnson in 1922.",
-    "The most recycled product in the world is the automobile.",
-    "Each Jelly Belly jelly belly bean has 4 calories.",
-    "In 1961, Italian artist Piero Manzoni packed his feces in cans, signed and mounted them, and then sold them as art.",
-    "Amongst pre-schoolers, Caillou is the fastest-ever-growing television show and is seen in close to 97% of U.S. households.",
-    "Ancient Egyptian women used to wear perfume cones made of wax that would melt in the heat letting out a nice fragrance.",
-    "The story of Rudolph the Red-Nosed Reindeer was written in 1939 for a store promotion by an advertising employee of the department store Montgomery Ward.",
-    "A baseball will go farther in hot temperature than in cold temperature.",
-    "Researchers have developed odourless socks. The sock fabric is made by attaching molecules that contain chlorine called halamines to textile fibers.",
-    "The world's termites outweigh the world's humans 10 to 1.",
-    "The fastest speed a raindrop had reached when falling is seven miles per hour.",
-    "Ray Kroc bought McDonalds for $2.7 million in 1961 from the McDonald brothers.",
-    "Over half the textile fibers that are used in the world are cotton.",
-    "Hang On Sloopy is the official rock song of Ohio.",
-    "Botanically a rhubarb is a vegetable. It was changed to a fruit in 1947 by a U.S. Custom Court.",
-    "The first jet engine was invented by Frank Whittleof of England in 1930.",
-    "Humpback whales are capable of living up to 95 years.",
-    "A goldfish has a memory span of three seconds.",
-    "The only predator that polar bears have are humans.",
-    "The flatulation from domesticated cows produce about 30% of the methane on this planet.",
-    "There is a dog museum in St. Louis, Missouri.",
-    "Many people in parts of China eat insects. Some common insects are bean worms, scorpions, and locusts.",
-    "The word Spain means \"the land of rabbits.\".",
-    "No piece of paper can be folded in half more than 7 times.",
-    "Finland has 187,888 lakes and 179,584 islands.",
-    "In the United States, ice cream is sold the most on a Sunday.",
-    "The big toe is the foot reflexology pressure point for the head.",
-    "According to legend, tea originated in China when tea leaves accidentally blew into a pot of boiling water.",
-    "The CN Tower, in Toronto, is the tallest freestanding structure in the world with a height of about 553 metres.",
-    "In 1836, Mexican General Santa Anna held an elaborate state funeral for his amputated leg. updated.",
-    "The first German car to be built solely outside of Germany is the BMW Z3.",
-    "In 1886, Coca-cola was first served at a pharmacy in Atlanta, Georgia for only five cents a glass. A pharmacist named John Pemberton created the formula for Coca-cola.",
-    "Abdul Kassam Ismael, Grand Vizier of Persia in the tenth century, carried his library with him wherever he went. Four hundred camels carried the 117,000 volumes.",
-    "The length of a human esophagus is 25 centimeters.",
-    "Finnish folklore states that when Santa comes to Finland to deliver gifts, he leaves his sleigh behind and rides on a goat named Ukko instead.",
-    "Historically, a blue ribbon has been awarded for first prize.",
-    "The average America online user spends 70 minutes day online.",
-    "There are 315 species of parrot in the world.",
-    "The tallest tree recorded is located in Humboldt Redwoods State Park, California. It is a coast redwood and has been measured at 117 metres high.",
-    "The sap of a banana plant leaves serious stains on hands and clothes that is extremely hard to remove.",
-    "One billion seconds is about 32 years.",
-    "The first open heart surgery was performed by Dr. Daniel Hall Williams in 1893.",
-    "About 26 per cent of all indoor water used by households in Sydney, Australia are for laundry.",
-    "Male koalas mark their territory by rubbing their chests on a tree. Male koalas have a dark scent gland in the middle of their chest.",
-    "Two out of five people end up marrying their first love.",
-    "The three most valuable brand names on earth: Marlboro, Coca Cola, and Budweiser, in that order.",
-    "Men are more likely to be colorblind than women. About one of out of 12 men are colorblind.",
-    "There are over 9 million beef and dairy cattle in New Zealand.",
-    "The Stanley Cup originally was only seven and a half inches high.",
-    "Vasaloppet, which is located in Sweden is the oldest, longest, and the biggest cross-country ski race in the world. Every year, 14,000 people compete in the race.",
-    "Romans, in the third century, believed that the lemon was an antidote for all poisons.",
-    "In just the first 56 days of life, the larva of the polyphemus moth eats about 86,000 times its birthweight.",
-    "You burn more calories sleeping than you do watching television.",
-    "Battle Creek, Michigan is referred to as the \"Cereal Bowl of America.\" The city produces the most breakfast cereals than any other city in the world.",
-    "There are over 200 parts in a typical telephone.",
-    "According to research, the most productive workday is Tuesday and the least productive is Friday.",
-    "The original meaning of the word grocer was referring to a person who traded food in wholesale. These people would usually sell in large quantities, or by the \"gross.\".",
-    "In the United States, the most frequent month for a tornado to occur is in May.",
-    "In 1983, a Japanese artist, Tadahiko Ogawa, made a copy of the Mona Lisa completely out of ordinary toast.",
-    "Humans and cows have the same gestation period, which is about nine months.",
-    "The expression cooked \"al dente\" means \"to the tooth.\" What this means is that the pasta should be somewhat firm, and offer some resistance to the tooth, but should also be tender.",
-    "Of all the golfers in Canada, 71.4% golfers are male, 28.6% are female.",
-    "In ancient Egypt, doctors used jolts from the electric catfish to reduce the pain of arthritis.",
-    "In the United States, approximately 50 million people fish per year.",
-    "The eight most popular foods to cause food allergies are: milk, eggs, wheat, peanuts, soy, tree nuts, fish, and shellfish.",
-    "Chicago has the largest cookie factory, where Nabisco made over 4.6 billion \"Oreo\" cookies in 1997.",
-    "Great White sharks have about 3,000 teeth.",
-    "Irish Wolfhound dogs have a short lifespan and live about 7-8 years.",
-    "Budweiser beer is named after a town in Czechoslovakia.",
-    "Millie the White House dog earned more than 4 times as much as President Bush in 1991. And, rightfully so.",
-    "Great Britain was the first country to issue postage stamps in 1840.",
-    "There are 122 pebbles per square inch on a Spalding basketball.",
-    "In 1884, Dr. Hervey D. Thatcher invented the milk bottle.",
-    "During the Gold Rush in 1849, some people paid as much as $100 for a simple glass of water.",
-    "In 1980, there was only one country in the world with no telephones - Bhutan.",
-    "Women hearts beat faster than men.",
-    "The largest dog in the world is the Irish Wolfhound.",
-    "Contrary to popular beliefs, chocolate does not cause acne.",
-    "An average women has 17 square feet of skin. When a women is in her ninth month of pregnancy she has 18.5 square feet of skin.",
-    "In Miami, Florida, roosting vultures have taken to snatching poodles from rooftop patios.",
-    "Using recycled aluminum cans and making news cans out of them saves 75% energy compared to making it from new material.",
-    "The patent number of the telephone is 174465.",
-    "There were no red colored M&Ms from 1976 to 1987.",
-    "An elephant can live up to the age of seventy, or in some cases even more.",
-    "Termites have been around for over 250 million years.",
-    "98% of houses in the United States have at least one television set.",
-    "The Montreal Canadians hockey team has won the most Stanley Cups with 24.",
-    "Mexican jumping beans jump because of moth larvae inside the bean.",
-    "Twelve men have landed on and explored the moon.",
-    "The word \"vamp\" is used to describe the upper front top of a shoe.",
-    "All dogs are the descendant of the wolf. These wolves lived in eastern Asia about 15,000 years ago.",
-    "Eating 11 pounds of apples will make you gain one pound of weight.",
-    "The most famous movie theatre is the \"Chinese Theatre\" located in Los Angeles, USA.",
-    "In the 1985 Boise, Idaho mayoral election, there were four write-in votes for Mr. Potato Head.",
-    "Another way to say \"every 9 years\" is Novennial.",
-    "The word Popcorn is derived from the middle English word \"poppe,\" which means \"explosive sound\".",
-    "Over 50% of the wedding in the U.S. occur in the afternoon.",
-    "Many hamsters only blink one eye at a time.",
-    "Thomas Edison designed a helicopter that would work with gunpowder. It ended up blowing up and also blew up his factory.",
-    "Watermelons are a popular gift to bring to a host in China or Japan.",
-    "On a ship a toilet is called a head.",
-    "On average, Americans eat one hundred acres of pizza a day. This amounts to about three hundred fifty slices per second.",
-    "On May 9, 1999 approximately 600,000 gallons of whiskey flowed into the Kentucky River during a fire at Wild Turkey Distillery in Lawrenceburg.",
-    "The oldest working Post Office in the world is located in the village of Sanquer, located in the Scottish Lowlands. It has been operating since 1712.",
-    "Donkeys kill more people annually than plane crashes.",
-    "The Nobel Peace prize was first awarded in 1901 to Jean Henry Dunant, who was the founder of the Swiss Red Cross.",
-    "Bile produced by the liver is responsible for making your feces a brownish, green colour.",
-    "Sugar Bear (the mascot for Golden Crisps cereal) was born in 1963.",
-    "The state of Tennessee was known as Franklin before 1796.",
-    "Each king in a deck of playing cards represents a great king from history. Spades - King David, Clubs - Alexander the Great, Hearts - Charlemagne, Diamonds - Julius Caesar.",
-    "To make one pound of butter, 29 cups of milk are needed.",
-    "A sheep, a duck, and a rooster were the first passengers in a hot air balloon.",
-    "A flea can jump 150 times its size. That is the same as a person able to jump up 1,000 feet in the air.",
-    "There are some bananas that are red instead of yellow.",
-    "The first American celebration of St. Patricks Day was at Boston in 1737.",
-    "An office desk has 400 times more bacteria than a toilet.",
-    "In 1998, approximately 1.6 billion tree seedlings were planted in the United States. This amounts to about five trees per American.",
-    "The USSR launched the world's first artificial satellite, Sputnik 1, in 1957.",
-    "Ancient Egyptians believed that onions would keep evil spirits away.",
-    "To lose one pound of fat, a person has to burn approximately 3,500 calories.",
-    "Isaac Newton used to be a member of parliament.",
-    "The speed of sound must be exceeded to produce a sonic boom.",
-    "The temperature of lightning bolts is sometimes hotter than the surface of the sun.",
-    "Ballroom dancing is a course at Brigham Young University in Utah.",
-    "There are more than 640 muscles in the human body.",
-    "The Koala bear is not really a bear, but is really related to the kangaroo and the wombat.",
-    "The reason why bubble gum is pink is because the inventor only had pink colouring left. Ever since then, the colour of bubble gum has been predominantly pink.",
-    "The average Hostess Twinkie is 68 percent air as measured by volume according to university researchers.",
-    "If you keep a Goldfish in the dark room, it will eventually turn white.",
-    "In a year, there are 60,000 trampoline injuries that occur in the U.S.",
-    "A person would have to drink more than 12 cups of hot cocoa to equal the amount of caffeine found in one cup of coffee.",
-    "Traveling by air is the safest means of transportation.",
-    "Montreal is the second largest French speaking city after Paris.",
-    "On average, an American home has 3-10 gallons of hazardous materials.",
-    "There has only been 193,000 metric tonnes of gold discovered to date.",
-    "The song \"Happy Birthday\" brings in about $2 million in licensing revenue to Warner Communications who hold the copyright to the song.",
-    "Most dinosaurs walked on their toes.",
-    "The largest hamburger cooked in the world weighed in at 6,040 pounds.",
-    "A person infected with the SARS virus, has a 95-98% chance of recovery.",
-    "The brain of an ant has about 250,000 brain cells.",
-    "\"I am.\" is the second shortest complete sentence in the English language.",
-    "The first television broadcast of the Oscars took place in 1953, hosted by Bob Hope on NBC.",
-    "The longest fangs of a snake are found on the Gaboon Viper (Bitis gabonica), and can reach over 2 inches in length.",
-    "When the divorce rate goes up in the United States, toy makers report that the sale of toys also rise.",
-    "Did you know you share your birthday with at least 9 other million people in the world.",
-    "A U.S. company came out with a toilet night-light that sends out a green warning beacon when the seat is up.",
-    "There was a 19th century Native American tribal chief who went under the name, \"Not Able to Fornicate.\".",
-    "Eating parsley after eating an onion can help in getting rid of onion breath.",
-    "Albert Einstein was offered the presidency of Israel in 1952, but he declined.",
-    "The space between your eyebrows is called the Glabella.",
-    "The first known contraceptive was crocodile dung, used by Egyptians in 2000 B.C.",
-    "The first toilet stall in a public washroom is the least likely to be used. It is also the cleanest.",
-    "In 1929, the Coca-Cola slogan was \"The Pause That Refreshes.\".",
-    "Native Americans used to use pumpkin seeds for medicine.",
-    "The Taj Mahal, located in Agra, India, was actually built for use as a tomb by Mogul ruler Shah Jehan for his wife, Arjuman Banu Begum.",
-    "The first Labor Day holiday was celebrated on Tuesday, September 5, 1882, in New York City.",
-    "Approximately 87% of dog owners say that when they watch T.V. their dog curls up beside them or at their feet.",
-    "Copper is the second most used metal in the world.",
-    "On average, Americans spend $1300 on utility bills annually.",
-    "The loss of eyelashes is referred to as madarosis.",
-    "A snail can sleep for 3 years.",
-    "An armadillo can walk under water.",
-    "There are more chickens than people in the world.",
-    "The idea of Christmas cards was invented by Englishman Henry Cole in 1843.",
-    "Pitcher Darold Knowles once pitched all seven games of one World Series.",
-    "The official state tree of Illinois is The White Oak.",
-    "One of the Bond girls in the James Bond movie, \"For Your Eyes Only,\" used to be a man.",
-    "A scorpion can have up to 12 eyes.",
-    "It is physically impossible for pigs to look up into the sky.",
-    "Crayola is a French word that means \"Oily chalk.\".",
-    "An artist from Chicago named Dwight Kalb created a statue of Madonna made out of 180 pounds of ham.",
-    "In the U.S. 7 out of 10 homes use candles.",
-    "In Spain, it is common to pour chocolate milk or cafe au lait on cereal for breakfast.",
-    "The Super Bowl is broadcast to over 182 countries in the world.",
-    "The first movie to ever cost $100 million to make is Terminator 2: Judgment Day in 1991.",
-    "Close to 80% of people who watch the Super Bowl on television, only do so to view the commercials.",
-    "A tree in metropolitan area will survive for approximately eight years.",
-    "The most diners per capita in the world are located in the U.S. state New Jersey.",
-    "Bamboo plants can grow up to 36 inches in a day.",
-    "The town with the most stop signs per capita than any other in the US: LaConner, Washington.",
-    "898 tornadoes were recorded to have occurred in the United States in the year 2000.",
-    "Some desert snails have been known to sleep for three to four years.",
-    "Chocolate was used as medicine during the 18th century. It was believed that chocolate could cure a stomach ache.",
-    "More than 100 professional cyclists participate in the Tour de France every year and the race is over 3,200 kilometers.",
-    "In the U.S., approximately 46% of the chicken that is eaten by people comes from restaurants or other food outlets.",
-    "An average person consumes the equivalent of 26 gallons of milk a year, including almost 28 pounds of cheese.",
-    "Goats do not have upper front teeth.",
-    "65% of the candy that is produced in an year is consumed by American adults 18 years and older.",
-    "On average, 81% of Americans 16 years of age and older watch television at least once in a day.",
-    "The total mileage driven by all U-Haul trucks in a year is enough to move a person from the Earth to the moon five times a day for an entire year.",
-    "There are some ice creams that are 75% air.",
-    "In the U.S. there are approximately 65.8 million cats.",
-    "Some farmers in Japan have learned to grow their watermelons into a square shape. They did this to conserve shelf space.",
-    "Over the course of one year, a coffee tree only produces about 1.5 pounds of coffee.",
-    "While still in college, Bill Gates and Paul Allen once built a special purpose machine called \"Traff-O-Data.\" It was a machine that would analyze information gathered by traffic monitors. They never found any buyers.",
-    "The length of brink of the Canadian \"Horseshoe\" Falls located in Niagara Falls, Ontario, Canada is 2600 feet.",
-    "A study revealed that men that were born with a low birth weight were less likely to get married.",
-    "The electric chair was invented by a dentist.",
-    "When the body is resting, it takes in about 10 litres of air every minute.",
-    "In an average lifetime, people spend four years traveling in cars and six months waiting for red light to turn green.",
-    "Reserves from the Irish army were used as extras in the movie \"Braveheart.\".",
-    "A crocodile cannot stick its tongue out.",
-    "Tiger Woods was introduced to golf at nine months of age by his father.",
-    "A \"hairbreadth away\" is 1/48 of an inch.",
-    "Baskin Robbins once made ketchup ice cream. This was the only vegetable flavoured ice cream produced. However, they discontinued it since they thought it would not sell well.",
-    "Rubber bands last longer when refrigerated.",
-    "Even though red roses looks the same, there are over 900 different types of red roses.",
-    "Owls swallow their prey whole because they have no teeth. After approximately 12 hours they cough up the feathers, bones, and fur in a shape of a football pellet.",
-    "The projection light used for IMAX theaters can be seen from space.",
-    "The nut \"filbert\" got its name from St. Philbert which is celebrated on August 22nd, which is also when the nut matures.",
-    "The A.A. Milne character of Winnie the Pooh made his animated film debut in 1966 in Winnie the Pooh and the Honey Tree.",
-    "Male rabbits are called \"bucks,\" females are \"does.\".",
-    "Research has indicated that indoor pollution is 10 times more toxic than outdoor pollution.",
-    "The number of births that occur in India each year is higher than the entire population of Australia.",
-    "China has more English speakers than the United States.",
-    "Each day, anywhere from 35-150 species of life go extinct.",
-    "There is an area located off the south-eastern Atlantic coast of the United States called the \"Bermuda Triangle.\" It is known for a high rate of unexplained losses of ships, small boats, and aircraft, which has led some people to believe that this triangle has supernatural powers.",
-    "During a typical human life span, the human heart will beat approximately 2.5 billion times.",
-    "When snakes are born with two heads, they fight each other for food.",
-    "In a lifetime, an average driver will release approximately 912 pints of wind inside a car.",
-    "Bananas trees are not really trees. They are considered to be giant herb plants.",
-    "In the United States, approximately 25,000 eye injuries occur that result in the person becoming totally blind.",
-    "Detroit, Michigan has more registered bowlers than any other city in the USA.",
-    "One gallon of used motor oil can ruin approximately one million gallons of fresh water.",
-    "The Chihuahua Desert is the largest desert in North America, and is over 200,000 square miles.",
-    "70% of the poor people in the world are female.",
-    "Corned beef got its name because this beef was preserved with pellets of salt that were the size of corn kernels, which was also referred to as \"corns\" of salt.",
-    "Broccoli was first introduced into France during the royal marriage of Catherine de Medici to Henry II of France.",
-    "On September 7, 1997, the first flight of the F-22a occurred.",
-    "The eye of a human can distinguish 500 shades of the gray.",
-    "Scientists have actually performed brain surgery on cockroaches.",
-    "Persia changed its name to Iran in 1935.",
-    "The Nike swoosh was invented by Caroline Davidson back in 1971. She received $35 for making the swoosh. The first shoe with the swoosh was introduced in 1972.",
-    "The sound made by the Victoria Falls in Zimbabwe is so loud that it can be heard 40 miles away.",
-    "In Greece, the climate is so warm that many of the cinemas do not even have roofs.",
-    "The word \"moose\" comes from the native Algonquian Indian word meaning \"twig eater.\".",
-    "Macadamia nuts are not sold in their shells because it takes 300 pounds per square inch of pressure to break the shell.",
-    "The blackberry bush is also called the \"bramble.\".",
-    "The majority of cats do not have any eyelashes.",
-    "Neptune was the first planet in our solar system to be discovered by mathematics.",
-    "There are mirrors on the moon. Astronauts left them so that laser beams could be bounced off of them from Earth. These beams help give us the distance to the moon give or take a few metres.",
-    "The letter J does not appear anywhere on the periodic table of the elements.",
-    "Alexander Graham Bell, the inventor of the telephone, never telephoned his wife or mother because they were both deaf.",
-    "Huge Moore, the inventor of Dixie cups got the idea for the name from a neighboring factory, the Dixie Doll Company.",
-    "Right-handed people live, on average, nine years longer than left-handed people do.",
-    "Smokers are twice as likely to develop lower back pain than non-smokers.",
-    "Over 40 million Americans have chronic bad breath.",
-    "Butterflies taste with their feet.",
-    "The fins of the Spiny Dogfish Shark are sometimes used as sandpaper for wood products.",
-    "The reason why flamingos are pink is because they eat shrimp which have a red pigment.",
-    "The name of the squiggly line \"~\" is called a tilde.",
-    "In the United States, turkeys are mostly raised in California.",
-    "Each year the Pentagon estimates their computer network is hacked about 250,000 times annually.",
-    "The first known American novelist to hand in a manuscript that was typed was Mark Twain. His typewriter was a Remington No.1, which was invented by Christopher Sholes and Carlos Glidden.",
-    "Weatherman Willard Scott was the first Ronald McDonald.",
-    "Spiders have claws at the ends of their legs.",
-    "In February 1878, the first telephone book was published in New Haven, Connecticut. The book was one page long and had fifty names in it.",
-    "There are over one billion people that are actively involved in rice growth.",
-    "The designated instrument for the city of Detroit is the accordion.",
-    "One-third pound stalk of broccoli contains more vitamin C than 204 apples.",
-    "The word \"comet\" comes from the Greek word \"kometes\" meaning long hair and referring to the tail.",
-    "The first toilet tank ever seen on television was on Leave it to Beaver.",
-    "The right lung of a human is larger than the left one. This is because of the space and placement of the heart.",
-    "In the movie \"Gandhi\" 300,000 extras appeared in the funeral scene. Of the 300,000, approximately 100,000 received a small fee, and the other 200,000 did it for free.",
-    "Every continent begins and ends in the same letter. eg AfricA, EuropE.",
-    "Americans eat approximately 20 pounds of pasta per person each year.",
-    "The Tibetan name for Mount Everest is Chomolungma.",
-    "The average stay for a prisoner on Alcatraz, when it was used as a prison, was five years.",
-    "On average, the rainfall across the Amazon is 7 feet annually.",
-    "The first box of Crayola that was ever sold had the same eight colors that are sold in the box today consisting of red, blue, yellow, green, violet, orange, black and brown. The box was sold for a nickel in 1903.",
-    "The colors yellow, red, and orange are used in fast food restaurants because those are the colors that stimulate hunger.",
-    "About 25 percent of all the energy consumed in the US is from natural gas.",
-    "Majority of brides plan their wedding for approximately 7 to 12 months.",
-    "In 1958 the United States sent three mice into space named, Mia, Laska and Benji.",
-    "A butterfly has to have a body temperature greater than 86 degrees to be able to fly.",
-    "The highest toll paid by a ship to cross the Panama Canal was by the Crown Princess on May 2, 1993 in the amount of $141,349.97 U.S. funds.",
-    "Approximately three jars of peanut butter are sold every second.",
-    "LSD is made from lysergic acid, which is found in ergot, a type of fungus.",
-    "In 1864, A Quebec farmer found a frog inside a hailstone.",
-    "An estimated 690 million people live in Africa.",
-    "There is a city called Smackover located in Arkansas.",
-    "If you fart consistently for 6 years and 9 months, enough gas is produced to create explosion that is equal to an atomic bomb.",
-    "The first telephone call from the White House was from Rutherford Hayes to Alexander Graham Bell.",
-    "In 1988, the largest ice cream sundae in history was made. It was made in Edmonton, Alberta, Canada, and weighed in at over 24 tons.",
-    "The most popular ethnic food in the United States is Italian food.",
-    "Jewelers Tiffany & Co., based in New York, are responsible for making the Super Bowl trophy.",
-    "\"Kemo Sabe\" means \"soggy shrub\" in Navajo.",
-    "Sharks are capable of going at least 6 weeks without eating; the record observed in an aquarium is 15 months by a species of shark known as the swell sharks).",
-    "Women blink nearly twice as much as men.",
-    "An adult esophagus can range from 10 to 14 inches in length and is one inch in diameter.",
-    "The meaning of Siberia is \"sleeping land.\".",
-    "Some toothpastes and deodorants contain the same chemicals found in antifreeze.",
-    "Canada has more inland waters and lakes than any other country in the world.",
-    "There are approximately 9,000 taste buds on the tongue.",
-    "The desert tortoise can live without having to drink any water. It extracts the water it needs from the vegetation it eats.",
-    "There are approximately 60 muscles in the face.",
-    "When opossums are playing 'possum, they are not \"playing.\" Theyactually pass out from sheer terror.",
-    "In 1949 UNICEF produced the first charity Christmas card. The picture shown on the card was painted by a seven year old girl.",
-    "In Australia, a dust-devil is called a \"willy-willy.",
-    "850 peanuts are needed to make an 18 oz. jar of peanut butter.",
-    "The word \"laser\" stands for \"Light Amplification by Stimulated Emission by radiation.\".",
-    "Mice will nurse babies that are not their own.",
-    "Iguanas can recognize their human handlers and greet them differently, compared with strangers.",
-    "The reason the soft drink Dr Pepper is called that is because the inventor Wade Morrison named it after Dr. Charles Pepper who had given him his first job.",
-    "Dolphins can swim and sleep at the same time.",
-    "The first recipe for a lasagna type dish was found to be from a British cookbook in the 14th century. Therefore, Italians were not the first ones to come up with the popular dish as believed.",
-    "In 1810, Peter Durand invented the tin can for preserving food.",
-    "Heinz Catsup leaving the bottle travels at 25 miles per year.",
-    "In New York City there are 6,374.6 miles of streets.",
-    "Some snails live on branches in trees.",
-    "Edinburgh has more booksellers per head of population than any other city in Britain.",
-    "There is a certain type of Hawk Moth caterpillar from Brazil that inflates its thorax, which makes its head look like a head of a snake when it feels it is in danger or alarmed.",
-    "Chocolate maker Cadbury uses more than sixty thousand tonnes of cocoa each year, in the United Kingdom alone.",
-    "The aorta, which is largest artery located in the body, is about the diameter of a garden hose.",
-    "The Hawaiian alphabet only has 12 letters.",
-    "Koalas sleep up to 19 hours a day.",
-    "During the holiday season, approximately $220 million worth of Poinsettias are sold.",
-    "It takes eight and a half minutes for light to get from the sun to earth.",
-    "The American Airlines Sports Center, in Dallas, has most toilets per capita than any other sports and entertainment venue in the USA.",
-    "The biggest disco ball in the world has a diameter of 2.41 meters and 137.89 kilograms. It also has 6,900 mirror squares on it.",
-    "In 1845, inventor Thomas Adams started the world's first chewing gum factory.",
-    "In their lifetime, house cats spend approximately 10,950 hours purring.",
-    "On average, a whole chicken from the grocery store weighs 3 pounds 12 ounces.",
-    "Wild Flamingos are pink because they consume vast quantities of algae and brine shrimp.",
-    "Ironically, watermelons, which are 92% water, originated from the Kalahari Desert in Africa.",
-    "It took eleven years to built the Taj Mahal, (1632-1643).",
-    "Teflon is the most slipperiest substance in the world.",
-    "The biggest candy eaters are the Dutch, who average 65 pounds of candy per person in a year.",
-    "There is a Harley-Davidson that was designed as an exact replica of a hamburger.",
-    "There are 500,000 detectable earthquakes in the world each year.",
-    "Smelling bananas can help a person lose weight.",
-    "Owen Falls Lake is the largest man-made lake in the world.",
-    "Some species of dolphin sleep with one eye open.",
-    "The first president to ride in an airplane was Franklin Roosevelt.",
-    "Every 238 years, the orbits of Neptune and Pluto change making Neptune at times the farthest planet from the sun.",
-    "There are over 500 different types of bananas.",
-    "The most disliked vegetable by Americans is Brussels sprouts.",
-    "The citric acid found in lemon juice is said to be able to dissolve a pearl.",
-    "In the Pacific Islands when people get burns they often use a banana leaf as treatment.",
-    "Did you know that there are coffee flavored PEZ?.",
-    "The word \"Denim\" comes from the French phrase \"serge de Nimes\" which is a fabric made in a town located in southern France.",
-    "Walt Disney holds the record for the most Oscar nominations with sixty-four.",
-    "There have been 191 coops in Bolivia since it became a sovereign country in 1825.",
-    "The sloth moves so slowly that green algae grows in the grooves of their hair.",
-    "In 1890, Scott Paper produced the first toilet paper to be available on a roll.",
-    "ABBA got their name by taking the first letter from each of their names (Agnetha, Bjorn, Benny, Anni-frid.).",
-    "A meteor has only destroyed one satellite, which was the European Space Agency's Olympus in 1993.",
-    "Thomas Edison was afraid of the dark.",
-    "The largest cultivated crop in the United States is corn.",
-    "Ringo Starr appeared in a Japanese advertisement for apple sauce. Ironically his name means \"apple sauce\" in Japanese.",
-    "Maine is the toothpick capital of the world.",
-    "In 1980, Saddam Hussein received a key to the city of Detroit.",
-    "A cow releases about 125 gallons of gas per day.",
-    "In an year, an average person makes 1,140 phone calls.",
-    "98% of brown bears in the United States are in Alaska.",
-    "The King Cobra has enough venom in its bite that it can kill up to 13 adults.",
-    "The silkworm moth has lost the ability to fly ever since it has been domesticated.",
-    "The Indian election in 1984 was the largest election of any country. Over 379,000,000 voters were eligible to vote at over 480,000 polling stations.",
-    "The Super Bowl is so popular that it is the number on at-home party event of the year.",
-    "The sun is approximately 149 million kilometers from the earth.",
-    "The term \"The Big Apple\" was coined by touring jazz musicians of the 1930s who used the slang expression \"apple\" for any town or city. Therefore, to play New York City is to play the big time - The Big Apple.",
-    "When the Statue of Liberty was moved from France to the United States, 214 crates were used to transport it. The Statue was also reduced to 350 pieces.",
-    "Each honeycomb in Honeycomb cereal has seven holes.",
-    "There are about 125 million multiples (twins, triplets, etc.) worldwide.",
-    "There are species of fish that can walk on land in search of water when its water source dries up. Some can survive as long as three days on land such as the snakehead fish.",
-    "The country with the highest consumption of candy at 29.5 pounds annually per person is Denmark.",
-    "In 1962, the first Wal-Mart opened up in Rogers, Arkansas.",
-    "In 1972, a gorilla by the name of \"Koko\" was taught ASL (American Sign Language) for the deaf. By the year 2000, the gorilla could understand approximately 2,000 English words.",
-    "Pigs have no sweat glands, which is why they stay in water or mud to keep cool.",
-    "The largest type of penguin is the Emperor Penguin which can stand to be almost 3.5 feet tall and weigh more than 90 pounds.",
-    "Ninety-nine percent of pumpkins sold in the United States are for the sole purpose of decoration.",
-    "The Eisenhower interstate system requires that one mile in every fivemust be straight. These straight sections are usable as airstrips intimes ofwar or other emergencies.",
-    "True spiders always have organs for spinning silk known as spinnerets.",
-    "Los Angeles is the most polluted city in the USA.",
-    "At the 1960 Winter Olympic Games, Walt Disney was head of the committee that organized the opening day ceremonies.",
-    "Apples are part of the rose family.",
-    "On average, an ear of a corn has 16 rows and approximately 800 kernels.",
-    "Walt Disney had a fear of mice.",
-    "An octopus has three hearts.",
-    "A giraffe is able to clean its ears with its own tongue.",
-    "The real name of actress Whoopi Goldberg is Caryn Elaine Johnson.",
-    "A crocodile can run up to a speed of 11 miles per hour.",
-    "Two-thirds of Canadians live in Quebec and Ontario.",
-    "Bananas were discovered by Alexander the Great in 327 B.C. when he conquered India.",
-    "The first fashion house to be set up was in 1858 by Charles Worth. He opened his store in Paris with the idea of having pre-made gowns presented on models to his customers.",
-    "Scientists have determined that having guilty feelings may actually damage your immune system.",
-    "The Leaning Tower of Pisa is 58.36 metres above the ground.",
-    "Cricket chirping can tell the temperature outside. Counting how many times a cricket chirps in 15 seconds and then adding 40 to that number will approximately tell you what the temperature is in Fahrenheit.",
-    "The word witch comes from the word \"wicca\" which translates to the \"wise one.\".",
-    "At just four years old Mozart was able to learn a piece of music in half an hour.",
-    "American Airlines saved $40,000 in 1987 by eliminating one olive from each salad served in first-class.",
-    "Finland is also known as \"the land of of the thousand lakes,\" because of the over 188,000 lakes found in this country.",
-    "In a lifetime, on average a honey bee produces 1/12th of a teaspoon of honey.",
-    "The longest game of Monopoly played underwater is 45 days.",
-    "Bubble gum contains rubber.",
-    "A Hungarian named Ladislo Biro invented the first ballpoint pen in 1938.",
-    "Odontophobia is the fear of teeth.",
-    "The word housekeeping was invented by Shakespeare.",
-    "A starfish can turn its stomach inside out.",
-    "A catfish has about 100,000 taste buds.",
-    "There were 43,687 toilet related accidents in the United States in 1996.",
-    "Beluga whales which are also called \"white whales\" are not born white. They are born grey in color, and by the age of six become completely white.",
-    "A man filed a lawsuit against his doctor because he survived longer than what the doctor had predicted.",
-    "Milton Bradley originally wanted to name the game Twister, Pretzel; but he could not since the name was copyrighted.",
-    "Cubic Zirconia is 55% heavier than real diamonds.",
-    "Coca-Cola was the first soft drink to be consumed in outer space.",
-    "In a day, a mature oak tree can draw approximately 50 gallons of water.",
-    "If an identical twin grows up without having a certain tooth, the other twin will most likely also grow up with that tooth missing.",
-    "In 1848, the first American pasta factory opened in Brooklyn, New York. The name of the man that opened it was Antoine Zerega.",
-    "The first hair dryer was a vacuum cleaner that was used for drying hair.",
-    "Bill Gates donated close to $100 million to fight AIDS in India. As a percent of his total wealth, this would be comparable to him donating ten cents if he only had $60.",
-    "Bird droppings are the chief export of Nauru, an island nation in the Western Pacific.",
-    "The TV show \"Saturday Night Live\" made its debut on October 11, 1975.",
-    "Rats can survive up to 14 days without any food.",
-    "It takes the Hubble telescope about 97 minutes to complete an orbit of the Earth. On average, the Hubble uses the equivalent amount of energy as 30 household lightbulbs to complete an orbit.",
-    "Albert Einstein was cremated and his ashes were spread over a river located in New Jersey.",
-    "The highest point in Pennsylvania is lower than the lowest point in Colorado.",
-    "Over 436,000 U.S. Troops were exposed to depleted uranium during the first Gulf war.",
-    "Americans on average use about 580 pounds of paper per year per person.",
-    "The snow leopard protects itself from extreme cold when it sleeps by wrapping its 3-foot-long tail around its nose.",
-    "Coca-Cola was originally green.",
-    "In gangster slang, a boxing match that is fixed is called a \"barney.\".",
-    "Ho-Ho-Kus, a small town in New Jersey, is the only town in the United States of America that has two dashes in its name.",
-    "Chili Powder was invented in the 19th century in the American Southwest.",
-    "Most snails are hermaphrodites, meaning they have both female and male reproductive organs.",
-    "A lifespan of an eyelash is approximately 150 days.",
-    "The Snickers chocolate bar was invented in 1930.",
-    "Elephants can't jump. Every other mammal can.",
-    "Dairy cows can produce 20 to 35 gallons of saliva a day.",
-    "In 1796, Napoleon was only 26 years old when he took command of the French Army of Italy.",
-    "There are some species of snails that are venomous. Their venom can be fatal to humans.",
-    "In humans, the epidermal layer of skin, which consists of many layers of skin regenerates every 27 days.",
-    "Tarantulas can live up to 30 years.",
-    "Ernest Vincent Wright wrote a fifty thousand-word novel, \"Gadsby,\" without any word containing the letter \"e.\".",
-    "The dumbest dog in the world is the Afghan Hounds.",
-    "Of all the words Dr. Seuss made up in his storybooks, only one has stuck in the English vocabulary: grinch, which is refers to a killjoy -- and it took more than 20 years.",
-    "The difference between horns and antlers is that horns never stop growing and antlers shed and grow every year.",
-    "There is cyanide in apple pips.",
-    "Thomas Watson, who was the chairman of IBM in 1943 predicted that there would probably only be a world market for five computers.",
-    "Automobile building is the largest manufacturing industry in the world.",
-    "The largest chicken egg ever laid weighed a pound and had a double yolk and shell.",
-    "In Quebec, Canada, an old law states that margarine must be a different colour than butter.",
-    "The 20th president of the United States, James Garfield, was able to write Greek with one hand and Latin with the other at the same time.",
-    "An ostrich's eye is bigger that it's brain.",
-    "It is estimated that by the end of 2000, there has been 142,600 tonnes of gold mined in the world.",
-    "Over 500 million gallons of Kool-Aid drink are consumed each year.",
-    "The most common rock on Earth is basalt.",
-    "The 3 most valuable brand names on earth: Marlboro, Coca-Cola, andBudweiser, in that order.",
-    "In 1902, the coat hanger was invented Albert Parkhouse who was frustrated at the lack of hooks available to hang up his coat at work. His company thought it was a good idea and patented the invention and unfortunately, Parkhouse never received any money for his idea.",
-    "The strongest gust of wind was recorded at the Mount Washington Observatory on April 12th, 1934, and measured 231 miles per hour.",
-    "Pilgrims did not eat with forks. They only used spoons, knives and their fingers.",
-    "Back in 1953, it took 27 hours to make one Marshmallow Peep. Now it takes only six minutes.",
-    "Honeybees use the sun as a compass which helps them navigate.",
-    "The 20th president of the United States James Garfield could write Greek with one hand and Latin with the other at the same time.",
-    "The most popular Twizzler candy flavour is strawberry.",
-    "The country of Andorra has a zero percent unemployment rate.",
-    "In 1965, the price for an issue of TV Guide was 15 cents.",
-    "The first lighthouse built in the USA was in Boston, MA in 1716.",
-    "The average US worker toils for two hours and 47 minutes of each working day just to pay income tax. Indeed, the average American pays more in taxes than for food, clothing and shelter put together.",
-    "The Hubble telescope is so powerful that it is like pointing a beam of light at a dime that is two hundred miles away.",
-    "There are more pigs than humans in Denmark.",
-    "Gorillas are considered apes, not monkeys. The way to distinguish between an ape and a monkey is that apes do not have tails.",
-    "The average height of an NBA basketball player is 6 feet 7 inches.",
-    "Frozen food can be just as nutritious as fresh food.",
-    "There are approximately 45 billion fat cells in an average adult.",
-    "The words moron, imbecile, and idiot are not interchangable. The one with the highest level of intelligence is a moron, followed, by an imbecile, and then idiot.",
-    "The feet have approximately 250,000 sweat glands.",
-    "Bank robber John Dillinger played professional baseball.",
-    "The largest stamp was issued by China and measured 210 x 65 mm.",
-    "There are more recreational golfers per capita in Canada than any other country in the world.",
-    "Retail sales for soft drinks in the United States in 2001 were more than sixty billion dollars.",
-    "Spotted skunks do handstands before they spray.",
-    "The most popular grown bulbs are tulips.",
-    "Apple seeds are poisonous as they contain a cyanide compound.",
-    "The loudest insect in the world is the male cicadas, which are like crickets. When they rub their abdomens, the sound made can be heard from 1300 feet.",
-    "The American Airlines Center in Dallas has more toilets per capita than any other sports and entertainment venue in the country.",
-    "People who have eaten beetles say that it tastes like apples.",
-    "Urine from men?s public urinals was sold as a commodity in Ancient Rome. It was used as a dye and for making clothes hard.",
-    "In the United States, six tubs of Cool Whip, a brand of whipping cream, are sold every second.",
-    "There are over three trillion craters on the moon, with some being having a diameter over three feet.",
-    "A cesium atom in an atomic clock that beats over nine billion times a second.",
-    "Actress Sally Field was paid $4,000 a week for her role in the TV show The Flying Nun.",
-    "A one ounce milk chocolate bar has 6 mg of caffeine.",
-    "The adult electric eel can produce a five hundred volt shock, which is enough to stun a horse.",
-    "Maine is the only state whose name is just one syllable.",
-    "Baby donkeys or baby mules are also known as \"Foals.\".",
-    "American novelist Mark Twain was the first known author to submit a typed manuscript.",
-    "The destruction of the Berlin Wall began when private citizens began to demolish entire sections of the Wall without interference from government officials on November 9, 1989.",
-    "The body of the average baby is 75% water.",
-    "A leech can gorge itself up to a maximum of five times its body weight.",
-    "The first toilet ever seen on television was on \"Leave It To Beaver\".",
-    "Since the United Nations was founded in 1945, there have been 140 wars.",
-    "Male sea horses are the ones that get impregnated rather than the females. Males have a pouch on their belly that provides incubation for the female?s eggs and they can have up to 1500 babies at one time.",
-    "The CIA has made a disk camera that is as big as a quarter. This gadget can take many pictures at a time when the disk is opened.",
-    "In 1942 the Jello company introduced Cola flavored jello, which only lasted a year.",
-    "There is now an ATM at McMurdo Station in Antarctica, which has a winter population of two hundred people.",
-    "American Airlines saved $40,000 in 1987 by eliminating one olive from each salad served in first class.",
-    "There is a town in Norway called \"Hell\".",
-    "The markings that are found on dice are called \"pips.\".",
-    "Lighthouse keepers were nicknamed \"wickies\" because they tended the lamps wick.",
-    "American Airlines saved $40,000 in 1987 by eliminating 1 olive from each salad served in first-class.",
-    "A group of kangaroos is called a mob.",
-    "The Hollywood sign was first erected in 1923. It was first erected as \"Hollywoodland.\".",
-    "Every three seconds, a new baby is born.",
-    "Halifax, Nova Scotia, Canada has the largest bar per capita than anywhere else in the world.",
-    "Roughly 42% of people in the United Kingdom snore.",
-    "Some Chinese chopsticks contain gold as one of their materials.",
-    "The longest acceptance speech in the history of the Oscars was by Greer Garson in 1942. She received an Oscar for Best Actress for the movie Mrs. Miniver, and her speech was five minutes and 30 seconds long.",
-    "In only eight minutes, the Space Shuttle can accelerate to a speed of 27,000 kilometres per hour.",
-    "The flea can jump 350 times its body length, that is like a 6 foot-tall human jumping the length of 7 football fields.",
-    "The Chinese politician Mao Zedong refused to ever brush his teeth and instead just washed his mouth with tea.",
-    "Anti-American demonstrators protesting in Bangladesh after the September 11, 2001 terrorist attacks carried posters of Osama bin Laden sitting alongside Bert, a beloved Sesame Street Muppet character.",
-    "An average American eats approximately 60 hot dogs per year.",
-    "Intelligent people have more zinc and copper in their hair.",
-    "During his lifetime, artist Vincent Van Gogh only sold one of his paintings (The Red Vineyard).",
-    "There is a certain species of kangaroo that is only 2.5 centimetres long when it is born.",
-    "Each day Americans 15 and over spend an average of 55 minutes of driving.",
-    "One average, men spend 60 hours a year shaving.",
-    "There are an equivalent number of cows and people in Friesland, Netherlands.",
-    "The Popsicle was invented by 11 year-old Frank Epperson in 1905. He left his drink outside with a stir stick in it and he noticed that it had frozen. He applied for a patent in 1923 and named it \"Epsicle.\" The name was later changed to Popsicle.",
-    "The concept of Boxing Day, which is on December 26th, was to give boxes of food and clothing to the poor. It is now viewed in some countries as a time to get merchandise from stores at reduced prices.",
-    "A headache and inflammatory pain can be reduced by eating 20 tart cherries.",
-    "The act of stretching and yawning is referred to as pandiculation.",
-    "A swordfish can live as long as 25 years and weigh up to 1,200 pounds.",
-    "The least likely day to eat out in the United States is Monday.",
-    "The longest word in the English language is 1909 letters long and it refers to a distinct part of DNA.",
-    "The San Francisco Cable cars are the only mobile National Monuments.",
-    "To produce a dozen eggs, a hen has to eat about four pounds of feed.",
-    "The longest recorded duration of a total solar eclipse was 7.5 minutes.",
-    "The amount of Kit Kat chocolate bars that are made at the York factory every 15 minutes are enough to outstack the Eiffel Tower.",
-    "The fastest running bird is the Ostrich, which has been clocked at 97.5 kilometres per hour.",
-    "Actor John Ritter was the voice of Clifford, from \"Clifford The Big Red Dog.\".",
-    "The Atlantic Ocean is saltier than the Pacific Ocean.",
-    "Both Thomas Jefferson and Jimmy Carter, U.S. presidents, were peanut farmers at one time.",
-    "Pebbles cereal was actually named after the shape of the cereal and not the Pebbles Flintstone character.",
-    "Soil that is heated by geysers are now making it possible to produce bananas in Iceland.",
-    "All the Krispy Kreme donut stores collectively could make a doughnut stack as high as the Empire State Building in only 2 minutes.",
-    "Oral-B were the first toothbrushes to go to the moon when they were aboard the Apollo 11 mission.",
-    "In 1955, only 330 Volkswagen Beetle's were sold at a price of $1800 each in the United States.",
-    "Surveys indicate that the number one reason people play BINGO is for leisure.",
-    "In Belgium, there is a museum that is just for strawberries.",
-    "Walter Hunt patented the safety pin in 1849. He later sold the patent rights for only $400.",
-    "The average bank teller loses about $250 every year.",
-    "The city of Seoul has been the capital city of Korea for more than 600 years.",
-    "No word in the English language rhymes with month, orange,silver or purple.",
-    "A dime has 118 ridges around the edge.",
-    "2.5 cans of Spam are consumed every second in the United States.",
-    "The number one cause of blindness in adults in the United States is diabetes.",
-    "Hydrogen solid is the most dense substance in the world, at 70.6g/cc.",
-    "There are over 1,800 known species of fleas.",
-    "The search engine Google got its name from the word \"googol,\" which refers to the number one with a hundred zeros after it.",
-    "Americans write approximately 50 billion checks a year making it the second most frequent payment method used after cash.",
-    "The word \"dexterity\", to do with skill is related to the right hand. The opposite of the word \"deter\" is \"sinister\", to do with evil, it is related to the left hand.",
-    "Romans used to believe that walnuts could cure head ailments during the Renaissance, since their shape was similar to that of a brain.",
-    "The first email was sent out by Ray Tomlinson in 1971.",
-    "The average number of pillowcases washed a day at the MGM Grand Hotel in Las Vegas is 15,000.",
-    "Oscar Wilde and his friends came up the with the word \"dude.\" It came from the words \"duds\" and \"attitude.\".",
-    "It took approximately 2.5 million blocks to build the Pyramid of Giza, which is one of the Great Pyramids.",
-    "On August 21st, 1911, someone stole the Mona Lisa, the most famous painting in the world, from the Louvre Museum. It was recovered two years later.",
-    "TWIX Caramel Cookie Bars were first introduced in 1979.",
-    "The plastic things on the end of shoelaces are called aglets.",
-    "The United Parcel Service shipped the killer whale Keiko (star of Disney movie \"Free Willy\") from Mexico City to Newport, Oregon in 1998.",
-    "It takes approximately 12 years for Jupiter to orbit the sun.",
-    "There are some hospitals in Shanghai that have issued a rule that a nurse must wear lipstick while on duty.",
-    "A Chinese Scientist discovered that the Earth is round during the Han Dynasty by measuring the sun and moon's path in the sky. He recorded this fact down in the imperial records but went unnoticed until it was unearthed recently but Chinese archaeologists.",
-    "The chances of making two holes-in-one in a round of golf are one in 67 million.",
-    "The most expensive animated movie is \"Prince of Egypt\", which cost $70 million to make.",
-    "The Lemon shark grows about 24,000 new teeth a year. A new set of teeth grow approximately every 14 days.",
-    "There are bananas called \"Red banana\" that are maroon to dark purple when ripe.",
-    "The largest spider ever was the Megarachne which had a diameter of 50 cm. The fossil was found in Argentina.",
-    "In Canada, the $1 and $2 come in the form of coins. The $1 is nicknamed a \"loonie\" because it contains a loon on it and the $2 is nicknamed the \"twonie\" because it is the equivalent of two \"loonies.\".",
-    "In ancient Greece, throwing an apple to a girl was a way to propose for marriage. If the girl caught it, that would mean she accepts.",
-    "Every day the human stomach produces about 2 liters of hydrochloric acid.",
-    "The fat from sheep, which is called tallow can be used to make soap and candles.",
-    "Adolf Hitler wanted to be an architect, but he failed the entrance exam at the architectural school in Vienna.",
-    "Lake Baikal is the oldest freshwater lake on Earth, having formed between 20 and 25 million years ago.",
-    "Gorilla gorilla gorilla is the scientific name for the animal gorilla.",
-    "The longest bout of sneezing recorded was by Donna Griffith. It began in January 13 1981 and continued until September 16 1983 and lasted for 978 days.",
-    "Some arthritis medications contain gold salts, which is used as an anti-inflammatory.",
-    "The Pacific island of Tonga once issued a stamp that was banana shaped.",
-    "There are three golf balls sitting on the moon.",
-    "A Canadian, Troy Hurtubise, spent $100,000 and almost went bankrupt building a RoboCop style suit so that he could withstand a bear attack.",
-    "When a porcupine is born, its quills are soft and mostly white, but harden within hours.",
-    "Gardening is said to be one of the best exercises for maintaining healthy bones.",
-    "The name Hasbro was invented by the name of the founders: HASsenfeld BROthers.",
-    "There is a town in Texas called Ding Dong. In 1990, the population was only twenty-two people.",
-    "The word Nike comes from Greek Mythology. Nike is the goddess of victory and was often depicted as a small winged figure whom the goddess Athene carried.",
-    "Whooping cranes are born with blue eyes that change to bright gold by the time they six months old.",
-    "Over 170,000 Indians from 210 tribes live in the Brazilian Amazon Rainforest.",
-    "The name Jeep came from the abbreviation used in the army for the\"General Purpose\" vehicle, G.P.",
-    "A baby kangaroo is called a joey.",
-    "The spider used in the 2002 movie Spider-Man was a Steatoda spider, not a black widow. The spider was given anaesthesia, and was then painted blue and red.",
-    "In 1908, the first machine to make lollipops opened for business in New Haven, Connecticut.",
-    "There is a doggy disco held in Italy every year where owners can dance with their dogs.",
-    "A piece of French toast that was partially eaten by Justin Timberlake sold on eBay.",
-    "Thirty-five percent of the people who use personal ads for dating arealready married.",
-    "There is an automobile model called Stutz Bearcat.",
-    "In one day, the Tootsie Roll Industry makes over 16 million lollipops.",
-    "The game Monopoly has been played by approximately 500 million people in the world, and the game is available in 26 languages.",
-    "There are no ants in Iceland, Antarctica and Greenland.",
-    "The number one cause of rabies in the United States are bats.",
-    "An owl has three eyelids.",
-    "Over 50% of lottery players go back to work after winning the jackpot.",
-    "In 1902, the game table tennis was brought to the U.S. from Europe by Parker Brothers.",
-    "Olives, which grow on trees, were first cultivated 5,000 years ago in Syria.",
-    "There are two credit cards for every person in the United States.",
-    "The reason why some people get a cowlick is because the growth of their hair is in a spiral pattern, which causes the hair to either stand straight up, or goes to a certain angle.",
-    "A man named Charles Osborne had the hiccups for approximately sixty-nine years.",
-    "Other than London, Liverpool is the most filmed British city, and was used to film more than 140 films in 2002.",
-    "A women from Berlin Germany has had 3,110 gallstones taken out of her gall bladder.",
-    "The city of Chicago has the only post office in the world where you can drive your car through.",
-    "Milk chocolate was invented in Switzerland by David Peter in 1876.",
-    "The Flintstones cartoon was the first thirty-minute cartoon to be aired during prime time.",
-    "Skippy Peanut Butter is sold more in the world than any other peanut butter.",
-    "The word \"walkman\" was included in the Oxford English Dictionary in 1986.",
-    "During WWII, because a lot of players were called to duty, the Pittsburgh Steelers and Philadelphia Eagles combined to become The Steagles.",
-    "The famous Christmas song \"Jingle Bells\" was written for a Thanksgiving program in 1857 by James Pierpont. At the time, the song was called \"The One-Horse Open Sleigh.\".",
-    "There are over one hundred billion galaxies with each galaxy having billions of stars.",
-    "Missouri has been to most NCAA tournaments than any other college without reaching the final four.",
-    "Mel Blanc (the voice of Bugs Bunny) was allergic to carrots.",
-    "Wood frogs can be frozen solid and then thawed, and continue living. They use the glucose in their body to protect their vital organs while they are in a frozen state.",
-    "When opossums are playing 'possum, they are not \"playing.\" They actually pass out from sheer terror.",
-    "In Czechhoslovakia, there is a church that has a chandelier made of human bones.",
-    "The total volume of mail that went through the Canadian postal system in 1950 was 1,362,310,155 items.",
-    "Leonardo da Vinci was dyslexic, and he often wrote backwards.",
-    "Annually Americans eat 45 million turkeys at Thanksgiving.",
-    "There are an estimated 2,500 collisions between birds and planes each year in the US.",
-    "The word \"limelight\" that is used in theatre to refer to the performers on the stage originated because before electricity was available lime was burned in a lamp, which created a white light that was directed at the performers.",
-    "Honolulu, Hawaii boasts the only royal palace in the United States of America.",
-    "The revenue that is generated from gambling is more than the revenue that comes from movies, cruise ships, recorded music, theme parks, and spectator sports combined.",
-    "Nerve impulses for muscle position travel at a speed of up to 390 feet per second.",
-    "An average person laughs about 15 times a day.",
-    "A penguin swims at a speed of approximately 15 miles per hour.",
-    "An orca whale can hold its breath for up to 15 minutes.",
-    "The study of the iris of the eye is called iridology.",
-    "The conjunctiva is a membrane that covers the human eye.",
-    "A 27 year old heir to a sausage empire was handed a ticket for 116,000 pounds for driving at 80 km/hr in a 40 km/hr zone. This is because the speeding tickets in Finland are based on how much money a person makes.",
-    "The word Cotton originates from the Arabic word \"Qutun.\".",
-    "There are only four words in the English language which end in\"-dous\": tremendous, horrendous, stupendous, and hazardous.",
-    "A caterpillar grows roughly 27,000 times its size when it first emerges as an egg.",
-    "The ant can lift 50 times its own weight, can pull 30 times its own weight, and always falls over on its right side when intoxicated.",
-    "Orcas (killer whales), when traveling in groups, breathe in unison.",
-    "Play-Doh was introduced in 1956 by Hasbro Inc. The only color availabe was an off white, and it came in one size which a one and a half pound can.",
-    "The Arctic Tern, which is a small bird, can fly a round trip from the Arctic to the Antarctic and back. This can be as long as twenty thousand miles per year. This is the longest migration for a bird.",
-    "Sponge Candy was invented in Buffalo, NY.",
-    "More than $1 billion is spent each year on neck ties in the United States.",
-    "Coca-cola used to use the slogan \"Good to the last drop,\" in 1908. This slogan was later used by Maxwell House.",
-    "Eating dandelions can make you urinate more.",
-    "Keeping Warm With an Axe, is the title of a real how-to book. Click Here For More Details.",
-    "Eating eight strawberries will provide you with more Vitamin C than an orange.",
-    "In a survey conducted in 2000 by Kimberly-Clark, it was found that men prefer to fold their toilet paper, and women like to wad it.",
-    "Even though the rose does not bear any fruit, the rose hips have more Vitamin C than most vegetables and fruits.",
-    "The Basenji dog is the only dog that is not able to bark.",
-    "A British term for slot machine is \"fruit machine\" or \"one-armed bandit.\".",
-    "The most popular vacation destinations for Americans in 1956 was Niagara Falls.",
-    "The spray WD-40 got its name because there were forty attempts needed before the creation of the \"water displacing\" substance.",
-    "To make one raindrop of water, it takes approximately a million cloud droplets.",
-    "The country of Bolivia is named after a fighter Simon Bolivar.",
-    "A cubic yard of air weighs about 2 pounds at sea level.",
-    "The word \"toy\" comes from an old English word that means \"tool.\".",
-    "Some people start to sneeze if they are exposed to sunlight or have a light shined into their eye.",
-    "Stewardesses is one of the longest words typed with only the left hand.",
-    "Uranus has 27 moons.",
-    "There is no element on Mendeleev's (the current) periodic table of elements abbreviated, either partially, or fully, with the letter J.",
-    "The state of Alaska has almost twice as many caribou as people.",
-    "It would take twenty new mid-size cars to generate the same amount of pollution that a mid-size 1960's car did.",
-    "The ostrich has two toes on each feet which gives it greater speed.",
-    "The most popular brand of raisins is Sunmaid.",
-    "Rocky Mountain spotted fever is a disease caused by ticks.",
-    "The oldest actor to win a Best Actor Oscar is Henry Fonda. He was 76 when he won it.",
-    "The first president to have a picture taken was John Quincy Adams.",
-    "Wasps that feed on ferment occasionally get drunk and pass out.",
-    "Everyday, more money is printed for Monopoly than the U.S. Treasury.",
-    "Racecar driver Lee Petty once left a pit stop and did a full lap at Nascar with a pit crew member still on the hood.",
-    "Cotton crops can be sprayed up to 40 times a year making it the most chemical-intensive crop in the world.",
-    "Every year, 50,000,000 automobiles are produced in the world.",
-    "Canadians Scott Abbott and Chris Haney invented Trivial Pursuit. They were planning on playing Scrabble and realized that some of the pieces were missing so they came up with the idea of making their own game; Trivial Pursuit.",
-    "Eighty percent of the Vanilla Beans which are used to make ice cream is grown in Madagascar.",
-    "Less than 1% of the women in the world will ever be able to wear a diamond that is the size of a carat or more.",
-    "The beeswax that is produced by Honey bees comes from eight paired glands that are located on the underside of their stomach.",
-    "U.S. Postal Service processes 38 million address changes each year.",
-    "The Toronto Maple Leafs used to be called the Toronto Arenas, then the St. Patricks and finally the Maple Leafs.",
-    "27 percent of U.S. male college students believe life is \"a meaningless existential hell.\" (big surprise, eh?).",
-    "St. Patrick explained the Holy Trinity to King Laoghaire, using the shamrock to illustrate the trinity.",
-    "Minnows have teeth located on a bone in their throat.",
-    "Energy is being wasted if a toaster is left plugged in after use.",
-    "Each king in a deck of playing cards represents a great king from history. Spades - King David; Clubs - Alexander the Great; Hearts -Charlemagne;and Diamonds - Julius Caesar.",
-    "There are 336 dimples on a regulation golf ball.",
-    "In the U.S., the milk production per dairy cow is approximately 12,000 pounds.",
-    "On average Americans spend 18% of their income on transportation.",
-    "Corn Flakes were invented after Will Keith Kellogg and his brother Dr. John Harvey Kellogg set about developing a nutritious cereal for the patients of a health resort in 1890.",
-    "Smiling releases endorphins in the body, which makes people feel better.",
-    "The turkey was once nominated to be the official bird of the United States.",
-    "In 1933, Mickey Mouse is believed to have received 800,000 fan letters.",
-    "The first British ship to use the SOS distress signal was the Titanic.",
-    "The honey badger can withstand hundreds of bee stings that would otherwise kill another animal.",
-    "The first restaurant to open in Hollywood was the Musso & Frank Grill in 1919.",
-    "Border collies are the most intelligent breed of dog.",
-    "Babies who wear disposable diapers are five times more likely to develop diaper rash than those that wear cotton diapers.",
-    "Banging your head against a wall uses 150 calories an hour.",
-    "There is a type of coffin made that can be used as a wine rack or picnic table before its final use.",
-    "The New York Stock Exchange started out as a coffee house.",
-    "There are more plastic flamingos in America than real ones.",
-    "The deepest mine in the world is the East Rand mine, which goes to a depth of about 3,585 metres.",
-    "The name of the character that is behind bars in the Monopoly board game is Jake the Jailbird.",
-    "Amazingly, goalies in the National Hockey League played without masks until the year 1959.",
-    "Polar bears are left handed.",
-    "The first fruit eaten on the moon was a peach.",
-    "Cleopatra married two of her brothers.",
-    "Tasmania is said to have the cleanest air in the world.",
-    "One out of 200 women is colorblind.",
-    "James Buchanan was the only unmarried president of the United States.",
-    "French artist, Michel Vienkot, uses cow dung as paint when he creates his pictures.",
-    "If you yelled for 8 years, 7 months and 6 days, you would have produced enough sound energy to heat one cup of coffee.",
-    "Most lipstick contains fish scales.",
-    "In Kentucky, it is illegal to carry ice cream in your back pocket.",
-    "It costs about 3 cents to make a $1 bill in the United States.",
-    "If all the Oreo cookies ever sold were stacked on top of one another, they would be as high as 13.3 million Sears Towers.",
-    "William Taft who was the U.S. president between 1909-1913 once got stuck in the White House bathtub.",
-    "In Russia, when flowers are given for a romantic occasions, flowers are given in odds numbers as even number of flowers is given at funerals only.",
-    "In Italy, Santa Claus is known by the name Babbo Natale.",
-    "Honorificabilitudinitatibus is the longest English word that consists strictly of alternating consonants and vowels.",
-    "One million cloud droplets are needed to make enough water to produce one raindrop.",
-    "Business.com is currently the most expensive domain name sold for $7.5 million.",
-    "Americans consume the most peanut butter in the world.",
-    "Marilyn Monroe had six toes.",
-    "The famous jewelry store Tiffany & Co. was established on September 18, 1837 in New York City. The amount of sales that were made the first day were $4.98.",
-    "Reports from owners of cats and dogs indicate that 21% of dogs and 7% of cats snore.",
-    "Movies approximately make five times more from video sales than ticket sales.",
-    "Average life span of a major league baseball: 7 pitches.",
-    "Jellyfish have been on Earth for over 650 million years. This is before sharks and dinosaurs.",
-    "A surfer once sued another surfer for \"stealing his wave.\" The case was thrown out because the court was unable to put a price on \"pain and suffering\" endured by the surfer watching someone else ride \"his\" wave.",
-    "The Spring peeper (a frog) can survive the winter season with 65% of its body water as ice.",
-    "Rabbits can live up to ten years.",
-    "Every three days a human stomach gets a new lining.",
-    "There are more Barbie dolls in Italy than there are Canadians in Canada.",
-    "Next to Warsaw, Chicago has the largest Polish population in the world.",
-    "In 1967, the IMAX film system was invented by Canadian Ivan Grame Ferguson to premier at Expo 67.",
-    "The Welwitschia plant can live up to 1,000 years.",
-    "Americans on the average eat 18 acres of pizza every day.",
-    "The majority of burglaries occur during the daytime when people are not home.",
-    "In 1905, Chapman and Skinner in San Francisco invented the first portable electric vacuum.",
-    "Canada beat Denmark 47-0 at the 1949 world hockey championships. new.",
-    "From the age of thirty, humans gradually begin to shrink in size.",
-    "American women, on average, spend 55 minutes per day getting showered, dressed, and groomed.",
-    "In a year, the average person walks four miles to make his or her bed.",
-    "The town of Olney, Illinois celebrates a \"Squirrel Day\" festival to honour the 200 albino squirrels that live in the town. The festival includes a squirrel blessing by a priest.",
-    "The chances of getting a cavity is higher if candy is eaten slowly throughout the day compared to eating it all at once and then brushing your teeth.",
-    "One out of 20 people have an extra rib.",
-    "An individual coral animal is called a polyp.",
-    "111,111,111 x 111,111,111 = 12,345,678,987,654,321.",
-    "The fastest moving land snake is the Black Mamba, which can move up to 7 miles per hour.",
-    "The average number of guests that attend a wedding in the U.S. is 189.",
-    "Over 200 varieties of watermelons are grown in the U.S.",
-    "Krispy Kreme make five million doughnut a day.",
-    "Fido means faithful in Latin.",
-    "The vegetable that is eaten most by Americans is potatoes. On average, a person eats about 140 pounds of potatoes annually.",
-    "Approximately 100,000 people get married in Las Vegas each year.",
-    "By federal law, for a noodle to actually be a noodle it must have 5.5 percent egg solids in it, otherwise it cannot be called a noodle.",
-    "In a lifetime, the average driver will honk 15,250 times.",
-    "Only two people signed the Declaration of Independence on July 4th, JohnHancock and Charles Thomson. Most of the rest signed on August 2, but the last signature wasn't added until 5 years later.",
-    "In China, fish is eaten more than three times what it is in the United States.",
-    "You can only smell 1/20th as well as a dog.",
-    "The only king without a moustache in a deck of cards is the king of hearts.",
-    "The adult human body requires about 88 pounds of oxygen daily.",
-    "The colour blue has a calming effect. It causes the brain to release calming hormones.",
-    "18% of an Americans income is spent on transportation.",
-    "Julie Nixon, daughter of Richard Nixon married David Eisenhower, grandson of Dwight Eisenhower.",
-    "Hockey pucks were originally made from frozen cow dung.",
-    "The name \"Muppet\" was coined by Jim Henson. The word was made from a combination of the word \"marionette\" and \"puppet.\".",
-    "The tallest freestanding sculpture in the world is Chief Crazy Horse in South Dakota, USA.",
-    "The expression \"Tying the Knot\" comes from an old Roman custom where the brides clothes were tied up all in knots and the groom was supposed to untie the knots.",
-    "On a bottle of brandy VSOP stand for \"Very Special Old Pale.\".",
-    "A butterfly can see the colors red, green, and yellow.",
-    "The longest town name in the world has 167 letters.",
-    "Bananas contain a natural chemical which can make a person happy. This same chemical is found in Prozac.",
-    "Humans breathe in and out approximately one litre of air in ten seconds.",
-    "The two top toys in 1950 were Silly Putty which sold for $1, and Crayola crayons which sold for 50 cents.",
-    "Annually 7 million tons of textiles and clothing is thrown out. Out of this, only 12% is used again or recycled.",
-    "The most common name in the world is Mohammed.",
-    "Lightning strikes the Empire States Building about seven times a year.",
-    "There are over 2,000 different types of cheese in the world.",
-    "Author Robert May considered the names of Reginald and Rollo before he settled on \"Rudolph, the red-nosed reindeer.\".",
-    "Montreal is actually located on an island.",
-    "A single chocolate chip gives enough energy to a human being to walk 150 feet.",
-    "Incas used to create pots in the shape of peanuts that were highly prized.",
-    "The Central African raffia palm is known to have the longest leaves. The leaves can measure up to 82.5 feet long.",
-    "Being lactose intolerant can cause chronic flatulence.",
-    "There are about 61,300 pizza restaurants in the United States of America.",
-    "Hawaiian alphabet has 12 letters.",
-    "Soy crayons have been invented to replace wax crayons and one acre of soybeans can produce over 80,000 crayons.",
-    "It takes a sloth up to six days to digest the food it eats.",
-    "The record for the longest Monopoly game played in a bathtub is ninety-nine hours.",
-    "The first time there was an instance where they had a separate toilet for women and men was in 1739 at a ball in Paris.",
-    "In 1970, Chip maker Intel purchased a pear orchard to build their corporate headquarters on.",
-    "Only President to win a Pulitzer: John F. Kennedy for \"Profiles in Courage\".",
-    "Most snakes have six rows of teeth.",
-    "The popular chocolate bar \"Three Musketeers\" got its name because when it was first introduced in 1932 there were three individual bars. The flavours were strawberry, chocolate, and vanilla.",
-    "Female alligators lay about 40 eggs that hatch in 60 - 70 days.",
-    "Japan uses the most energy per year than any other country.",
-    "The coconut is the largest seed in the world.",
-    "Pikeville, Kentucky consumes the most Pepsi per capita then any other American city.",
-    "The highest consumption of Pizza occurs during Super Bowl week.",
-    "Former U.S. President Franklin Pierce was arrested during his term as President for running over an old lady with his horse, but the charges were later dropped.",
-    "Before 1859, baseball umpires were seated in padded chairs behind home plate.",
-    "Baseball games between college teams have been played since the Civil War.",
-    "The most expensive perfume in the world is Parfum VI, which was made by Arthur Burnham. A 4 inch bottle which is covered with diamonds and 24-carat gold costs $71,380.",
-    "The largest known hailstone to have fallen was in Germany in 1925, which weighed close to four and a half pounds.",
-    "Bill Gates house was partially designed using a Macintosh computer. new.",
-    "The smoke that is produced by a fire kills more people than a burn does because of carbon monoxide and other dangerous gases.",
-    "More than half the time spent in United States courts is cases that involve automobiles.",
-    "The world record for rocking non-stop in a rocking chair is 480 hours held by Dennis Easterling, of Atlanta, Georgia.",
-    "In most advertisements, including newspapers, the time displayed on a watch is 10:10.",
-    "John Van Wormer invented paper milk cartons after dropping a bottle of milk one morning. The bottle broke spilling the milk everywhere. That annoyance was enough for Van Wormer to come up with the idea.",
-    "Pilgrims did not eat potatoes for Thanksgiving as they thought they were poisonous.",
-    "No other animal gives us more by-products than the hog. These by-products include pig suede, buttons, glass, paint brushes, crayons, chalk, and insulation to name a few.",
-    "The American Kestrel hawk weighs only four ounces.",
-    "All of the Peking ducks in the United States are descendents from three ducks and one drake imported to Long Island, New York in 1873.",
-    "In 1685, New France used playing cards as currency because of the shortage of coins.",
-    "Bill Gates began programming computers at age 13.",
-    "The Mexican version of the Tooth Fairy is known as the Tooth Mouse, which takes the tooth and leaves treasures in its place.",
-    "The garfish has green bones.",
-    "In 1991, during an attempted political coup on Russian President Boris Yelstin, food supplies had dwindled down at the parliament buildings so they ordered Pizza Hut to deliver pizzas.",
-    "The Chihuahua was named after the Mexican state where they were discovered.",
-    "Women take three times longer than men when using the toilet.",
-    "The amount of aluminum that Americans throw out in three months is enough to rebuild all American commercial planes.",
-    "An airplane mechanic invented Slinky while he was playing with engine parts and realized the possible secondary use for the springs. Barbie was invented by Ruth Handler after watching her daughter play with baby dolls imagining then in grown up roles.",
-    "Thomas Edison, the inventor of the light bulb was afraid of the dark.",
-    "Singer Chaka Khan came out with a line of chocolates called \"Chakalates.\".",
-    "In 1960 there were 16,067 gambling slots in Nevada. By 1999, this number rose to 205,726 slots which would be one slot for every 10 people residing there.",
-    "It is very common for babies in New Zealand to sleep on sheepskins. This is to help them gain weight faster, and retain their body heat.",
-    "In one night, an adult hippopotamus eats approximately 150 pounds of grass.",
-    "There are over 600 different pasta shapes.",
-    "In the USA, 32% of employees eat lunch and work at the same time.",
-    "The only two days of the year in which there are no professional sports games (MLB, NBA, NHL, or NFL) are the day before and the day after the Major League all-stars Game.",
-    "One gallon of pure maple syrup weighs 11 pounds.",
-    "In 1989, the space shuttle Discovery carried 32 fertilized chicken eggs into orbit.",
-    "Of all the countries, Brazil has the most plant species, with over 56,000.",
-    "There are about 6,800 languages in the world.",
-    "Alaska got its name from the Aluet word \"Alyeska\" which means \"The Great Land.\".",
-    "The first Tupperware item marketed was the seven-ounce bathroom cup in 1945.",
-    "On average, a hen lays 300 eggs per year.",
-    "On average men spend 51 minutes a day grooming themselves.",
-    "Chedder cheese is the best selling cheese in the USA.",
-    "In 1890, there was no sunshine for the whole month of December in Westminster in London.",
-    "A regulation baseball has exactly 108 stitches.",
-    "The food that is digested in your stomach is called \"chyme.\".",
-    "The dragonfly has not changed over the last 300 million years.",
-    "Davao City, located at the Southern state of Philippines, is the largest city in the world in terms of area.",
-    "Lake Malawi has the largest number of fish species in the world.",
-    "Pineapples were first called \"anana\", which is Caribbean for \"excellent fruit.\".",
-    "It takes about a half a gallon of water to cook macaroni, and about a gallon to clean the pot.",
-    "Serving ice cream on cherry pie was once illegal in Kansas.",
-    "The life expectancy of a $100 bill is nine years.",
-    "The five Olympic rings represent the five continents linked together in friendship.",
-    "The name of the award given to honor the best sites on the Internet is called \"The Webby Award.\".",
-    "Great Britain has the highest consumption of ice cream than any other European nation.",
-    "The Arctic Ocean covers an area of about 14,056,000 sq miles.",
-    "St. Louis, Missouri was the first U.S. city to host the summer Olympics in 1904.",
-    "A newborn kangaroo weighs approximately 0.03 ounces and is small enough to fit in a teaspoon.",
-    "In 1994, 7-Eleven coined the term \"brain freeze.\" The word was developed to explain the feeling people get when drinking a Slurpee.",
-    "Early sewing machines were destroyed by mobs or workers who felt their jobs were threatened by automation.",
-    "A cockroach can change directions up to 25 times in a second.",
-    "Club Direct, a travel insurance company in Britain, provides insurance plans for protection from falling coconuts.",
-    "The largest LEGO castle that was ever built was built with 400,000 LEGO bricks and was 4.45 m x 5.22 m.",
-    "A sheep, a duck and a rooster were the first passengers in a hot air balloon.",
-    "Forty-one percent of women apply body and hand moisturizer at least three times a day.",
-    "Elephants have been known to learn up to 60 commands.",
-    "The average cocoon contains about 300-400 metres of silk.",
-    "DC-10, the name of an airplane stands for \"Douglas Commercial.\".",
-    "Anise is the scent on the artificial rabbit that is used in greyhound races.",
-    "On average, a strawberry has 200 seeds on it.",
-    "The nickname for a Japanese businessmen is \"Salarymen.\".",
-    "The dot that appears over the letter \"i\" is called a tittle.",
-    "The most popular meal ordered at restaurants in the U.S. is fried chicken.",
-    "The word \"umbrella\" is derived from the Latin root word \"umbra\", which means shade or shadow.",
-    "India has the most post offices in the world.",
-    "4% of an apples is made up of minerals and vitamins, and over 80% is made up of water.",
-    "Thirty-five percent of the people who use personal ads for dating are already married.",
-    "Water that is safe to drink is referred to as POTABLE.",
-    "By recycling just one glass bottle, the amount of energy that is being saved is enough to light a 100 watt bulb for four hours.",
-    "To be born on Sunday was considered a sign of great sin during the Puritan times.",
-    "Walmart-mart sells more apparel a year than all the other competing department stores combined.",
-    "The silk that is produced by spiders is stronger than steel.",
-    "Teflon was accidently discovered by scientist Dr. Roy Plunkett while he was conducting a coolant gas experiment in 1938.",
-    "The puma and the leopard are the highest jumping mammals. They are able to reach a height of 16.5 feet.",
-    "Humans are the only primates that don't have pigment in the palms of their hands.",
-    "In a year approximately 900 million trees are cut down to make the raw materials needed for American pulp mills and paper.",
-    "A Connecticut Toy maker, Herobuilders, sells action figures of President George W. Bush, Islamic militant Osama bin Laden, New York Mayor Rudolph Giuliani and British Prime Minister Tony Blair, which are all major figures tied to the September 11, 2001 WTC attacks.",
-    "A sneeze can travel as fast as one hundred miles per hour.",
-    "Ten radishes only contain eight calories.",
-    "Eating a banana at night can help in falling asleep.",
-    "About 30% of Canadians rely on getting their water from the ground for their domestic use.",
-    "Early Romans used to use porcupine quills as toothpicks.",
-    "The game of squash originated in the United Kingdom. It came about after a few boys, who were waiting for their turn to play racquets, knocked a ball around in a confined area adjoining the racquets court.",
-    "Four out of five brides in the U.S. have a job.",
-    "The first Olympic games only had one event - a foot race.",
-    "The biggest hamburger that was served was 8,266 pounds. It was made at the Burger Fest in Seymour, Wisconsin.",
-    "The smallest bone in the human body is the stapes bone which is located in the ear.",
-    "Dill seeds are so small that approximately 10,000 dill seeds would be required to make an ounce.",
-    "Lake Nicaragua boasts the only fresh-water sharks in the entire world.",
-    "Every single hamster in the United States today comes from a single litter captured in Syria in 1930.",
-    "There are 50% more males that are left handed compared to females.",
-    "Men can read smaller print than women; women can hear better.",
-    "In China, people eat a bar of chocolate for every 1,000 chocolate bars eaten by the British.",
-    "Mangos are valued for their reputation as an aphrodisiac in countries such as Guatemala and India.",
-    "Emus cannot walk backwards.",
-    "In 1969, the American side of Niagara Falls was stopped completely for several months.",
-    "The platypus uses its bill to find animals that it feeds on. Its bill can sense the tiny electric fields that their preys emit.",
-    "Slaves under the last emperors of China wore pigtails so they could be picked out quickly.",
-    "Mars is the home of Olympus Mons, the largest known volcano in our solar system.",
-    "Women who drink more than two cups of coffee a day have a higher chance of developing osteoporosis.",
-    "Pluto is the only planet in our solar system that has not been visited by a spacecraft.",
-    "Chinese Crested dogs can get acne.",
-    "In 1952, the first TV toy commercial aired. It was for Mr. Potato Head.",
-    "Some toothpastes contain antifreeze.",
-    "Watermelon is considered a good gift to give a host in Japan and China.",
-    "The actor who played the T-1000 in Terminator 2 (Robert Patrick) and the lead singer of Filter are brothers.",
-    "Mules have one horse and one donkey for a parent.",
-    "January is named for the Roman god Janus. Janus was a temple god who could look forward and backward at the same time.",
-    "The smallest bone in the human body is the stirrup bone, which is located in the ear.",
-    "In July 1874, a swarm of Rocky Mountain locusts flew over Nebraska covering an area estimated at 198,600 square miles. It is estimated that the swarm contained about 12.5 trillion insects. These insects became extinct thirty years later.",
-    "In 1976, a Los Angeles secretary named Jannene Swift officially married a fifty pound rock. More than twenty people witnessed the ceremony.",
-    "The steepest street in the world is Baldwin Street located in Dunedin, New Zealand. It has an incline of 38%.",
-    "The oldest inhabited house in Scotland is the Traquair Castle. The castle has had 27 kings as visitors.",
-    "Brazil produces the most oranges in the world.",
-    "In 1963, Mister Rogers was ordained as a Presbyterian minister.",
-    "In the U.S., over one million gallons of cosmetics, drinks, and lotions are sold that contain aloe in them per year.",
-    "In the spring of 1975, a baby in Detroit fell 14 stories and landed on Joseph Figlock, who was walking below. A few years later it happened again. Figlock and both babies survived.",
-    "In 410 A.D. Alaric the Visigoth demanded that Rome give him three thousand pounds of pepper as ransom.",
-    "Depending on the size, it can take an oyster anywhere from few months to many years to form a pearl.",
-    "Average age of top GM executives in 1994: 49.8 years. Average age ofthe Rolling Stones: 50.6.",
-    "The production of toilet paper in China began in 1391, which was used for the Emperors.",
-    "The first museum in Moscow that was set up in 1791 was the Natural History Laboratory at Moscow University. This later was changed to the Zoological Museum.",
-    "Princess Anne from the British royal family competed in the 1976 Summer Olympics.",
-    "Adult earwigs can float in water for up to 24 hours.",
-    "Whale oil was used in some car transmissions until 1973.",
-    "Everyday, U.S. business use enough paper to circle the Earth over 20 times.",
-    "Men are four times more likely to be struck by lightning than women.",
-    "Doctors in Canada use an adhesive similar to Krazy Glue instead of stitches, lowering the possibility of bacterial infection and minimizing scarring.",
-    "The word \"maverick\" came into use after Samuel Maverick, a Texan, refused to brand his cattle. Eventually any unbranded calf became known as a Maverick.",
-    "Strawberries are a member of the rose family.",
-    "An earthquake on Dec. 16, 1811 caused parts of the Mississippi River to flow backwards.",
-    "A house cat spends 70% of its time sleeping.",
-    "Penguins can jump as high as 6 feet in the air.",
-    "Bats always turn left when exiting a cave.",
-    "Every year, more than one million miles of Twizzlers licorice is made.",
-    "About 1 in 5,000 North Atlantic lobsters are born bright blue.",
-    "The name \"cranberry\" comes from German and Dutch settlers. The berry was initially called \"crane berry.\" The reason it was called this was because when the flowers bloom, the petals of the flowers twist backwards and look very much like the head of a crane. Eventually the name was shortened down to be \"cranberry.\".",
-    "The cost to build the Empire State Building was $40,948,900.",
-    "A dog by the name of Laika was launched into space aboard the Russian spacecraft Sputnik 2 in 1957.",
-    "The first hot air balloon flight traveled for 5.5 miles over Paris and lasted for 23 minutes.",
-    "When playing competitive darts the player must be 7 feet 9 1/4 inches back from the dartboard. Also the board must be 5 feet 8 inches above the floor.",
-    "In Britain, The Red Lion is the most common name for a pub.",
-    "The town of Churchill, Manitoba, located in Canada, is known as the \"Polar Bear Capital of the World\".",
-    "Every continent has a city called Rome.",
-    "A colony of bees have to fly almost fifty-five thousand miles and tap two million flowers to make one pound of honey.",
-    "An adult porcupine has approximately 30,000 quills on its body, which are replaced every year.",
-    "The word tulip comes from the Turkish word for turban.",
-    "If the population of China walked past you in single file, the line would never end because of the rate of reproduction.",
-    "The Goliath beetle is about the size of your fist and can weigh as much as 3-4 ounces.",
-    "The giant squid has the largest eyes in the world.",
-    "In 1873, Colgate made a toothpaste that was available in a jar.",
-    "The odds of having quadruplets are 1 in 729,000.",
-    "Niagara Falls actually stopped flowing back in 1848 for about 20 hours because there was ice that was blocking the Niagara River.",
-    "Adolf Hitler was one of the people that was responsible in the creation of the Volkswagen Beetle. He came up with the idea of producing a car that was cheap enough for the average German working man to afford.",
-    "The name \"Lego\" came from the Danish word LEg Godt, which means \"play well.\".",
-    "The full name of the Titanic ship is R.M.S. Titanic, which stands for Royal Mail Steamship.",
-    "Crabs have very small hairs on their claws and other parts of their body to help detect water currents and vibrations.",
-    "In 1978, the World Water Speed record was made by Ken Warby from Australia. His average speed was 317.6 mph, and his jet-powered hydroplane was 27 feet long called \"Spirit of Australia.\" Warby built the boat himself in his back yard.",
-    "Mary Hart, the co-anchor of Entertainment Tonight, has each of her legs insured for one million dollars.",
-    "The Cincinnati Reds are the oldest professional baseball team.",
-    "Early hockey games allowed as many as 30 players a side on the ice.",
-    "The average Super Bowl party has 18 people.",
-    "A language becomes extinct in this world every two weeks.",
-    "In 1832, in Paisley, Scotland the first municipal water filtration works was opened.",
-    "The microwave was invented after a researcher walked by a radar tube and a chocolate bar melted in his pocket.",
-    "The stomach of an adult can hold 1.5 liters of material.",
-    "Bo Jackson set a Monday Night Football record by rushing for 222 yards in one game against the Seattle Seahawks, including a 91-yard TD run.",
-    "The trunk of an elephant can hold up to two gallons of water.",
-    "On average, pigs live for about 15 years.",
-    "Russian I.M. Chisov survived a 21,980 plunge out of a plane with no parachute. He landed on the steep side of a snow-covered mountain with only a fractured pelvis and slight concussion.",
-    "The oldest roller coaster in the world is the Leap-The-Dips roller coaster located in Lakemont Park in Pennsylvania. The roller coaster was built in 1902.",
-    "Close to fifty percent of the bacteria in the mouth lives on the surface of our tongue.",
-    "Montreal has an underground city, which has over 2,000 shops and 26 kilometres of walkways. This is the largest underground network for any city.",
-    "Stalks of sugar cane can reach up to 30 feet.",
-    "There are 10 million bacteria at the place where you rest your hands at a desk.",
-    "Michael Jordan makes more money from Nike annually than all of the Nike factory workers in Malaysia combined.",
-    "Soaking beans for twelve hours in water before they are cooked can reduce flatulence caused by beans.",
-    "On average, a typical dairy cow lies down and stands up about 14 times a day.",
-    "Pepper was sold as individual grains during the Elizabethan times. The guards at the London docks had to sew up their pockets so they would not steal any of the pepper.",
-    "The University of Plymouth was the first university to offer a degree in surfing.",
-    "In 1888, Hollywood was founded by Harvey and Daeida Wilcox, who named the city after their summer home in Chicago.",
-    "George Washington grew hemp in his garden.",
-    "The deepest cave in the world is the \"Lamprechtsofen-Vogelshacht\" cave which can be found in Salzburg, Austria. The cave is 5,354 feet deep.",
-    "Indonesia consists only of islands - 13,667 total.",
-    "There are approximately 75,000,000 horses in the world.",
-    "The two factories of the Jelly Belly Candy Company produces approximately 100,000 pounds of jelly beans a day. this amounts to about 1,250,000 jelly beans an hour.",
-    "Tohru Iwatani, the inventor of the video game Pac-Man, came up with the idea when he saw a pizza with a slice missing at a dinner party.",
-    "Chopsticks originated from China approximately 4,000 years ago.",
-    "Bats can detect food up to 18 feet away and what type of insect the food may be using their sense of echolocation.",
-    "The worlds tallest free fall rollercoaster is The Giant Drop located in Australia. The drops is 120 meters which is equivalent to a 39 storey building.",
-    "Infants spend more time dreaming than adults do.",
-    "The laundry detergent Tide, has a market share of about forty percent market.",
-    "The YKK that you see on zippers stands for Yoshida Kogyo Kabushiki Kaisha which is the name of the founder of the zipper manufacturing company in Japan.",
-    "Mosquitoes are attracted to the color blue more than any other color.",
-    "96% of people put the peanut butter on first when making a peanut butter and jelly sandwich.",
-    "Ukrainian people celebrate Christmas on January 7th, which is the Orthodox Christmas Day.",
-    "Acorns were used as a coffee substitute during the American Civil War.",
-    "The average price for a major league baseball game in 2004 is $19.82.",
-    "On average, the Pentagon uses 666 rolls of toilet paper in one day.",
-    "The average lifespan of a human taste bud is ten days.",
-    "According to the American Institute of Stress, job stress approximately costs the U.S. industry over $300 billion dollars per year.",
-    "The sentence \"the quick brown fox jumps over the lazy dog\" uses every letter in the english language.",
-    "Shridhar Chillal from India is known to have the record for the longest fingernails in the world, which were each at least three feet long.",
-    "Of married couples, 70% of men and 60% of women have cheated on their spouse.",
-    "People from the United States eat the most chicken per person than anywhere else in the world.",
-    "Harley Proctor got the idea to name the soap \"Ivory\" while he was listening to a bible reading at a church in 1879.",
-    "Touching and stroking a plant will aid in it growing healthy.",
-    "Former U.S. president Ronald Reagan worked as a lifeguard in his youth at a beach near Dixon, Illinois and saved over 77 lives.",
-    "Airports that are at higher altitudes require a longer airstrip due to lower air density.",
-    "The human body makes anywhere from 1 to 3 pints of saliva every 24 hours.",
-    "The word \"diastema\" is the word for having a gap between your teeth.",
-    "The fastest bird in the world is the Peregrine Falcon, which can reach speeds in excess of two hundred miles per hour.",
-    "In 1894, the carnival made its debut in North America.",
-    "Kiwis are the only known bird to have nostrils located at the tip of their beak.",
-    "The strike note of the Liberty Bell in Philadelphia, Pennsylvania is e-flat.",
-    "In 1747, the first American mention of the Christmas tree occurred. However, it was a not a tree but instead a pyramid made out of wood and decorated with apples and evergreen boughs.",
-    "The pound key (#) on the keyboard is called an octothorpe.",
-    "Polar bears have been known to swim more than 60 miles without resting.",
-    "The country of Fiji is made up of 332 islands.",
-    "Only 55 percent of all Americans know that the sun is a star.",
-    "Polar bears are excellent swimmers. They have been known to swim more than 60 miles without a rest.",
-    "Pearls are rarely found in North American oysters.",
-    "Coconuts kill more people in the world than sharks do.",
-    "Wham-O manufactured twenty-thousand hula-hoops a day at the peak of hula-hoop popularity in 1958.",
-    "The average weight of a newborn baby is 7 lbs. 6 oz. For a triplet baby it is 3 lbs. 12 oz.",
-    "France is known as the perfume capital of the world.",
-    "Nylon is a man-made fibre that is made from coal and petroleum.",
-    "To manufacture a new car approximately 148,000 liters of water is needed.",
-    "Pixie, a Siberian Husky, gave birth to 7 puppies, one of which was bright green.",
-    "On average, the ratio of yellow kernels to white kernals in a bag of popcorn is 9:1.",
-    "Singer Michael Jackson owns the rights to the South Carolina State anthem.",
-    "The actual smallest sovereign entity in the world is the Sovereign Military Order of Malta (S.M.O.M.). It is located in the city of Rome, Italy, has an area of two tennis courts, and as of 2001 has a population of 80, 20 less people than the Vatican. It is a sovereign entity under international law, just as the Vatican is.",
-    "66% of wedding cards are hand delivered by people.",
-    "France, Switzerland, United Kingdom, Greece, and Australia have always been in the modern Olympics since it began in 1896.",
-    "The reason why golf balls have dimples on them is because it helps in the ball to move a farther distance by reducing drag.",
-    "Oak trees can live 200 or more years.",
-    "It is impossible to sneeze with your eyes open.",
-    "Peanut butter is an effective way to to remove chewing gum from hair or clothes.",
-    "The Tonle Sap River in Cambodia flows north for almost half the year and then south for the rest of the year.",
-    "Squids move through the ocean using a jet of water forced out of the body by a siphon.",
-    "Native Indians have been known to paint their doors blue, which they believe keeps the bad spirits out.",
-    "Atlantic salmon can jump as high as 4.5 meters out of the water.",
-    "Charles Darwin spent 39 years studying earthworms.",
-    "Actress Jamie Lee Curtis invented a special diaper for babies that has a pocket.",
-    "The three wealthiest families in the world have more assets than the combined wealth of the forty-eight poorest nations.",
-    "The age of a saguaro cactus is calculated by its height.",
-    "Scientists with high-speed cameras have discovered that rain drops are not tear shaped but rather look like hamburger buns.",
-    "The only flying saucer launch pad in the world is located in St. Paul, Alberta, Canada.",
-    "In 1992, when EuroDisney first opened in France, the public beat some of the park characters because at the time most people had been against the park being built.",
-    "The hump of a camel can weigh up to 35 kilograms.",
-    "Saturday is the most popular day for people to eat out in the United States.",
-    "The iron disulfide (Pyrite) is considered \"fool's gold\" because it looks very similar to gold.",
-    "In the late 1800's, washing machines and butter churners were sometimes powered by dogs walking on treadmills.",
-    "Consuming chocolate was once considered a sin during the 16th and 17th century. During that time it was provided in the form of a drink and since drinking wine during lent was a sin, so was drinking chocolate.",
-    "The word \"checkmate\" in chess comes from the Persian phrase \"Shah-Mat,\" which means the king is dead.",
-    "Every three seconds a baby is born somewhere in the world.",
-    "The only desert in Canada is located in Osoyoos, British Columbia.",
-    "The first cheerleaders in the U.S. were men.",
-    "A small drip from a faucet can waste up to 50 gallons of water daily, which is enough water to run a dishwasher twice on a full cycle.",
-    "Donkeys can live between 30 to 50 years in captivity.",
-    "The Golden Gate Bridge was first opened in 1937.",
-    "The makers of Crayola produce over 2 billion crayons in a year.",
-    "If Wal-Mart was classified as a country, it would be the 24th most productive country in the world.",
-    "The New Zealand Kiwi bird cannot fly.",
-    "A Russian man who wore a beard during the time of Peter the Great had to pay a special tax.",
-    "The reason why locusts swarm are because when they are in groups, a \"hot-spot\" behind their hind legs is stimulated, which in turn causes their destructive nature. A large swarm of locusts can eat eighty thousand tons of corn in a day.",
-    "The word \"sophomore\" means \"sophisticated moron.\".",
-    "Karate actually originated in India, but was developed further in China.",
-    "Each year, Americans throw away 25 trillion Styrofoam cups.",
-    "One of the steepest main streets in Canada is located in Saint John, New Brunswick. Over a distance of two blocks the street rises about 80 feet.",
-    "Baby robins eat 14 feet of earthworms every day.",
-    "In the United States, the first cookbook was published in 1796 and it contained a recipes for watermelon rind pickles.",
-    "The first company to mass produce teddy bears was the Ideal Toy Company.",
-    "The only lizard that has a voice is the Gecko.",
-    "There are 158 verses in the Greek National Anthem.",
-    "Even though a polar bears fur looks white it is actually colourless and is made with hollow tubes. The reason the bear looks white is because the rough inner surface of the tubes make light scatter and reflect at many different angles which gives the white appearance.",
-    "In Denmark, people eat about 36 pounds of candy a year. The highest consumption of candy of any country.",
-    "The first television show to show any portion of a toilet was on \"Leave it to Beaver.\" After fighting for ten weeks to show the toilet, CBS would only allow the producers to show the toilet tank, and not the whole toilet.",
-    "Colonel Sanders traveled over 250,000 miles a year visiting various parts of his Kentucky Fried Chicken Empire.",
-    "There are five years in a quinquennium.",
-    "The human face is made up of 14 bones.",
-    "The peanut is not a nut, it is actually a legume.",
-    "40 percent of the almonds in the world are used by manufacturers of chocolate.",
-    "Hair and fingernails are made from the same substance, keratin.",
-    "The only popcorn museum in the world is lcoated in Marion, Ohio, USA.",
-    "David Rice Atchinson was President of the United States for exactly one day. This happened due to a glitch in American law at the time. new.",
-    "The most common injury caused by cosmetics is to the eye by a mascara wand.",
-    "A mother hen turns her egg approximately 50 times in a day. This is so the yolk does not stick to the shell.",
-    "Before air conditioning was invented, white cotton slipcovers were put on furniture to keep the air cool.",
-    "The purpose of tonsils is to destroy foreign substances that are swallowed or breathed in.",
-    "Adolf Hitler loved chocolate cake.",
-    "The material to build the Taj Mahal was brought in from various parts of India by a fleet of 1000 elephants.",
-    "A mole can dig a tunnel three hundred feet long in a single night.",
-    "Ellen Macarthur, yachtswoman, had a total of 891 naps in 94 days that were each 36 minutes long while on her Vendee Round the Globe yacht race.",
-    "The movie \"Cleopatra\" cost $44 million to make in 1963. The same movie would now cost $300 million to make taking inflation into account.",
-    "An adult \"Gold Frog\" measures to be 9.8 millimeters in body length.",
-    "A volcano has enough power to shoot ash as high as 50 km into the atmosphere.",
-    "Levan, which is located in Utah, got its name from \"navel\" which is levan spelt backwards. It was named this because it is in the center of Utah.",
-    "German immigrant, Louis Prang was the first to bring Christmas cards to America.",
-    "Approximately 75% of human poop is made of water.",
-    "It was believed by Ancient Hindus that the world was a sphere and rested on the back of four elephants, which stood on a turtle.",
-    "Japanese research has concluded that moderate drinking can boost IQ levels.",
-    "Almonds are members of the peach family.",
-    "Belize is the only country in the world with a jaguar preserve.",
-    "The human liver performs over 500 functions.",
-    "In the United States, about 33% of land is covered by forests.",
-    "The early occurrence of a fetus yawning is at eleven weeks after conception.",
-    "Sheep can detect other sheep faces like humans do. They can remember up to 50 sheep faces.",
-    "The word breakfast was coined due to the fact that after sleeping for hours, we are \"breaking our fast.\".",
-    "On average, a car driver will swear or blashpheme 32,025 times in their lifetime while driving.",
-    "Actor Charlie Chaplin made 81 movies over a career that spanned 50 years.",
-    "It is common in Israel and Egypt to eat watermelon with feta cheese.",
-    "A common name for pincurls is also spitcurls because woman sometimes wet their hair with their saliva before curling it.",
-    "Every 30 seconds a house fire doubles in size.",
-    "In New Mexico, over eleven thousand people have visited a tortilla chip that appeared to have the face of Jesus Christ burned into it.",
-    "Many years ago, a fish was caught that was 33 inches long and seemed to be heavier than it should. When they cut the fish, fishermen found a full of bottle of ale inside it.",
-    "Wheel of Fortune star Vanna White holds the record for putting her hands together approximately 140,000 times to clap.",
-    "TYPEWRITER, is one of the longest words that can be made using the letters only one row of the keyboard.",
-    "The name \"Tonka\" trucks was named after Lake Minnetonka located in Minnesota. Tonka means \"great\" in Sioux.",
-    "Over 20 million BluBlocker sunglasses have been sold since its debut in 1986. They now come in over 100 different styles.",
-    "A cat has 32 muscles in each ear.",
-    "About twenty-five percent of the population sneeze when they are exposed to light.",
-    "An ant can detect a movement through 5 centimeters of earth.",
-    "On average, 749 pounds of paper products is used by an American individual annually.",
-    "The \"Mexican Hat Dance\" is the official dance of Mexico.",
-    "Post-It Notes, which are adhesive notes, were invented while looking for a way to improve the acrylate adhesive found in tapes.",
-    "Polar bears can smell seal from 20 miles away.",
-    "In 1916, Charlie Chaplin was making $10,000 a week, making him the highest paid actor of his time.",
-    "The DNA of humans is closer to a rat than a cat.",
-    "Each year 96 billion pounds of food is wasted in the U.S.",
-    "On November 29, 2000, Pope John Paul II was named an \"Honorary Harlem Globetrotter.\".",
-    "There are about 34,000 species of spiders.",
-    "Fried spiders taste like nuts.",
-    "In Ireland, a prime minister is a called a Taoiseach.",
-    "The only real person to be a Pez head was Betsy Ross.",
-    "A person that is struck by lightning has a greater chance of developing motor neurone disease.",
-    "Thirty percent of all bingo players are under the age of 35.",
-    "The Bible has been translated into Klingon.",
-    "There are over 2,000 different species of cactuses.",
-    "The mother of famous astronomer Johannes Kepler was accused of being a witch.",
-    "In the movie \"Babe\", the piglet was played by over 30 different piglets they outgrew the part so quickly during the production of the film.",
-    "Research indicates that mosquitoes are attracted to people who have recently eaten bananas.",
-    "The word \"America\" comes from the European explorer \"Amerigo Vespucci.",
-    "The only two days of the year in which there are no professional sports games (MLB, NBA, NHL, or NFL) are the day before and the day after theMajorLeague All-Star Game.",
-    "A leech has 32 brains.",
-    "Before 1883, the three-cent U.S. stamp was also used for advertising. The advertisment was located on the back of the stamp for various products.",
-    "One barrel of petroleum holds 42 gallons.",
-    "Singer Alice Cooper once had a live chicken thrown at him during a concert in Toronto. He threw the chicken back at the crowd and all the publicity surrounding the incident skyrocketed his singing career.",
-    "One pound of maple syrup can make eight pounds of candy or sugar.",
-    "The highest bridge in the world is located in the Himalyan mountains. It was built by the Indian Army, in 1982, and is about 5,600 metres above sea level.",
-    "Ukrainian monk, Dionysius Exiguus, created the modern day Christian calendar.",
-    "In 1953, racecar driver Tim Flock raced at Nascar with a monkey in the seat beside him.",
-    "When explorers first arrived in Venezuela, they were reminded of Venice. They named the country \"Little Venice\", which translated into Spanish is Venezuela.",
-    "The capital of Vermont, Montpelier is the only state capital in the United States that does not have a McDonalds.",
-    "In India, pickled ginger, minced mutton and a cottage cheese like substance are popular pizza toppings.",
-    "Milk and cheese can aid in the reduction of tooth decay.",
-    "After the \"Popeye\" comic strip was launched in 1931, spinach consumption went up by thirty-three percent in the United States.",
-    "75% of all raisins eaten by people in the United States are eaten at breakfast.",
-    "There is a large brass statue of Winnie-the-Pooh in Lima, Peru.",
-    "Before the 17th century, carrots used to be the colour purple.",
-    "In the United States, lightning hits the ground 40 million times a year.",
-    "Every year, Burger King restaurants prepare over 950,000 pounds of bacon for their breakfast customers.",
-    "As an iceberg melts, it makes a fizzing sound because of the compressed air bubbles popping in the ice.",
-    "27% of female lottery winners hid their winning ticket in their bras.",
-    "Construction workers hard hats were first invented and used in the building of the Hoover Dam in 1933.",
-    "A seagull can drink salt water because it has special glands that filter out the salt.",
-    "The palms of your hands and the soles of your feet cannot tan, or grow hair.",
-    "Bugs Bunny was originally called \"Happy Rabbit.\".",
-    "The first product that the toy company Mattel came out with was picture frames.",
-    "People with darker skin will not wrinkle as fast as people with lighter skin.",
-    "A dentist from Buffalo New York named Alfred P. Southwick invented the electric chair.",
-    "Goat meat contains up to 45 percent less saturated fat than chicken meat.",
-    "In ancient Egypt, the only person who was allowed to wear cotton was the High Priest.",
-    "The name Wendy was made up for the book \"Peter Pan.\".",
-    "Dolphins sleep with one eye open.",
-    "Unlike a frog a toad cannot jump.",
-    "People living on the east coast prefer creamy peanut butter, while people living on the west coast prefer chunky peanut butter.",
-    "The male howler monkey of Central and South America is the noisiest land animal, which can be heard clearly from a distance of ten miles away.",
-    "Polar bears can smell seals who are 20 miles away.",
-    "Crayola Crayons currently has over 120 different crayon colors.",
-    "Canadians eat more Kraft Dinner (Macaroni and Cheese) per capita than any other country in the world.",
-    "From all the states, Montana has the most different species of animals.",
-    "\"Go.\" is the shortest complete sentence in the English language.",
-    "On average, 350 squirts are needed from milking a cow to make a gallon of milk.",
-    "The only South East Asian country that has never been colonized by a Western Power is Thailand.",
-    "In ancient Egypt, the dung beetle symbolized eternal rebirth and the Sun God Khepri.",
-    "Approximately 10.5 gallons of water is used in a dishwasher. Washing the dishes by hand can use up to 20 gallons of water.",
-    "The Eiffel Tower weight is approximately 9441 tons.",
-    "In 2002, the most popular car color in North America was silver.",
-    "The average person changes their career every 13 years.",
-    "Impotence is grounds for divorce in 26 U.S. states.",
-    "The most reproduced image in the world is Mickey Mouse, which can be found on over 7,500 different items.",
-    "The average temperature on Earth is 15 degrees celcius.",
-    "The Australian box-jellyfish has eight eyes.",
-    "In 1693, the postage rate of a letter was determined by how much light went through the letter. The less the light went through the letter the more expensive the rate would be. This technique was referred to as candling.",
-    "The majority of American models are skinnier than 98% of American women.",
-    "The sentence \"The quick brown fox jumps over the lazy dog.\" uses everyletter in the alphabet. (Developed by Western Union to Test telex/twxcommunications).",
-    "85% of weddings are held in a synagogue or church.",
-    "When a predator is chasing an impala, a type of antelope, it runs in a zig zag formation jumping as high as three metres.",
-    "The only state to grow pineapples is Hawaii.",
-    "When Heinz ketchup leaves the bottle, it travels at a rate of 25 milesper year.",
-    "In a year, the average Americans eats approximately 18 pounds of Turkey.",
-    "On Sunday, December 7, 1941 at 7:55 AM, the attack on Pearl Harbor commenced.",
-    "Japan has approximately 200 volcanoes and is home to 10% of the active volcanoes in the world.",
-    "When the Pez mint dispenser was first introduced it was meant to replace the activity of smoking.",
-    "Tripolini pasta was named for the Italian conquest of Tripoli in Libya.",
-    "Thirty to 40 gallons of sugar maple sap must be boiled down to make just one gallon of maple syrup.",
-    "The longest freshwater shoreline in the world is located in the state of Michigan.",
-    "A duck's quack doesn't echo, and no one knows why.",
-    "For people that are lactose intolerant, chocolate aids in helping milk digest easier.",
-    "From 1526 to 1707, the first six Mogul emperors of India ruled in unbroken succession from father to son.",
-    "The 1912 Olympics was the last Olympics that gave out gold medals that were made entirely out of gold.",
-    "The capital of Burkina Faso is Ouagadougou.",
-    "All of Chrysler's PT Cruisers are built in Mexico.",
-    "The 1912, a wrestling match in Stockholm between Finn Alfred Asikainen and Russian Martin Klein lasted more than 11 hours. Klein eventually won, but was to tired to participate in the championship match.",
-    "The name \"Snickers\" for the popular candy bar was named after a horse that the Mars family owned.",
-    "A honey bee has four wings.",
-    "Spiders usually have eight eyes, but still they cannot see that well.",
-    "Colonel Sanders original white suit was auctioned off for $80,000 in February 2002.",
-    "In China, September 20 is \"Love Your Teeth Day.\".",
-    "The 1988 move \"Big\" which was directed by Penny Marshall was the first movie by a female director to gross over $100 million domestically.",
-    "An oyster can change its gender.",
-    "When Coca-Cola began to be sold in China, they used characters that would sound like \"Coca-Cola\" when spoken. Unfortunately, what they turned out to mean was \"Bite the wax tadpole\". It did not sell well.",
-    "The city of Tokyo was originally called Edo.",
-    "Being a weeb doesn't win you a Rocket League match. - Alawapr",
-    "The world record for time without sleep is 264 hours (11 days) by Randy Gardner in 1965.",
-    "Australia has had stamps that actually look like gems. In 1995 and 1996 they used a special technology to make the stamps look like diamonds and opals.",
-    "The U.S. paid Russia $7.2 million for Alaska in 1867.",
-    "After twenty-seven years, Betty Rubble made her debut as a Flintstones Vitamin in 1996.",
-    "The book \"Little Red Riding Hood\" was banned in 1990 by two school districts in California. They did this because in the book there was a picture of a basket that had a bottle of wine in it.",
-    "31% of employees skip lunch entirely.",
-    "66% of home based businesses are owned by women.",
-    "An elephant cannot jump.",
-    "Every square inch of the human body has about 19,000,000 skin cells.",
-    "Chef Boyardee is actually a real person. His real name is Hector Boiardi and he was born in northern Italy in 1898.",
-    "The first Ford cars had Dodge engines.",
-    "It takes seven to ten days to make a jelly belly jellybean.",
-    "Every 25 miles a car produces one pound of pollution.",
-    "Dexter is the smallest type of cow. This cow was bred to be a small size for household living.",
-    "The word \"diamond\" comes from the Greek word \"adamas,\" which means \"unconquerable.\".",
-    "Research indicates that babies who suck on pacifiers are more prone to ear aches.",
-    "The first African-American to receive a Nobel Peace Prize was Ralph J. Bunche in 1950.",
-    "Natural gas does not have any odor. In order to detect a gas leak, some gas companies add a chemical that smells similar like rotten eggs.",
-    "The first Life Saver flavour, which was peppermint, was invented in 1912 and it was called Pep-O-Mint.",
-    "The Olympics were originally held for the Greek god Zeus.",
-    "The word \"nerd\" was first coined by Dr. Suess in the book \"If I Ran to the Zoo.\".",
-    "To tell if a egg is fully cooked or raw, just spin it. If the egg wobbles then it is still raw, and if it easily spins it is fully cooked.",
-    "Throughout the South, peanuts were known as \"Monkey Nuts,\" and \"Goober peas,\" before the civil war.",
-    "The most popular American city for Kool-Aid sales is St. Louis, Missouri.",
-    "Annually 17 tons of gold is used to make wedding rings in the United States.",
-    "The first commercial chewing gum was sold in 1848 by John B. Curtis, who also made the gum. He called the gum \"State of Maine Pure Spruce Gum.\".",
-    "At 120 miles per hour, a Formula One car generates so much downforce that it can drive upside down on the roof of a tunnel.",
-    "At one time, pumpkins were recommended for removing freckles.",
-    "Bill Russell was the first black head coach of a major league pro sports team.",
-    "No word in the English language rhymes with month, orange, silver, and purple.",
-    "In the original movie \"101 Dalmatians,\" there are exactly 6,469,952 spots on all 101 Dalmatians as they are shown in 113,760 frames of the film combined.",
-    "Urophobia is the fear of urine or urinating.",
-    "Minimum wage was 0.25 per hour when it was first enacted in 1938.",
-    "Every person has a unique tongue print.",
-    "Every day, over 1,300 babies are born prematurely in the USA.",
-    "Pound for pound, hamburgers cost more than new cars.",
-    "In an episode of the 1962 cartoon The Jestons, Jane Jetson is talking through a video phone. The phone number of The Jetsons was VENUS-1234.",
-    "In Los Angeles, there are fewer people than there are automobiles.",
-    "A cow averages 40,000 jaw movements a day.",
-    "Tomatina is the legendary Spanish tomato-throwing festival held in Bunol, Spain.",
-    "The largest apple pie ever baked was forty by twenty three feet.",
-    "Traditionally, wild cabbage was used as an aphrodisiac.",
-    "Venus is the only planet that rotates clockwise.",
-    "Enamel is hardest substance in the human body.",
-    "The hydra, which is related to the jellyfish, can grow its body back in a couple of days if it is cut in half.",
-    "The human heart beast roughly 35 million times a year.",
-    "False Bay, on the southern tip of Africa and close to Cape Town, South Africa, is a breeding ground for great white sharks, which feed off the thousands of seals in the bay. However, it is the only area in the known world in which these sharks are known to breach - they attack the seals by coming up vertically, often leaping clear of the water with their prey in their mouths.",
-    "To make an espresso 42 coffee beans are needed.",
-    "A chance of a woman having twins is increased after the age of 35. About 1 in 27 women will give birth to twins after this age. After 50 the chances of having twins is 1 in 9.",
-    "99% of the blueberries that are produced in the United States are produced in the state of Maine.",
-    "The sole purpose of a drone bee is to mate with the queen bee.",
-    "The king of hearts is the only king without a mustache.",
-    "Dolphins hear by having sound waves transmit through their skull to their inner ear region.",
-    "An adult sheep can eat between 1 to 4 kg of food per day.",
-    "It takes about three hours for food to be broken down in the human stomach.",
-    "German cockroaches can survive for up to one month without food and two weeks without water.",
-    "A duck s quack doesn't echo, and no one knows why.",
-    "The most senior crayon maker Emerson Moser retired after making 1.4 billion crayons for Crayola. It was then that he revealed that he was actually colorblind.",
-    "No one in Greece has memorized all 158 verses.",
-    "A ear trumpet was used before the hearing aid was invented by people who had difficulty hearing.",
-    "The average number of bridesmaids at a wedding is four.",
-    "In 1999, All Nippon Airlines, had one of its jets fully decorated with Pokemon characters from nose to tail on its exterior.",
-    "When a polar bear cub is born, it can not see or hear. It takes approximately a month for the cub to start to see and hear.",
-    "Two objects have struck the earth with enough force to destroy a whole city. Each object, one in 1908 and again in 1947, struck regions of Siberia. Not one human being was hurt either time.",
-    "Pound for pound, leopards are said to be seven times stronger than humans.",
-    "In the United States, 80% of households have oatmeal in their kitchen.",
-    "Although the outsides of a bone are hard, they are generally light and soft inside. They are about 75% water.",
-    "In 1888, an Egyptian peasant discovered an estimated three hundred thousand mummified cats in Beni Hassan, Egypt. Of the cats that were not stolen once, the find was made public, the remaining mummified cats were shipped to Great Britain to be used as agricultural fertilizer.",
-    "Used in art the word \"sfumato\" refers to the subtle blending of an outline by gradually blending one tone into another.",
-    "The fur of the binturong, also known as the \"Asian Bear Cat,\" smells like popcorn. The scent is believed to come from a gland located near the tail.",
-    "The words \"abstemioius,\" and \"facetious\" both have all the five vowels in them in order.",
-    "Close to fifty percent of Internet shoppers spend over five hours a week online.",
-    "Women are four times more likely to have foot problems than men.",
-    "In 1997, the record for the highest skydive by a dog at 4,572 feet was established by a dog named Brutus.",
-    "The city of Nottingham in England was the first city to have Braille signs (signs for the blind) in its shopping malls for the blind.",
-    "In every episode of Seinfeld there is a Superman somewhere.",
-    "Mosquitoes have teeth.",
-    "Every year Alaska has about 5,000 earthquakes, 1,000 of which measure above 3.5 on the Richter scale.",
-    "Kite flying is a professional sport in Thailand.",
-    "As a defense mechanism, the North American Opossum closes its eyes and becomes totally limp. Basically it plays dead.",
-    "Ed Cox from San Francisco invented the pot scrubbing S.O.S. pads in 1917. His wife came up with the name, which stands for \"Save Our Saucepans.\".",
-    "Central Park located in New York has 125 drinking fountains.",
-    "The average life expectancy of an ant is about 90 days.",
-    "Americans consumed more than twenty billion hot dogs in 2000.",
-    "The parents of Albert Einstein were worried that he was mentally slow because it took him a long time to learn how to speak.",
-    "On average, an American makes three pounds of garbage in a day.",
-    "In 1894 the first big Coke sign was found on the side of a building located in Cartersville, Georgia, and still exists today.",
-    "The youngest pope was 11 years old.",
-    "There are over 1,000,000 swimming pools in Florida, even though the ocean is no farther than 80 miles away.",
-    "Only 5 to 10 percent of cheetah cubs make it to adulthood.",
-    "The favorite honeymoon place is Hawaii.",
-    "Basketball was invented by Canadian James Naismith in 1891.",
-    "In-vitro babies are born in Australia more than any other country in the world.",
-    "A giraffe can go longer without water than a camel.",
-    "Termites are roasted and eaten like popcorn in South Africa.",
-    "In 1938, Cliquot Club ginger ale was the first soft drink to be canned.",
-    "Antarctica is the only land on our planet that is not owned by any country.",
-    "There are some types of chocolates that are actually good for the arteries and heart.",
-    "To make butter more attractive in colour, carrot juice was used by people in the Middle Ages.",
-    "Some silkworms can spin cocoons that contain more than two miles of silk.",
-    "A species of dolphin is born naturally blind in the Indus and Ganges rivers in South Asia. These dolphins have a highly sophisticated sonar system and swim on only one side of their body.",
-    "In the United States, 8.5 million cosmetic surgical and non-surgical procedures were done in the year 2001.",
-    "Dumbest Dog: Afghan hound.",
-    "The longest distance a deep water lobster has been recorded to travel is 225 miles.",
-    "The reason why the Mexican sombrero hat is so wide is to provide shade for the entire body.",
-    "You can send a postcard from Hell. There is a small town located in the Cayman Islands called \"Hell.\" They even have a post office.",
-    "The reason why milk is white is because it contains a protein called Casein, which is white. Milk also contains fat, which is also white.",
-    "Five percent of the people who use personal ads for dating are already married.",
-    "75-90% of primary physician visits are due to stress.",
-    "The word Karate means, \"empty hand.\".",
-    "The Kodiak, which is native to Alaska, is the largest bear and can measure up to eight feet and weigh as much as 1,700 pounds.",
-    "Hippos can live up to 40 years in the wild.",
-    "One ounce of chocolate has about 20 mg of caffeine in it.",
-    "Leonardo Da Vinci invented the scissors.",
-    "The human body has approximately 37,000 miles of capillaries.",
-    "Of all the restaurants that are opened, 90% of them fail in the first year. Of the remaining ones that survive, 90% of those fail in the second year.",
-    "The word \"lethologica\" describes the state of not being able to remember the word you want.",
-    "In the 13th century, quality standards for paste were assigned by the Pope.",
-    "Four billion pounds of watermelon were grown in the United States in 1999.",
-    "The longest one-syllable word in the English language is \"screeched.\".",
-];
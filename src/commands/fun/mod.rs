@@ -1,11 +1,14 @@
 pub mod action;
 pub mod animal;
 pub mod coinflip;
+pub mod custom_content;
 pub mod fotd;
 pub mod magik;
 pub mod owoify;
 pub mod qotd;
 pub mod quote;
 pub mod skin;
+pub mod text;
 pub mod tod;
+pub mod trivia;
 pub mod wyr;
@@ -14,15 +14,7 @@ pub async fn owoify(
 ) -> Result<(), Error> {
     ctx.defer().await?;
 
-    let transformed_text: String = text
-        .chars()
-        .map(|c| match c {
-            'L' | 'R' => 'W',
-            'l' | 'r' => 'w',
-            'O' => 'U',
-            _ => c,
-        })
-        .collect();
+    let transformed_text = super::text::owoify(&text);
 
     ctx.send(
         CreateReply::default().embed(
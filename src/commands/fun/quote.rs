@@ -1,26 +1,53 @@
 use std::vec;
 
+use crate::content::{self, Filter, Pool, Tag};
 use crate::Error;
 
-use super::super::{EMBED_COLOR, LIGHTBULB_ICON};
+use super::super::{EMBED_COLOR, ERROR_EMBED_COLOR, ERROR_EMOJI, LIGHTBULB_ICON};
 use poise::serenity_prelude::CreateEmbed;
 use poise::CreateReply;
-use rand::seq::SliceRandom;
 
 /// Get a cool quote
 #[poise::command(slash_command, broadcast_typing)]
-pub async fn quote(ctx: poise::Context<'_, (), Error>) -> Result<(), Error> {
+pub async fn quote(
+    ctx: poise::Context<'_, (), Error>,
+    #[description = "Only get quotes by this author."]
+    #[autocomplete = "author_autocomplete"]
+    author: Option<String>,
+    #[description = "Only get quotes about this topic."]
+    #[autocomplete = "category_autocomplete"]
+    category: Option<String>,
+    #[description = "Only get quotes in this language."]
+    #[autocomplete = "language_autocomplete"]
+    language: Option<String>,
+) -> Result<(), Error> {
     ctx.defer().await?;
 
-    // It's safe to use .expect() since `QUOTES` will never be empty.
-    // .choose() will only return `None` if it is empty.
-    let quote = QUOTES.choose(&mut rand::thread_rng()).expect("Unreachable");
+    let filter = Filter {
+        category,
+        author,
+        language,
+        ..Default::default()
+    };
+
+    let Some(quote) = content::random_matching(Pool::Quotes, &filter) else {
+        ctx.send(
+            CreateReply::default().embed(
+                CreateEmbed::default()
+                    .title("Error")
+                    .description(format!("{ERROR_EMOJI} No quotes match those filters."))
+                    .color(ERROR_EMBED_COLOR),
+            ),
+        )
+        .await?;
+        return Ok(());
+    };
 
     ctx.send(
         CreateReply::default().embed(
             CreateEmbed::default()
                 .title("Here's your quote!")
-                .description((*quote).to_string())
+                .description(quote)
                 .color(EMBED_COLOR)
                 .thumbnail(LIGHTBULB_ICON),
         ),
@@ -30,2805 +57,20 @@ pub async fn quote(ctx: poise::Context<'_, (), Error>) -> Result<(), Error> {
     Ok(())
 }
 
-pub const QUOTES: [&str; 2800] = [
-    "\"We are all in the gutter, but some of us are looking at the stars.\" - Oscar Wilde",
-    "\"It is better to light a single candle than to curse the darkness.\" - Eleanor Roosevelt",
-    "\"Always look on the bright side of things. If you can't comprehend this, polish that which has become dull until it begins to shine.\" - Zen Proverb",
-    "\"Be the silent watcher of your thoughts and behavior. You are beneath the thinker.\" - Eckhart Tolle",
-    "\"Living a life that has a greater purpose and living up to it is the ultimate degree that we should strive for.\" - Gurbaksh Chahal",
-    "\"The real measure of your wealth is how much you'd be worth if you lost all your money.\" - Unknown",
-    "\"Changing minds is harder than finding people who already agree.\" - Jack Butcher",
-    "\"As soon as you trust yourself, you will know how to live.\" - Johann Wolfgang von Goethe",
-    "\"We can either add to our character each day, or we can fritter away our energies in distractions.\" - Ming-Dao Deng",
-    "\"The ones who are crazy enough to think they can change the world, are the ones that do.\" - Steve Jobs",
-    "\"People become attached to their burdens sometimes more than the burdens are attached to them.\" - George Bernard Shaw",
-    "\"Learning never exhausts the mind. \" - Leonardo da Vinci",
-    "\"Your first and foremost job as a leader is to take charge of your own energy and then help to orchestrate the energy of those around you.\" - Peter Drucker",
-    "\"It's the unknown we fear when we look upon death and darkness, nothing more.\" - Albus Dumbledore",
-    "\"Begin at once to live, and count each separate day as a separate life.  \" - Seneca",
-    "\"Your time is limited, so don't waste it living someone elses. life. Don't be trapped by dogma - which is living with the results of other people's thinking.\" - Steve Jobs",
-    "\"Normal is the average of deviance.\" - Rita Mae Brown",
-    "\"Only he who has no use for the empire is fit to be entrusted with it.\" - Zhuangzi",
-    "\"You can't wait for inspiration. You have to go after it with a club. \" - Jack London",
-    "\"Love doesn't need reason. It speaks from the irrational wisdom of the heart.\" - Deepak Chopra",
-    "\"Create with the heart; build with the mind.\" - Criss Jami",
-    "\"Remember, hope is a good thing, maybe the best of things.\" - Stephen King",
-    "\"The first principle of success is desire.\" - Robert Collier",
-    "\"Forget mistakes. Forget failure. Forget everything except what you are going to do now and do it. Today is your lucky day!\" - Og Mandino",
-    "\"Often we pass beside happiness without seeing it, without looking at it, or even if we have seen and looked at it, without recognizing it.\" - Alexandre Dumas",
-    "\"People are weird. When we find someone with weirdness that is compatible with ours, we team up and call it love.\" - Dr. Seuss",
-    "\"Life is either a daring adventure, or nothing.\" - Helen Keller",
-    "\"If you're brave enough to say goodbye, life will reward you with a new hello.\" - Paulo Coelho",
-    "\"Every moment you get is a gift. Spend it on things that matter. Don't spend it by dwelling on unhappy things.\" - Celestine Chua",
-    "\"A dream doesn't become reality through magic it takes sweat, determination and hard work.\" - Colin Powell",
-    "\"I think it's very important to have a feedback loop, where you're constantly thinking about what you've done and how you could be doing it better.\" - Elon Musk",
-    "\"Learning is a gift, even when pain is your teacher.\" - Michael Jordan",
-    "\"Know your gifts and share them.\" - Les Brown",
-    "\"An un-examined life is not worth living.\" - Socrates",
-    "\"He is richest who is content with the least, for content is the wealth of nature.\" - Socrates",
-    "\"To the world you may be one person; but to one person you may be the world.\" - Dr. Seuss",
-    "\"Lack of emotion causes lack of progress and lack of motivation.\" - Tony Robbins",
-    "\"A woman unsatisfied must have luxuries. But a woman who loves a man would sleep on a board.\" - D. H. Lawrence",
-    "\"Reading should be a pleasure, not a chore.\" - Joan Rivers",
-    "\"I enjoy life when things are happening. I don't care if it's good things or bad things. That means you're alive.\" - Joan Rivers",
-    "\"Open your mind before your mouth.\" - Aristophanes",
-    "\"Work joyfully and peacefully, knowing that right thoughts and right efforts inevitably bring about right results.\" - James Allen",
-    "\"Enjoy when you can, and endure when you must.\" - Johann Wolfgang von Goethe",
-    "\"No matter how much preparation we do, in the real tests of our lives, we'll be in unfamiliar terrain.\" - Josh Waitzkin",
-    "\"There is no greater agony than bearing an untold story inside you.\" - Maya Angelou",
-    "\"Do not bite at the bait of pleasure, till you know there is no hook beneath it.\" - Thomas Jefferson",
-    "\"Never say never, because limits, like fears, are often just an illusion.\" - Michael Jordan",
-    "\"You miss 100% of the shots you don't take.\" - Wayne Gretzky",
-    "\"Thinking is a habit, and like any other habit, it can be changed; it just takes effort and repetition.\" - John Eliot",
-    "\"You become stronger with every problem you face and every obstacle you overcome.\" - Celestine Chua",
-    "\"Trying to define yourself is like trying to bite your own teeth.\" - Zen Proverb",
-    "\"Everything is possible. The impossible just takes longer.\" - Dan Brown",
-    "\"Today is plenty; right now is enough. Tomorrow will come in good time. Until it does, live the depth of now.\" - Ralph Marston",
-    "\"Relax. Nothing is under control.\" - Adi Da Samraj",
-    "\"Don't wait for extraordinary opportunities. Seize common occasions and make them great.\" - Orison Swett Marden",
-    "\"Winning isn't everything, but wanting to win is.\" - Vince Lombardi",
-    "\"Poetry is written with tears, fiction with blood, and history with invisible ink.\" - Carlos Ruiz Zafon",
-    "\"Make no small plans for they have no power to stir the soul.\" - Niccolo Machiavelli",
-    "\"If you don't have time to do it right, when will you have time to do it over?\" - John Wooden",
-    "\"When you love someone, you love the person as they are, and not as you'd like them to be.\" - Leo Tolstoy",
-    "\"Meanings are not determined by situations, but we determine ourselves by the meanings we give to situations.\" - Alfred Adler",
-    "\"Whatever you do in life, surround yourself with smart people who'll argue with you.\" - John Wooden",
-    "\"If you believe you can, you can. If you believe you can't, then, well you can't.\" - Celestine Chua",
-    "\"There is no truth. There is only perception.\" - Gustave Flaubert",
-    "\"Everything great that has ever happened to humanity has begun as a single thought in someone's mind.\" - Yanni",
-    "\"Sometimes, the only way to evolve is to open ourselves fully.\" - Kamal Ravikant",
-    "\"The guest will judge better of a feast than the cook.\" - Aristotle",
-    "\"Your only limitations are those you set up in your mind or permit others to set up for you.\" - Og Mandino",
-    "\"To think is easy. To act is hard. But the hardest thing in the world is to act in accordance with your thinking.\" - Johann Wolfgang von Goethe",
-    "\"You may forget with whom you laughed, but you will never forget with whom you wept.  \" - Kahlil Gibran",
-    "\"Life is a journey, not a destination.\" - Dan Millman",
-    "\"Take the attitude of a student. Never be too big to ask questions. Never know too much to learn something new.\" - Og Mandino",
-    "\"Conscience is a man's compass. \" - Vincent van Gogh",
-    "\"The dead receive more flowers than the living because regret is stronger than gratitude.\" - Anne Frank",
-    "\"I know of only one duty, and that is to love.\" - George Bernard Shaw",
-    "\"All men are equal before fish.\" - Herbert Hoover",
-    "\"The pain of parting is nothing to the joy of meeting again.\" - Charles Dickens",
-    "\"Big people don't make people feel small.\" - Robin Sharma",
-    "\"Give a man a fish and you feed him for a day. Teach a man to fish and you feed him for a lifetime.\" - Lao Tzu",
-    "\"We are all like the bright moon, we still have our darker side.  \" - Kahlil Gibran",
-    "\"Don't let the noise of others' opinions drown out your own inner voice.\" - Steve Jobs",
-    "\"Those who attain the middle Dominate the whole.\" - Ming-Dao Deng",
-    "\"Man should fear never beginning to live.\" - Marcus Aurelius",
-    "\"To forget the whole world is easy; to make the whole world forget you is hard.\" - Zhuangzi",
-    "\"Every man is guilty of all the good he did not do. \" - Voltaire",
-    "\"Life goes by very fast. And the worst thing in life that you can have is a job that you hate, and have no energy and creativity in.\" - Robert Greene",
-    "\"It is better to offer no excuse than a bad one. \" - George Washington",
-    "\"When you say yes to change, you are choosing to navigate that path peacefully and happily and see where it takes you.\" - Sonia Ricotti",
-    "\"Remembering that you are going to die is the best way I know to avoid the trap of thinking you have something to lose.\" - Steve Jobs",
-    "\"Move out of your comfort zone. You can only grow if you are willing to feel awkward and uncomfortable when you try something new.\" - Brian Tracy",
-    "\"Mistakes are painful when they happen, but years later a collection of mistakes is what is called experience.\" - Denis Waitley",
-    "\"If it is not right, do not do it, if it is not true, do not say it.\" - Marcus Aurelius",
-    "\"The soul should always stand ajar, ready to welcome the ecstatic experience.\" - Emily Dickinson",
-    "\"Friendship takes time and energy if it's going to work.\" - Betty White",
-    "\"Stop being a prisoner of your past. Become the architect of your future.\" - Robin Sharma",
-    "\"You cannot change your destination overnight but you can change your direction overnight.\" - Jim Rohn",
-    "\"If you are patient in one moment of anger, you will escape a hundred days of sorrow. \" - Chinese Proverb",
-    "\"Who you are is always right.\" - Ming-Dao Deng",
-    "\"Your next step is simple. You are the first domino.\" - Gary Keller",
-    "\"The road to success is always under construction.\" - Steve Harvey",
-    "\"I've always tried to go a step past wherever people expected me to end up.\" - Beverly Sills",
-    "\"You must find the place inside yourself where nothing is impossible.\" - Deepak Chopra",
-    "\"Everyone wants to go to Heaven, but no one wants to die.\" - Robert Kiyosaki",
-    "\"Don't just sit there. Do something. The answers will follow.\" - Mark Manson",
-    "\"There is only one thing that makes a dream impossible to achieve: the fear of failure.\" - Paulo Coelho",
-    "\"Concentrated power has always been the enemy of liberty.\" - Ronald Reagan",
-    "\"If you don't know where you're going, you will probably end up somewhere else.\" - Laurence J. Peter",
-    "\"Walking with a friend in the dark is better than walking alone in the light.\" - Helen Keller",
-    "\"No one can hurt you without your consent.\" - Eleanor Roosevelt",
-    "\"He who wishes to be obeyed must know how to command.\" - Niccolo Machiavelli",
-    "\"If there is a book that you want to read, but it hasn't been written yet, then you must write it.\" - Toni Morrison",
-    "\"Chase your passion, not your pension. \" - Denis Waitley",
-    "\"A golden cage is still just a cage.\" - Anita Krizzan",
-    "\"The true soldier fights not because he hates what is in front of him, but because he loves what is behind him.\" - Gilbert Chesterton",
-    "\"All our knowledge has its origins in our perceptions.\" - Leonardo da Vinci",
-    "\"You may be disappointed if you fail, but you are doomed if you don't try.\" - Beverly Sills",
-    "\"It is our choices that show what we truly are, far more than our abilities.\" - Albus Dumbledore",
-    "\"Scared people want comfort and certainty so they avoid failure. People living fully seek challenges because this is where they feel alive.\" - Maxime Lagace",
-    "\"A man with outward courage dares to die: a man with inner courage dares to live.\" - Lao Tzu",
-    "\"Eighty percent of success is showing up. \" - Woody Allen",
-    "\"Successful people tend to become more successful because they are always thinking about their successes.\" - Brian Tracy",
-    "\"You are your best thing.\" - Toni Morrison",
-    "\"May you live all the days of your life.\" - Jonathan Swift",
-    "\"Death is not an ending. It is a transformation.\" - Ming-Dao Deng",
-    "\"If I love myself I love you. If I love you I love myself.\" - Rumi",
-    "\"A person who does not read is no better than one cannot read.\" - Earl Nightingale",
-    "\"To be totally at leisure for one day is to be immortal for one day. \" - Chinese Proverb",
-    "\"Dreams are the royal road to the unconscious. \" - Sigmund Freud",
-    "\"Find the game where you can win, and then commit your life to playing it; and play to win.\" - Robert Kiyosaki",
-    "\"There is no such thing as a great talent without great willpower.\" - Honore de Balzac",
-    "\"Happiness can be found, even in the darkest of times, if one only remembers to turn on the light.\" - Albus Dumbledore",
-    "\"Heal the past, live the present, dream the future.\" - Mary Engelbreit",
-    "\"Don't spend time beating on a wall, hoping to transform it into a door.\" - Coco Chanel",
-    "\"The one who boasts does so only out of a feeling of inferiority.\" - Alfred Adler",
-    "\"There is peace even in the storm.\" - Vincent van Gogh",
-    "\"Amateurs sit and wait for inspiration, the rest of us just get up and got to work.\" - Stephen King",
-    "\"You can do two things at once, but you can't focus effectively on two things at once.\" - Gary Keller",
-    "\"There is no excuse for not trying.\" - Barack Obama",
-    "\"Do not anticipate trouble, or worry about what may never happen. Keep in the sunlight.\" - Benjamin Franklin",
-    "\"Knowing yourself is the beginning of all wisdom.\" - Aristotle",
-    "\"I no doubt deserved my enemies, but I don't believe I deserved my friends.\" - Walt Whitman",
-    "\"The biggest mistake we could ever make in our lives is to think we work for anybody but ourselves.\" - Brian Tracy",
-    "\"Do the hard jobs first. The easy jobs will take care of themselves.\" - Dale Carnegie",
-    "\"Believe in your infinite potential. Your only limitations are those you set upon yourself.\" - Roy T. Bennett",
-    "\"The seasons do not push one another; neither do clouds race the wind across the sky. All things happen in their own good time.\" - Dan Millman",
-    "\"Difficulties strengthen the mind, as labor does the body.  \" - Seneca",
-    "\"Curiosity is the most powerful thing you own. Imagination is a force that can actually manifest a reality.\" - James Cameron",
-    "\"He who fails to plan, plans to fail.\" - Unknown",
-    "\"By the practice of meditation, you will find that you are carrying within your heart a portable paradise.\" - Paramahansa Yogananda",
-    "\"Always forgive your enemies - nothing annoys them so much. \" - Oscar Wilde",
-    "\"To forgive means pardoning the unpardonable.\" - Gilbert Chesterton",
-    "\"Only put off until tomorrow what you are willing to die having left undone.\" - Pablo Picasso",
-    "\"Associate with people who are likely to improve you.  \" - Seneca",
-    "\"When one door closes another door opens. \" - Alexander Graham Bell",
-    "\"Everybody needs a passion. That's what keeps life interesting.\" - Betty White",
-    "\"Sometimes adversity is what you need to face in order to become successful.\" - Zig Ziglar",
-    "\"The purpose of life is the expansion of happiness.\" - Deepak Chopra",
-    "\"If you want something new, you have to stop doing something old.\" - Peter Drucker",
-    "\"As long as a human being worries about when he will die, and what he has that is his, all of his works are zero.\" - Kabir",
-    "\"Always remember that you are absolutely unique. Just like everyone else. \" - Margaret Mead",
-    "\"Life is a challenge, meet it! Life is a dream, realize it! Life is a game, play it! Life is love, enjoy it!\" - Sathya Sai Baba",
-    "\"Instead of worrying about what you cannot control, shift your energy to what you can create.\" - Roy T. Bennett",
-    "\"Small men think they are small; great men never know they are great. \" - Chinese Proverb",
-    "\"Try to be a rainbow in someone's cloud.\" - Maya Angelou",
-    "\"Never let the fear of striking out keep you from playing the game.\" - Babe Ruth",
-    "\"All men who have achieved great things have been great dreamers. \" - Orison Swett Marden",
-    "\"The birth of the mind is the death of the senses.\" - Dan Millman",
-    "\"It is safer to search in the maze than to remain in a cheeseless situation.\" - Spencer Johnson",
-    "\"Giving is the secret of a healthy life. Not necessarily money, but whatever a person has of encouragement, sympathy and understanding.\" - John D. Rockefeller",
-    "\"Go for it now. The future is promised to no one.\" - Wayne Dyer",
-    "\"What most people need to learn in life is how to love people and use things instead of using people and loving things.\" - Unknown",
-    "\"All life is an experiment. The more experiments you make, the better.\" - Ralph Waldo Emerson",
-    "\"Rest and be kind, you don't have to prove anything.\" - Jack Kerouac",
-    "\"If you are lucky enough to find love, remember it is there and don't throw it away.\" - Stephen Hawking",
-    "\"To affect the quality of the day, that is the highest of arts.\" - Henry David Thoreau",
-    "\"Become the kind of leader that people would follow voluntarily, even if you had no title or position.\" - Brian Tracy",
-    "\"Happiness cannot be traveled to, owned, earned, worn or consumed.\" - Denis Waitley",
-    "\"We live only now. Everything else is either passed or is unknown.\" - Marcus Aurelius",
-    "\"The destiny of man is in his own soul. \" - Herodotus",
-    "\"We must accept finite disappointment, but never lose infinite hope.\" - Martin Luther King, Jr.",
-    "\"Extraordinary results happen only when you give the best you have to become the best you can be at your most important work.\" - Gary Keller",
-    "\"The more you are focused on time - past and future - the more you miss the Now, the most precious thing there is.\" - Eckhart Tolle",
-    "\"When you have a dream, you've got to grab it and never let go.\" - Carol Burnett",
-    "\"Only the educated are free.\" - Epictetus",
-    "\"Having lots of money while not having inner peace is like dying of thirst while bathing in the ocean.\" - Paramahansa Yogananda",
-    "\"Procrastination is one of the most common and deadliest of diseases and its toll on success and happiness is heavy.\" - Wayne Gretzky",
-    "\"Your spirit is the true shield.\" - Morihei Ueshiba",
-    "\"Most of us live our lives by accident - we live as it happens. Fulfilment comes when we live our lives on purpose.\" - Simon Sinek",
-    "\"The weak can never forgive. Forgiveness is the attribute of the strong.\" - Mahatma Gandhi",
-    "\"Your chances of success in any undertaking can always be measured by your belief in yourself.\" - Robert Collier",
-    "\"Life is a gift. Never forget to enjoy and bask in every moment you are in.\" - Celestine Chua",
-    "\"The meaning of life is that it stops.\" - Franz Kafka",
-    "\"Great things are not done by impulse, but by a series of small things brought together.\" - Vincent van Gogh",
-    "\"The only man who never makes mistakes is the man who never does anything.\" - Theodore Roosevelt",
-    "\"All that we see or seem is but a dream within a dream.\" - Edgar Allan Poe",
-    "\"Even though you are on the right track - you will get run over if you just sit there.\" - Will Rogers",
-    "\"Why fit in when you were born to stand out?\" - Dr. Seuss",
-    "\"There is no self-discovery without pain and loss.\" - Anita Krizzan",
-    "\"An investment in knowledge pays the best interest.\" - Benjamin Franklin",
-    "\"The whole problem with the world is the fools and fanatics are always so sure of themselves, and wiser people are full of doubts.\" - George Bernard Shaw",
-    "\"When the time is gone, you can never get it back.\" - Stephen King",
-    "\"Youth has no age.\" - Pablo Picasso",
-    "\"Tall oaks grow from little acorns.\" - Andrew Carnegie",
-    "\"My definition of success is control.\" - Kenneth Branagh",
-    "\"The only way to make sense out of change is to plunge into it, move with it, and join the dance.\" - Alan Watts",
-    "\"It's not what you say out of your mouth that determines your life, it's what you whisper to yourself that has the most power!\" - Robert Kiyosaki",
-    "\"A single dream is more powerful than a thousand realities.\" - J.R.R. Tolkien",
-    "\"There is no one giant step that does it. It's a lot of little steps.\" - Peter A. Cohen",
-    "\"When you consider things like the stars, our affairs don't seem to matter very much, do they?\" - Virginia Woolf",
-    "\"Silence is a source of great strength.\" - Lao Tzu",
-    "\"It is not because things are difficult that we do not dare; it is because we do not dare that things are difficult.  \" - Seneca",
-    "\"Shoot for the moon, because even if you miss, you'll land among the stars.\" - Les Brown",
-    "\"It's not whether you get knocked down, its whether you get back up.\" - Vince Lombardi",
-    "\"The ability to observe without evaluating is the highest form of intelligence.\" - Jiddu Krishnamurti",
-    "\"Words without actions are the assassins of idealism.\" - Herbert Hoover",
-    "\"Resilience is not what happens to you. It's how you react to, respond to, and recover from what happens to you.\" - Jeffrey Gitomer",
-    "\"Everything has its beauty, but not everyone sees it. \" - Confucius",
-    "\"The more you take responsibility for your past and present, the more you are able to create the future you seek.\" - Celestine Chua",
-    "\"Respect other people's feelings. It might mean nothing to you, but it could mean everything to them.\" - Roy T. Bennett",
-    "\"A vital part of the happiness formula is self-discipline. Whoever conquers himself knows deep happiness that fills the heart with joy.\" - Norman Vincent Peale",
-    "\"Your success and happiness lie in you.\" - Helen Keller",
-    "\"Productize your perspective.\" - Jack Butcher",
-    "\"A needle is not sharp at both ends. \" - Chinese Proverb",
-    "\"The more rejection you get, the better you are, the more you've learned, the closer you are to your outcome.\" - Tony Robbins",
-    "\"If we did all the things we are capable of, we would literally astound ourselves.\" - Thomas Edison",
-    "\"Best be yourself, imperial, plain, and true.\" - Robert Browning",
-    "\"It is not fair to ask of others what you are not willing to do yourself.\" - Eleanor Roosevelt",
-    "\"The businessman's tool is value.\" - Ayn Rand",
-    "\"Never do things others can do and will do if there are things others cannot do or will not do.\" - Amelia Earhart",
-    "\"You must live in the present, launch yourself on every wave, find your eternity in each moment. Fools stand on their island of opportunities and look toward another land. There is no other land; there is no other life but this.\" - Henry David Thoreau",
-    "\"The person who is his own master cannot tolerate another boss. \" - Chinese Proverb",
-    "\"Lust, anger and greed are the three gates to self-destructive hell.\" - Bhagavad Gita",
-    "\"Character is the result of two things: mental attitude and the way we spend our time.\" - Elbert Hubbard",
-    "\"Don't think money does everything or you are going to end up doing everything for money.\" - Voltaire",
-    "\"Our happiness depends on the habit of mind we cultivate.\" - Norman Vincent Peale",
-    "\"Success is not to be pursued, it is to be attracted by the person you become.\" - Jim Rohn",
-    "\"Sorrow is how we learn to love.\" - Rita Mae Brown",
-    "\"Write to help yourself, publish to help others.\" - Jack Butcher",
-    "\"I succeeded by saying what everyone else is thinking.\" - Joan Rivers",
-    "\"Freedom is the only worthy goal in life. It is won by disregarding things that lie beyond our control.\" - Epictetus",
-    "\"Life is too short to waste your time on people who don't respect, appreciate, and value you.\" - Roy T. Bennett",
-    "\"If you are going to achieve excellence in big things, you develop the habit in little matters.\" - Colin Powell",
-    "\"Don't cry because it's over. Smile because it happened.\" - Dr. Seuss",
-    "\"When you can't make them see the light, make them feel the heat.\" - Ronald Reagan",
-    "\"The successful warrior is the average man, with laser-like focus.\" - Bruce Lee",
-    "\"I know not all that may be coming, but be it what it will, I'll go to it laughing.\" - Herman Melville",
-    "\"Forgive those who have hurt you.\" - Les Brown",
-    "\"Throw your heart over the bar and your body will follow.\" - Norman Vincent Peale",
-    "\"Duty makes us do things well, but love makes us do them beautifully.  \" - Zig Ziglar",
-    "\"Thinking is the hardest work there is, which is probably the reason why so few engage in it.\" - Henry Ford",
-    "\"Human beings can alter their lives by altering their attitudes of mind.\" - William James",
-    "\"The friendship that can cease has never been real. \" - St. Jerome",
-    "\"The foolish reject what they see, not what they think; the wise reject what they think, not what they see.\" - Huang Po",
-    "\"No one ever is defeated until defeat has been accepted as a reality.\" - Napoleon Hill",
-    "\"Positive thinking can be contagious. Being surrounded by winners helps you develop into a winner.\" - Arnold Schwarzenegger",
-    "\"Being ignorant is not so much a shame, as being unwilling to learn.\" - Benjamin Franklin",
-    "\"The pessimist sees difficulty in every opportunity. The optimist sees opportunity in every difficulty.\" - Winston Churchill",
-    "\"You can put lipstick on a pig. It's still a pig.\" - Barack Obama",
-    "\"The best revenge is to be unlike him who performed the injury.\" - Marcus Aurelius",
-    "\"When it comes to mastering a skill, time is the magic ingredient.\" - Robert Greene",
-    "\"Human beings believe just as they breathe - in order to survive.\" - Carlos Ruiz Zafon",
-    "\"One is never afraid of the unknown; one is afraid of the known coming to an end.\" - Jiddu Krishnamurti",
-    "\"Patience expands your options. If you insist on immediate gratification, your choices are severely limited.\" - Ralph Marston",
-    "\"Words used to attract the dull of wit are not to be relied on.\" - Huang Po",
-    "\"Fear of death is fear of surrender to Infinity. Learn to surrender, to exist at Infinity while alive, and fear of death dissolves.\" - Adi Da Samraj",
-    "\"The face is the mirror of the mind, and eyes without speaking confess the secrets of the heart. \" - St. Jerome",
-    "\"The quieter you become, the more you are able to hear.\" - Rumi",
-    "\"The most important single ingredient in the formula of success is knowing how to get along with people.\" - Theodore Roosevelt",
-    "\"Always show more kindness than seems necessary because the person receiving it needs it more than you will ever know.\" - Colin Powell",
-    "\"If anything is worth doing, do it with all your heart.\" - Buddha",
-    "\"Success and failure come and go, but don't let them define you. It's who you are that matters.\" - Kamal Ravikant",
-    "\"It's wise to tell the truth. It's even wiser to tell it kindly.\" - Maxime Lagace",
-    "\"Most people get ahead during the time that others waste.\" - Henry Ford",
-    "\"We are all born ignorant, but one must work hard to remain stupid.\" - Benjamin Franklin",
-    "\"Words, once they are printed, have a life of their own.\" - Carol Burnett",
-    "\"If it's not broken, tinker with it till you find out how it works.\" - Bob Proctor",
-    "\"If you're not making mistakes, then you're not doing anything.\" - John Wooden",
-    "\"Never take your position for granted and never let any favors you receive go to your head.\" - Robert Greene",
-    "\"If you want to see things just as they are, then you yourself must practice just as you are.\" - Dogen",
-    "\"What people need and what they want may be very different.\" - Elbert Hubbard",
-    "\"You may be one person in this world, but to one person you may mean the world.\" - Unknown",
-    "\"The best answer to anger is silence.\" - Marcus Aurelius",
-    "\"Self-control is strength.\" - James Allen",
-    "\"No snowflake ever falls in the wrong place.\" - Zen Proverb",
-    "\"Perhaps the most tragic thing about mankind is that we are all dreaming about some magical garden over the horizon, instead of enjoying the roses that are right outside today.\" - Andrew Carnegie",
-    "\"There is nothing more truly artistic than to love people.\" - Vincent van Gogh",
-    "\"Being In The Present Means Tuning Out Distractions And Paying Attention To What Is Important, Now.\" - Spencer Johnson",
-    "\"No man is more cheated than the selfish man.\" - Henry Ward Beecher",
-    "\"It is important to fight and fight again, and keep fighting, for only then can evil be kept at bay though never quite eradicated.\" - Albus Dumbledore",
-    "\"Be kind whenever possible. It is always possible.\" - Dalai Lama",
-    "\"Welcome every morning with a smile. Look on the new day as another gift from your Creator, another golden opportunity.\" - Og Mandino",
-    "\"Believe you can and you will be halfway there.\" - Lolly Daskal",
-    "\"We suffer for the simple reason that suffering is biologically useful. It is nature's preferred agent for inspiring change.\" - Mark Manson",
-    "\"Winners are not people who never fail, but people who never quit.\" - Arnold Schwarzenegger",
-    "\"How to get more opportunity: Create more opportunity for others.\" - Jack Butcher",
-    "\"The dreamers are the saviors of the worlds.\" - James Allen",
-    "\"The Truth is realized in an instant; the Act is practiced step by step.\" - Seungsahn",
-    "\"If you want to be sad, no one in the world can make you happy. But if you make up your mind to be happy, no one and nothing on earth can take that happiness from you.\" - Paramahansa Yogananda",
-    "\"It all depends on what you choose to believe.\" - Spencer Johnson",
-    "\"Behind every cloud is another cloud.\" - Judy Garland",
-    "\"We meet ourselves time and again in a thousand disguises on the path of life.\" - Carl Jung",
-    "\"Action may not always bring happiness, but there is no happiness without action.\" - William James",
-    "\"To lose your temper is only useful once a year.\" - Colin R. Davis",
-    "\"By words the mind is winged.\" - Aristophanes",
-    "\"You have to accept whatever comes, and the only important thing is that you meet it with the best you have to give.\" - Eleanor Roosevelt",
-    "\"Find your Light; They can't love you if they can't see you.\" - Bette Midler",
-    "\"Be like a postage stamp. Stick to it until you get there.\" - Bob Proctor",
-    "\"Change is hard at first, messy in the middle and gorgeous at the end.\" - Robin Sharma",
-    "\"No one is useless in this world who lightens the burdens of another.\" - Charles Dickens",
-    "\"Knowing your own darkness is the best method for dealing with the darknesses of other people.\" - Carl Jung",
-    "\"Discipline yourself, and others won't need to.\" - John Wooden",
-    "\"Music is like creating an emotional painting. The sounds are the colors.\" - Yanni",
-    "\"The attempt to escape from pain, is what creates more pain.\" - Gabor Mate",
-    "\"Never buy a thing you do not want, because it is cheap, it will be dear to you.\" - Thomas Jefferson",
-    "\"He is not a lover who does not love forever.\" - Euripides",
-    "\"It is the power of the mind to be unconquerable.\" - Seneca",
-    "\"We are all different. Don't judge, understand instead.\" - Roy T. Bennett",
-    "\"Stop wasting time looking at someone else's reality while doing nothing about yours.\" - Steve Harvey",
-    "\"True love stories never have endings.\" - Richard Bach",
-    "\"Where the willingness is great, the difficulties cannot be great.\" - Niccolo Machiavelli",
-    "\"A man is but a product of his thoughts. What he thinks he becomes.\" - Mahatma Gandhi",
-    "\"The quality, not the longevity, of one's life is what is important.\" - Martin Luther King, Jr.",
-    "\"Unexpressed emotions will never die. They are buried alive and will come forth later in uglier ways.\" - Sigmund Freud",
-    "\"If you cling to a certain thought with dynamic will power, it finally assumes a tangible outward form.\" - Paramahansa Yogananda",
-    "\"Don't change your mind just because people are offended; change your mind if you're wrong.\" - Criss Jami",
-    "\"To be prepared is half the victory. \" - Miguel de Cervantes",
-    "\"As you think, so shall you become. \" - Bruce Lee",
-    "\"Not all of us can do great things. But we can do small things with great love.\" - Mother Teresa",
-    "\"The soul is neither born, and nor does it die.\" - Bhagavad Gita",
-    "\"It is not the knowing that is difficult, but the doing. \" - Chinese Proverb",
-    "\"The greatest treasures are those invisible to the eye but found by the heart.\" - Judy Garland",
-    "\"Life is trying things to see if they work.\" - Ray Bradbury",
-    "\"Trusting our intuition often saves us from disaster. \" - Anne Wilson Schaef",
-    "\"Friendship is like money, easier made than kept.\" - Samuel Butler",
-    "\"We can change our lives. We can do, have, and be exactly what we wish.\" - Tony Robbins",
-    "\"Live in the sunshine, swim the sea, drink the wild air.\" - Ralph Waldo Emerson",
-    "\"A heart is not judged by how much you love, but by how much you are loved by others.\" - Judy Garland",
-    "\"Don't allow your mind to tell your heart what to do. The mind gives up easily.\" - Paulo Coelho",
-    "\"Do what is right, not what is easy, nor what is popular.\" - Roy T. Bennett",
-    "\"Failure is an option, fear is not.\" - James Cameron",
-    "\"It is impossible for a man to learn what he thinks he already knows.\" - Epictetus",
-    "\"Many answers to what you seek don't lie 'out there'. If you look inwards, you'll find the answer has been in you all along.\" - Celestine Chua",
-    "\"Rewards and punishment is the lowest form of education.\" - Zhuangzi",
-    "\"The whole world is a series of miracles, but we're so used to them we call them ordinary things.\" - Hans Christian Andersen",
-    "\"Don't worry about being successful, but work toward being significant and the success will naturally follow.\" - Oprah Winfrey",
-    "\"We are all faced with a series of great opportunities brilliantly disguised as impossible situations. \" - Charles Swindoll",
-    "\"Fear is stupid. So are regrets.\" - Marilyn Monroe",
-    "\"In all chaos there is a cosmos, in all disorder a secret order.\" - Carl Jung",
-    "\"Empty your mind, be formless, shapeless, like water.\" - Bruce Lee",
-    "\"Never close your lips to those whom you have already opened your heart.\" - Charles Dickens",
-    "\"A lot of people have gone further than they thought they could because someone else thought they could.\" - Zig Ziglar",
-    "\"Better to live until you die.\" - Dan Millman",
-    "\"If you find you are not valued and appreciated, then shift your attention to places where you will be valued and appreciated.\" - Celestine Chua",
-    "\"Self-knowledge is the beginning of self-correction.\" - Norman Vincent Peale",
-    "\"If you're waiting until you feel talented enough to make it, you'll never make it.\" - Criss Jami",
-    "\"The content of your character is your choice. Day by day, what you choose, what you think and what you do is who you become.\" - Heraclitus",
-    "\"He who cheats the earth will be cheated by the earth.\" - Chinese Proverb",
-    "\"Investing in yourself is the best investment you will ever make.\" - Robin Sharma",
-    "\"If a thing is worth doing, it is worth doing badly.\" - Gilbert Chesterton",
-    "\"Wherever you are is the entry point.\" - Kabir",
-    "\"Nothing is hopeless; we must hope for everything.\" - Euripides",
-    "\"It is better to be looked over than overlooked.\" - Mae West",
-    "\"The more acquaintances you have, the less you know them.\" - Chinese Proverb",
-    "\"There is only the one reality, neither to be realized nor attained.\" - Huang Po",
-    "\"We never know how high we are till we are called to rise. Then if we are true to form our statures touch the skies.\" - Emily Dickinson",
-    "\"Receive without pride, let go without attachment.\" - Marcus Aurelius",
-    "\"Take interest and even delight in doing the small things well.\" - Jim Rohn",
-    "\"You have within you right now, everything you need to deal with whatever the world can throw at you.\" - Brian Tracy",
-    "\"We cannot always do great things in life, but we can do small things with great love.\" - Dan Millman",
-    "\"If you hang out with chickens, you're going to cluck and if you hang out with eagles, you're going to fly.\" - Steve Maraboli",
-    "\"Be true to yourself, help others, make each day your masterpiece\" - John Wooden",
-    "\"Those who can make you believe absurdities can make you commit atrocities. \" - Voltaire",
-    "\"I try more and more to be myself, caring relatively little whether people approve or disapprove.\" - Vincent van Gogh",
-    "\"We have no more right to consume happiness without producing it than to consume wealth without producing it.\" - George Bernard Shaw",
-    "\"Do good by stealth, and blush to find it fame.\" - Alexander Pope",
-    "\"Take into account that great love and great achievements involve great risk.\" - Dalai Lama",
-    "\"Everything that irritates us about others.\" - Carl Jung",
-    "\"You talk when you cease to be at peace with your thoughts.\" - Kahlil Gibran",
-    "\"Make peace within, and there will be no one who can overcome you. And no one you will wish to overcome.\" - Dan Millman",
-    "\"Never was anything great achieved without danger.\" - Niccolo Machiavelli",
-    "\"If you pick up a starving dog and make him prosperous he will not bite you. This is the principal difference between a dog and man.\" - Mark Twain",
-    "\"Life without love is like a tree without blossoms or fruit.\" - Kahlil Gibran",
-    "\"Love yourself with the same intensity you would use to pull yourself up if you were hanging off a cliff.\" - Kamal Ravikant",
-    "\"For fast-acting relief try slowing down.\" - Lily Tomlin",
-    "\"If you do not change direction, you may end up where you are heading.\" - Lao Tzu",
-    "\"Any idea, plan, or purpose may be placed in the mind through repetition of thought.\" - Napoleon Hill",
-    "\"If you can't fly, run. If you can't run, walk. If you can't walk, crawl, but by all means, keep moving.\" - Martin Luther King, Jr.",
-    "\"Learn to value yourself, which means: fight for your happiness.\" - Ayn Rand",
-    "\"Fear not the path of Truth for the lack of People walking on it.\" - Robert F. Kennedy",
-    "\"There is an essence of when you are fearless, you become more creative.\" - Gurbaksh Chahal",
-    "\"Many people will walk in and out of your life, but only true friends will leave footprints in your heart.\" - Eleanor Roosevelt",
-    "\"Life is about choice. You can choose to be a victim or anything else you like to be.\" - Celestine Chua",
-    "\"Death is not sad; the sad thing is that most people don't really live at all.\" - Dan Millman",
-    "\"You must either modify your dreams or magnify your skills.\" - Jim Rohn",
-    "\"It is not enough to win a war; it is more important to organize the peace.\" - Aristotle",
-    "\"Show me a hero, and I'll write you a tragedy.\" - F. Scott Fitzgerald",
-    "\"The more people you help become successful the more successful you become.\" - Steve Harvey",
-    "\"You have the freedom to be yourself, your true self, here and now, and nothing can stand in your way.\" - Richard Bach",
-    "\"You were born to win, but to be a winner, you must plan to win, prepare to win, expect to win.\" - Arnold Schwarzenegger",
-    "\"Knowledge is a treasure, but practice is the key to it.\" - Lao Tzu",
-    "\"Common sense is not so common. \" - Voltaire",
-    "\"The truth is rarely pure and never simple. \" - Oscar Wilde",
-    "\"Always turn a negative situation into a positive situation.\" - Michael Jordan",
-    "\"I want the fishing rod, not the fish.\" - Yanni",
-    "\"An ounce of performance is worth pounds of promises.\" - Mae West",
-    "\"No great discovery was ever made without a bold guess. \" - Isaac Newton",
-    "\"I have never in my life learned anything from any man who agreed with me.\" - Dudley Field Malone",
-    "\"Without the rain there would be no rainbow.\" - Gilbert Chesterton",
-    "\"Be of good cheer about death, and know this of a truth, that no evil can happen to a good man, either in life or after death.\" - Socrates",
-    "\"I like the dreams of the future better than the history of the past.\" - Thomas Jefferson",
-    "\"Teachers open the door. You enter by yourself. \" - Chinese Proverb",
-    "\"Believe that life is worth living and your belief will help create the fact.\" - William James",
-    "\"The mind can calculate, but the spirit yearns, and the heart wants what the heart wants.\" - Stephen King",
-    "\"The greatest step towards a life of simplicity is to learn to let go.\" - Steve Maraboli",
-    "\"Each day provides it's own gifts.\" - Marcus Aurelius",
-    "\"It is children only who enjoy the present; their elders either live on the memory of the past or the hope of the future.\" - Nicolas Chamfort",
-    "\"To handle yourself, use your head; to handle others, use your heart.\" - Eleanor Roosevelt",
-    "\"Don't try to be young. Just open your mind. Stay interested in stuff.\" - Betty White",
-    "\"Unless a man is master of his soul, all other kinds of mastery amount to little.\" - Theodore Roosevelt",
-    "\"You need to be doing fewer things for more effect instead of doing more things with side effects.\" - Gary Keller",
-    "\"Awakening is possible only for those who seek it and want it.\" - G.I. Gurdjieff",
-    "\"If you can see it in your mind, you can hold it in your mind.\" - Steve Harvey",
-    "\"Success does not consist in never making mistakes but in never making the same one a second time.\" - George Bernard Shaw",
-    "\"Riches don't make a man rich, they only make him busier.\" - Christopher Columbus",
-    "\"I do not seek. I find.\" - Pablo Picasso",
-    "\"I take the position that I'm always to some degree wrong, and the aspiration is to be less wrong.\" - Elon Musk",
-    "\"Rivers know this: there is no hurry. We shall get there some day.\" - A.A. Milne",
-    "\"How you think when you lose determines how long it will be until you win.\" - Gilbert Chesterton",
-    "\"Would you rather live your life according to the approval of others or aligned with your truth and your dreams?\" - Robin Sharma",
-    "\"Not everything that weighs you down is yours to carry.\" - Unknown",
-    "\"Either you choose to stay in the shallow end of the pool or you go out into the ocean.\" - Christopher Reeve",
-    "\"Human happiness and moral duty are inseparably connected. \" - George Washington",
-    "\"Realize deeply that the present moment is all you have. Make the NOW the primary focus of your life. \" - Eckhart Tolle",
-    "\"Because a thing seems difficult for you, do not think it impossible.\" - Marcus Aurelius",
-    "\"Throw me to the wolves and I will return leading the pack.  \" - Seneca",
-    "\"Success is the progressive realization of a worthy goal or ideal.\" - Earl Nightingale",
-    "\"Be fascinated instead of frustrated.\" - Jim Rohn",
-    "\"Better to light a candle than to curse the darkness. \" - Chinese Proverb",
-    "\"We must not sit down and wait for miracles. Up and be going!\" - John Eliot",
-    "\"There is no greatness where there is not simplicity, goodness, and truth.\" - Leo Tolstoy",
-    "\"The temptation to give up is strongest just before victory.\" - Zen Proverb",
-    "\"It's always to soon to quit!\" - Norman Vincent Peale",
-    "\"Know the rules well, so you can break them effectively.\" - Dalai Lama",
-    "\"When the world pushes you to your knees, you're in the perfect position to pray.\" - Rumi",
-    "\"Men do not attract which they want but that which they are.\" - James Allen",
-    "\"The future belongs to those who believe in the beauty of their dreams.\" - Eleanor Roosevelt",
-    "\"It is better to learn late than never.\" - Publilius Syrus",
-    "\"Patience is a form of wisdom. It demonstrates that we understand and accept the fact that sometimes things must unfold in their own time.\" - Jon Kabat-Zinn",
-    "\"Time doesn't heal emotional pain, you need to learn how to let go.\" - Roy T. Bennett",
-    "\"Yesterday is history, tomorrow is a mystery, today is God's gift, that's why we call it the present.\" - Joan Rivers",
-    "\"Life goes by fast. Enjoy it. Calm down. It's all funny.\" - Joan Rivers",
-    "\"You don't have to be great to start, but you have to start to be great.  \" - Zig Ziglar",
-    "\"It's better to stand by someone's side than by yourself.\" - Jack London",
-    "\"Whatever you think the world is withholding from you, you are withholding from the world.\" - Eckhart Tolle",
-    "\"The moment you stop to think about whether you love someone, you've already stopped loving that person forever.\" - Carlos Ruiz Zafon",
-    "\"The more knowledge you have, the more you're free to rely on your instincts.\" - Arnold Schwarzenegger",
-    "\"The ego wants comfort and certainty. The soul wants to live fully.\" - Maxime Lagace",
-    "\"It is during our darkest moments that we must focus to see the light.\" - Aristotle",
-    "\"Character is doing what you don't want to do but know you should do.\" - Joyce Meyer",
-    "\"Life is too important to be taken seriously.\" - Oscar Wilde",
-    "\"Simplicity is the glory of expression.\" - Walt Whitman",
-    "\"Trust, but verify.\" - Ronald Reagan",
-    "\"Of all men's miseries the bitterest is this: to know so much and to have control over nothing. \" - Herodotus",
-    "\"To love means loving the unlovable.\" - Gilbert Chesterton",
-    "\"The quality of your life is determined by the quality of your thoughts.\" - Unknown",
-    "\"We learn to walk by falling down. If we never fell down, we would never walk.\" - Robert Kiyosaki",
-    "\"Peace is the result of retraining your mind to process life as it is, rather than as you think it should be.  \" - Wayne Dyer",
-    "\"Every artist dips his brush in his own soul, and paints his own nature into his pictures.\" - Henry Ward Beecher",
-    "\"Over time, loneliness gets inside you and doesn't go away.\" - Carlos Ruiz Zafon",
-    "\"Never whine, never complain, never try to justify yourself.\" - Robert Greene",
-    "\"I admire those who do good and expect nothing in return.\" - Roy T. Bennett",
-    "\"Be content with what you have, rejoice in the way things are. When you realize there is nothing lacking, the whole world belongs to you.\" - Lao Tzu",
-    "\"If no one ever broke the rules, then we'd never advance.\" - Simon Sinek",
-    "\"He who knows best knows how little he knows.\" - Thomas Jefferson",
-    "\"It is very obvious that we are not influenced by 'facts' but by our interpretation of the facts.\" - Alfred Adler",
-    "\"Associate yourself with men of good quality, if you esteem your own reputation; for 'tis better to be alone than in bad company. \" - George Washington",
-    "\"Conscience is a dog that does not stop us from passing but that we cannot prevent from barking.\" - Nicolas Chamfort",
-    "\"Wishing to be friends is quick work, but friendship is a slow ripening fruit.\" - Aristotle",
-    "\"Art, like morality, consists of drawing the line somewhere.\" - Gilbert Chesterton",
-    "\"When everything seems to be going against you, remember that the airplane takes off against the wind, not with it.\" - Henry Ford",
-    "\"My mind seems to have become a kind of machine for grinding general laws out of large collections of facts.\" - Charles Darwin",
-    "\"Don't ever save anything for a special occasion. Being alive is the special occasion.\" - Mary Engelbreit",
-    "\"All religions, arts and sciences are branches of the same tree. \" - Albert Einstein",
-    "\"The less you try to impress, the more peaceful you can be.\" - Maxime Lagace",
-    "\"You play the hand you're dealt. I think the game's worthwhile.  \" - Christopher Reeve",
-    "\"Those who seek the easy way do not seek the true way.\" - Dogen",
-    "\"The mind that turns ever outward Will have no end to craving. Only the mind turned inward Will find a still-point of peace.\" - Ming-Dao Deng",
-    "\"Genius is one percent inspiration and ninety-nine percent perspiration.\" - Thomas Edison",
-    "\"If you're afraid to die, you're afraid to live. You can't have one without the other.\" - Rita Mae Brown",
-    "\"Be not afraid of growing slowly, be afraid only of standing still. \" - Chinese Proverb",
-    "\"When you're the only sane person, you look like the only insane person.\" - Criss Jami",
-    "\"The most important battle is one to conquer yourself.\" - Yanni",
-    "\"My sun sets to rise again.\" - Robert Browning",
-    "\"Observe things as they are and don't pay attention to other people.\" - Huang Po",
-    "\"If you cannot understand something, then you have understood it incorrectly.\" - Kabir",
-    "\"The idea of ourselves is our escape from the fact of what we really are.\" - Jiddu Krishnamurti",
-    "\"Every act of creation is first an act of destruction.\" - Pablo Picasso",
-    "\"The only way out is through.\" - Robert Frost",
-    "\"You are not only responsible for what you say, but also for what you do not say. \" - Martin Luther",
-    "\"The least deviation from truth will be multiplied later.\" - Aristotle",
-    "\"When we think about our strengths, we are strong. When we think about our weaknesses, we are weak.\" - Peter A. Cohen",
-    "\"Great leaders are almost always great simplifiers, who can cut through argument, debate and doubt, to offer a solution everybody can understand.\" - Colin Powell",
-    "\"The world is open for play, everything and everybody is mockable in a wonderful way.\" - Robin Williams",
-    "\"Some people feel the rain. Others just get wet. \" - Bob Dylan",
-    "\"A man is not called wise because he talks and talks again; but if he is peaceful, loving and fearless then he is in truth called wise. \" - Buddha",
-    "\"Someday death will take us to another star.\" - Vincent van Gogh",
-    "\"Ask no questions, and you'll be told no lies.\" - Charles Dickens",
-    "\"There is always risk, so learn to manage risk instead of avoiding it.\" - Robert Kiyosaki",
-    "\"All has not been said and never will be.\" - Samuel Beckett",
-    "\"Some people are old at 18 and some are young at 90. Time is a concept that humans created.\" - Yoko Ono",
-    "\"There is nothing like looking if you want to find something.\" - J.R.R. Tolkien",
-    "\"If someone betrays you once, it's their fault; if they betray you twice, it's your fault.\" - Eleanor Roosevelt",
-    "\"Everyone you admire was once a beginner.\" - Jack Butcher",
-    "\"Each of us is a unique strand in the intricate web of life and here to make a contribution.\" - Deepak Chopra",
-    "\"I have not failed. I've just found 10,000 ways that won't work.\" - Thomas Edison",
-    "\"Realistic people do not accomplish extraordinary things.\" - John Eliot",
-    "\"It's easy to be a critic, but being a doer requires effort, risk, and change.  \" - Wayne Dyer",
-    "\"The more we value things, the less we value ourselves.  \" - Bruce Lee",
-    "\"The greatest of all mistakes is to do nothing because you think you can only do a little.  \" - Zig Ziglar",
-    "\"Failure is feedback. Learn from it and move on.\" - Jack Butcher",
-    "\"The greatest enemy of knowledge is not ignorance, it's the illusion of knowledge.\" - Stephen Hawking",
-    "\"Everyone faces challenges in life. It's a matter of how you learn to overcome them and using them to your advantage.\" - Celestine Chua",
-    "\"If you have only one smile in you give it to the people you love.\" - Maya Angelou",
-    "\"A diamond is a piece of charcoal that handled stress exceptionally well.\" - Unknown",
-    "\"Living life in style also means living a life of balance.\" - Jim Rohn",
-    "\"All problems are interpersonal relationship problems.\" - Alfred Adler",
-    "\"Winners are not afraid of losing. But losers are. Failure is part of the process of success. People who avoid failure also avoid success.\" - Robert Kiyosaki",
-    "\"From the errors of others, a wise man corrects his own.\" - Publilius Syrus",
-    "\"Never be bored, and you will never be boring.\" - Eleanor Roosevelt",
-    "\"You will have bad times, but they will always wake you up to the stuff you weren't paying attention to.\" - Robin Williams",
-    "\"An inch of time is an inch of gold but you can't buy that inch of time with an inch of gold.\" - Chinese Proverb",
-    "\"One of the most difficult things is not to change society - but to change yourself.\" - Nelson Mandela",
-    "\"Enlightenment is intimacy with all things.\" - Dogen",
-    "\"We have to live life with a sense of urgency so not a minute is wasted.\" - Les Brown",
-    "\"No man will make a great leader who wants to do it all himself or to get all the credit for doing it.\" - Andrew Carnegie",
-    "\"Opportunity often comes disguised in the form of misfortune or temporary defeat.\" - Napoleon Hill",
-    "\"We can spend our whole lives escaping from the monsters of our minds.\" - Pema Chodron",
-    "\"I defeat my enemies when I make them my friends.\" - Dalai Lama",
-    "\"Find something you love and do it better than everyone else.\" - Gurbaksh Chahal",
-    "\"Happiness is a choice and a skill and you can dedicate yourself to learning that skill and making that choice.\" - Naval Ravikant",
-    "\"I didn't get there by wishing for it or hoping for it, but by working for it.\" - Estee Lauder",
-    "\"Wheresoever you go, go with all your heart.\" - Confucius",
-    "\"Make improvements, not excuses. Seek respect, not attention.\" - Roy T. Bennett",
-    "\"All life is a manifestation of the spirit, the manifestation of love.\" - Morihei Ueshiba",
-    "\"Sometimes out of your biggest misery, comes your greatest gain.\" - Steve Harvey",
-    "\"To earn more you must learn more.\" - Brian Tracy",
-    "\"Integrity is the ability to stand by an idea.\" - Ayn Rand",
-    "\"When you change what you believe, you change what you do.\" - Spencer Johnson",
-    "\"A person hears only what they understand.\" - Johann Wolfgang von Goethe",
-    "\"A very little key will open a very heavy door.\" - Charles Dickens",
-    "\"Follow your instincts. That is where true wisdom manifests itself.\" - Oprah Winfrey",
-    "\"Freeing oneself from words is liberation. \" - Bodhidharma",
-    "\"The way we feel is the direct result of what we think.\" - Peter A. Cohen",
-    "\"Peace if possible, truth at all costs.\" - Martin Luther",
-    "\"If you want things to be different, perhaps the answer is to become different yourself.\" - Norman Vincent Peale",
-    "\"The art of being wise is knowing what to overlook.\" - William James",
-    "\"Forgive yourself for your faults and your mistakes and move on.\" - Les Brown",
-    "\"A man's worth is no greater than his ambitions.\" - Marcus Aurelius",
-    "\"No duty is more urgent than that of returning thanks.\" - James Allen",
-    "\"The best dreams happen when you're awake.\" - Cherie Gilderbloom",
-    "\"If you want to keep your memories, you first have to live them.\" - Bob Dylan",
-    "\"Happiness is only real when shared.\" - Christopher McCandless",
-    "\"The single most powerful asset we all have is our mind.\" - Robert Kiyosaki",
-    "\"True life is lived when tiny changes occur.\" - Leo Tolstoy",
-    "\"Troubles are often the tools by which God fashions us for better things.\" - Henry Ward Beecher",
-    "\"Talk does not cook rice.\" - Chinese Proverb",
-    "\"Enjoy every minute of life. Never second-guess life.\" - Michael Jordan",
-    "\"If you want to achieve greatness stop asking for permission.\" - Unknown",
-    "\"The goal of an artist is to create the definitive work that cannot be surpassed.\" - George Bernard Shaw",
-    "\"I am not proud, but I am happy; and happiness blinds, I think, more than pride.\" - Alexandre Dumas",
-    "\"Knowing others is wisdom, knowing yourself is enlightenment.\" - Lao Tzu",
-    "\"Wrong turns are as important as right turns. More important, sometimes.\" - Richard Bach",
-    "\"Some of the greater things in life are unseen; that's why you close your eyes when you kiss, cry, or dream.\" - Unknown",
-    "\"If you don't know, the thing to do is not to get scared, but to learn.\" - Ayn Rand",
-    "\"If you look for perfection, you'll never be content.\" - Leo Tolstoy",
-    "\"You live by what you thrill to, and there's the end of it.\" - D. H. Lawrence",
-    "\"If you haven't the strength to impose your own terms upon life, then you must accept the terms it offers you.\" - T.S. Eliot",
-    "\"Your time is limited, so don't waste it living someone elses. life.\" - Steve Jobs",
-    "\"Great deeds are usually wrought at great risks.\" - Herodotus",
-    "\"As you think, you travel, and as you love, you attract.\" - James Allen",
-    "\"If we are honest with ourselves, most of us will have to admit that we live out our lives in an ocean of fear.\" - Jon Kabat-Zinn",
-    "\"Good luck is opportunity meeting preparedness.\" - Deepak Chopra",
-    "\"Words can only have a positive effect on others if and when they are ready to listen.\" - Christopher Reeve",
-    "\"Enthusiasm is a vital element toward the individual success of every man or woman.\" - Conrad Hilton",
-    "\"Anger, ego, jealousy are the biggest diseases,Keep yourself aloof from these three diseases.\" - Sathya Sai Baba",
-    "\"Give me six hours to chop down a tree and I will spend the first four sharpening the axe.\" - Abraham Lincoln",
-    "\"Respect was invented to cover the empty place where love should be.\" - Leo Tolstoy",
-    "\"Why change? Everyone has his own style. When you have found it, you should stick to it.\" - Audrey Hepburn",
-    "\"What you resist, persists.\" - Carl Jung",
-    "\"Little minds have little worries, big minds have no time for worries.\" - Ralph Waldo Emerson",
-    "\"Life is what you make it. Always has been, always will be.\" - Eleanor Roosevelt",
-    "\"You are now, and you do become, what you think about.\" - Earl Nightingale",
-    "\"We learn by pushing ourselves and finding what really lies at the outer reaches of our abilities.\" - Josh Waitzkin",
-    "\"The wise man reads both books and life itself.\" - Lin Yutang",
-    "\"I learned the value of hard work by working hard. \" - Margaret Mead",
-    "\"Death is as sure for that which is born, as birth is for that which is dead. Therefore grieve not for what is inevitable.\" - Bhagavad Gita",
-    "\"The most creative act you will ever undertake is the act of creating yourself.\" - Deepak Chopra",
-    "\"To get angry is like to revenge yourself for the guilt of others.\" - Jonathan Swift",
-    "\"There is always room in your life for thinking bigger, pushing limits and imagining the impossible.\" - Tony Robbins",
-    "\"Most of the evil in this world is done by people with good intentions.\" - T.S. Eliot",
-    "\"You have to give up some of the old so that you can make room for the new.\" - Yanni",
-    "\"You can discover what your enemy fears most by observing the means he uses to frighten you.\" - Eric Hoffer",
-    "\"The day is for honest men, the night for thieves.\" - Euripides",
-    "\"The glow of one warm thought is to me worth more than money.\" - Thomas Jefferson",
-    "\"Experience is simply the name we give our mistakes. \" - Oscar Wilde",
-    "\"Never argue with stupid people, they will drag you down to their level and then beat you with experience.\" - Mark Twain",
-    "\"I am always doing what I cannot do yet, in order to learn how to do it.\" - Vincent van Gogh",
-    "\"In any moment of decision, the best thing you can do is the right thing. The worst thing you can do is nothing.\" - Theodore Roosevelt",
-    "\"Turn off your mind, relax, and float downstream.\" - John Lennon",
-    "\"A change is brought about because ordinary people do extraordinary things.\" - Barack Obama",
-    "\"Do not pray for an easy life, pray for the strength to endure a difficult one.  \" - Bruce Lee",
-    "\"Life comes at us in waves. We can't predict or control those waves, but we can learn to surf.\" - Dan Millman",
-    "\"Confidence is like a dragon where, for every head cut off, two more heads grow back.\" - Criss Jami",
-    "\"It's better to dance than to march through life.\" - Yoko Ono",
-    "\"The things I carry are my thoughts. That's it. They are the only weight. My thoughts determine whether I am free and light or burdened.\" - Kamal Ravikant",
-    "\"To arrive at the simplest truth requires years of contemplation.\" - Isaac Newton",
-    "\"Sadness is but a wall between two gardens.\" - Kahlil Gibran",
-    "\"We must not be afraid of dreaming the seemingly impossible if we want the seemingly impossible to become a reality.\" - Vaclav Havel",
-    "\"The heart wants what it wants - or else it does not care.\" - Emily Dickinson",
-    "\"You purchase pain with all that joy can give and die of nothing but a rage to live.\" - Alexander Pope",
-    "\"Our deeds determine us, as much as we determine our deeds.\" - George Eliot",
-    "\"In the middle of difficulty lies opportunity.\" - Albert Einstein",
-    "\"Reflect upon your present blessings of which every man has many - not on your past misfortunes, of which all men have some.\" - Charles Dickens",
-    "\"Those who cannot change their minds cannot change anything.\" - George Bernard Shaw",
-    "\"Today will never happen again. Don't waste it with a false start or no start at all.\" - Og Mandino",
-    "\"A leader is one who knows the way, goes the way, and shows the way.\" - Unknown",
-    "\"It is your attitude, not your aptitude, that determines your altitude.\" - Zig Ziglar",
-    "\"Death walks faster than the wind and never returns what he has taken.\" - Hans Christian Andersen",
-    "\"Have the fearless attitude of a hero and the loving heart of a child.\" - Soyen Shaku",
-    "\"The journey is what brings us happiness not the destination.\" - Dan Millman",
-    "\"The purpose of life is the life of purpose.\" - Robin Sharma",
-    "\"Fortunate are those who take the first steps.\" - Paulo Coelho",
-    "\"To travel is to be alive, but to get somewhere is to be dead.\" - Alan Watts",
-    "\"When everything seems to be going against you, remember that the airplane takes off against the wind, not with it. \" - Henry Ford",
-    "\"Not until we are lost do we begin to understand ourselves.\" - Henry David Thoreau",
-    "\"Distant water does not put out a nearby fire.\" - Chinese Proverb",
-    "\"The most efficient way of rendering the poor harmless is to teach them to want to imitate the rich.\" - Carlos Ruiz Zafon",
-    "\"All human beings can alter their lives by altering their attitudes.\" - Andrew Carnegie",
-    "\"Winners never quit and quitters never win.\" - Vince Lombardi",
-    "\"Act as if what you do makes a difference. It does.\" - William James",
-    "\"You can't let your failures define you. You have to let your failures teach you.\" - Barack Obama",
-    "\"With self-discipline, almost anything is possible.\" - Theodore Roosevelt",
-    "\"Aim for the moon. If you miss, you may hit a star.\" - W. Clement Stone",
-    "\"The moment you doubt whether you can fly, you cease for ever to be able to do it.\" - James Matthew Barrie",
-    "\"Do not dwell in the past, do not dream of the future, concentrate the mind on the present moment.\" - Buddha",
-    "\"There are few reasons for telling the truth, but for lying the number is infinite.\" - Carlos Ruiz Zafon",
-    "\"The desire for more positive experience is itself a negative experience.\" - Mark Manson",
-    "\"Yesterday is the past, tomorrow is the future, today is a gift - that's why it's called the present.\" - George Bernard Shaw",
-    "\"The majority of what you want will come from the minority of what you do.\" - Gary Keller",
-    "\"When you are tempted to give up, your breakthrough is probably just around the corner.\" - Joyce Meyer",
-    "\"There are only encounters in history. There are no accidents.\" - Pablo Picasso",
-    "\"I skate to where the puck is going to be, not where it has been.\" - Wayne Gretzky",
-    "\"Imagination is the beginning of creation.\" - George Bernard Shaw",
-    "\"Real growth comes through intense, difficult, and challenging situations.\" - Kamal Ravikant",
-    "\"See what you're doing wrong, laugh at it, change and do better.\" - Spencer Johnson",
-    "\"Happiness is a choice, not a result. Nothing will make you happy until you choose to be happy.\" - Ralph Marston",
-    "\"Don't let the fear of losing be greater than the excitement of winning.\" - Robert Kiyosaki",
-    "\"We tend to live up to our expectations.\" - Earl Nightingale",
-    "\"If you realize that you're the problem, then you can change yourself, learn something and grow wiser. Don't blame other people for your problems.\" - Robert Kiyosaki",
-    "\"Determine never to be idle. No person will have occasion to complain of the want of time who never loses any.\" - Thomas Jefferson",
-    "\"Fashion fades, only style remains the same.\" - Coco Chanel",
-    "\"Projecting your mind into a successful situation is the most powerful means to achieve goals.  \" - Estee Lauder",
-    "\"Our stories may be singular, but our destination is shared.\" - Barack Obama",
-    "\"When people are free to do as they please, they usually imitate each other.\" - Eric Hoffer",
-    "\"A successful man is one who can lay a firm foundation with the bricks others have thrown at him.  \" - David Brinkley",
-    "\"Be confident, not certain.\" - Eleanor Roosevelt",
-    "\"Time goes from present to past.\" - Shunryu Suzuki",
-    "\"Most of the important things in the world have been accompanied by people who have kept on trying when there seemed to be no hope at all.\" - Dale Carnegie",
-    "\"The wise accomplish all that they want without arousing the envy or scorn of others.\" - Ming-Dao Deng",
-    "\"The world doesn't always give you want, but it often gives you what you need.\" - Naval Ravikant",
-    "\"Do not ignore the passion that burns in you. Spend time to discover your gift.\" - Steve Harvey",
-    "\"Do not take life too seriously. You will never get out of it alive.\" - Elbert Hubbard",
-    "\"You have power over your mind - not outside events. Realize this, and you will find strength.\" - Marcus Aurelius",
-    "\"It's the job that's never started that takes the longest to finish.\" - J.R.R. Tolkien",
-    "\"For all evils there are two remedies - time and silence.\" - Alexandre Dumas",
-    "\"To be in harmony with the wholeness of things is not to have anxiety over imperfections.\" - Dogen",
-    "\"Wisdom consists not so much in knowing what to do in the ultimate as knowing what to do next.\" - Herbert Hoover",
-    "\"Trust thyself: every heart vibrates to that iron string.\" - Ralph Waldo Emerson",
-    "\"Life is not just the passing of time. Life is the collection of experiences and their intensity.\" - Jim Rohn",
-    "\"People who say it cannot be done should not interrupt those who are doing it.\" - George Bernard Shaw",
-    "\"Fishing is much more than fish.\" - Herbert Hoover",
-    "\"Make your mind your own business.\" - Jack Butcher",
-    "\"We will outstretch the hand if you unclench your fist.\" - Barack Obama",
-    "\"The most important thing in communication is to hear what isn't being said.\" - Peter Drucker",
-    "\"Don't waste time on excuses. Just get it done.\" - Ralph Marston",
-    "\"One of the first signs of the beginning of understanding is the wish to die. \" - Franz Kafka",
-    "\"If you aren't sure which way to do something, do it both ways and see which works better.\" - John Carmack",
-    "\"Fear does not have any special power unless you empower it by submitting to it.\" - Les Brown",
-    "\"However difficult life may seem, there is always something you can do and succeed at.\" - Stephen Hawking",
-    "\"The eyes of others our prisons; their thoughts our cages.\" - Virginia Woolf",
-    "\"Every poem should remind the reader that they are going to die.\" - Edgar Allan Poe",
-    "\"The best time to plant a tree was 20 years ago. The second best time is now.\" - Zen Proverb",
-    "\"Change is never easy, but always possible.\" - Barack Obama",
-    "\"You can't have anything worthwhile without difficulties.\" - Harry S. Truman",
-    "\"We must create our own world or we will die from inaction.\" - Robert Greene",
-    "\"People think you're crazy if you talk about things they don't understand.\" - Elvis Presley",
-    "\"There's no next time. It's now or never.\" - Celestine Chua",
-    "\"People who are unable to motivate themselves must be content with mediocrity, no matter how impressive their other talents.\" - Andrew Carnegie",
-    "\"Making money isn't hard in itself... What's hard is to earn it doing something worth devoting one's life to.\" - Carlos Ruiz Zafon",
-    "\"Just trust that everything is unfolding the way it is supposed to. Don't resist... Great things are waiting for you around the corner.\" - Sonia Ricotti",
-    "\"When it is dark enough, you can see the stars.\" - Ralph Waldo Emerson",
-    "\"Adventure is not outside man; it is within.\" - George Eliot",
-    "\"You don't earn loyalty in a day. You earn loyalty day-by-day.\" - Jeffrey Gitomer",
-    "\"When you get up in the morning, you have two choices - either to be happy or to be unhappy. Just choose to be happy.\" - Norman Vincent Peale",
-    "\"Society is composed of two great classes those who have more dinners than appetite, and those who have more appetite than dinners.\" - Nicolas Chamfort",
-    "\"Anger is an acid that can do more harm to the vessel in which it is stored than to anything on which it is poured.\" - Mark Twain",
-    "\"Definiteness of purpose is the starting point of all achievement. \" - W. Clement Stone",
-    "\"It doesn't matter how much you want. What really matters is how much you want it.\" - Ralph Marston",
-    "\"Learn to sit back and observe, not everything needs a reaction.\" - Unknown",
-    "\"There's more to life than being a passenger.\" - Amelia Earhart",
-    "\"Just because you are happy it does not mean that the day is perfect but that you have looked beyond its imperfections.\" - Bob Marley",
-    "\"The road to success is always under construction..\" - Lily Tomlin",
-    "\"I can think of nothing less pleasurable than a life devoted to pleasure.\" - John D. Rockefeller",
-    "\"Thought is so cunning, so clever, that it distorts everything for its own convenience.\" - Jiddu Krishnamurti",
-    "\"Human behavior flows from three main sources: desire, emotion, and knowledge.\" - Plato",
-    "\"Happiness is not a goal...it's a by-product of a life well lived.\" - Eleanor Roosevelt",
-    "\"It is ignorance that causes most mistakes.\" - Harry S. Truman",
-    "\"Life becomes easier and more beautiful when we can see the good in other people.\" - Roy T. Bennett",
-    "\"Dreams have only one owner at a time. That's why dreamers are lonely.\" - William Faulkner",
-    "\"The important thing is not to stop questioning. Curiosity has its own reason for existing.\" - Albert Einstein",
-    "\"There are two ways of seeing: with the body and with the soul. The body's sight can sometimes forget, but the soul remembers forever.\" - Alexandre Dumas",
-    "\"Your neighbor is the man who needs you.\" - Elbert Hubbard",
-    "\"Life reflects your own thoughts back to you.\" - Napoleon Hill",
-    "\"You can never cross the ocean unless you have the courage to lose sight of the shore.\" - Christopher Columbus",
-    "\"All cruelty springs from weakness.  \" - Seneca",
-    "\"If you love somebody, let them go, for if they return, they were always yours. If they don't, they never were.\" - Kahlil Gibran",
-    "\"Nothing like a health problem to turn up the contrast dial for the rest of life.\" - Naval Ravikant",
-    "\"The major difference between the big shot and the little shot is the big shot is just a little shot who kept on shooting.\" - Zig Ziglar",
-    "\"The four P's to success are pressure, persistence, perseverance, and prayer.\" - Steve Harvey",
-    "\"The merit of all things lies in their difficulty.\" - Alexandre Dumas",
-    "\"I was angered, for I had no shoes. Then I met a man who had no feet. \" - Chinese Proverb",
-    "\"The best and most beautiful things in the world cannot be seen or even touched - they must be felt with the heart.\" - Helen Keller",
-    "\"Intelligent people make many mistakes because they cannot believe the world is really as foolish as it is.\" - Nicolas Chamfort",
-    "\"Art is the signature of civilizations. \" - Beverly Sills",
-    "\"Nothing brings down walls as surely as acceptance.\" - Deepak Chopra",
-    "\"Generosity is giving more than you can, and pride is taking less than you need.\" - Kahlil Gibran",
-    "\"In the end, it's not the years in your life that count. It's the life in your years.\" - Abraham Lincoln",
-    "\"Anyone who reads a book with a sense of obligation does not understand the art of reading.\" - Lin Yutang",
-    "\"Youth ages, immaturity is outgrown, ignorance can be educated, and drunkenness sobered, but stupid lasts forever.\" - Aristophanes",
-    "\"The greatest of empires, is the empire over one's self.\" - Publilius Syrus",
-    "\"Truth is like most opinions - best unexpressed.\" - Kenneth Branagh",
-    "\"The pen is the tongue of the mind. \" - Miguel de Cervantes",
-    "\"There is no path to Happiness. Happiness is the path.\" - Dan Millman",
-    "\"Maybe you are searching among the branches, for what only appears in the roots.\" - Rumi",
-    "\"There are two things a person should never be angry at, what they can help, and what they cannot.\" - Plato",
-    "\"The goal is not to be perfect by the end, the goal is to be better today.\" - Simon Sinek",
-    "\"Fools read fast. Geniuses reread.\" - Maxime Lagace",
-    "\"If you want work well done, select a busy man; the other kind has no time.\" - Elbert Hubbard",
-    "\"Take away love and our earth is a tomb.\" - Robert Browning",
-    "\"Go for it now. The future is promised to no one. \" - Wayne Dyer",
-    "\"Everyone thinks of changing the world, but no one thinks of changing himself.\" - Leo Tolstoy",
-    "\"Goodness is the only investment that never fails.\" - Henry David Thoreau",
-    "\"Nothing has any power over me other than that which I give it through my conscious thoughts.\" - Tony Robbins",
-    "\"Before preparing to improve the world, first look around your own home three times. \" - Chinese Proverb",
-    "\"To know your Enemy, you must become your Enemy.\" - Sun Tzu",
-    "\"A man sees in the world what he carries in his heart.\" - Johann Wolfgang von Goethe",
-    "\"A good master leads you to the true master within. Only that master, who is your own higher self, can adequately answer all questions.\" - Ming-Dao Deng",
-    "\"If you've made a mistake, it's better just to laugh at it.\" - Zen Proverb",
-    "\"Believe in one thing too much and you have no room for new ideas.  \" - Ray Bradbury",
-    "\"Don't measure yourself by what you have accomplished, but by what you should have accomplished with your ability.\" - John Wooden",
-    "\"You can only lose something that you have, but you cannot lose something that you are.\" - Eckhart Tolle",
-    "\"Every day may not be good, but there's something good in every day.\" - Unknown",
-    "\"Cultivate the merry heart, develop the happiness habit, and life will become a continual feast.\" - Norman Vincent Peale",
-    "\"Success is often achieved by those who don't know that failure is inevitable.\" - Coco Chanel",
-    "\"Muddy water is best cleared by leaving it alone.\" - Alan Watts",
-    "\"There are more quarrels smothered by just shutting your mouth, and holding it shut, than by all the wisdom in the world.\" - Henry Ward Beecher",
-    "\"Guilt is perhaps the most painful companion of death.\" - Coco Chanel",
-    "\"There are no shortcuts to any place worth going. \" - Beverly Sills",
-    "\"If you don't read the newspaper, you're uninformed. If you read the newspaper, you're misinformed.\" - Mark Twain",
-    "\"Focused, hard work is the real key to success.\" - John Carmack",
-    "\"Courage is found in unlikely places.\" - J.R.R. Tolkien",
-    "\"But better die than live mechanically a life that is a repetition of repetitions.\" - D. H. Lawrence",
-    "\"Whether you have a problem in your life or not depends upon your own attitude, your own understanding.\" - Shunryu Suzuki",
-    "\"If you do the work you get rewarded. There are no shortcuts in life.\" - Michael Jordan",
-    "\"Life happens in the river, not the shore.\" - Maxime Lagace",
-    "\"Every hour of every day is an unspeakably perfect miracle.\" - Walt Whitman",
-    "\"It is startling to realize how much unbelief is necessary to make belief possible.\" - Eric Hoffer",
-    "\"Sometimes things become possible if we want them bad enough.\" - T.S. Eliot",
-    "\"Never confuse a single defeat with a final defeat.\" - F. Scott Fitzgerald",
-    "\"Peace is letting it be. Letting life flow, letting emotions flow through you.\" - Kamal Ravikant",
-    "\"Be the change that you want to see in the world.\" - Mahatma Gandhi",
-    "\"Failure is a great teacher. If you're open to it every mistake has a lesson to offer.\" - Oprah Winfrey",
-    "\"Success is the child of drudgery and perseverance. It cannot be coaxed or bribed; pay the price and it is yours. \" - Orison Swett Marden",
-    "\"You find peace not by rearranging the circumstances of your life, but by realizing who you are at the deepest level.\" - Eckhart Tolle",
-    "\"Sometimes letting things go is an act of far greater power than defending or hanging on.\" - Eckhart Tolle",
-    "\"The man who does not value himself, cannot value anything or anyone.\" - Ayn Rand",
-    "\"Wealth is the product of man's capacity to think.\" - Ayn Rand",
-    "\"No matter how smart we may think we are, no matter how committed we are to our truth, we can lose our way.\" - Kamal Ravikant",
-    "\"Love is the absence of judgment.\" - Dalai Lama",
-    "\"Don't be afraid to stand for what you believe in, even if that means standing alone.\" - Unknown",
-    "\"When you are content to be simply yourself and don't compare or compete, everybody will respect you. \" - Lao Tzu",
-    "\"People are not disturbed by things, but by the views they take of them.\" - Epictetus",
-    "\"A good teacher opens the door for you, but you must enter the room by yourself.\" - Zen Proverb",
-    "\"The soul without imagination is what an observatory would be without a telescope.\" - Henry Ward Beecher",
-    "\"If you want to know what God thinks about money just look at the people He gives it to.\" - Alexander Pope",
-    "\"It is secondary whether we choose belief or defiance. What is precious is that we are always able to choose.\" - Ming-Dao Deng",
-    "\"Success is not the result of making money; making money is the result of success - and success is in direct proportion to our service.\" - Earl Nightingale",
-    "\"The thing about smart people is that they seem like crazy people to dumb people.\" - Stephen Hawking",
-    "\"Ever tried. Ever failed. No matter. Try Again. Fail again. Fail better. \" - Samuel Beckett",
-    "\"The road to success and the road to failure are almost exactly the same.\" - Colin R. Davis",
-    "\"Decide what your truth is. Then live it.\" - Kamal Ravikant",
-    "\"We're kept from our goal not by obstacles, but by a clear path to a lesser goal.\" - Bhagavad Gita",
-    "\"I will love the light for it shows me the way, yet I will endure the darkness because it shows me the stars.\" - Og Mandino",
-    "\"People will forget what you said, people will forget what you did, but people will never forget how you made them feel.\" - Maya Angelou",
-    "\"Surrender to what is. Let go of what was. Have faith in what will be.\" - Sonia Ricotti",
-    "\"Life is like stepping onto a boat which is about to sail out to sea and sink.\" - Shunryu Suzuki",
-    "\"The greatest work that kindness does to others is that it makes them kind themselves.\" - Amelia Earhart",
-    "\"To some degree, you control your life by controlling your time.\" - Conrad Hilton",
-    "\"You are today where your thoughts have brought you.\" - James Allen",
-    "\"It's only after you've stepped outside your comfort zone that you begin to change, grow, and transform.\" - Roy T. Bennett",
-    "\"Unless you're ashamed of yourself now and then, you're not honest.\" - William Faulkner",
-    "\"Feelings come and go like clouds in a windy sky. Conscious breathing is my anchor.\" - Thich Nhat Hanh",
-    "\"Listen to what you know instead of what you fear.\" - Richard Bach",
-    "\"He who is not contented with what he has, would not be contented with what he would like to have.\" - Socrates",
-    "\"The river that flows in you also flows in me.\" - Kabir",
-    "\"Happiness is not something ready made. It comes from your own actions.\" - Dalai Lama",
-    "\"Fear of death is fear of the Unknown.\" - Adi Da Samraj",
-    "\"Dance first. Think later. It's the natural order.\" - Samuel Beckett",
-    "\"Everyone sees what you appear to be, few experience what you really are.\" - Niccolo Machiavelli",
-    "\"Only by acceptance of the past, can you alter it.\" - T.S. Eliot",
-    "\"Mistakes are always forgivable, if one has the courage to admit them. \" - Bruce Lee",
-    "\"Today I begin a new life. I will greet this day with love in my heart.\" - Og Mandino",
-    "\"You want to live but do you know how to live? You are scared of dying and tell me, is the kind of life you lead really any different from being dead?\" - Seneca",
-    "\"It is in the darkness that one finds the light.\" - Meister Eckhart",
-    "\"My instinct is always to seek out challenges as opposed to avoiding them.\" - Josh Waitzkin",
-    "\"The trouble with the rat race is that even if you win, you're still a rat.\" - Lily Tomlin",
-    "\"Success is nothing more than a few simple disciplines practiced every day.\" - Jim Rohn",
-    "\"Logic is like the sword - those who appeal to it shall perish by it.\" - Samuel Butler",
-    "\"The finish line is just the beginning of a whole new race.\" - Unknown",
-    "\"It's your road, and yours alone, others may walk it with you, but no one can walk it for you.\" - Rumi",
-    "\"Passion is born deaf and dumb.\" - Honore de Balzac",
-    "\"Some people dream of success, while others wake up and work hard at it.\" - Napoleon Hill",
-    "\"Is it really possible to tell someone else what one feels?\" - Leo Tolstoy",
-    "\"The only real mistake is the one from which we learn nothing. \" - Henry Ford",
-    "\"Keep others in suspense, cultivate an air of unpredictability.\" - Robert Greene",
-    "\"Excellence is not an exception, it is a prevailing attitude.\" - Colin Powell",
-    "\"Sometimes you put walls up not to keep people out, but to see who cares enough to break them down.\" - Socrates",
-    "\"The universe doesn't allow perfection.\" - Stephen Hawking",
-    "\"A man who acquires the ability to take full possession of his own mind may take possession of anything else to which he is justly entitled.\" - Andrew Carnegie",
-    "\"To be able to forget means sanity.\" - Jack London",
-    "\"Anyone who wants to achieve a dream must stay strong, focused and steady.\" - Estee Lauder",
-    "\"Very little is needed to make a happy life; it is all within yourself, in your way of thinking.\" - Marcus Aurelius",
-    "\"The wisest men follow their own direction.\" - Euripides",
-    "\"The best way to find yourself is to lose yourself in the service of others.\" - Mahatma Gandhi",
-    "\"Children are our greatest natural resource.\" - Herbert Hoover",
-    "\"The highest form of ignorance is when you reject something you don't know anything about. \" - Wayne Dyer",
-    "\"Change the changeable, accept the unchangeable, and remove yourself from the unacceptable. \" - Denis Waitley",
-    "\"If you want to be happy, do not dwell in the past, do not worry about the future, focus on living fully in the present.\" - Roy T. Bennett",
-    "\"Even if you're sure you can win, be careful that you can live with what you lose.\" - Gary Keller",
-    "\"There is no right or wrong, only a series of possibilities that shift with each thought, feeling, and action that you experience.\" - Deepak Chopra",
-    "\"Better a diamond with a flaw than a pebble without one.\" - Chinese Proverb",
-    "\"There is little success where there is little laughter.\" - Andrew Carnegie",
-    "\"Waste not fresh tears over old griefs.\" - Euripides",
-    "\"Saying nothing sometimes says the most.\" - Emily Dickinson",
-    "\"Nature does not hurry, yet everything is accomplished.\" - Lao Tzu",
-    "\"Motivation gets you going and habit gets you there.  \" - Zig Ziglar",
-    "\"It's not what you've got; it's what you do that makes the difference.\" - Celestine Chua",
-    "\"Most people achieved their greatest success one step beyond what looked like their greatest failure.\" - Brian Tracy",
-    "\"The strength of a wall is neither greater nor less than the courage of the men who defend it. \" - Genghis Khan",
-    "\"People are doing the best that they can from their own level of consciousness.\" - Deepak Chopra",
-    "\"Our truest life is when we are in dreams awake.\" - Henry David Thoreau",
-    "\"The wiser you get, the less you care to look like a fool.\" - Maxime Lagace",
-    "\"Never give way to laziness.\" - Bhagavad Gita",
-    "\"Rich people have small TVs and big libraries, and poor people have small libraries and big TVs.\" - Zig Ziglar",
-    "\"Continuous effort - not strength or intelligence - is the key to unlocking our potential.\" - Winston Churchill",
-    "\"Like it or not, this moment is all we really have to work with.\" - Jon Kabat-Zinn",
-    "\"Obstacles are those frightful things you see when you take your eyes off your goal.\" - Henry Ford",
-    "\"Remember that the best relationship is one in which your love for each other exceeds your need for each other.\" - Dalai Lama",
-    "\"If I have seen further than others, it is by standing upon the shoulders of giants.\" - Isaac Newton",
-    "\"What would you do if you weren't afraid?\" - Spencer Johnson",
-    "\"Knowing is not enough, we must apply. Willing is not enough, we must do.  \" - Bruce Lee",
-    "\"Words are the clothes thoughts wear.\" - Samuel Beckett",
-    "\"The more we have the less we own.\" - Meister Eckhart",
-    "\"The measure of wisdom is how calm you are when facing any given situation.\" - Naval Ravikant",
-    "\"Life is 10% what happens to you and 90% how you react to it. \" - Charles Swindoll",
-    "\"What the mind can conceive and believe, and the heart desire, you can achieve.\" - Norman Vincent Peale",
-    "\"Thinking is the hardest work there is. That is why so few people engage in it.\" - Robert Kiyosaki",
-    "\"The first thing you learn in life is you're a fool. The last thing you learn in life is you're the same fool.  \" - Ray Bradbury",
-    "\"Time flies whether you are having fun or not.\" - Mary Engelbreit",
-    "\"Money is only a tool. It will take you wherever you wish, but it will not replace you as the driver.\" - Ayn Rand",
-    "\"Do not look upon this world with fear and loathing. Bravely face whatever the gods offer.\" - Morihei Ueshiba",
-    "\"Great things are not accomplished by those who yield to trends and fads and popular opinion.\" - Jack Kerouac",
-    "\"There are more people who wish to be loved than there are who are willing to love. \" - Nicolas Chamfort",
-    "\"Faithless is he that says farewell when the road darkens.\" - J.R.R. Tolkien",
-    "\"Your heart is the size of an ocean. Go find yourself in its hidden depths.\" - Rumi",
-    "\"Don't talk about what you have done or what you are going to do.\" - Thomas Jefferson",
-    "\"The quicker you let go of old cheese, the sooner you find new cheese.\" - Spencer Johnson",
-    "\"To live is the rarest thing in the world. Most people exist, that is all. \" - Oscar Wilde",
-    "\"Don't waste your energy on being angry at something that somebody did six months ago or a year ago. It's over.\" - Joan Rivers",
-    "\"Life is like playing the violin in public and learning the instrument as one goes on.\" - Samuel Butler",
-    "\"A loving heart is the truest wisdom.\" - Charles Dickens",
-    "\"You change the world by being yourself.\" - Yoko Ono",
-    "\"A clear conscience is the sure sign of a bad memory.\" - Mark Twain",
-    "\"One of the oldest human needs is having someone to wonder where you are when you don't come home at night. \" - Margaret Mead",
-    "\"The man who removes a mountain begins by carrying away small stones.\" - William Faulkner",
-    "\"Surround yourself with people who take their work seriously, but not themselves, those who work hard and play hard.\" - Colin Powell",
-    "\"Heroes don't need to talk about what they did.\" - W.P. Kinsella",
-    "\"The secret of change is to focus all your energy not on fighting the old, but on building the new.\" - Dan Millman",
-    "\"It is under the greatest adversity that there exists the greatest potential for doing good, both for oneself and others.\" - Dalai Lama",
-    "\"If you try, you might get exactly what you want. If you don't, you don't.\" - Unknown",
-    "\"Success is achieved and maintained by those who try and keep trying. \" - W. Clement Stone",
-    "\"Life itself is the most wonderful fairy tale.\" - Hans Christian Andersen",
-    "\"Trust your instincts: my first reaction is almost invariably the right one.  \" - Estee Lauder",
-    "\"People inspire you, or they drain you. Pick them wisely.\" - Les Brown",
-    "\"We forge the chains we wear in life.\" - Charles Dickens",
-    "\"The spirit is beyond destruction. No one can bring an end to spirit which is everlasting.\" - Bhagavad Gita",
-    "\"To bear trials with a calm mind robs misfortune of its strength and burden.\" - Seneca",
-    "\"There are no secrets to success. It is the result of preparation, hard work, and learning from failure.\" - Colin Powell",
-    "\"All we have to decide is what to do with the time that is given to us.\" - J.R.R. Tolkien",
-    "\"Among the things you can give and still keep are your word, a smile, and a grateful heart.\" - Zig Ziglar",
-    "\"Stress happens when the mind resists what is.\" - Dan Millman",
-    "\"It's not the men in your life that matters, it's the life in your men.\" - Mae West",
-    "\"If you do not conquer self, you will be conquered by self.\" - Napoleon Hill",
-    "\"Discontent is the first necessity of progress.\" - Thomas Edison",
-    "\"Less is more.\" - Robert Browning",
-    "\"You cannot always control what goes on outside. But you can always control what goes on inside. \" - Wayne Dyer",
-    "\"The people who are crazy enough to think they can change the world are the ones who do.\" - Steve Jobs",
-    "\"No idea, no matter how good, will work if it doesn't get used.\" - Peter A. Cohen",
-    "\"Others have seen what is and asked why. I have seen what could be and asked why not\" - Pablo Picasso",
-    "\"Decide upon your major definite purpose in life and then organize all your activities around it.\" - Brian Tracy",
-    "\"We need never be ashamed of our tears.\" - Charles Dickens",
-    "\"Much learning does not teach understanding.\" - Heraclitus",
-    "\"Anyone telling you it's pointless to share your perspective is sharing their perspective.\" - Jack Butcher",
-    "\"Outstanding people have one thing in common: an absolute sense of mission.  \" - Zig Ziglar",
-    "\"It is the mark of an educated mind to be able to entertain a thought without accepting it.\" - Aristotle",
-    "\"When you want something in life, you just gotta reach out and grab it.\" - Christopher McCandless",
-    "\"Not all those who wander are lost.\" - J.R.R. Tolkien",
-    "\"Forget yesterday - it has already forgotten you. Don't sweat tomorrow - you haven't even met. Instead, open your eyes and your heart to a truly precious gift - today.\" - Steve Maraboli",
-    "\"He who knows, does not speak. He who speaks, does not know.\" - Lao Tzu",
-    "\"At every moment where language can't go, that's your mind.\" - Bodhidharma",
-    "\"Every experience is a lesson. Every loss is a gain.\" - Sathya Sai Baba",
-    "\"When you are able to employ your will always for constructive purposes, you become the controller of your destiny.\" - Paramahansa Yogananda",
-    "\"People do not decide their futures, they decide their habits and their habits decide their futures.\" - Gary Keller",
-    "\"In order to be irreplaceable one must always be different.\" - Coco Chanel",
-    "\"Everyone can rise above their circumstances and achieve success if they are dedicated to and passionate about what they do.\" - Nelson Mandela",
-    "\"We are shaped and fashioned by what we love.\" - Johann Wolfgang von Goethe",
-    "\"Friends ask you questions; enemies question you.\" - Criss Jami",
-    "\"For your life to be great, your faith must be bigger than your fear.\" - Robin Sharma",
-    "\"Every man gotta right to decide his own destiny.\" - Bob Marley",
-    "\"If you don't like something change it; if you can't change it, change the way you think about it.\" - Mary Engelbreit",
-    "\"No tree, it is said, can grow to heaven unless its roots reach down to hell.\" - Carl Jung",
-    "\"Play your part in life, but never forget that it is only a role.\" - Paramahansa Yogananda",
-    "\"It is far easier to start something than it is to finish it.\" - Amelia Earhart",
-    "\"Think how really precious is the time you have to spend, whether it's at work or with your family. Every minute should be enjoyed and savored.\" - Earl Nightingale",
-    "\"Everything is created twice, first in the mind and then in reality.\" - Robin Sharma",
-    "\"Absorb what is useful, discard what is not, add what is uniquely your own.\" - Bruce Lee",
-    "\"If you want to find the secrets of the universe, think in terms of energy, frequency and vibration. \" - Nikola Tesla",
-    "\"Most great people have achieved their greatest success just one step beyond their greatest failure.\" - Napoleon Hill",
-    "\"Admire as much as you can. Most people do not admire enough.\" - Vincent van Gogh",
-    "\"Don't shortchange yourself when it comes to investing in your own better future.\" - Jim Rohn",
-    "\"Be happy in the moment, that's enough. Each moment is all we need, not more.\" - Mother Teresa",
-    "\"Life is like underwear, should be changed twice a day.  \" - Ray Bradbury",
-    "\"The best way out is always through.\" - Robert Frost",
-    "\"Life is a process. We are a process. The universe is a process.\" - Anne Wilson Schaef",
-    "\"Progress comes to those who train and train; reliance on secret techniques will get you nowhere.\" - Morihei Ueshiba",
-    "\"Winners don't Quit. That's why they Win.\" - Unknown",
-    "\"It takes half your life before you discover life is a do-it-yourself project.\" - Napoleon Hill",
-    "\"Vision is the art of seeing things invisible.\" - Jonathan Swift",
-    "\"Opportunities don't often come along. So, when they do, you have to grab them.\" - Audrey Hepburn",
-    "\"In order to carry a positive action, we must develop here a positive vision.\" - Dalai Lama",
-    "\"The purpose of life is to live it, to taste experience to the utmost, to reach out eagerly and without fear for newer and richer experience.\" - Eleanor Roosevelt",
-    "\"The ladder of success is best climbed by stepping on the rungs of opportunity.\" - Ayn Rand",
-    "\"The hard path is often the right one. The river teaches more than the shore.\" - Maxime Lagace",
-    "\"There is a difference between giving directions and giving direction.\" - Simon Sinek",
-    "\"Manifest plainness, embrace simplicity, reduce selfishness, have few desires.\" - Lao Tzu",
-    "\"The future is no more uncertain than the present.\" - Walt Whitman",
-    "\"Life is a question and how we live it is our answer.\" - Gary Keller",
-    "\"The best teacher is experience and not through someone's distorted point of view.\" - Jack Kerouac",
-    "\"New beginnings are disguised as painful endings.\" - Lao Tzu",
-    "\"No experience is a cause of success or failure. We do not suffer from the shock of our experiences, so-called trauma - but we make out of them just what suits our purposes.\" - Alfred Adler",
-    "\"Optimism is the faith that leads to achievement. Nothing can be done without hope and confidence.\" - Helen Keller",
-    "\"The way to get started is to quit talking and begin doing.\" - Walt Disney",
-    "\"Do you have the patience to wait until your mud settles and the water is clear?\" - Lao Tzu",
-    "\"Friendship is one of the most tangible things in a world which offers fewer and fewer supports.\" - Kenneth Branagh",
-    "\"Question everything. Learn something. Answer nothing.\" - Euripides",
-    "\"Facts are the enemy of truth. \" - Miguel de Cervantes",
-    "\"The fear of death follows from the fear of life. A man who lives fully is prepared to die at any time.\" - Mark Twain",
-    "\"Wherever there is danger, there lurks opportunity; wherever there is opportunity, there lurks danger. The two are inseparable.\" - Earl Nightingale",
-    "\"Never spend your money before you have earned it.\" - Thomas Jefferson",
-    "\"TEAMWORK: the fuel that allows common people attain uncommon results.\" - Andrew Carnegie",
-    "\"You do not read a book for the book's sake, but for your own.\" - Earl Nightingale",
-    "\"Whatever your mind can conceive and believe, it can achieve.\" - Napoleon Hill",
-    "\"There are no uninteresting things, only uninterested people.\" - Gilbert Chesterton",
-    "\"Let each man exercise the art he knows.\" - Aristophanes",
-    "\"The most tragic thing in the world is a man of genius who is not a man of honor.\" - George Bernard Shaw",
-    "\"You only live once, but if you do it right, once is enough.\" - Mae West",
-    "\"Believe you can and you're halfway there.\" - Theodore Roosevelt",
-    "\"You are the only problem you will ever have and you are the only solution.\" - Bob Proctor",
-    "\"Smell the cheese often so you know when it is getting old.\" - Spencer Johnson",
-    "\"We are all in the gutter, but some of us are looking at the stars. \" - Oscar Wilde",
-    "\"The best mind-altering drug is the truth.\" - Lily Tomlin",
-    "\"Be regular and orderly in your life, so that you may be violent and original in your work.\" - Gustave Flaubert",
-    "\"Live life as though nobody is watching, and express yourself as though everyone is listening.\" - Nelson Mandela",
-    "\"There will always be suffering. But we must not suffer over the suffering.\" - Alan Watts",
-    "\"Whatever you decide to do, make sure it makes you happy.\" - Paulo Coelho",
-    "\"To the mind that is still, the whole universe surrenders.\" - Lao Tzu",
-    "\"Go as far as you can see and you will see further.  \" - Zig Ziglar",
-    "\"At the end of the day, let there be no excuses, no explanations, no regrets.\" - Steve Maraboli",
-    "\"Success is good at any age, but the sooner you find it, the longer you will enjoy it.\" - Napoleon Hill",
-    "\"Things do not have meaning. We assign meaning to everything.\" - Tony Robbins",
-    "\"Walk towards the sunshine, and the shadows will fall behind you.\" - Mary Engelbreit",
-    "\"If you don't like something change it. If you can't change it, change your attitude about it. Don't complain.\" - Maya Angelou",
-    "\"No man is happy who does not think himself so.\" - Norman Vincent Peale",
-    "\"If you practiced for the day, then you have won. If you were lazy for the day, then you have lost.\" - Ming-Dao Deng",
-    "\"In order for you to be the BEST you can be for others, first you must be BEST for yourself.\" - Jeffrey Gitomer",
-    "\"If you put water into a cup, it becomes the cup.\" - Bruce Lee",
-    "\"Life is love and love is life.\" - Elvis Presley",
-    "\"By changing nothing, nothing changes.\" - Celestine Chua",
-    "\"Those who are enlightened never stop forging themselves.\" - Morihei Ueshiba",
-    "\"He who can, does.\" - George Bernard Shaw",
-    "\"Nothing is really work unless you would rather be doing something else. \" - James Matthew Barrie",
-    "\"The golden opportunity you are seeking is within yourself.\" - Mary Engelbreit",
-    "\"If we all did the things we are capable of doing, we would literally astound ourselves.\" - Thomas Edison",
-    "\"Often in the real world, it's not the smart that get ahead, but the bold.\" - Robert Kiyosaki",
-    "\"Within every desire is the mechanics of its fulfillment.\" - Deepak Chopra",
-    "\"Giving is living. If you stop wanting to give, there's nothing more to live for.\" - Audrey Hepburn",
-    "\"Everyone is a moon, and has a dark side which he never shows to anybody.\" - Mark Twain",
-    "\"You can't build a reputation on what you are going to do. \" - Henry Ford",
-    "\"Deeds will not be less valiant because they are upraised.\" - J.R.R. Tolkien",
-    "\"No man can get rich himself unless he enriches others.\" - Earl Nightingale",
-    "\"There is no sadder sight than a young pessimist.\" - Mark Twain",
-    "\"Your worth consists in what you are and not in what you have.\" - Thomas Edison",
-    "\"Rudeness is the weak man's imitation of strength.\" - Eric Hoffer",
-    "\"It doesn't matter if the glass is half empty or half full. Be grateful you have a glass - you're the only person that can decide what's in it.\" - Gurbaksh Chahal",
-    "\"Every limit is a beginning as well as an ending.\" - George Eliot",
-    "\"Wake up and smile! Wake up and be grateful!\" - Steve Harvey",
-    "\"You use a glass mirror to see your face; you use works of art to see your soul.\" - George Bernard Shaw",
-    "\"Don't seek, don't search, don't ask, don't knock, don't demand - relax.\" - Osho",
-    "\"If your happiness depends on what somebody else does, I guess you do have a problem.\" - Richard Bach",
-    "\"We suffer more often in imagination than in reality.\" - Seneca",
-    "\"The law of harvest is to reap more than you sow. Sow an act, and you reap a habit. Sow a habit and you reap a character. Sow a character and you reap a destiny.\" - James Allen",
-    "\"To every action there is always opposed an equal reaction.\" - Isaac Newton",
-    "\"A friend to all is a friend to none.\" - Aristotle",
-    "\"What the caterpillar calls the end of the world, the master calls a butterfly.\" - Richard Bach",
-    "\"It is better to fail in originality than to succeed in imitation.\" - Herman Melville",
-    "\"Any fool can paint a picture, but it takes a wise man to be able to sell it.\" - Samuel Butler",
-    "\"Faith is taking the first step even when you don't see the whole staircase.\" - Martin Luther King, Jr.",
-    "\"Every blessing ignored becomes a curse.\" - Paulo Coelho",
-    "\"When meditation is mastered, the mind is unwavering like the flame of a lamp in a windless place.\" - Bhagavad Gita",
-    "\"Resentment or grudges do no harm to the person against whom you hold these feelings but every day and every night of your life, they are eating at you.\" - Norman Vincent Peale",
-    "\"Throughout this life, you can never be certain of living long enough to take another breath.\" - Zen Proverb",
-    "\"Unless you change how you are, you will always have what you got.\" - Jim Rohn",
-    "\"Prefer to be defeated in the presence of the wise than to excel among fools.\" - Dogen",
-    "\"Success is built sequentially. It's one thing at a time.\" - Gary Keller",
-    "\"Don't give up the fight, Stand up for your rights.\" - Bob Marley",
-    "\"Care about what other people think and you will always be their prisoner.\" - Lao Tzu",
-    "\"The most virtuous are those who content themselves with being virtuous without seeking to appear so.\" - Plato",
-    "\"Yesterday is but today's memory, tomorrow is today's dream.\" - Kahlil Gibran",
-    "\"If you want to change how you see your problems, you have to change what you value and/or how you measure failure/success.\" - Mark Manson",
-    "\"Change yourself - you are in control.\" - Mahatma Gandhi",
-    "\"When we allow negative messages to fester in our head, they take on a life of their own.\" - Lolly Daskal",
-    "\"Success is about doing the right thing, not about doing everything right.\" - Gary Keller",
-    "\"Blessed is he who expects nothing, for he shall be disappointed.\" - Jonathan Swift",
-    "\"Always render more and better service than is expected of you, no matter what your task may be.\" - Og Mandino",
-    "\"You're on earth. There's no cure for that.\" - Samuel Beckett",
-    "\"Most people fail in life because they major in minor things.\" - Tony Robbins",
-    "\"High thoughts must have high language.\" - Aristophanes",
-    "\"Those who know, do. Those that understand, teach.\" - Aristotle",
-    "\"Realize deeply that the present moment is all you ever have. \" - Eckhart Tolle",
-    "\"We're responsible for everything that happens to us.\" - Yanni",
-    "\"Always keep your eyes open. Keep watching. Because whatever you see can inspire you.\" - Grace Coddington",
-    "\"Heaven is right where you are standing.\" - Morihei Ueshiba",
-    "\"Time you enjoy wasting, was not wasted.\" - John Lennon",
-    "\"Men are disturbed not by things, but by the view which they take of them.\" - Epictetus",
-    "\"This is such a short and precious life that it's really important that you don't spend it being unhappy.\" - Naval Ravikant",
-    "\"The act of taking the first step is what separates the winners from the losers.\" - Brian Tracy",
-    "\"The time is now, the place is here. Stay in the present.\" - Dan Millman",
-    "\"Work is always an antidote to depression.\" - Eleanor Roosevelt",
-    "\"Change begets change. Nothing propagates so fast.\" - Charles Dickens",
-    "\"It's your life; you don't need someone's permission to live the life you want. Be brave to live from your heart.\" - Roy T. Bennett",
-    "\"A person is only by the thoughts that he chooses.\" - James Allen",
-    "\"When things go wrong, don't go with them.\" - Elvis Presley",
-    "\"The world only exists in your eyes. You can make it as big or as small as you want.\" - F. Scott Fitzgerald",
-    "\"Believe nothing you hear, and only one half that you see.\" - Edgar Allan Poe",
-    "\"Information changes your mind. Action changes your circumstances.\" - Jack Butcher",
-    "\"What you do today can improve all your tomorrows.\" - Ralph Marston",
-    "\"Pursue what catches your heart, not what catches your eyes.\" - Roy T. Bennett",
-    "\"The sculptor produces the beautiful statue by chipping away such parts of the marble block as are not needed - it is a process of elimination.\" - Elbert Hubbard",
-    "\"You have brains in your head. You have feet in your shoes. You can steer yourself any direction you choose.\" - Dr. Seuss",
-    "\"Success comes from knowing that you did your best to become the best that you are capable of becoming.\" - John Wooden",
-    "\"Leaders spend 5% of their time on the problem & 95% of their time on the solution. Get over it & crush it!\" - Tony Robbins",
-    "\"Every time you are tempted to react in the same old way, ask if you want to be a prisoner of the past or a pioneer of the future.\" - Deepak Chopra",
-    "\"Follow your heart, listen to your inner voice, stop caring about what others think.\" - Roy T. Bennett",
-    "\"Create each day anew.\" - Morihei Ueshiba",
-    "\"The first rule of business; treat others like they want to treat you.\" - Charles Dickens",
-    "\"All leaders are readers.\" - Jim Rohn",
-    "\"The whole is greater than the sum of its parts.\" - Aristotle",
-    "\"It is better to disappoint people with the truth than to appease them with a lie.\" - Simon Sinek",
-    "\"You don't need to be better than anyone else, you just need to be better than you used to be.\" - Wayne Dyer",
-    "\"Act the way you want to be and soon you'll be the way you act.\" - Les Brown",
-    "\"It is the nature of the wise to resist pleasures, but the foolish to be a slave to them.\" - Epictetus",
-    "\"Some of us learn from other people's mistakes and the rest of us have to be other people.  \" - Zig Ziglar",
-    "\"Good, better, best. Never let it rest. 'Til your good is better and your better is best. \" - St. Jerome",
-    "\"Life is the art of drawing sufficient conclusions from insufficient premises.\" - Samuel Butler",
-    "\"Nourish the mind like you would your body. The mind cannot survive on junk food.\" - Brian Tracy",
-    "\"We are what we repeatedly do. Excellence, then, is not an act, but a habit.\" - Aristotle",
-    "\"It is truth that liberates, not your effort to be free.\" - Jiddu Krishnamurti",
-    "\"Don´t hesitate or allow yourself to make excuses. Just get out and do it.\" - Christopher McCandless",
-    "\"Sometimes you will never know the value of something, until it becomes a memory.\" - Dr. Seuss",
-    "\"Wrong does not cease to be wrong because the majority share in it.\" - Leo Tolstoy",
-    "\"Listen to the secret sound, the real sound, which is inside you.\" - Kabir",
-    "\"When you have vision it affects your attitude. Your attitude is optimistic rather than pessimistic. \" - Charles Swindoll",
-    "\"Most people fail in life not because they aim too high and miss, but because they aim too low and hit.\" - Les Brown",
-    "\"Fears are nothing more than a state of mind.\" - Napoleon Hill",
-    "\"Confidence breeds beauty.  \" - Estee Lauder",
-    "\"The person who cannot control his words shows that he cannot control himself.\" - Robert Greene",
-    "\"It's very important to like the people you work with, otherwise life and your job is gonna be quite miserable.\" - Elon Musk",
-    "\"Knowledge has to be improved, challenged, and increased constantly, or it vanishes.\" - Peter Drucker",
-    "\"Don't put off living to next week, next month, next year or next decade. The only time you're ever living is in this moment.\" - Celestine Chua",
-    "\"Be strong. Be confident. Be the star of your own life.\" - Estee Lauder",
-    "\"The happiest people in the world are those who feel absolutely terrific about themselves.\" - Brian Tracy",
-    "\"We must have strong minds, ready to accept facts as they are.\" - Harry S. Truman",
-    "\"Failure is a prerequisite for great success. If you want success faster, double your rate of failure.\" - Brian Tracy",
-    "\"The more you like yourself, the less you are like anyone else, which makes you unique.\" - Walt Disney",
-    "\"Words have no power to impress the mind without the exquisite horror of their reality.\" - Edgar Allan Poe",
-    "\"Be who you are and say what you feel because those who mind don't matter and those who matter don't mind.\" - Dr. Seuss",
-    "\"The only thing more frustrating than slanderers is those foolish enough to listen to them.\" - Criss Jami",
-    "\"In spite of everything, I shall rise again.\" - Vincent van Gogh",
-    "\"In a world that is constantly changing, it is to our advantage to learn how to adapt and enjoy something better.\" - Spencer Johnson",
-    "\"We take care of the future best by taking care of the present now.\" - Jon Kabat-Zinn",
-    "\"He who permits himself to tell a lie once, finds it much easier to do it the second time.\" - Thomas Jefferson",
-    "\"Most people do not really want freedom, because freedom involves responsibility, and most people are frightened of responsibility.\" - Sigmund Freud",
-    "\"To be truly positive in the eyes of some, you have to risk appearing negative in the eyes of others.\" - Criss Jami",
-    "\"The quality of your life is determined by the quality of questions you ask.\" - Tony Robbins",
-    "\"Whatever happens always happens on time.\" - Zen Proverb",
-    "\"Make peace with your inner turmoil.\" - Unknown",
-    "\"The world is a puzzle; no need to make sense out of it.\" - Socrates",
-    "\"Always try to be a little kinder than necessary.\" - James Matthew Barrie",
-    "\"Thoughts become things. If you see it in your mind, you will hold it in your hand.\" - Bob Proctor",
-    "\"Whatever you are, be a good one.\" - Abraham Lincoln",
-    "\"Always be a first-rate version of yourself, instead of a second-rate version of someone else.\" - Judy Garland",
-    "\"Life belongs to the living, and he who lives must be prepared for changes.\" - Johann Wolfgang von Goethe",
-    "\"A single act of kindness throws out roots in all directions, and the roots spring up and make new trees.\" - Amelia Earhart",
-    "\"Men learn to love the woman they are attracted to. Women learn to become attracted to the man they fall in love with.\" - Woody Allen",
-    "\"Try not to become a man of success, but rather try to become a man of value.\" - Albert Einstein",
-    "\"Those who look for the bad in people will surely find it.\" - Abraham Lincoln",
-    "\"Only those who do nothing at all make no mistakes.\" - Unknown",
-    "\"Well done is better than well said.\" - Benjamin Franklin",
-    "\"Your mind is infinite, it's your doubts that are limiting.\" - Robert Kiyosaki",
-    "\"If you aim at nothing, you will hit it every time.  \" - Zig Ziglar",
-    "\"Your home is where your thoughts find peace.\" - Zen Proverb",
-    "\"Life and the simple beauty of it is too good to pass up.\" - Christopher McCandless",
-    "\"Note that this journey is uniquely yours, no one elses. So the path has to be your own.\" - Jon Kabat-Zinn",
-    "\"The unthankful heart discovers no mercies; but the thankful heart will find, in every hour, some heavenly blessings.\" - Henry Ward Beecher",
-    "\"Engage in those actions and thoughts that nurture the good qualities you want to have.\" - Paramahansa Yogananda",
-    "\"Life is a constant series of opportunities.\" - Ming-Dao Deng",
-    "\"Courage is not having the strength to go on; it is going on when you don't have the strength.\" - Theodore Roosevelt",
-    "\"Life moves on and so should we.\" - Spencer Johnson",
-    "\"Fear, uncertainty, and discomfort are your compasses toward growth.\" - Celestine Chua",
-    "\"Change yourself and you have done your part in changing the world.\" - Paramahansa Yogananda",
-    "\"If you can handle rejection, you'll learn to get everything you want.\" - Tony Robbins",
-    "\"Being entirely honest with oneself is a good exercise.\" - Sigmund Freud",
-    "\"Talent is luck. The important thing in life is courage.\" - Woody Allen",
-    "\"Be approximately right rather than exactly wrong.\" - John Tukey",
-    "\"Rather than waste time and energy worrying, use that time and energy to constructively deal with whatever is causing the worry.\" - Celestine Chua",
-    "\"To improve is to change, so to be perfect is to change often.\" - Winston Churchill",
-    "\"We are all self-made, but only the successful will admit it.\" - Earl Nightingale",
-    "\"Being wrong brings the opportunity for growth.\" - Mark Manson",
-    "\"Where words fail, music speaks.\" - Hans Christian Andersen",
-    "\"Give out what you most want to come back.\" - Robin Sharma",
-    "\"The meaning of life is just to be alive. It is so plain and so obvious and so simple.\" - Alan Watts",
-    "\"The shoe that fits one person pinches another; there is no recipe for living that suits all cases.\" - Carl Jung",
-    "\"I am who I am today because of the choices I made yesterday.\" - Eleanor Roosevelt",
-    "\"A Rolling Stone Gathers No Moss.\" - Publilius Syrus",
-    "\"Invisible things are the only realities.\" - Edgar Allan Poe",
-    "\"Be brave enough to suck at something new.\" - Unknown",
-    "\"To himself everyone is immortal; he may know that he is going to die, but he can never know he is dead.\" - Samuel Butler",
-    "\"The more reasons you have for achieving your goal, the more determined you will become.\" - Brian Tracy",
-    "\"The size of your success is measured by the strength of your desire; the size of your dream; and how you handle disappointment along the way.\" - Robert Kiyosaki",
-    "\"Don't wait for the right opportunity: create it.\" - George Bernard Shaw",
-    "\"Dark times lie ahead of us and there will be a time when we must choose between what is easy and what is right.\" - Albus Dumbledore",
-    "\"It isn't that they can't see the solution. It is that they can't see the problem.\" - Gilbert Chesterton",
-    "\"Never put off to tomorrow what you can do to-day.\" - Thomas Jefferson",
-    "\"Real loss is only possible when you love something more than you love yourself.\" - Robin Williams",
-    "\"Nothing you do is going to matter that much in the long run. Don't take yourself so seriously.\" - Naval Ravikant",
-    "\"What we know is a drop, what we don't know is an ocean. \" - Isaac Newton",
-    "\"Progress is limited by your ability to change your mind.\" - Jack Butcher",
-    "\"Your purpose will be clear only when you listen to your heart.\" - Lolly Daskal",
-    "\"It is only depth of character that determines the profundity with which we face life.\" - Ming-Dao Deng",
-    "\"You know the more one does the more one can do.\" - Amelia Earhart",
-    "\"Strive for excellence, not perfection, because we don't live in a perfect world.\" - Joyce Meyer",
-    "\"Don't try to steer the river.\" - Deepak Chopra",
-    "\"Make yourself so useful to other people, that they think you are irreplaceable.\" - Og Mandino",
-    "\"We have a choice everyday regarding the attitude we will embrace for that day.\" - Charles Swindoll",
-    "\"Learn to use your emotions to think, not think with your emotions.\" - Robert Kiyosaki",
-    "\"If you are far from the enemy, make him believe you are near.\" - Sun Tzu",
-    "\"The world doesn't owe you anything. It was here first.\" - Mark Twain",
-    "\"Have the courage to follow your heart and intuition. They somehow know what you truly want to become.\" - Steve Jobs",
-    "\"Success is stumbling from failure to failure with no loss of enthusiasm.\" - Winston Churchill",
-    "\"To understand the heart and mind of a person, look not at what he has already achieved, but at what he aspires to.\" - Kahlil Gibran",
-    "\"The less you talk about your shame, the more of it you have.\" - Mark Manson",
-    "\"Confidence comes from crossing thresholds.\" - Kamal Ravikant",
-    "\"Nothing important is learned; it is simply remembered.\" - Carlos Ruiz Zafon",
-    "\"Beauty is not in the face; beauty is a light in the heart.\" - Kahlil Gibran",
-    "\"No need to hurry. No need to sparkle. No need to be anybody but oneself.\" - Virginia Woolf",
-    "\"Be yourself; everyone else is already taken.\" - Oscar Wilde",
-    "\"We would accomplish many more things if we did not think of them as impossible.\" - Vince Lombardi",
-    "\"Where there's life, there's hope.\" - Stephen Hawking",
-    "\"To be truly ignorant, be content with your own knowledge.\" - Zhuangzi",
-    "\"A mountain never yields to the wind no matter how strong it is.\" - Zen Proverb",
-    "\"Success is not what you do when you are on top.  Success is how high you bounce when you hit the bottom.\" - Sonia Ricotti",
-    "\"Intense love does not measure, it just gives.\" - Mother Teresa",
-    "\"A man who dares to waste one hour of time has not discovered the value of life.\" - Charles Darwin",
-    "\"One machine can do the work of fifty ordinary men. No machine can do the work of one extraordinary man.\" - Elbert Hubbard",
-    "\"If we have no peace, it is because we have forgotten that we belong to each other.\" - Mother Teresa",
-    "\"Do not go where the path may lead, go instead where there is no path and leave a trail.\" - Ralph Waldo Emerson",
-    "\"One of the really tough things is figuring out what questions to ask. Once you figure out the question, then the answer is relatively easy.\" - Elon Musk",
-    "\"Good judgment comes from experience, and experience comes from bad judgment.\" - Rita Mae Brown",
-    "\"Fear is a natural reaction to moving closer to the truth.\" - Pema Chodron",
-    "\"One loses many laughs by not laughing at oneself.\" - Mary Engelbreit",
-    "\"Tension is who you think you should be. Relaxation is who you are.\" - Chinese Proverb",
-    "\"There can be no deep disappointment where there is not deep love.\" - Martin Luther King, Jr.",
-    "\"Persistence is very important. You should not give up unless you are forced to give up.\" - Elon Musk",
-    "\"Through selfless service, you will always be fruitful and find the fulfillment of your desires.\" - Bhagavad Gita",
-    "\"The most important thing is to enjoy your life, to be happy, it's all that matters.\" - Audrey Hepburn",
-    "\"Zen insists that the whole trouble is just our failure to realize that there is no problem.\" - Bruce Lee",
-    "\"The only place success comes before work is in the dictionary.\" - Vince Lombardi",
-    "\"Life must be rich and full of loving-it's no good otherwise, no good at all, for anyone.  \" - Jack Kerouac",
-    "\"Progress lies not in enhancing what is, but in advancing toward what will be.\" - Kahlil Gibran",
-    "\"We will now discuss in a little more detail the Struggle for Existence.\" - Charles Darwin",
-    "\"Any thought that is passed on to the subconscious often enough and convincingly enough is finally accepted.\" - Robert Collier",
-    "\"Longevity is a fleeting moment that lasts forever.\" - Vidal Sassoon",
-    "\"Be happy now, without reason - or you never will be at all.\" - Dan Millman",
-    "\"It is hard to fail, but it is worse never to have tried to succeed.\" - Theodore Roosevelt",
-    "\"The only problem we really have is we think we're not supposed to have problems! Problems call us to higher level- - face & solve them now!\" - Tony Robbins",
-    "\"The past has no power over the present moment. \" - Eckhart Tolle",
-    "\"It doesn't matter how slow you go, as long as you don't stop.\" - Confucius",
-    "\"I hear and I forget. I see and I remember. I do and I understand. \" - Confucius",
-    "\"Where we fall are the stepping-stones for our journey.\" - Lolly Daskal",
-    "\"Weeds are flowers too, once you get to know them.\" - A.A. Milne",
-    "\"Problems remain as problems because people are busy defending them rather than finding solutions.\" - Celestine Chua",
-    "\"Cleverness is not wisdom.\" - Euripides",
-    "\"Nothing is too high for a man to reach, but he must climb with care and confidence\" - Hans Christian Andersen",
-    "\"Quality is more important than quantity. One home run is much better than two doubles.\" - Steve Jobs",
-    "\"If you run you stand a chance of losing, but if you don't run you've already lost.\" - Barack Obama",
-    "\"If I can stop one heart from breaking, I shall not live in vain.\" - Emily Dickinson",
-    "\"Circumstances rule men; men do not rule circumstances. \" - Herodotus",
-    "\"Life needs to be more than just solving problems every day. You need to wake up and be excited about the future.\" - Elon Musk",
-    "\"Every man's work, whether it be literature or music or pictures or architecture or anything else, is always a portrait of himself.\" - Samuel Butler",
-    "\"The best of us sometimes eat our words.  \" - Albus Dumbledore",
-    "\"Disobedience is the true foundation of liberty. The obedient must be slaves.\" - Henry David Thoreau",
-    "\"No one returns from a long journey the same person they were before.\" - Zen Proverb",
-    "\"We shape clay into a pot, but it is the emptiness inside that holds whatever we want.\" - Lao Tzu",
-    "\"Any game becomes important when you know and love the players.\" - W.P. Kinsella",
-    "\"You can easily judge the character of a man by how he treats those who can do nothing for him.\" - Johann Wolfgang von Goethe",
-    "\"Listening well is as powerful as talking well, and is also as essential to true conversation.\" - Chinese Proverb",
-    "\"Keep true. Never be ashamed of doing right. Decide what you think is right and stick to it.\" - George Eliot",
-    "\"What do we live for, if it is not to make life less difficult for each other?\" - George Eliot",
-    "\"Our greatest glory is not in never falling but in rising every time we fall.\" - Confucius",
-    "\"We suffer more often in imagination than in reality.  \" - Seneca",
-    "\"A gentleman is one who puts more into the world than he takes out.\" - George Bernard Shaw",
-    "\"The wisdom of life consists in the elimination of the nonessentials.\" - Lin Yutang",
-    "\"It is literally true that you can succeed best and quickest by helping others succeed.\" - Napoleon Hill",
-    "\"Every day is an opportunity to a make a new happy ending.\" - Jonathan Swift",
-    "\"The salvation of the world lies in the human heart.\" - Vaclav Havel",
-    "\"Life begins where fear ends.\" - Osho",
-    "\"If you've never eaten while crying you don t know what life tastes like.\" - Johann Wolfgang von Goethe",
-    "\"The worst part of success is trying to find someone who is happy for you.\" - Bette Midler",
-    "\"If you need inspiration, don't do it.\" - Elon Musk",
-    "\"The man who asks a question is a fool for a minute, the man who does not ask is a fool for life.\" - Confucius",
-    "\"There is only one thing in the world worse than being talked about, and that is not being talked about. \" - Oscar Wilde",
-    "\"Look to the beauty of this day, miracles are all around you.\" - Mary Engelbreit",
-    "\"Do what you can, with what you have, where you are.\" - Theodore Roosevelt",
-    "\"It is up to each of us to sing as we feel moved by the overall song of life.\" - Ming-Dao Deng",
-    "\"If you genuinely want something, don't wait for it - teach yourself.\" - Gurbaksh Chahal",
-    "\"If we are not able to smile, then the world will not have peace.\" - Thich Nhat Hanh",
-    "\"A wise man makes his own decisions, but an ignorant man mindlessly follows the crowd.\" - Chinese Proverb",
-    "\"Men trust their ears less than their eyes.\" - Herodotus",
-    "\"If you don't program yourself, life will program you.\" - Les Brown",
-    "\"He who cannot be a good follower cannot be a good leader.\" - Aristotle",
-    "\"We are most nearly ourselves when we achieve the seriousness of the child at play.\" - Heraclitus",
-    "\"Creativity is the greatest rebellion in existence.\" - Osho",
-    "\"It's beautiful to be alone. To be alone does not mean to be lonely. It means the mind is not influenced and contaminated by society.\" - Jiddu Krishnamurti",
-    "\"She who loves roses must be patient and not cry out when she is pierced by thorns.\" - Kenji Miyazawa",
-    "\"If you want something you've never had you must be willing to do something you've never done.\" - Thomas Jefferson",
-    "\"Happiness is not the absence of problems, it's the ability to deal with them.\" - Steve Maraboli",
-    "\"It's not what you pay a man, but what he costs you that counts.\" - Will Rogers",
-    "\"Nothing in the world is ever completely wrong. Even a stopped clock is right twice a day.\" - Paulo Coelho",
-    "\"Make your stand today. On this spot. On this day.\" - Ming-Dao Deng",
-    "\"Change is not a four letter word...but often your reaction to it is!\" - Jeffrey Gitomer",
-    "\"Still your waters.\" - Josh Waitzkin",
-    "\"Life is one long process of getting tired.\" - Samuel Butler",
-    "\"Debt is the slavery of the free.\" - Publilius Syrus",
-    "\"I never said it would be easy, I only said it would be worth it.\" - Mae West",
-    "\"The present moment dies every moment to become the past , is reborn every moment into the future. All experience is now. Now never ends.\" - Deepak Chopra",
-    "\"For every effect there is a root cause. Find and address the root cause rather than try to fix the effect, as there is no end to the latter.\" - Celestine Chua",
-    "\"When asked, how do you write? I invariably answer, one word at a time.\" - Stephen King",
-    "\"Blessed is he who expects nothing, for he shall never be disappointed.\" - Alexander Pope",
-    "\"Luck is a matter of preparation meeting opportunity.\" - Oprah Winfrey",
-    "\"It's not how much we give but how much love we put into giving.\" - Mother Teresa",
-    "\"Progress is impossible without change, and those who cannot change their minds cannot change anything.\" - George Bernard Shaw",
-    "\"Incredible change happens in your life when you decide to take control of what you do have power over instead of craving control over what you don't.\" - Steve Maraboli",
-    "\"If you want peace, you won't get it with violence.\" - John Lennon",
-    "\"If you set your goals ridiculously high and its a failure, you will fail above everyone elses success.\" - James Cameron",
-    "\"Better to have, and not need, than to need, and not have.\" - Franz Kafka",
-    "\"Enthusiasm makes up for a host of deficiencies.\" - Barack Obama",
-    "\"Wheels of justice grind slow but grind fine.\" - Sun Tzu",
-    "\"The cost of leadership is self-interest.\" - Simon Sinek",
-    "\"Mind is the creator of everything. You should therefore guide it to create only good.\" - Paramahansa Yogananda",
-    "\"Successful people are not without problems. They're simply people who've learned to solve their problems.\" - Earl Nightingale",
-    "\"In the long run, the sharpest weapon of all is a kind and gentle spirit.\" - Anne Frank",
-    "\"The only limits in your life are those that you set yourself.\" - Celestine Chua",
-    "\"You can have the mind or you can have the moment.\" - Naval Ravikant",
-    "\"Patience is not the ability to wait but the ability to keep a good attitude while waiting.\" - Joyce Meyer",
-    "\"There is no medicine like hope, no incentive so great, and no tonic so powerful as expectation of something tomorrow. \" - Orison Swett Marden",
-    "\"Great things happen to those who don't stop believing, trying, learning, and being grateful.\" - Roy T. Bennett",
-    "\"I think self-awareness is probably the most important thing towards being a champion. \" - Billie Jean King",
-    "\"I've always lived in the moment.\" - Yanni",
-    "\"Out of suffering have emerged the strongest souls; the most massive characters are seared with scars.\" - Kahlil Gibran",
-    "\"Sometimes it is harder to deprive oneself of a pain than of a pleasure.\" - F. Scott Fitzgerald",
-    "\"None are more hopelessly enslaved than those who falsely believe they are free.\" - Johann Wolfgang von Goethe",
-    "\"A real decision is measured by the fact that you've taken a new action. If there's no action, you haven't truly decided.\" - Tony Robbins",
-    "\"There are no mistakes in life, only lessons.\" - Robin Sharma",
-    "\"Absurdity is the ecstasy of intellectualism.\" - Criss Jami",
-    "\"Happiness depends on your mindset and attitude.\" - Roy T. Bennett",
-    "\"No matter how tall the mountain is, it cannot block the sun.\" - Chinese Proverb",
-    "\"Avoiding failure is to avoid progress.\" - Unknown",
-    "\"Having an open mind is one of the most important qualities we can possess. Once our minds close, we stop evolving.\" - Yanni",
-    "\"Catch, then, O catch the transient hour; Improve each moment as it flies! \" - St. Jerome",
-    "\"As mortals, we're ruled by conditions, not by ourselves.\" - Bodhidharma",
-    "\"It is useless to attempt to reason a man out of a thing he was never reasoned into.\" - Jonathan Swift",
-    "\"To be great is to be misunderstood.\" - Ralph Waldo Emerson",
-    "\"Simplicity is a difficult thing to achieve.  \" - Charlie Chaplin",
-    "\"Treat everyone with politeness and kindness, not because they are nice, but because you are.\" - Roy T. Bennett",
-    "\"Till it has loved, no man or woman can become itself.\" - Emily Dickinson",
-    "\"Have a heart that never hardens, and a temper that never tires, and a touch that never hurts.\" - Charles Dickens",
-    "\"Fallacies do not cease to be fallacies because they become fashions.\" - Gilbert Chesterton",
-    "\"No matter what happens in life, never lose sight of who you are.\" - Yanni",
-    "\"Better the cottage where one is merry than the palace where one weeps.\" - Chinese Proverb",
-    "\"Thinking is difficult, that's why most people judge.\" - Carl Jung",
-    "\"Until you change how you get things done, you'll never know what works best.\" - Roy T. Bennett",
-    "\"Life is a succession of lessons, which must be lived to be understood.\" - Ralph Waldo Emerson",
-    "\"A life spent making mistakes is not only more honorable but more useful than a life spent doing nothing.\" - George Bernard Shaw",
-    "\"The greatest miracle is to be alive.\" - Thich Nhat Hanh",
-    "\"Remember that sometimes not getting what you want is a wonderful stroke of luck.\" - Dalai Lama",
-    "\"Insanity is relative. It depends on who has who locked in what cage.  \" - Ray Bradbury",
-    "\"The giving of love is an education in itself.\" - Eleanor Roosevelt",
-    "\"Laziness may appear attractive, but work gives satisfaction.\" - Anne Frank",
-    "\"Our joy does not have to be based on our circumstances.\" - Joyce Meyer",
-    "\"Your hardest times often lead to the greatest moments of your life. Keep going. Tough situations build strong people in the end.\" - Roy T. Bennett",
-    "\"Inspiration exists, but it has to find you working.\" - Pablo Picasso",
-    "\"We must cultivate our garden. \" - Voltaire",
-    "\"Identify your problems, but give your power and energy to solutions.\" - Tony Robbins",
-    "\"Decide whether or not the goal is worth the risks involved. If it is, stop worrying.\" - Amelia Earhart",
-    "\"The measure of who we are is what we do with what we have.\" - Vince Lombardi",
-    "\"A clear conscience never fears midnight knocking.\" - Chinese Proverb",
-    "\"It is useless to meet revenge with revenge; it will heal nothing.\" - J.R.R. Tolkien",
-    "\"If you can change your mind, you can change your life.\" - William James",
-    "\"Charms strike the sight, but merit wins the soul.\" - Alexander Pope",
-    "\"Conquer the devils with a little thing called love.\" - Bob Marley",
-    "\"Confuse them with your silence and amaze them with your actions.\" - Unknown",
-    "\"Learn only how to avoid seeking for and attaching yourselves to anything.\" - Huang Po",
-    "\"A fool sees himself as another, but a wise man sees others as himself.\" - Dogen",
-    "\"The older you get, the better you get. Unless you're a banana.\" - Betty White",
-    "\"If you can't imagine it, you can't have it.\" - Toni Morrison",
-    "\"Success is not final, failure is not fatal: it is the courage to continue that counts.\" - Winston Churchill",
-    "\"The key to immortality is first living a life worth remembering. \" - Bruce Lee",
-    "\"Every time we liberate a woman, we liberate a man. \" - Margaret Mead",
-    "\"It is in your moments of decision that your destiny is shaped.\" - Tony Robbins",
-    "\"You can't get to a place that you don't believe exists.\" - Unknown",
-    "\"Perfection is not attainable, but if we chase perfection we can catch excellence.\" - Vince Lombardi",
-    "\"Opportunity is missed by most people because it is dressed in overalls and looks like work.\" - Thomas Edison",
-    "\"Your problem isn't the problem. Your reaction is the problem.\" - Unknown",
-    "\"Too much sanity may be madness and the maddest of all, to see life as it is and not as it should be. \" - Miguel de Cervantes",
-    "\"People have the illusion that all over the world, all the time, all kinds of fantastic things are happening.\" - David Brinkley",
-    "\"Give more than you think you can, trusting that you are richer than you think.\" - Jon Kabat-Zinn",
-    "\"The art of being happy lies in the power of extracting happiness from common things.\" - Henry Ward Beecher",
-    "\"Either you run the day or the day runs you.\" - Jim Rohn",
-    "\"A bird does not sing because it has an answer. It sings because it has a song.\" - Chinese Proverb",
-    "\"To belittle, you have to be little.\" - Kahlil Gibran",
-    "\"The real meditation is how you live your life.\" - Jon Kabat-Zinn",
-    "\"When at last you are come to the ocean of happiness, do not go back thirsty.\" - Kabir",
-    "\"Do not try to be on the side of the majority, but live according to your inner laws.\" - Marcus Aurelius",
-    "\"Simple words, repeated daily, can change your life.\" - Maxime Lagace",
-    "\"Don't gain the world and lose your soul. Wisdom is better than silver and gold.\" - Bob Marley",
-    "\"Don't take yourself too seriously, pretty soon you can find the humor in our everyday lives.\" - Betty White",
-    "\"Old age is like everything else. To make a success of it, you've got to start young.\" - Theodore Roosevelt",
-    "\"The energy of the mind is the essence of life.\" - Aristotle",
-    "\"Before enlightenment; chop wood, carry water. After enlightenment; chop wood, carry water.\" - Buddha",
-    "\"The greatest weariness comes from work not done.\" - Eric Hoffer",
-    "\"When you stop questioning, you stop learning.\" - Lolly Daskal",
-    "\"If you want to feel happy, do something for yourself. If you want to feel fulfilled, do something for someone else.\" - Simon Sinek",
-    "\"Don't stumble over something that's behind you.\" - Steve Harvey",
-    "\"What's obvious to you isn't obvious to most people. Operate from this perspective and you'll help more people.\" - Jack Butcher",
-    "\"We may not be responsible for the world that created our minds, but we can take responsibility for the mind with which we create our world.\" - Gabor Mate",
-    "\"The consequences of today are determined by the actions of the past. To change your future, alter your decisions today.\" - Unknown",
-    "\"Do not fight with pigs - you will be smeared in mud but the pig will like it.\" - George Bernard Shaw",
-    "\"Challenge yourself to find the good and beautiful thing inside of everyone.\" - Mark Manson",
-    "\"Constantly think about how you could be doing things better.\" - Elon Musk",
-    "\"He who knows how to wait need make no concessions.\" - Sigmund Freud",
-    "\"Our attitude toward life determines life's attitude towards us.\" - Earl Nightingale",
-    "\"People acting together as a group can accomplish things which no individual acting alone could ever hope to bring about.\" - Franklin D. Roosevelt",
-    "\"Freedom from desire leads to inner peace.\" - Lao Tzu",
-    "\"Before you embark on a journey of revenge, dig two graves. \" - Confucius",
-    "\"It's very strange that the people you love are often the people you're most cruel to.\" - Kenneth Branagh",
-    "\"Work out your own salvation. Do not depend on others.\" - Buddha",
-    "\"You can have results or excuses, but not both.\" - Arnold Schwarzenegger",
-    "\"Perplexity is the beginning of knowledge.\" - Kahlil Gibran",
-    "\"In the middle of chaos lies opportunity.  \" - Bruce Lee",
-    "\"It is important that we forgive ourselves for making mistakes. We need to learn from our errors and move on.\" - Steve Maraboli",
-    "\"Everything around us is made up of energy. To attract positive things in your life, start by giving off positive energy.\" - Celestine Chua",
-    "\"Hope and fear come from feeling that we lack something; they come from a sense of poverty.\" - Pema Chodron",
-    "\"Strategy is a commodity, execution is an art.\" - Peter Drucker",
-    "\"Being spiritual has nothing to do with what you believe and everything to do with your state of consciousness.\" - Eckhart Tolle",
-    "\"The harder the conflict, the greater the triumph. \" - George Washington",
-    "\"Forget safety. Live where you fear to live.\" - Rumi",
-    "\"A thing constructed can only be loved after it is constructed; but a thing created is loved before it exists.\" - Charles Dickens",
-    "\"Success is liking yourself, liking what you do, and liking how you do it.\" - Maya Angelou",
-    "\"Today you are you, that is truer than true. There is no one alive who is you-er than you.\" - Dr. Seuss",
-    "\"Where there is no struggle, there is no strength. \" - Oprah Winfrey",
-    "\"There is no absolute success in the world, only constant progress.\" - Jonathan Swift",
-    "\"Because of your smile, you make life more beautiful.\" - Thich Nhat Hanh",
-    "\"If you don't break your ropes while you're alive, do you think ghosts will do it after?\" - Kabir",
-    "\"He suffers more than necessary, who suffers before it is necessary.  \" - Seneca",
-    "\"Two roads diverged in a wood, and I took the one less traveled by, and that has made all the difference.\" - Robert Frost",
-    "\"You must conceive it in your heart and mind before you can receive it. If you believe then all things are possible.\" - Norman Vincent Peale",
-    "\"Courage is knowing what not to fear.\" - Plato",
-    "\"Time is more valuable than money. You can get more money, but you cannot get more time.\" - Jim Rohn",
-    "\"The smallest act of kindness is worth more than the greatest intention.  \" - Kahlil Gibran",
-    "\"You must be willing to do what others won't do, to have what others won't have.\" - Les Brown",
-    "\"Let us always meet each other with smile, for the smile is the beginning of love.\" - Mother Teresa",
-    "\"I don't ever give up. I'd have to be dead or completely incapacitated.\" - Elon Musk",
-    "\"Just throw away all thoughts of imaginary things, and stand firm in that which you are.\" - Kabir",
-    "\"Do not look for a sanctuary in anyone except your self.\" - Buddha",
-    "\"You can live a whole life time never being awake.\" - Dan Millman",
-    "\"Your work is to discover your work and then, with all your heart, to give yourself to it.\" - Buddha",
-    "\"With the new day comes new strength and new thoughts.\" - Eleanor Roosevelt",
-    "\"Happiness is a gift and the trick is not to expect it, but to delight in it when it comes.\" - Charles Dickens",
-    "\"A wise man can learn more from a foolish question than a fool can learn from a wise answer.  \" - Bruce Lee",
-    "\"Emancipate yourself from mental slavery, none but ourselves can free our mind.\" - Bob Marley",
-    "\"The greatest doer must also be a great dreamer.\" - Theodore Roosevelt",
-    "\"To become what you are not, behave as you do not.\" - T.S. Eliot",
-    "\"Wherever you are, it's the place you need to be.\" - Maxime Lagace",
-    "\"Everything you've ever wanted is on the other side of fear.\" - Brian Tracy",
-    "\"Against the assault of laughter nothing can stand.\" - Mark Twain",
-    "\"Where violence reigns, reason is weak.\" - Nicolas Chamfort",
-    "\"It is not as important how we start, but how we finish.\" - Joyce Meyer",
-    "\"Truth is not something outside to be discovered, it is something inside to be realized.\" - Osho",
-    "\"When the mind is calm, how quickly, how smoothly, how beautifully you will perceive everything.\" - Paramahansa Yogananda",
-    "\"There is no significance in life without struggle.\" - Lolly Daskal",
-    "\"In the End, we will remember not the words of our enemies, but the silence of our friends.\" - Martin Luther King, Jr.",
-    "\"You are free, and that is why you are lost.\" - Franz Kafka",
-    "\"Life is either a daring adventure, or it is nothing.\" - Helen Keller",
-    "\"You can only be afraid of what you think you know.\" - Jiddu Krishnamurti",
-    "\"We think too much and feel too little.\" - Charlie Chaplin",
-    "\"True wisdom comes to each of us when we realize how little we understand about life, ourselves, and the world around us.\" - Socrates",
-    "\"I would rather be a little nobody, then to be an evil somebody.\" - Abraham Lincoln",
-    "\"Great minds discuss ideas. Average minds discuss events. Small minds discuss people.\" - Eleanor Roosevelt",
-    "\"Life's too mysterious to take too serious.\" - Mary Engelbreit",
-    "\"I would rather die on my feet than live on my knees.\" - Euripides",
-    "\"The truth is not for all men, but for those who seek it.\" - Ayn Rand",
-    "\"To wish you were someone else is to waste the person you are.\" - Unknown",
-    "\"Imagination is the true magic carpet.\" - Norman Vincent Peale",
-    "\"Help others and give something back.\" - Arnold Schwarzenegger",
-    "\"Do all things with love.\" - Og Mandino",
-    "\"Every day passes whether you participate or not.\" - Ming-Dao Deng",
-    "\"It's easy to wish for health when you're sick.  When you're doing well, you need just as much vigilance.\" - Kamal Ravikant",
-    "\"Everything flows, nothing stands still.\" - Heraclitus",
-    "\"You're braver than you believe, and stronger than you seem, and smarter than you think.\" - A.A. Milne",
-    "\"Most of us will do anything to avoid facing ourselves.\" - Lolly Daskal",
-    "\"If he cannot stop the mind that seeks after fame and profit, he will spend his life without finding peace.\" - Dogen",
-    "\"Grudges are for those who insist that they are owed something; forgiveness, however, is for those who are substantial enough to move on.\" - Criss Jami",
-    "\"Little by little, one travels far.\" - J.R.R. Tolkien",
-    "\"The more you trust yourself, the less you compare yourself to others.\" - Roy T. Bennett",
-    "\"He who loses wealth loses much; he who loses a friend loses more; but he that loses his courage loses all.\" - Miguel de Cervantes",
-    "\"When you're at the end of your rope, tie a knot and hold on.\" - Theodore Roosevelt",
-    "\"Life is but one dream flowing into another.\" - Ming-Dao Deng",
-    "\"Failure is the highway to success.\" - Og Mandino",
-    "\"Strategy without tactics is the slowest route to victory. Tactics without strategy is the noise before defeat.\" - Sun Tzu",
-    "\"It's your life-but only if you make it so.\" - Eleanor Roosevelt",
-    "\"Life is like riding a bicycle. To keep your balance you must keep moving.\" - Albert Einstein",
-    "\"Many have died; you also will die. The drum of death is being beaten. The world has fallen in love with a dream. Only sayings of the wise will remain.\" - Kabir",
-    "\"Anxiety is the dizziness of freedom.\" - Soren Kierkegaard",
-    "\"If the grass is greener on the other side, maybe that's because you're not taking good care of your grass.\" - Unknown",
-    "\"It's possible in ways both large or small to make a difference in someone's life.\" - Yanni",
-    "\"Your visions will become clear only when you can look into your own heart. Who looks outside, dreams; who looks inside, awakes.\" - Carl Jung",
-    "\"From morning till night, we should never rely on a single thing.\" - Huang Po",
-    "\"In peace, sons bury their fathers. In war, fathers bury their sons. \" - Herodotus",
-    "\"Shoot for the moon. Even if you miss, you'll land among the stars.\" - Norman Vincent Peale",
-    "\"In youth we run into difficulties. In old age difficulties run into us. \" - Beverly Sills",
-    "\"We run to win, not just to be in the race.  \" - Vince Lombardi",
-    "\"Failure will never overtake me if my determination to succeed is strong enough.\" - Og Mandino",
-    "\"All I can do is be me, whoever that is.\" - Bob Dylan",
-    "\"Man is affected not by events but by the view he takes of them.  \" - Seneca",
-    "\"I believe that potential is unlimited - success depends on daring to act on dreams.   \" - Estee Lauder",
-    "\"FEAR has two meanings: 'Forget Everything And Run' or 'Face Everything And Rise.' The choice is yours.\" - Zig Ziglar",
-    "\"Before you speak, ask yourself, is it kind, is it necessary, is it true, does it improve the silence?\" - Sathya Sai Baba",
-    "\"No man ever steps in the same river twice, for it's not the same river and he's not the same man.\" - Heraclitus",
-    "\"Luck is what happens when preparation meets opportunity.  \" - Seneca",
-    "\"Stop acting so small. You are the universe in ecstatic motion.\" - Rumi",
-    "\"The key to success is failure.\" - Michael Jordan",
-    "\"Sometimes you have to shut your eyes, so you can see the real beauty.\" - Kilian Jornet",
-    "\"The decisions of our past are the architects of our present.\" - Dan Brown",
-    "\"Courage is the willingness to engage in a risk-taking behavior regardless of whether the consequences are unknown or possibly adverse.\" - Alfred Adler",
-    "\"Time stays long enough for anyone who will use it.\" - Leonardo da Vinci",
-    "\"Don't Let Yesterday Take Up Too Much Of Today.\" - Will Rogers",
-    "\"If you tell the truth, you don't have to remember anything.\" - Mark Twain",
-    "\"I believe that one of life's greatest risks is never daring to risk.\" - Oprah Winfrey",
-    "\"Hope is important because it can make the present moment less difficult to bear. If we believe that tomorrow will be better, we can bear a hardship today.\" - Thich Nhat Hanh",
-    "\"Don't settle, and don't struggle.\" - Naval Ravikant",
-    "\"Even the finest sword plunged into salt water will eventually rust.\" - Sun Tzu",
-    "\"Even if you stumble and fall down, it doesn't mean you've chosen the wrong path.\" - Zen Proverb",
-    "\"Imagination is more important than knowledge.\" - Albert Einstein",
-    "\"We ourselves feel that what we are doing is just a drop in the ocean. But the ocean would be less because of that missing drop.\" - Mother Teresa",
-    "\"Nothing is more real than nothing.\" - Samuel Beckett",
-    "\"In life you need either inspiration or desperation.\" - Tony Robbins",
-    "\"If you do what you've always done, you'll get what you've always gotten.\" - Tony Robbins",
-    "\"To find yourself, think for yourself.\" - Socrates",
-    "\"Without the confidence, nothing can be accomplished.\" - Sathya Sai Baba",
-    "\"A fall into a ditch makes you wiser.\" - Chinese Proverb",
-    "\"A man is literally what he thinks.\" - James Allen",
-    "\"Watch what you say, and whatever you say, practice it.\" - Soyen Shaku",
-    "\"Love is not about possession. Love is about appreciation.\" - Osho",
-    "\"True prayer has no set form.\" - Morihei Ueshiba",
-    "\"It is better to change an opinion than to persist in a wrong one.\" - Socrates",
-    "\"Make each day your masterpiece.\" - John Wooden",
-    "\"Do every act of your life as if it were your last.\" - Marcus Aurelius",
-    "\"Without education, we are in a horrible and deadly danger of taking educated people seriously.\" - Gilbert Chesterton",
-    "\"Stay away from people who makes you feel like you are wasting their time.\" - Paulo Coelho",
-    "\"Your friends will know you better in the first minute you meet than your acquaintances will know you in a thousand years.\" - Richard Bach",
-    "\"A liar knows that he is a liar, but one who speaks mere portions of truth in order to deceive is a craftsman of destruction.\" - Criss Jami",
-    "\"Educating the mind without educating the heart is no education at all.\" - Aristotle",
-    "\"Whatever happened, happened for the good. Whatever is happening, is happening for the good. Whatever will happen, will also happen for the good.\" - Bhagavad Gita",
-    "\"Wit is educated insolence.\" - Aristotle",
-    "\"Your best life will not be found in comfort. It will be found in fighting for what you believe in.\" - Maxime Lagace",
-    "\"Never argue with ignorance. It only leads to frustration.\" - Unknown",
-    "\"In order to control myself I must first accept myself by going with and not against my nature.\" - Bruce Lee",
-    "\"Wise people, even though all laws were abolished, would still lead the same life.\" - Aristophanes",
-    "\"Quality means doing it right when no one is looking.\" - Henry Ford",
-    "\"Let the beauty of what you love be what you do.\" - Rumi",
-    "\"A pessimist sees the difficulty in every opportunity; an optimist sees the opportunity in every difficulty.\" - Winston Churchill",
-    "\"To be fully alive is to feel that everything is possible.\" - Eric Hoffer",
-    "\"He is a wise man who does not grieve for the things which he has not, but rejoices for those which he has.\" - Epictetus",
-    "\"Quiet people have the loudest minds.\" - Stephen Hawking",
-    "\"To lead an orchestra, you must turn your back on the crowd.\" - Aristotle",
-    "\"You came empty handed, and you will leave empty handed.\" - Bhagavad Gita",
-    "\"Talk sense to a fool and he calls you foolish.\" - Euripides",
-    "\"Superstars think like superstars long before the fans or the press anoint them.\" - John Eliot",
-    "\"The power of human thought grows exponentially with the number of minds that share that thought.\" - Dan Brown",
-    "\"You just can't beat the person who never gives up. \" - Babe Ruth",
-    "\"What loneliness is more lonely than distrust?\" - George Eliot",
-    "\"Be kind, for everyone you meet is fighting a harder battle.\" - Plato",
-    "\"Remain calm, serene, always in command of yourself. You will then find out how easy it is to get along. \" - Paramahansa Yogananda",
-    "\"Man is made by his belief. As he believes, so he is.\" - Bhagavad Gita",
-    "\"This is the real secret of life - to be completely engaged with what you are doing in the here and now. And instead of calling it work, realize it is play.\" - Alan Watts",
-    "\"People spend too much time doing and not enough time thinking about what they should be doing.\" - Naval Ravikant",
-    "\"Show kindness but never expect it. Show authenticity but never expect it. Only the wise are indifferent to things they can't control.\" - Maxime Lagace",
-    "\"Do it with passion, or not at all.\" - Rosa Nouchette Carey",
-    "\"Trying to predict the future is like trying to drive down a country road at night with no lights while looking out the back window.\" - Peter Drucker",
-    "\"The older you get the stronger the wind gets - and it's always in your face.\" - Pablo Picasso",
-    "\"A strong man overcomes an obstacle, a wise man goes the whole way.\" - Zen Proverb",
-    "\"Life is to be fortified by many friendships. To love, and to be loved, is the greatest happiness of existence.\" - Sydney Smith",
-    "\"A wise person should have money in their head, but not in their heart.\" - Jonathan Swift",
-    "\"Each time we don't say what we wanna say, we're dying.\" - Yoko Ono",
-    "\"Ideas do not reach perfection in a day, no matter how much study is put upon them.\" - Alexander Graham Bell",
-    "\"I am thankful to all who said no to me. It is because of them that I'm doing it myself.\" - Albert Einstein",
-    "\"It is amazing what you can accomplish if you do not care who gets the credit.\" - Harry S. Truman",
-    "\"Animals don't hate, and we're supposed to be better than them.\" - Elvis Presley",
-    "\"As you grow older you will discover that you have two hands. One for helping yourself, the other for helping others.\" - Audrey Hepburn",
-    "\"Don't set your own goals by what other people make important.\" - Lolly Daskal",
-    "\"It is in the heart that makes a man rich. He is rich according to what he is, not according to what he has.\" - Henry Ward Beecher",
-    "\"Do not be so open-minded that your brains fall out.\" - Gilbert Chesterton",
-    "\"If life is a video game, the graphics are great, but the plot is confusing and the tutorial is way too long.\" - Elon Musk",
-    "\"Your very silence shows you agree.\" - Euripides",
-    "\"There are no secrets to success. It is the result of preparation, hard work, learning from failure.\" - Colin Powell",
-    "\"Anything you may hold firmly in your imagination can be yours.\" - William James",
-    "\"Any fool can turn a blind eye but who knows what the ostrich sees in the sand.\" - Samuel Beckett",
-    "\"You gain strength, courage and confidence by every experience in which you really stop to look fear in the face.\" - Eleanor Roosevelt",
-    "\"If there were no God, there would be no atheists.\" - Gilbert Chesterton",
-    "\"You cannot have a positive life and a negative mind.\" - Joyce Meyer",
-    "\"Remember, today is the tomorrow you worried about yesterday.\" - Dale Carnegie",
-    "\"What you are afraid of is never as bad as what you imagine. The fear you let build up in your mind is worse than the situation that actually exists.\" - Spencer Johnson",
-    "\"A simple rule in dealing with those who are hard to get along with is to remember that this person is striving to assert his superiority; and you must deal with him from that point of view.\" - Alfred Adler",
-    "\"Walk slowly but never walk backward.\" - Unknown",
-    "\"Do good, live in the most positive and joyful way possible every day.\" - Roy T. Bennett",
-    "\"Peace is not the absence of conflict, but the ability to cope with it.\" - Unknown",
-    "\"Judge your success by what you had to give up in order to get it.\" - Dalai Lama",
-    "\"The only thing worse than being blind is having sight but no vision.\" - Helen Keller",
-    "\"Believe in yourself. You are braver than you think, more talented than you know, and capable of more than you imagine.\" - Roy T. Bennett",
-    "\"To raise your consciousness, surround yourself with people of higher consciousness.\" - Celestine Chua",
-    "\"It's not the size of the dog in the fight, it's the size of the fight in the dog.\" - Mark Twain",
-    "\"You'll get more from being a peacemaker than a warrior.\" - Arnold Schwarzenegger",
-    "\"Trust is the glue that holds people together and is the lubricant that keeps an organization moving forward.\" - Colin Powell",
-    "\"Keep your face always toward the sunshine -  shadows will fall behind you.\" - Walt Whitman",
-    "\"I do not exist to impress the world. I exist to live my life in a way that will make me happy.\" - Richard Bach",
-    "\"I begin with an idea and then it becomes something else.\" - Pablo Picasso",
-    "\"A mistake is only an error, it becomes a mistake when you fail to correct it.\" - John Lennon",
-    "\"If you truly love Nature, you will find beauty everywhere. \" - Vincent van Gogh",
-    "\"Success is getting what you want... Happiness is wanting what you get.\" - Dale Carnegie",
-    "\"The scariest moment is always just before you start. After that, things can only get better.\" - Stephen King",
-    "\"Dreams are the touchstones of our characters.\" - Henry David Thoreau",
-    "\"Man invented language to satisfy his deep need to complain.\" - Lily Tomlin",
-    "\"You drown not by falling into a river, but by staying submerged in it.\" - Paulo Coelho",
-    "\"Happiness depends upon ourselves.\" - Aristotle",
-    "\"A real friend is one who walks in when the rest of the world walks out.\" - Unknown",
-    "\"I can never decide whether my dreams are the result of my thoughts or my thoughts the result of my dreams.\" - D. H. Lawrence",
-    "\"The purpose of life is to contribute in some way to making things better.\" - Robert F. Kennedy",
-    "\"Nothing will work unless you do.\" - Maya Angelou",
-    "\"You're never given a dream without also being given the power to make it true.\" - Richard Bach",
-    "\"Behind every beautiful thing, there's some kind of pain.\" - Bob Dylan",
-    "\"Growth comes at the point of resistance.\" - Josh Waitzkin",
-    "\"You will succeed because most people are lazy.\" - Shahir Zag",
-    "\"You can often change your circumstances by changing your attitude.\" - Eleanor Roosevelt",
-    "\"Life is not always a matter of holding good cards, but sometimes, playing a poor hand well. \" - Jack London",
-    "\"I am not the outcome. I am never the result. I am only the effort.\" - Kamal Ravikant",
-    "\"Appear weak when you are strong, and strong when you are weak.\" - Sun Tzu",
-    "\"Man must use what he has, not hope for what is not.\" - G.I. Gurdjieff",
-    "\"Passion is energy. Feel the power that comes from focusing on what excites you. \" - Oprah Winfrey",
-    "\"Only the hand that erases can write the true thing.\" - Meister Eckhart",
-    "\"Change will not come if we wait for some other person, or if we wait for some other time.\" - Barack Obama",
-    "\"If you want to make God laugh, tell him about your plans. \" - Woody Allen",
-    "\"Instead of thinking about what you are going to do when you retire, think about how you can do that now and make a living from it.\" - Celestine Chua",
-    "\"Knowledge is learning something everyday. Wisdom is letting go of something everyday.\" - Unknown",
-    "\"Every traveler has a home of his own, and he learns to appreciate it the more from his wandering.\" - Charles Dickens",
-    "\"The important thing is not what one is born with but what use one makes of that equipment.\" - Alfred Adler",
-    "\"The opposite of courage in our society is not cowardice... it is conformity.\" - Earl Nightingale",
-    "\"To lead the people, walk behind them.\" - Lao Tzu",
-    "\"The only difference between success and failure is the ability to take action.\" - Alexander Graham Bell",
-    "\"The future is the worst thing about the present.\" - Gustave Flaubert",
-    "\"The first half of life is devoted to forming a healthy ego, the second half is going inward and letting go of it.\" - Carl Jung",
-    "\"Every saint has a past, and every sinner has a future.\" - Oscar Wilde",
-    "\"The health of nations is more important than the wealth of nations.\" - Will Rogers",
-    "\"Whenever you find yourself on the side of the majority, it is time to reform.\" - Mark Twain",
-    "\"Mastery is not a function of genius or talent, it is a function of time and intense focus applied to a particular field of knowledge.\" - Robert Greene",
-    "\"If you are not living each day with excitement, energy, and passion, then you are not living true to your life purpose.\" - Celestine Chua",
-    "\"All wealth is the product of labor.\" - John Locke",
-    "\"Ripe fruit falls by itself - but it doesn't fall in your mouth. \" - Chinese Proverb",
-    "\"Some changes look negative on the surface but you will soon realize that space is being created in your life for something new to emerge.\" - Eckhart Tolle",
-    "\"From a certain point onward there is no longer any turning back. That is the point that must be reached.\" - Franz Kafka",
-    "\"Successful people do what unsuccessful people are not willing to do.\" - Jim Rohn",
-    "\"Be thankful for everything that happens in your life; it's all an experience.\" - Roy T. Bennett",
-    "\"Making your mark on the world is hard. If it were easy, everybody would do it.\" - Barack Obama",
-    "\"Remember we're all in this alone.\" - Lily Tomlin",
-    "\"By going within. Nowhere you can go is more peaceful - more free of interruptions - than your own soul.\" - Marcus Aurelius",
-    "\"A flower falls, even though we love it; and a weed grows, even though we do not love it.\" - Dogen",
-    "\"To avoid criticism, do nothing, say nothing, and be nothing.\" - Elbert Hubbard",
-    "\"Our virtues and our failings are inseparable, like force and matter. When they separate, man is no more. \" - Nikola Tesla",
-    "\"True friendship ought never to conceal what it thinks. \" - St. Jerome",
-    "\"To live outside the law, you must be honest. \" - Bob Dylan",
-    "\"Give so much away people insist on paying you.\" - Jack Butcher",
-    "\"Wisdom is doing now what you are going to be happy with later on.\" - Joyce Meyer",
-    "\"Growth is in a series of mistakes. That's the only way you learn.\" - Steve Harvey",
-    "\"Every second you have on this planet is very precious and it's your responsibility that you're happy.\" - Naval Ravikant",
-    "\"If you put yourself in a position where you have to stretch outside your comfort zone, then you are forced to expand your consciousness.\" - Unknown",
-    "\"You can never get enough of what you don't need to make you happy.\" - Eric Hoffer",
-    "\"Money poisons you when you've got it, and starves you when you haven't.\" - D. H. Lawrence",
-    "\"Perpetual optimism is a force multiplier.\" - Colin Powell",
-    "\"If a man knows not to which port he sails, no wind is favorable.  \" - Seneca",
-    "\"The secret of getting ahead is getting started.\" - Mark Twain",
-    "\"Imagination is everything. It is the preview of life's coming attractions.\" - Albert Einstein",
-    "\"Coming together is a beginning, staying together is progress, and working together is success. \" - Henry Ford",
-    "\"To do great work one must be very idle as well as very industrious.\" - Samuel Butler",
-    "\"Integrity is telling myself the truth. And honesty is telling the truth to other people.\" - Spencer Johnson",
-    "\"Be curious, not judgmental.\" - Walt Whitman",
-    "\"You may be disappointed if you fail, but you are doomed if you don't try. \" - Beverly Sills",
-    "\"A mentor is someone who sees more talent and ability within you, than you see in yourself, and helps bring it out of you.\" - Bob Proctor",
-    "\"We build too many walls and not enough bridges.\" - Isaac Newton",
-    "\"Peace begins with a smile.\" - Mother Teresa",
-    "\"As soon as you truly commit to making something happen, the 'how' will reveal itself.\" - Tony Robbins",
-    "\"Hope is a waking dream.\" - Aristotle",
-    "\"My guiding principle is this: Guilt is never to be doubted. \" - Franz Kafka",
-    "\"As a matter of fact is an expression that precedes many an expression that isn't.\" - Laurence J. Peter",
-    "\"Try all things, hold fast that which is good.\" - John Locke",
-    "\"A goal is a dream with a deadline.\" - Napoleon Hill",
-    "\"It's not what happens to you, but how you react to it that matters.\" - Epictetus",
-    "\"Don't stop when you're tired. Stop when you're done.\" - Marilyn Monroe",
-    "\"Mastering others is strength, mastering yourself is true power.\" - Lao Tzu",
-    "\"Be courteous to all, but intimate with few, and let those few be well tried before you give them your confidence. \" - George Washington",
-    "\"Your life does not get better by chance, it gets better by change.\" - Jim Rohn",
-    "\"I must also have a dark side if I am to be whole.\" - Carl Jung",
-    "\"Don't let the noise of others opinions drown out your inner voice.\" - Steve Jobs",
-    "\"If your mind is empty, it is always ready for anything, it is open to everything.\" - Shunryu Suzuki",
-    "\"The Only Thing That Is Constant Is Change\" - Heraclitus",
-    "\"I have no methods; all I do is accept people as they are.\" - Joan Rivers",
-    "\"Only by attracting the best people will you accomplish great deeds.\" - Colin Powell",
-    "\"You get paid in direct proportion to the difficulty of problems you solve.\" - Elon Musk",
-    "\"Everyday is a second chance.\" - Unknown",
-    "\"We should not look back unless it is to derive useful lessons from past errors.\" - George Washington",
-    "\"Do not think you will necessarily be aware of your own enlightenment.\" - Dogen",
-    "\"If you want to turn a vision into reality, you have to give 100% and never stop believing in your dream.\" - Arnold Schwarzenegger",
-    "\"Few things are more deceptive than memories.\" - Carlos Ruiz Zafon",
-    "\"The Art of Peace begins with you.\" - Morihei Ueshiba",
-    "\"Nothing gives one person so much advantage over another as to remain always cool and unruffled under all circumstances.\" - Thomas Jefferson",
-    "\"You are the sky. Everything else - it's just the weather.\" - Pema Chodron",
-    "\"The person who says something is impossible should not interrupt the person who is doing it.\" - Chinese Proverb",
-    "\"A dead thing can go with the stream, but only a living thing can go against it.\" - Gilbert Chesterton",
-    "\"Great loves too must be endured.\" - Coco Chanel",
-    "\"You are not what you think you are, You are not what others think you are, You are what you think others think you are.\" - Unknown",
-    "\"Success is going from failure to failure without losing your enthusiasm\" - Winston Churchill",
-    "\"I love fools' experiments. I am always making them.\" - Charles Darwin",
-    "\"It is in changing that we find purpose.\" - Heraclitus",
-    "\"We win by helping each other win.\" - Jack Butcher",
-    "\"Happiness comes when you believe in what you are doing, know what you are doing, and love what you are doing.\" - Brian Tracy",
-    "\"What a liberation to realize that 'the voice in my head' is not who I am. Who am I then? The one who sees that.\" - Eckhart Tolle",
-    "\"Life does not get better by chance, it gets better by change.\" - Jim Rohn",
-    "\"Make your life a mission - not an intermission.\" - Unknown",
-    "\"Be grateful for what you have now, and nothing should be taken for granted.\" - Roy T. Bennett",
-    "\"Obstacles don't block the path, they are the path.\" - Zen Proverb",
-    "\"Don't be afraid of missing opportunities. Behind every failure is an opportunity somebody wishes they had missed.\" - Lily Tomlin",
-    "\"Live your life as an exclamation rather than an explanation.\" - Isaac Newton",
-    "\"We have to walk in a way that we only print peace and serenity on the Earth. Walk as if you are kissing the Earth with your feet.\" - Thich Nhat Hanh",
-    "\"The biggest adventure you can ever take is to live the life of your dreams. \" - Oprah Winfrey",
-    "\"Everything starts with a dream. A particle of our imagination that teases us of what the future could be like, as long as we fight for it.\" - Gurbaksh Chahal",
-    "\"Doing what you love is the cornerstone of having abundance in your life. \" - Wayne Dyer",
-    "\"Never be afraid to raise your voice for honesty and truth and compassion against injustice and lying and greed.\" - William Faulkner",
-    "\"Stop wasting time defending your problems and work on addressing them instead.\" - Celestine Chua",
-    "\"Attitude is a little thing that makes a big difference.\" - Winston Churchill",
-    "\"Help others achieve their dreams and you will achieve yours.\" - Les Brown",
-    "\"No man is free who is not master of himself.\" - Epictetus",
-    "\"It is often safer to be in chains than to be free. \" - Franz Kafka",
-    "\"The two most powerful warriors are patience and time.\" - Leo Tolstoy",
-    "\"If you can't feed a hundred people, then feed just one.\" - Mother Teresa",
-    "\"Whatever your heart clings to and confides in, that is really your God.\" - Martin Luther",
-    "\"To be of use to the world is the only way to be happy.\" - Hans Christian Andersen",
-    "\"A day without laughter is a day wasted.\" - Charlie Chaplin",
-    "\"The future doesn't belong to the faint-hearted; it belongs to the brave.\" - Ronald Reagan",
-    "\"Throughout this life, you can never be certain of living long enough to take another breath.\" - Huang Po",
-    "\"Nothing good ever comes of violence.\" - Martin Luther",
-    "\"I learned that courage was not the absence of fear, but the triumph over it. The brave man is not he who does not feel afraid, but he who conquers that fear.\" - Nelson Mandela",
-    "\"A creative man is motivated by the desire to achieve, not by the desire to beat others.\" - Ayn Rand",
-    "\"Waste no more time arguing about what a good man should be. Be one.\" - Marcus Aurelius",
-    "\"Not how long, but how well you have lived is the main thing.\" - Seneca",
-    "\"We've got to live, no matter how many skies have fallen.\" - D. H. Lawrence",
-    "\"A little impatience will spoil great plans.\" - Chinese Proverb",
-    "\"The grass is greener where you water it.\" - Neil Barringham",
-    "\"Thinking will not overcome fear but action will. \" - W. Clement Stone",
-    "\"Don't let your learning lead to knowledge. Let your learning lead to action.\" - Jim Rohn",
-    "\"A minute's success pays the failure of years.\" - Robert Browning",
-    "\"Conflict cannot survive without your participation. \" - Wayne Dyer",
-    "\"Be a master of change rather than a victim of change.\" - Brian Tracy",
-    "\"You don't need a weatherman to know which way the wind blows.\" - Bob Dylan",
-    "\"Go and do the things you can't. That is how you get to do them.\" - Pablo Picasso",
-    "\"Turn the pain into power.\" - Unknown",
-    "\"You're only given a little spark of madness. You mustn't lose it.\" - Robin Williams",
-    "\"It always seems impossible until it's done.\" - Nelson Mandela",
-    "\"Be gentle with yourself. Think less and feel more. Be as happy as you can. You only have this moment.\" - Dan Millman",
-    "\"It's not about what it is, it's about what it can become.\" - Dr. Seuss",
-    "\"Discipline is the mark of intelligent living.\" - Sathya Sai Baba",
-    "\"You can suffer the pain of change or suffer remaining the way you are.\" - Joyce Meyer",
-    "\"Once you choose hope, anything's possible.\" - Christopher Reeve",
-    "\"The secret of life isn't what happens to you, but what you do with what happens to you.\" - Norman Vincent Peale",
-    "\"The key to success is to focus our conscious mind on things we desire not things we fear. \" - Brian Tracy",
-    "\"The less people know, the more stubbornly they know it.\" - Osho",
-    "\"Normality is a paved road: It's comfortable to walk, but no flowers grow on it.\" - Vincent van Gogh",
-    "\"There is a wisdom of the head, and there is a wisdom of the heart.\" - Charles Dickens",
-    "\"Take care of your cents: dollars will take care of themselves.\" - Thomas Jefferson",
-    "\"Life is simply what our feelings do to us.\" - Honore de Balzac",
-    "\"You are not stuck where you are unless you decide to be.  \" - Wayne Dyer",
-    "\"Never settle for the crumbs of life.\" - Og Mandino",
-    "\"Wisdom is the oneness of mind that guides and permeates all things.\" - Heraclitus",
-    "\"A path is made by walking on it.\" - Zhuangzi",
-    "\"If you want something then you lose everything. If you don't want anything then you already have everything.\" - Seungsahn",
-    "\"Everybody's life is either rewarding or an example.\" - Tony Robbins",
-    "\"Just as true humor is laughter at oneself, true humanity is knowledge of oneself.\" - Alan Watts",
-    "\"The less you open your heart to others, the more your heart suffers.\" - Deepak Chopra",
-    "\"The future belongs to those who learn more skills and combine them in creative ways.\" - Robert Greene",
-    "\"You'll see it when you believe it. \" - Wayne Dyer",
-    "\"Nothing that results in human progress is achieved with unanimous consent.\" - Christopher Columbus",
-    "\"We create the world and ourselves;\" - Ming-Dao Deng",
-    "\"Live your life and forget your age.\" - Norman Vincent Peale",
-    "\"It's easier to lose than to win.  \" - Wayne Gretzky",
-    "\"A self that goes on changing is a self that goes on living.\" - Virginia Woolf",
-    "\"Greatest success comes just one step beyond the point at which defeat overtakes you.\" - Unknown",
-    "\"A crisis is an opportunity riding the dangerous wind.\" - Chinese Proverb",
-    "\"If you see yourself as prosperous, you will be.\" - Robert Collier",
-    "\"Success in any endeavor depends on the degree to which it is an expression of your true self. \" - Ralph Marston",
-    "\"Anyone who has ever made anything of importance was disciplined.\" - Andrew Hendrixson",
-    "\"Keep away from people who try to belittle your ambitions.\" - Mark Twain",
-    "\"We can know only that we know nothing. And that is the highest degree of human wisdom.\" - Leo Tolstoy",
-    "\"The two most important days in your life are the day you are born and the day you find out why.\" - Mark Twain",
-    "\"When you judge another, you do not define them, you define yourself\" - Wayne Dyer",
-    "\"You will face many defeats in life, but never let yourself be defeated.\" - Maya Angelou",
-    "\"Know yourself and you will win all battles.\" - Sun Tzu",
-    "\"It's OK to have your eggs in one basket as long as you control what happens to that basket.\" - Elon Musk",
-    "\"It is not what we get. But who we become, what we contribute... that gives meaning to our lives.\" - Tony Robbins",
-    "\"None of us can change our yesterdays but all of us can change our tomorrows.\" - Colin Powell",
-    "\"What's the point in being smart enough to read, if everything you read makes you dumb?\" - C. Sean McGee",
-    "\"There are no differences but differences of degree between different degrees of difference and no difference.\" - William James",
-    "\"Great thoughts speak only to the thoughtful mind, but great actions speak to all mankind.\" - Theodore Roosevelt",
-    "\"If you read a lot of books, you're considered well-read. But if you watch a lot of TV, you're not considered well-viewed.\" - Lily Tomlin",
-    "\"Creativity is intelligence having fun.\" - Albert Einstein",
-    "\"Selfishness and greed, individual or national, cause most of our troubles.\" - Harry S. Truman",
-    "\"What we call the beginning is often the end. And to make an end is to make a beginning. The end is where we start from.\" - T.S. Eliot",
-    "\"The more I want to get something done, the less I call it work.\" - Richard Bach",
-    "\"Do not allow your inner doubts to keep you from achieving what you can do.\" - Lolly Daskal",
-    "\"No one can compete with you on being you. Most of life is a search for who and what needs you the most.\" - Naval Ravikant",
-    "\"You don't need anybody to tell you who you are or what you are. You are what you are!\" - John Lennon",
-    "\"If you correct your mind, the rest of your life will fall into place.\" - Lao Tzu",
-    "\"To succeed takes more than the desire to win. It also takes the acceptance that we could fail.\" - Simon Sinek",
-    "\"Do anything, but let it produce joy.\" - Walt Whitman",
-    "\"Dreams provide nourishment for the soul, just as a meal does for the body.\" - Paulo Coelho",
-    "\"Even when a friend does something you do not like, he continues to be your friend. \" - Genghis Khan",
-    "\"When you move beyond your fear, you feel free.\" - Spencer Johnson",
-    "\"To be angry is to revenge the faults of others on ourselves.\" - Alexander Pope",
-    "\"You can tell more about a person by what he says about others than you can by what others say about him.\" - Audrey Hepburn",
-    "\"We can have more than we've got because we can become more than we are.\" - Jim Rohn",
-    "\"Man's character is his fate.\" - Heraclitus",
-    "\"Words are the clothing of our thoughts.\" - Jonathan Swift",
-    "\"Nothing we see or hear is perfect. But right there in the imperfection is perfect reality.\" - Shunryu Suzuki",
-    "\"Once you learn to quit, it becomes a habit.\" - Vince Lombardi",
-    "\"Short cuts make long delays.\" - J.R.R. Tolkien",
-    "\"Respect is earned by effort not age, all things age without effort.\" - C. Sean McGee",
-    "\"Your conscience is the measure of the honesty of your selfishness. Listen to it carefully.\" - Richard Bach",
-    "\"Sometimes it is more important to discover what one cannot do, than what one can do.\" - Lin Yutang",
-    "\"A life without a cause is a life without effect.\" - Paulo Coelho",
-    "\"It is our experiences that mold us into who we are...during times of adversity our true character will show.\" - Unknown",
-    "\"The privilege of a lifetime is to become who you truly are.\" - Carl Jung",
-    "\"It's much better to do good in a way that no one knows anything about it.\" - Leo Tolstoy",
-    "\"Our heart is a treasury; if you pour out all its wealth at once, you are bankrupt.\" - Honore de Balzac",
-    "\"Rest in reason; move in passion.\" - Kahlil Gibran",
-    "\"If you don't like something, change it. If you can't change it, change your attitude.\" - Maya Angelou",
-    "\"Don't hate the player. Change the game.\" - Steve Harvey",
-    "\"Reality is the leading cause of stress among those in touch with it.\" - Lily Tomlin",
-    "\"Trust is built and maintained by many small actions over time.\" - Lolly Daskal",
-    "\"The goal is not to show how great you are to others, but how vulnerable you are to yourself.\" - Maxime Lagace",
-    "\"A wise man will be master of his mind, a fool will be its slave.\" - Publilius Syrus",
-    "\"Change your thoughts and you change your world.\" - Norman Vincent Peale",
-    "\"Everything is in your own heart.\" - Thich Nhat Hanh",
-    "\"Public opinion is the worst of all opinions.\" - Nicolas Chamfort",
-    "\"It only takes a split second to smile and forget, yet to someone that needed it, it can last a lifetime.\" - Steve Maraboli",
-    "\"You were put on this earth to achieve your greatest self, to live out your purpose, and to do it courageously.\" - Steve Maraboli",
-    "\"The way out is in.\" - Thich Nhat Hanh",
-    "\"The few who do are the envy of the many who only watch.\" - Jim Rohn",
-    "\"The truly rich men are the ones who have health, loved ones and a work they love.\" - Maxime Lagace",
-    "\"Before you can see the Light, you have to deal with the darkness.\" - Dan Millman",
-    "\"The ability to hold two competing thoughts in one's mind and still be able to function is the mark of a superior mind.\" - F. Scott Fitzgerald",
-    "\"Everyone has oceans to fly, if they have the heart to do it. Is it reckless? Maybe. But what do dreams know of boundaries.\" - Amelia Earhart",
-    "\"They who have conquered doubt and fear have conquered failure.\" - James Allen",
-    "\"Rest when you're weary. Refresh and renew yourself, your body, your mind, your spirit. Then get back to work.\" - Ralph Marston",
-    "\"Be polite to all, but intimate with few.\" - Thomas Jefferson",
-    "\"Motivation is what gets you started. Habit is what keeps you going.\" - Jim Rohn",
-    "\"If you go out looking for friends, you're going to find they are very scarce. If you go out to be a friend, you'll find them everywhere.\" - Zig Ziglar",
-    "\"The biggest adventure is what lies ahead.\" - J.R.R. Tolkien",
-    "\"Stuff your eyes with wonder... live as if you'd drop dead in ten seconds. See the world. It's more fantastic than any dream made or paid for in factories.\" - Ray Bradbury",
-    "\"Love is the expression of one's values.\" - Ayn Rand",
-    "\"One small positive thought can change your whole day.\" - Zig Ziglar",
-    "\"Get mad, then get over it.\" - Colin Powell",
-    "\"Every moment is a fresh beginning.\" - T.S. Eliot",
-    "\"There are people who have money and people who are rich.\" - Coco Chanel",
-    "\"Just get out and do it. You will be very, very glad that you did.\" - Christopher McCandless",
-    "\"Courage is almost a contradiction in terms. It means a strong desire to live taking the form of a readiness to die.\" - Gilbert Chesterton",
-    "\"Success is about creating value.\" - Candice Carpenter",
-    "\"Real learning comes about when the competitive spirit has ceased.\" - Jiddu Krishnamurti",
-    "\"Life is and will ever remain an equation incapable of solution, but it contains certain known factors. \" - Nikola Tesla",
-    "\"The deeper the truth in a creative work, the longer it will live.  \" - Charlie Chaplin",
-    "\"We can choose to look at whatever we want.  We can look at what's wrong in our life or we can look at what's right.\" - Sonia Ricotti",
-    "\"Imagination means nothing without doing.  \" - Charlie Chaplin",
-    "\"You are free, and that is why you are lost. \" - Franz Kafka",
-    "\"There's no coming to consciousness without pain\" - Carl Jung",
-    "\"A Quitter never wins - and - a Winner never quits.\" - Napoleon Hill",
-    "\"All great truths begin as blasphemies.\" - George Bernard Shaw",
-    "\"You have the ability, now apply yourself.\" - Benjamin Mays",
-    "\"The greatest gift that you can give to others is the gift of unconditional love and acceptance. \" - Brian Tracy",
-    "\"Failure is an option here. If things are not failing, you are not innovating enough.\" - Elon Musk",
-    "\"We are born from a quiet sleep, and we die to a calm awakening\" - Zhuangzi",
-    "\"If you are unable to find the truth right where you are, where else do you expect to find it?\" - Dogen",
-    "\"Opportunities multiply as they are seized.\" - Sun Tzu",
-    "\"Gratitude is the fairest blossom which springs from the soul.\" - Henry Ward Beecher",
-    "\"an action committed in anger is an action doomed to failure. \" - Genghis Khan",
-    "\"Have the courage to be ignorant of a great number of things, in order to avoid the calamity of being ignorant of everything.\" - Sydney Smith",
-    "\"The opportunity of defeating the enemy is provided by the enemy himself.\" - Sun Tzu",
-    "\"You are the average of the five people you spend most time with.\" - Jim Rohn",
-    "\"The secret of perfect health lies in keeping the mind always cheerful - never worried, never hurried, never borne down by any fear, thought or anxiety.\" - Sathya Sai Baba",
-    "\"The question isn't who is going to let me; it's who is going to stop me.\" - Ayn Rand",
-    "\"I find the best way to love someone is not to change them, but instead, help them reveal the greatest version of themselves.\" - Steve Maraboli",
-    "\"Absence makes the heart grow fonder.\" - Eleanor Roosevelt",
-    "\"If you quit once it becomes a habit.\" - Michael Jordan",
-    "\"The truth is, everyone is going to hurt you. You just got to find the ones worth suffering for.\" - Bob Marley",
-    "\"The significance of life is living.\" - Jiddu Krishnamurti",
-    "\"Do your duty and a little more and the future will take care of itself.\" - Andrew Carnegie",
-    "\"Values are like fingerprints. Nobody's are the same, but you leave 'em all over everything you do.\" - Elvis Presley",
-    "\"You may think I'm small, but I have a universe inside my mind.\" - Yoko Ono",
-    "\"Don't bother people for help without first trying to solve the problem yourself.\" - Colin Powell",
-    "\"What gets measured gets improved.\" - Robin Sharma",
-    "\"Plants grow weak without wind.\" - Maxime Lagace",
-    "\"Lost Time is never found again.\" - Benjamin Franklin",
-    "\"Just because something isn't a lie does not mean that it isn't deceptive.\" - Criss Jami",
-    "\"Where focus goes, energy flows.\" - Tony Robbins",
-    "\"Yesterday is gone. Tomorrow has not yet come. We have only today.\" - Mother Teresa",
-    "\"Every night before going to sleep, we must ask ourselves: what weakness did I overcome today? What virtue did I acquire?\" - Seneca",
-    "\"The greatest value of a picture is when it forces us to notice what we never expected to see.\" - John Tukey",
-    "\"If you're afraid - don't do it, - if you're doing it - don't be afraid! \" - Genghis Khan",
-    "\"Meditate. It won't be fun but it will surface what you need to deal with.\" - Naval Ravikant",
-    "\"The ego wants to go fast. The soul needs to go slow.\" - Maxime Lagace",
-    "\"Don't spend major time on minor things.\" - Jim Rohn",
-    "\"The difference between treason and patriotism is only a matter of dates.\" - Alexandre Dumas",
-    "\"Rather than love, than money, than fame, give me truth.\" - Henry David Thoreau",
-    "\"People who belittle people, will be LITTLE people, and will accomplish very Little\" - Norman Vincent Peale",
-    "\"Avoiding danger is no safer in the long run than exposure.\" - Helen Keller",
-    "\"Cry. Forgive. Learn. Move on. Let your tears water the seeds of your future happiness.\" - Steve Maraboli",
-    "\"Honesty is the first chapter in the book of wisdom.\" - Thomas Jefferson",
-    "\"When you've got nothing, you've got nothing to lose.\" - Bob Dylan",
-    "\"I'm an optimist by choice not by stupidity.\" - Yanni",
-    "\"The educated differ from the uneducated as much as the living differ from the dead.\" - Aristotle",
-    "\"Obstacles are those frightful things you see when you take your eyes off your goals.\" - Sydney Smith",
-    "\"Nearly every crisis seems to be the worst one, but after it's over, it isn't so bad.\" - Harry S. Truman",
-    "\"To create more positive results in your life, replace 'if only' with 'next time'.\" - Celestine Chua",
-    "\"Unless someone truly has the power to say no, they never truly have the power to say yes.\" - Dan Millman",
-    "\"Poverty and hardship are created by false thinking.\" - Bodhidharma",
-    "\"You don't know who is important to you until you actually lose them.\" - Mahatma Gandhi",
-    "\"You are always your own best guru, your own best teacher, the answers are always inside you.\" - Sathya Sai Baba",
-    "\"Be mindful. Be grateful. Be positive. Be true. Be kind.\" - Roy T. Bennett",
-    "\"You must welcome change as the rule but not as your ruler. \" - Denis Waitley",
-    "\"One beam, no matter how big, cannot support an entire house on its own.\" - Chinese Proverb",
-    "\"Confidence comes not from always being right but not fearing to be wrong.\" - Unknown",
-    "\"Many a man has failed because he had his wishbone where his backbone should have been.\" - Ronald Reagan",
-    "\"The sun is new each day.\" - Heraclitus",
-    "\"Instead of being intimidated by the limitations, be inspired to find new ways around them.\" - Ralph Marston",
-    "\"You can not live your life just based on what everyone else thinks.\" - Joyce Meyer",
-    "\"Anything worth doing is worth doing slowly.\" - Mae West",
-    "\"You Create Your Own Present By What You Give Your Attention To Today.\" - Spencer Johnson",
-    "\"Life doesn't get easier or more forgiving, we get stronger and more resilient.\" - Steve Maraboli",
-    "\"Everything will be ok in the end. If it's not ok, it's not the end.\" - John Lennon",
-    "\"If I can pay the bills, I'm happy.\" - Morgan Wootten",
-    "\"Experience is a comb which nature gives us when we are bald. \" - Chinese Proverb",
-    "\"Don't blame others. it won't make you a better person.\" - Lolly Daskal",
-    "\"A journey of a thousand miles begins with a single step.\" - Lao Tzu",
-    "\"Whenever you do a thing, act as if all the world were watching.\" - Thomas Jefferson",
-    "\"I have lived with several Zen masters -- all of them cats.\" - Eckhart Tolle",
-    "\"I am thankful to all those who said no. It's because of them, I did it myself.  \" - Wayne Dyer",
-    "\"Happiness is pretty simple: someone to love, something to do, something to look forward to.\" - Rita Mae Brown",
-    "\"Peace is not something you wish for; It's something you make, something you do, something you are, and something you give away.\" - John Lennon",
-    "\"Each path to knowledge involves different rules and these rules are not interchangeable.\" - Barack Obama",
-    "\"The way of success is the way of continuous pursuit of knowledge.\" - Napoleon Hill",
-    "\"Treat every moment as your last. It is not preparation for something else.\" - Shunryu Suzuki",
-    "\"No matter who you are, we're creatures of habit. The better your habits are, the better they will be in pressure situations.\" - Wayne Gretzky",
-    "\"A dream you dream alone is only a dream. A dream you dream together is reality.\" - Yoko Ono",
-    "\"Work as though you would live forever, and live as though you would die today. Go another mile.\" - Og Mandino",
-    "\"Someone who points out your flaws to you is not necessarily your enemy. Someone who speaks of your virtues is not necessarily your friend.\" - Zen Proverb",
-    "\"There are no second chances in life, except to feel remorse.\" - Carlos Ruiz Zafon",
-    "\"In order to be walked over, you have to be lying down.\" - Celestine Chua",
-    "\"Without struggle, no progress and no result. Every breaking of habit produces a change in the machine.\" - G.I. Gurdjieff",
-    "\"There are no constraints on the human mind, no walls around the human spirit, no barriers to our progress except those we ourselves erect.\" - Ronald Reagan",
-    "\"A man that flies from his fear may find that he has only taken a short cut to meet it.\" - J.R.R. Tolkien",
-    "\"I am not absentminded. It is the presence of mind that makes me unaware of everything else.\" - Gilbert Chesterton",
-    "\"Only to the extent that we expose ourselves over and over to annihilation can that which is indestructible in us be found.\" - Pema Chodron",
-    "\"Success comes to those who become success conscious.\" - Napoleon Hill",
-    "\"A rational person can find peace by cultivating indifference to things outside of their control.\" - Naval Ravikant",
-    "\"True it is, we only hate those whom we do not know.\" - Andrew Carnegie",
-    "\"Don't explain your philosophy. Embody it.\" - Epictetus",
-    "\"I am not what happened to me, I am what I choose to become.\" - Carl Jung",
-    "\"Love the life you live, live the life you love.\" - Bob Marley",
-    "\"People think about what they don't want and attract more of the same.\" - Unknown",
-    "\"It is not how much we have, but how much we enjoy, that makes happiness.\" - Charles Spurgeon",
-    "\"The mind is the limit.\" - Arnold Schwarzenegger",
-    "\"Creative endeavors are by their nature uncertain.\" - Robert Greene",
-    "\"Paths are made by walking.\" - Franz Kafka",
-    "\"I don't pretend to know everything; I just only speak on matters I know I'll win.\" - Criss Jami",
-    "\"Life isn't about finding yourself. Life is about creating yourself.\" - George Bernard Shaw",
-    "\"May you find grace as you surrender to life. May you find happiness, as you stop seeking it.\" - Dan Millman",
-    "\"When you make a choice, you change the future.\" - Deepak Chopra",
-    "\"Looking after my health today gives me a better hope for tomorrow. \" - Anne Wilson Schaef",
-    "\"Walk with those seeking truth... RUN FROM THOSE WHO THINK THEY'VE FOUND IT.\" - Deepak Chopra",
-    "\"Genius is patience.\" - Isaac Newton",
-    "\"We turn not older with years but newer every day.\" - Emily Dickinson",
-    "\"The only way to 'find out if it will work out' is to do it.\" - Simon Sinek",
-    "\"Enjoy life. There's plenty of time to be dead.\" - Hans Christian Andersen",
-    "\"There are no contests in the Art of Peace. A true warrior is invincible because he or she contests with nothing.\" - Morihei Ueshiba",
-    "\"Life is the dancer and you are the dance.\" - Eckhart Tolle",
-    "\"When you are tough on yourself, life is going to be infinitely easier on you.  \" - Zig Ziglar",
-    "\"There is no failure except in no longer trying. There is no defeat except from within, no insurmountable barrier except our own inherent weakness of purpose.\" - Elbert Hubbard",
-    "\"Do not stop thinking of life as an adventure.\" - Eleanor Roosevelt",
-    "\"You have as much laughter as you have faith.\" - Martin Luther",
-    "\"If you do not tell the truth about yourself you cannot tell it about other people.\" - Virginia Woolf",
-    "\"To perceive is to suffer.\" - Aristotle",
-    "\"The highest level of wisdom is when you not only accept but love adversity.\" - Maxime Lagace",
-    "\"It is easy to discover what another has discovered before.\" - Christopher Columbus",
-    "\"A man sooner or later discovers that he is the master-gardener of his soul, the director of his life.\" - James Allen",
-    "\"Don't let someone elses. opinion become your reality.\" - Les Brown",
-    "\"A good character is the best tombstone. Carve your name on hearts, not on marble.\" - Charles Spurgeon",
-    "\"The way to change others' minds is with affection, and not anger.\" - Dalai Lama",
-    "\"Great minds are always feared by lesser minds.\" - Dan Brown",
-    "\"Actions are the seeds of fate. Deeds grow into destiny.\" - Harry S. Truman",
-    "\"Never trust he who trusts everyone.\" - Carlos Ruiz Zafon",
-    "\"Always do what is right. It will gratify half of mankind and astound the other.\" - Mark Twain",
-    "\"Trying to define yourself is like trying to bite your own teeth.\" - Alan Watts",
-    "\"The swift wind of compromise is a lot more devastating than the sudden jolt of misfortune. \" - Charles Swindoll",
-    "\"Kindness in words creates confidence. Kindness in thinking creates profoundness. Kindness in giving creates love. \" - Lao Tzu",
-    "\"You will never do anything in this world without courage. It is the greatest quality of the mind next to honor.\" - James Allen",
-    "\"The chief cause of failure and unhappiness is trading what you want most for what you want right now.\" - Zig Ziglar",
-    "\"It is not that we have too little time to do all the things we need to do , it is that we feel the need to do too many things in the time we have.\" - Gary Keller",
-    "\"To do two things at once is to do neither.\" - Publilius Syrus",
-    "\"Even the smallest person can change the course of the future.\" - J.R.R. Tolkien",
-    "\"Worry is a waste of emotional reserve.\" - Ayn Rand",
-    "\"My favorite things in life don't cost any money. It's really clear that the most precious resource we all have is time.\" - Steve Jobs",
-    "\"I live by letting things happen.\" - Dogen",
-    "\"A man who cannot tolerate small misfortunes can never accomplish great things.\" - Chinese Proverb",
-    "\"Awaken. Take heed, do not squander your life.\" - Dogen",
-    "\"What's right is what's left if you do everything else wrong.\" - Robin Williams",
-    "\"You don't get in life what you want; you get in life what you are.\" - Les Brown",
-    "\"Whatever you do,strive to do it so well that no man living and no man dead and no man yet to be born could do it any better.\" - Benjamin Mays",
-    "\"Anger begins with folly, and ends with repentance. \" - Beverly Sills",
-    "\"It is the greatest of all mistakes to do nothing because you can only do little; do something.\" - Sydney Smith",
-    "\"Life is essentially an endless series of problems. The solution to one problem is merely the creation of another.\" - Mark Manson",
-    "\"If I persist long enough I will win.\" - Og Mandino",
-    "\"No great mind has ever existed without a touch of madness.\" - Aristotle",
-    "\"Many of life's failures are people who did not realize how close they were to success when they gave up.\" - Thomas Edison",
-    "\"Fear can hold you prisoner. Hope can set you free.\" - Stephen King",
-    "\"Be Brave and Take Risks: You need to have faith in yourself. Be brave and take risks. You don't have to have it all figured out to move forward.\" - Roy T. Bennett",
-    "\"We have a duty to maintain the light of consciousness to make sure it continues into the future.\" - Elon Musk",
-    "\"Never fear quarrels, but seek hazardous adventures.\" - Alexandre Dumas",
-    "\"Why do you stay in prison when the door is so wide open?\" - Rumi",
-    "\"Each day your life grows a day shorter. Make every move count.\" - Ming-Dao Deng",
-    "\"If you only knew the magnificence of the 3, 6, and 9, then you would have a key to the universe.\" - Nikola Tesla",
-    "\"Go confidently in the direction of your dreams. Live the life you've imagined.\" - Arnold Schwarzenegger",
-    "\"I would rather be hated for who I am than loved for who I'm not.  \" - Wayne Dyer",
-    "\"Presents are made for the pleasure of who gives them, not the merits of who receives them.\" - Carlos Ruiz Zafon",
-    "\"Always seek out the seed of triumph in every adversity.\" - Og Mandino",
-    "\"The greatest discovery of all time is that a person can change their future by merely changing their attitude.\" - Oprah Winfrey",
-    "\"Be the reason someone smiles. Be the reason someone feels loved and believes in the goodness in people.\" - Roy T. Bennett",
-    "\"The fear of death is more to be feared, than death itself.\" - Publilius Syrus",
-    "\"Intelligent men are cruel. Stupid men are monstrously cruel.\" - Jack London",
-    "\"To a mind that is still, the entire universe surrenders.\" - Zhuangzi",
-    "\"When you have to make a choice and don't make it, that is in itself a choice.\" - William James",
-    "\"The wise learn many things from their enemies.\" - Aristophanes",
-    "\"Through discipline comes freedom.\" - Aristotle",
-    "\"All things come into being by conflict of opposites.\" - Heraclitus",
-    "\"Obstacles can't stop you. Problems can't stop you. Most of all, other people can't stop you. Only you can stop you.\" - Jeffrey Gitomer",
-    "\"If you want peace, accept. If you want suffering, expect.\" - Maxime Lagace",
-    "\"A journey of a thousand miles must begin with a single step.\" - Lao Tzu",
-    "\"If you chase two rabbits, you catch none.\" - Confucius",
-    "\"No one ever said life was fair. Just Eventful.\" - Carol Burnett",
-    "\"Study logic and math, because once you've mastered them, you won't fear any book.\" - Naval Ravikant",
-    "\"Alone we can do so little; together we can do so much.\" - Helen Keller",
-    "\"The impossible can always be broken down into possibilities.\" - Unknown",
-    "\"Don't be pushed around by the fears in your mind. Be led by the dreams in your heart.\" - Roy T. Bennett",
-    "\"They say love is the best investment; the more you give, the more you get in return.\" - Audrey Hepburn",
-    "\"The future will be shaped by those willing to commit their minds and their bodies to the task.\" - Robert F. Kennedy",
-    "\"Adventure is worthwhile in itself.\" - Amelia Earhart",
-    "\"Life is not a problem to be solved, but a reality to be experienced.\" - Soren Kierkegaard",
-    "\"Your gift is something that you can do innately better than anything else.\" - Steve Harvey",
-    "\"If you try to micromanage yourself all you're going to do is make yourself miserable.\" - Naval Ravikant",
-    "\"Fools talk, cowards are silent, wise men listen.\" - Carlos Ruiz Zafon",
-    "\"Happiness is the spiritual experience of living every minute with love, grace and gratitude.\" - Denis Waitley",
-    "\"The nearer a man comes to a calm mind, the closer he is to strength.\" - Marcus Aurelius",
-    "\"We all give up great expectations along the way.\" - Carlos Ruiz Zafon",
-    "\"He who violates another's honor loses his own.\" - Publilius Syrus",
-    "\"Many roads lead to the path, but basically there are only two: reason and practice. \" - Bodhidharma",
-    "\"If you truly expect something to be difficult, it will be.\" - Peter A. Cohen",
-    "\"A good stance and posture reflect a proper state of mind.\" - Morihei Ueshiba",
-    "\"There are two kinds of perfect people: those who are dead, and those who have not been born yet.\" - Chinese Proverb",
-    "\"Only those who will risk going too far can possibly find out how far one can go.\" - T.S. Eliot",
-    "\"The only defense against the world is a thorough knowledge of it.\" - John Locke",
-    "\"It's better to have a short life that is full of what you like doing, than a long life spent in a miserable way.\" - Alan Watts",
-    "\"Love your enemies, for they tell you your faults.\" - Benjamin Franklin",
-    "\"Science can flourish only in an atmosphere of free speech. \" - Albert Einstein",
-    "\"Numbing the pain for a while will only make it worse when you finally feel it.\" - Albus Dumbledore",
-    "\"Doubt is an uncomfortable condition, but certainty is a ridiculous one.\" - Voltaire",
-    "\"The big secret in life is that there is no big secret. Whatever your goal, you can get there if you're willing to work.\" - Oprah Winfrey",
-    "\"Your past does not equal your future.\" - Tony Robbins",
-    "\"Love is the energy of life.\" - Robert Browning",
-    "\"A scientific man ought to have no wishes, no affections, - a mere heart of stone.\" - Charles Darwin",
-    "\"Embrace the life you have and stop wishing that you could be someone else.\" - Joyce Meyer",
-    "\"If you want to achieve anything in this world, you have to get used to the idea that not everyone will like you.\" - Simon Sinek",
-    "\"Champions keep playing until they get it right.\" - Billie Jean King",
-    "\"The way to love anything is to realize that it may be lost.\" - Gilbert Chesterton",
-    "\"It is never too late to be what you might have been.\" - George Eliot",
-    "\"If you take responsibility for yourself you will develop a hunger to accomplish your dream.\" - Les Brown",
-    "\"Just try to be the best you can be; never cease trying to be the best you can be. That's in your power.\" - John Wooden",
-    "\"He who is untrue to his own cause cannot command the respect of others.\" - Albert Einstein",
-    "\"The heart that gives, gathers.\" - Lao Tzu",
-    "\"Management is doing things right; leadership is doing the right things.\" - Peter Drucker",
-    "\"The busy man is never wise and the wise man is never busy.\" - Lin Yutang",
-    "\"Most people dabble their way through life, never deciding to master anything in particular.\" - Tony Robbins",
-    "\"Time is a game played beautifully by children.\" - Heraclitus",
-    "\"Faults are yours to fix, not to curse.\" - Colin Powell",
-    "\"Life can be the scariest and most beautiful thing simultaneously.\" - Maxime Lagace",
-    "\"Bloom where you are planted!\" - Mary Engelbreit",
-    "\"I can live without money, but I cannot live without love.\" - Judy Garland",
-    "\"You have to keep breaking your heart until it opens.\" - Rumi",
-    "\"I don't walk away from things that I think are unfinished.\" - Arnold Schwarzenegger",
-    "\"Excellence is not a skill. It is an attitude. \" - Ralph Marston",
-    "\"Attitude drives actions. Actions drive results. Results drive lifestyles.\" - Jim Rohn",
-    "\"Focus on how far you have come in life rather than looking at the accomplishments of others.\" - Lolly Daskal",
-    "\"Don't concern yourself with the money. Be of service. Build. Work. Dream. Create.\" - Earl Nightingale",
-    "\"You're always free to change your mind and choose a different future, or a different past.\" - Richard Bach",
-    "\"You can't make someone feel good about themselves until you feel good about yourself.\" - Robin Sharma",
-    "\"The enemy is a very good teacher.\" - Dalai Lama",
-    "\"A gentleman accepts the responsibility of his actions and bears the burden of their consequences.\" - William Faulkner",
-    "\"Laws are like cobwebs, which may catch small flies, but let wasps and hornets break through.\" - Jonathan Swift",
-    "\"Live calmly. The time will come when the flowers bloom by themselves.\" - Zen Proverb",
-    "\"Surround yourself only with people who are going to take you higher.\" - Oprah Winfrey",
-    "\"Always focus on the front windshield and not the rear view mirror.\" - Colin Powell",
-    "\"Loss is nothing else but change and change is nature's delight.\" - Marcus Aurelius",
-    "\"If ever you feel like an animal among men, be a lion.\" - Criss Jami",
-    "\"Victims recite problems, leaders provide solutions.\" - Robin Sharma",
-    "\"One always begins to forgive a place as soon as it's left behind.\" - Charles Dickens",
-    "\"Inspire yourself to be great. Being good isn't good enough.\" - Gurbaksh Chahal",
-    "\"The most effective way to do it, is to do it.\" - Amelia Earhart",
-    "\"Not engaging in ignorance is wisdom. \" - Bodhidharma",
-    "\"Every moment of happiness requires a great amount of Ignorance\" - Honore de Balzac",
-    "\"When the fish is caught we pay no more attention to the trap.\" - Huang Po",
-    "\"What do we live for if not to make life less difficult for each other?\" - T.S. Eliot",
-    "\"Beginning today, treat everyone you meet as if they were going to be dead by midnight. Your life will never be the same again.\" - Og Mandino",
-    "\"Action is the foundational key to all success.\" - Pablo Picasso",
-    "\"There are no limitations to the mind except those we acknowledge...\" - Napoleon Hill",
-    "\"Education breeds confidence. Confidence breeds hope. Hope breeds peace.\" - Confucius",
-    "\"People who bite the hand that feeds them usually lick the boot that kicks them.\" - Eric Hoffer",
-    "\"Darkness must pass. A new day will come. And when the sun shines, it will shine out the clearer.\" - J.R.R. Tolkien",
-    "\"A thing must be loved before it is lovable.\" - Gilbert Chesterton",
-    "\"The season of failure is the best time for sowing the seeds of success. \" - Paramahansa Yogananda",
-    "\"Freedom itself demands discomfort.\" - Mark Manson",
-    "\"Good things aren't supposed to just fall into your lap.\" - Audrey Hepburn",
-    "\"Many people spend more time looking at their failures than focusing on their successes.\" - Lolly Daskal",
-    "\"FOCUS - Follow One Course Until Successful\" - Robert Kiyosaki",
-    "\"Where there is anger, there is always pain underneath.\" - Eckhart Tolle",
-    "\"It takes a great deal of bravery to stand up to our enemies, but just as much to stand up to our friends.\" - Albus Dumbledore",
-    "\"I cannot make my days longer so I strive to make them better.\" - Henry David Thoreau",
-    "\"Follow the man who seeks the truth; run from the man who has found it.\" - Vaclav Havel",
-    "\"You can avoid reality, but you cannot avoid the consequences of avoiding reality.\" - Ayn Rand",
-    "\"The less you want, the richer you are. The more you need in order to be happy, the more miserable you'll be.\" - Yanni",
-    "\"Failure is just information and an opportunity to change your course.\" - Oprah Winfrey",
-    "\"Be a good animal, true to your instincts.\" - D. H. Lawrence",
-    "\"Age is a very high price to pay for maturity.\" - William James",
-    "\"Life is a series of choices and all we can do is make them.\" - Kamal Ravikant",
-    "\"An approximate answer to the right question is worth a great deal more than a precise answer to the wrong question.\" - John Tukey",
-    "\"The more perfect a person is on the outside, the more demons they have on the inside.\" - Sigmund Freud",
-    "\"No man is happy unless he believes he is.\" - Publilius Syrus",
-    "\"Some of your greatest lessons come from your darkest moments.\" - Roger Lee",
-    "\"We are willing to believe anything other than the truth.\" - Carlos Ruiz Zafon",
-    "\"Learn from the mistakes of others. You can't live long enough to make them all yourself.\" - Eleanor Roosevelt",
-    "\"Half the world is composed of people who have something to say and can't, and the other half who have nothing to say and keep on saying it.\" - Robert Frost",
-    "\"Singleness of purpose is one of the chief essentials for success in life, no matter what may be one's aim.\" - John D. Rockefeller",
-    "\"Good entrepreneurs don't fail because they stay at it.\" - Naval Ravikant",
-    "\"Life is a balance of holding on and letting go.\" - Rumi",
-    "\"Where ever you are, you are one with the clouds and one with the sun and the stars you see. You are one with everything.\" - Shunryu Suzuki",
-    "\"Stop being tormented by everyone else's reaction to you.\" - Joyce Meyer",
-    "\"Inner peace is when you realize that, in the end, nothing matters.\" - Maxime Lagace",
-    "\"Loving thoughts and actions are clearly beneficial for our physical and mental health.\" - Dalai Lama",
-    "\"Some people find fault like there is a reward for it.  \" - Zig Ziglar",
-    "\"Falling down is not failure. Failure comes when you stay where you have fallen.\" - Socrates",
-    "\"The chief enemy of creativity is good sense.\" - Pablo Picasso",
-    "\"The only thing new in the world is the history you do not know.\" - Harry S. Truman",
-    "\"Nothing ever goes away until it has taught us what we need to know.\" - Pema Chodron",
-    "\"Courage Is a Love Affair with the Unknown\" - Osho",
-    "\"Leadership is solving problems\" - Colin Powell",
-    "\"Doing the best at this moment puts you in the best place for the next moment.\" - Oprah Winfrey",
-    "\"Life is what happens to you while you're busy making other plans.\" - John Lennon",
-    "\"If you don't find the time, if you don't do the work, you don't get the results.\" - Arnold Schwarzenegger",
-    "\"Elegance does not consist in putting on a new dress.\" - Coco Chanel",
-    "\"If you want to be successful, you have to jump, there's no way around it.\" - Steve Harvey",
-    "\"Closing your eyes and listening to silence is self-care.\" - Maxime Lagace",
-    "\"Life has more imagination than we carry in our dreams.\" - Christopher Columbus",
-    "\"Family not only needs to consist of merely those whom we share blood, but also of those whom we'd give blood.\" - Charles Dickens",
-    "\"The best fighter is never angry.\" - Lao Tzu",
-    "\"Life should be touched, not strangled.  \" - Ray Bradbury",
-    "\"What we dwell on is who we become.\" - Oprah Winfrey",
-    "\"Our life isn't how much we can take out, but how much we can put in.  \" - Estee Lauder",
-    "\"When it hurts - observe. Life is trying to teach you something.\" - Anita Krizzan",
-    "\"We can see through others only when we can see through ourselves.\" - Bruce Lee",
-    "\"Be still when you have nothing to say; when genuine passion moves you, say what you've got to say, and say it hot.\" - D. H. Lawrence",
-    "\"We have more possibilities available in each moment than we realize.\" - Thich Nhat Hanh",
-    "\"Wealth consists not in having great possessions, but in having few wants.\" - Epictetus",
-    "\"You must find the place inside yourself where nothing is impossible.\" - Les Brown",
-    "\"It is not titles that honour men, but men that honour titles.\" - Niccolo Machiavelli",
-    "\"At first dreams seem impossible, then improbable, then inevitable.\" - Christopher Reeve",
-    "\"When I let go of what I am, I become what I might be.\" - Lao Tzu",
-    "\"You can start changing our world for the better daily, no matter how small the action.\" - Nelson Mandela",
-    "\"A man has to learn that he cannot command things but that he can command himself; that he cannot coerce the wills of others but that he can mold and master his own will.\" - James Allen",
-    "\"Think and wonder, wonder and think.\" - Dr. Seuss",
-    "\"It is dangerous to be right in matters on which the established authorities are wrong.\" - Voltaire",
-    "\"If you can't explain it simply, you don't understand it well enough.\" - Albert Einstein",
-    "\"Circumstances do not make the man, they reveal him.\" - James Allen",
-    "\"Youth is happy because it has the ability to see beauty. Anyone who keeps the ability to see beauty never grows old. \" - Franz Kafka",
-    "\"The present moment is filled with joy and happiness. If you are attentive, you will see it.\" - Thich Nhat Hanh",
-    "\"He who possesses most must be most afraid of loss.\" - Leonardo da Vinci",
-    "\"Though no one can go back and make a brand new start, anyone can start from now and make a brand new ending.\" - Carl Bard",
-    "\"Life may be full of pain but that's not an excuse to give up.\" - Arnold Schwarzenegger",
-    "\"We need a backbone, not a wishbone.\" - Joyce Meyer",
-    "\"Constantly seek criticism. A well thought out critique of whatever you're doing is as valuable as gold.\" - Elon Musk",
-    "\"You become a worrier by practicing worry. You become free of worry by practicing the opposite.\" - Norman Vincent Peale",
-    "\"If everyone is moving forward together, then success takes care of itself.\" - Henry Ford",
-    "\"Nothing ever happened in the past that can prevent you from being present now; and if the past cannot prevent you from being present now, what power does it have?\" - Eckhart Tolle",
-    "\"Happiness must be grown in one's own garden.\" - Mary Engelbreit",
-    "\"You are a function of what the whole universe is doing in the same way that a wave is a function of what the whole ocean is doing.\" - Alan Watts",
-    "\"If you build the guts to do something, anything, then you better save enough to face the consequences.\" - Criss Jami",
-    "\"The more you praise and celebrate your life, the more there is in life to celebrate. \" - Oprah Winfrey",
-    "\"If you focus on success, you'll have stress. But if you pursue excellence, success will be guaranteed.\" - Deepak Chopra",
-    "\"The sun shines upon good and bad alike.\" - Hans Christian Andersen",
-    "\"I alone cannot change the world, but I can cast a stone across the waters to create many ripples.\" - Mother Teresa",
-    "\"True knowledge exists in knowing that you know nothing.\" - Socrates",
-    "\"If you change the way you look at things, the things you look at change. \" - Wayne Dyer",
-    "\"If there's a definition of freedom, I think it's this: living life on your terms.\" - Kamal Ravikant",
-    "\"Not why the addiction but why the pain.\" - Gabor Mate",
-    "\"Life is like a wheel. Sooner or later, it always comes around to where you started again.\" - Stephen King",
-    "\"Even a hare will bite when it is cornered. \" - Chinese Proverb",
-    "\"Anyone who stops learning is old, whether at twenty or eighty. Anyone who keeps learning stays young. \" - Henry Ford",
-    "\"If you're walking down the right path and you're willing to keep walking, eventually you'll make progress.\" - Barack Obama",
-    "\"A tiny change today brings a dramatically different tomorrow.\" - Richard Bach",
-    "\"Reversing your treatment of the man you have wronged is better than asking his forgiveness.\" - Elbert Hubbard",
-    "\"You cannot swim for new horizons until you have courage to lose sight of the shore.\" - William Faulkner",
-    "\"A hut full of laughter is richer than a palace full of sadness.\" - Zen Proverb",
-    "\"What worries you, masters you.\" - John Locke",
-    "\"Those who bring sunshine into the lives of others cannot keep it from themselves.\" - James Matthew Barrie",
-    "\"Do something to move yourself toward your major goal every day.\" - Brian Tracy",
-    "\"A day wasted on others is not wasted on one's self.\" - Charles Dickens",
-    "\"Sometimes your joy is the source of your smile, but sometimes your smile can be the source of your joy.\" - Thich Nhat Hanh",
-    "\"Don't let the silly little dramas of each day get you down. For you are here to do great things.\" - Ralph Marston",
-    "\"Every day do something that will inch you closer to a better tomorrow.\" - Unknown",
-    "\"We don't stumble accidentally into an amazing life. It takes decision, a commitment to consistently work on ourselves.\" - Kamal Ravikant",
-    "\"Never let your memories be greater than your dreams.\" - Doug Ivester",
-    "\"Don't learn to do, but learn in doing.\" - Samuel Butler",
-    "\"Cherish forever what makes you unique, cuz you're really a yawn if it goes.\" - Bette Midler",
-    "\"When we strive to become better than we are, everything around us becomes better, too.\" - Paulo Coelho",
-    "\"Your heart is full of fertile seeds, waiting to sprout.\" - Morihei Ueshiba",
-    "\"People who avoid failure also avoid success.\" - Robert Kiyosaki",
-    "\"You can, you should, and if you're brave enough to start, you will.\" - Stephen King",
-    "\"Once a decision was made, I didn't worry about it afterward.\" - Harry S. Truman",
-    "\"The trouble with most of us is that we'd rather be ruined by praise than saved by criticism.\" - Norman Vincent Peale",
-    "\"The only reason to be alive is to enjoy it.\" - Rita Mae Brown",
-    "\"Death smiles at us all. All we can do is smile back.\" - Marcus Aurelius",
-    "\"Wherever you are, and whatever you do, be in love.\" - Rumi",
-    "\"People seldom do what they believe in. They do what is convenient, then repent.\" - Bob Dylan",
-    "\"Insanity: doing the same thing over and over again and expecting different results.\" - Albert Einstein",
-    "\"If you focus on results and finding shortcuts, you'll get impatient. If you focus on the process and doing the right thing, you'll be unstoppable.\" - Maxime Lagace",
-    "\"Learn from the rejection and turn it into an opportunity!\" - Mary Engelbreit",
-    "\"Time heals what reason cannot.  \" - Seneca",
-    "\"The degree of responsibility you take for your life determines how much change you can create in it.\" - Celestine Chua",
-    "\"Hope itself is like a star- not to be seen in the sunshine of prosperity, and only to be discovered in the night of adversity.\" - Charles Spurgeon",
-    "\"So we beat on, boats against the current, borne back ceaselessly into the past.\" - F. Scott Fitzgerald",
-    "\"The longer we dwell on our misfortunes, the greater is their power to harm us.\" - Voltaire",
-    "\"Remember the entrance to the sanctuary is inside you.\" - Rumi",
-    "\"Who wishes to fight must first count the cost.\" - Sun Tzu",
-    "\"No one can confidently say that he will still be living tomorrow.\" - Euripides",
-    "\"Lack of direction, not lack of time, is the problem. We all have twenty-four hour days.\" - Zig Ziglar",
-    "\"Every great change is preceded by chaos.\" - Deepak Chopra",
-    "\"The more you are motivated by Love, The more Fearless & Free your action will be.\" - Dalai Lama",
-    "\"No one has ever become poor by giving.\" - Anne Frank",
-    "\"If you want others to be happy, practice compassion. If you want to be happy, practice compassion.\" - Dalai Lama",
-    "\"Get busy living, or get busy dying.\" - Stephen King",
-    "\"To be alive - is Power.\" - Emily Dickinson",
-    "\"You get in life what you have the courage to ask for.\" - Oprah Winfrey",
-    "\"Never let the things you can't do stop you from doing what you can.\" - Ronald Reagan",
-    "\"The man who moved the mountain was the one who began carrying away the smallest stones.\" - Zen Proverb",
-    "\"You win more from losing than winning.\" - Morgan Wootten",
-    "\"Those who realize their folly are not true fools.\" - Zhuangzi",
-    "\"In a world of change, the learners shall inherit the earth, while the learned shall find themselves perfectly suited for a world that no longer exists.\" - Eric Hoffer",
-    "\"Failure means you've now learned another valuable lesson that pushes you one step closer to success.\" - Steve Harvey",
-    "\"Freedom lies in being bold.\" - Robert Frost",
-    "\"Supreme excellence consists of breaking the enemy's resistance without fighting.\" - Sun Tzu",
-    "\"Your dream has to be bigger than your fear.\" - Steve Harvey",
-    "\"Do not fear failure but rather fear not trying.\" - Roy T. Bennett",
-    "\"The truth is simple. If it was complicated, everyone would understand it.\" - Walt Whitman",
-    "\"Always dream and shoot higher than you know you can do. Do not bother just to be better than your contemporaries or predecessors. Try to be better than yourself.\" - William Faulkner",
-    "\"Live in each season as it passes; breathe the air, drink the drink, taste the fruit, and resign yourself to the influence of the earth.\" - Henry David Thoreau",
-    "\"We are always complaining that our days are few, and acting as though there would be no end of them.  \" - Seneca",
-    "\"There is a cosmic law which says that every satisfaction must be paid for with a dissatisfaction.\" - G.I. Gurdjieff",
-    "\"Light a candle instead of cursing the darkness.\" - Eleanor Roosevelt",
-    "\"Here and now...breathe and relax...in battle and in life.\" - Dan Millman",
-    "\"I have lost friends, some by death...others by sheer inability to cross the street.\" - Virginia Woolf",
-    "\"Intelligence, imagination, and knowledge are essential resources, but only effectiveness converts them into results.\" - Peter Drucker",
-    "\"Hope is not a strategy. Luck is not a factor. Fear is not an option.\" - James Cameron",
-    "\"All problems are illusions of the mind.\" - Eckhart Tolle",
-    "\"No problem can be solved from the same level of consciousness that created it.\" - Albert Einstein",
-    "\"The mirror is a worthless invention. The only way to truly see yourself is in the reflection of someone elses. eyes.\" - Voltaire",
-    "\"Life is a traveling to the edge of knowledge, then a leap taken.\" - D. H. Lawrence",
-    "\"The chief danger in life is that you may take too many precautions.\" - Alfred Adler",
-    "\"Numbing the pain for a while will make it worse when you finally feel it.\" - Albus Dumbledore",
-    "\"Courage is the price that life exacts for granting peace.\" - Amelia Earhart",
-    "\"If you have to be in a soap opera try not to be cast in the worst role.\" - Judy Garland",
-    "\"Even in the grave, all is not lost.\" - Edgar Allan Poe",
-    "\"The secret of getting started is breaking your complex, overwhelming tasks into small manageable tasks, and then starting on the first one.\" - Mark Twain",
-    "\"The best way to predict the future is to create it.\" - Abraham Lincoln",
-    "\"Words are like Leaves; and where they most abound, Much Fruit of Sense beneath is rarely found.\" - Alexander Pope",
-    "\"Darkness cannot drive out darkness; only light can do that. Hate cannot drive out hate; only love can do that.\" - Martin Luther King, Jr.",
-    "\"The source of a true smile is an awakened mind.\" - Thich Nhat Hanh",
-    "\"Believe in yourself! Have faith in your abilities! Without a humble but reasonable confidence in your own powers you cannot be successful or happy.\" - Norman Vincent Peale",
-    "\"Where does a thought go when it's forgotten?\" - Sigmund Freud",
-    "\"By perseverance the snail reached the ark.\" - Charles Spurgeon",
-    "\"It is easier to fight for one's principles than to live up to them. \" - Alfred Adler",
-    "\"Only I can change my life. No one can do it for me.\" - Carol Burnett",
-    "\"Some men see things as they are and ask why. Others dream things that never were and ask why not.\" - George Bernard Shaw",
-    "\"Receive without complaint, Work with fate.\" - Ming-Dao Deng",
-    "\"Being a good example is the best form of service.\" - Sathya Sai Baba",
-    "\"Nothing makes a man broad-minded like adversity.\" - Will Rogers",
-    "\"The roots of education are bitter, but the fruit is sweet.\" - Aristotle",
-    "\"The essence of the Way is detachment.\" - Bodhidharma",
-    "\"Be truthful about your emotions, and use your mind and emotions in your favor, not against yourself.\" - Robert Kiyosaki",
-    "\"Hold yourself responsible for a higher standard than anybody else expects of you.\" - Henry Ward Beecher",
-    "\"When you believe in a thing, believe in it all the way, implicitly and unquestionable.\" - Walt Disney",
-    "\"Why compare yourself with others? No one in the entire world can do a better job of being you than you.\" - Unknown",
-    "\"Do not be awe struck by other people and try to copy them. Nobody can be you as efficiently as you can.\" - Norman Vincent Peale",
-    "\"Your imagination is your preview of life's coming attractions.\" - Albert Einstein",
-    "\"The people who succeed are irrationally passionate about something.\" - Naval Ravikant",
-    "\"Keep your eyes on the stars, but remember to keep your feet on the ground.\" - Theodore Roosevelt",
-    "\"Take care of your body. It's the only place you have to live.\" - Jim Rohn",
-    "\"For changes to be of any true value, they've got to be lasting and consistent.\" - Tony Robbins",
-    "\"From error to error one discovers the entire truth.\" - Sigmund Freud",
-    "\"An empty head is not really empty; it is stuffed with rubbish. Hence the difficulty of forcing anything into an empty head.\" - Eric Hoffer",
-    "\"Boldness makes even the smallest animal dangerous.\" - Robert Greene",
-    "\"If you see a get rich quick scheme, that's someone else trying to get rich off of you.\" - Naval Ravikant",
-    "\"In order to attain the impossible, one must attempt the absurd.\" - Miguel de Cervantes",
-    "\"To seek is to suffer. To seek nothing is bliss.\" - Bodhidharma",
-    "\"Your work is going to fill a large part of your life, and the only way to be truly satisfied is to do what you believe is great work. \" - Steve Jobs",
-    "\"Faith means believing the unbelievable.\" - Gilbert Chesterton",
-    "\"You can do nothing to change the past, and the future will never come exactly as you plan or hope for.\" - Dan Millman",
-    "\"What have you done today to make someone else happy?\" - Deepam Chaterjee",
-    "\"How many times do you have to get hit over the head until you figure out who's hitting you?\" - Harry S. Truman",
-    "\"When you rise in the morning, form a resolution to make the day a happy one for a fellow creature.\" - Sydney Smith",
-    "\"If you're really truthful with yourself, it's a wonderful guidance.  \" - Charlie Chaplin",
-    "\"The healthiest response to life is joy.\" - Deepak Chopra",
-    "\"We must be free not because we claim freedom, but because we practice it.\" - William Faulkner",
-    "\"Nothing can survive without food. Everything we consume acts either to heal us or to poison us.\" - Thich Nhat Hanh",
-    "\"I will not walk backward in life.\" - J.R.R. Tolkien",
-    "\"Don't be afraid to go slowly. Be afraid of stopping.\" - Zen Proverb",
-    "\"Work for something because it is good, not just because it stands a chance to succeed.\" - Vaclav Havel",
-    "\"The fact of the matter is that there will be nothing learned from any challenge in which we don't try our hardest.\" - Josh Waitzkin",
-    "\"Don't seek, don't avoid, don't expect. Take what comes and be happy.\" - Maxime Lagace",
-    "\"One must be deeply aware of the impermanence of the world.\" - Dogen",
-    "\"The only person you should try to be better than is the person you were yesterday.\" - Brian Tracy",
-    "\"My actions are my only true belongings. I cannot escape the consequences of my actions. My actions are the ground upon which I stand.\" - Thich Nhat Hanh",
-    "\"One day, in retrospect, the years of struggle will strike you as the most beautiful.\" - Sigmund Freud",
-    "\"Loyalty and devotion lead to bravery. Bravery leads to the spirit of self-sacrifice. The spirit of self-sacrifice creates trust in the power of love.\" - Morihei Ueshiba",
-    "\"Dream big and dare to fail.\" - Norman Vaughan",
-    "\"Life is ten percent what you experience and ninety percent how you respond to it.\" - Unknown",
-    "\"Nothing can bring you peace but yourself.\" - Dale Carnegie",
-    "\"The meaning of life is to find your gift. The purpose of life is to give it away.\" - Pablo Picasso",
-    "\"Life and death are of supreme importance. Time swiftly passes by and opportunity is lost.\" - Dogen",
-    "\"Our greatest fears lie in anticipation.\" - Honore de Balzac",
-    "\"Expect the best, plan for the worst, and prepare to be surprised. \" - Denis Waitley",
-    "\"Things turn out best for the people who make the best of the way things turn out.\" - John Wooden",
-    "\"Live as if you were to die tomorrow. Learn as if you were to live forever.\" - Mahatma Gandhi",
-    "\"The tragedy of life doesn't lie in not reaching your goal. The tragedy lies in having no goals to reach.\" - Benjamin Mays",
-    "\"Confidence is what you have before you understand the problem.\" - Woody Allen",
-    "\"Forever is composed of now's.\" - Emily Dickinson",
-    "\"What kills the creative force is not age or lack of talent, but our own spirit, our own attitude.\" - Robert Greene",
-    "\"You are never too old to set another goal or to dream a new dream.\" - Les Brown",
-    "\"Showing off is the fool's idea of glory. \" - Bruce Lee",
-    "\"There are no ordinary moments.\" - Dan Millman",
-    "\"All you need is passion. If you have a passion for something, you'll create the talent.\" - Yanni",
-    "\"Happiness is the meaning and the purpose of life, the whole aim and end of human existence.\" - Aristotle",
-    "\"Consider the rights of others before your own feelings, and the feelings of others before your own rights.\" - John Wooden",
-    "\"He who hesitates is a damned fool.\" - Mae West",
-    "\"Whatever we plant in our subconscious mind and nourish with repetition and emotion will one day become reality.\" - Earl Nightingale",
-    "\"Life is just the same as learning to swim. Do not be afraid of making mistakes, for there is no other way of learning how to live!\" - Alfred Adler",
-    "\"The beautiful journey of today can only begin when we learn to let go of yesterday.\" - Steve Maraboli",
-    "\"You are the average of the five people you spend the most time with.\" - Jim Rohn",
-    "\"Success is never final, failure is never fatal. It's courage that counts.\" - John Wooden",
-    "\"Failure is acceptable. Not trying is a whole different ball park.\" - Michael Jordan",
-    "\"The sweetest pleasure arises from difficulties overcome.\" - Publilius Syrus",
-    "\"Knowledge isn't power until it is applied.\" - Dale Carnegie",
-    "\"Peace is not absence of conflict, it is the ability to handle conflict by peaceful means.\" - Ronald Reagan",
-    "\"Do not believe that you will reach your destination without leaving the shore. \" - Chinese Proverb",
-    "\"Every one of us is the sum total of his own thoughts.\" - Earl Nightingale",
-    "\"Everything begins with an idea.\" - Earl Nightingale",
-    "\"Words are clothes that thoughts wear\" - Samuel Butler",
-    "\"The number one cause of failure is the fear of failure.\" - Steve Harvey",
-    "\"There are only two ways to live your life. One is as if nothing is a miracle. The other is as if everything is a miracle.\" - Albert Einstein",
-    "\"The more something threatens your identity, the more you will avoid it.\" - Mark Manson",
-    "\"Learning is a weightless treasure you can always carry easily.\" - Chinese Proverb",
-    "\"Nothing has more strength than dire necessity.\" - Euripides",
-    "\"Empty yourself of everything - let the mind become still.\" - Lao Tzu",
-    "\"Never do to others what you would not like them to do to you. \" - Confucius",
-    "\"Life is ours to be spent, not to be saved. \" - D. H. Lawrence",
-    "\"The more often a stupidity is repeated, the more it gets the appearance of wisdom.\" - Voltaire",
-    "\"Abundance of knowledge does not teach men to be wise.\" - Heraclitus",
-    "\"We must learn to live together as brothers or perish together as fools.\" - Martin Luther King, Jr.",
-    "\"Never feel shame for trying and failing, for he who has never failed is he who has never tried.\" - Og Mandino",
-    "\"Give credit where credit is due: simply said, if you want loyalty and best effort, you must be thoughtful.  \" - Estee Lauder",
-    "\"Convinced myself, I seek not to convince.\" - Edgar Allan Poe",
-    "\"Nothing is easier than fault finding.\" - Og Mandino",
-    "\"A lie can travel half way around the world while the truth is putting on its shoes.\" - Mark Twain",
-    "\"The constant assertion of belief is an indication of fear.\" - Jiddu Krishnamurti",
-    "\"Do not regret the past. Look to the future.\" - Soyen Shaku",
-    "\"The greatest deception men suffer is from their own opinions.\" - Leonardo da Vinci",
-    "\"Nature loves to hide.\" - Heraclitus",
-    "\"The Law of Concentration states that whatever you dwell upon grows. The more you think about something, the more it becomes part of your reality.\" - Brian Tracy",
-    "\"Start each day with a positive thought and a grateful heart.\" - Roy T. Bennett",
-    "\"The path is difficult to ensure worthiness. The lazy look elsewhere, The persevering find riches.\" - Ming-Dao Deng",
-    "\"If they wrote it to make money, don't read it.\" - Naval Ravikant",
-    "\"Preoccupied with a single leaf you won't see the tree.\" - Unknown",
-    "\"Sometimes a change of perspective is all it takes to see the light.\" - Dan Brown",
-    "\"Turn your wounds into wisdom. \" - Oprah Winfrey",
-    "\"Getting lost along your path is a part of finding the path you are meant to be on.\" - Robin Sharma",
-    "\"Too many of us are not living our dreams because we are living our fears.\" - Les Brown",
-    "\"A zen master's life is one continuous mistake.\" - Dogen",
-    "\"It is indeed a radical act of love just to sit down and be quiet for a time by yourself.\" - Jon Kabat-Zinn",
-    "\"Be willing to be a beginner every single morning.\" - Meister Eckhart",
-    "\"People who have goals succeed because they know where they're going. It's that simple.\" - Earl Nightingale",
-    "\"Chop your own wood and it will warm you twice. \" - Henry Ford",
-    "\"Arise, slay thy enemies, enjoy a prosperous kingdom.\" - Bhagavad Gita",
-    "\"Patience is a bitter plant, but its fruit is sweet.\" - Chinese Proverb",
-    "\"Talk to someone about themselves and they'll listen for hours.\" - Dale Carnegie",
-    "\"Fool me once, shame on you; fool me twice, shame on me. \" - Chinese Proverb",
-    "\"You're never too old for anything.\" - Betty White",
-    "\"Identification with our mind causes thought to become compulsive.\" - Eckhart Tolle",
-    "\"Things cannot forever go downward. There are limits to everything—even the cold, and the darkness, and the wind, and the dying.\" - Ming-Dao Deng",
-    "\"To plant a garden is to believe in tomorrow.  \" - Audrey Hepburn",
-    "\"One reason so few of us achieve what we truly want is that we never direct our focus; we never concentrate our power.\" - Tony Robbins",
-    "\"It's just a bad day, not a bad life.\" - Mary Engelbreit",
-    "\"Getting over a painful experience is much like crossing monkey bars. You have to let go at some point in order to move forward.\" - Unknown",
-    "\"Better late than never.\" - Spencer Johnson",
-    "\"No man becomes rich unless he enriches others.\" - Andrew Carnegie",
-    "\"What people say, what people do, and what they say they do are entirely different things. \" - Margaret Mead",
-    "\"Happiness is an effect of doing what you love, not an end goal to be achieved.\" - Celestine Chua",
-    "\"What a book a devil's chaplain might write on the clumsy, wasteful, blundering, low, and horribly cruel work of nature!\" - Charles Darwin",
-    "\"The first rule of handling conflict is don't hang around people who are constantly engaging in conflict.\" - Naval Ravikant",
-    "\"It is difficult to free fools from the chains they revere. \" - Voltaire",
-    "\"Do one thing every day that scares you.\" - Eleanor Roosevelt",
-    "\"One of the keys to happiness is a bad memory.\" - Rita Mae Brown",
-    "\"Belief creates the actual fact.\" - William James",
-    "\"I never dwell on what happened. You can't change it. Move forward.\" - Joan Rivers",
-    "\"We crave for new sensations but soon become indifferent to them. The wonders of yesterday are today common occurrences \" - Nikola Tesla",
-    "\"Everyone must choose one of two pains: The pain of discipline or the pain of regret.\" - Jim Rohn",
-    "\"Only when I fall do I get up again.\" - Vincent van Gogh",
-    "\"I dream my painting and I paint my dream.\" - Vincent van Gogh",
-    "\"You can't go forward and backwards at the same time.\" - Steve Harvey",
-    "\"A leader is best when people barely know he exists, when his work is done, his aim fulfilled, they will say: we did it ourselves.\" - Lao Tzu",
-    "\"I'm not afraid of death, but I'm in no hurry to die.\" - Stephen Hawking",
-    "\"Even if you persuade me, you won't persuade me.\" - Aristophanes",
-    "\"The world is changed by your example, not by your opinion.\" - Paulo Coelho",
-    "\"The fewer the words, the better the prayer.\" - Martin Luther",
-    "\"If you love life, don't waste time, for time is what life is made up of. \" - Bruce Lee",
-    "\"When you reach the top of the mountain, keep climbing. \" - Zen Proverb",
-    "\"Things may come to those who wait, but only the things left by those who hustle.\" - Abraham Lincoln",
-    "\"The strongest and sweetest songs yet remain to be sung.\" - Walt Whitman",
-    "\"A man is great not because he hasn't failed; a man is great because failure hasn't stopped him.\" - Confucius",
-    "\"Truth is the offspring of silence and meditation.\" - Isaac Newton",
-    "\"He will win who knows when to fight and when not to fight.\" - Sun Tzu",
-    "\"You give but little when you give of your possessions. It is when you give of yourself that you truly give.\" - Kahlil Gibran",
-    "\"A fear of weakness only strengthens weakness.\" - Criss Jami",
-    "\"If I can laugh, I can live.\" - Christopher Reeve",
-    "\"The most wasted day of all is that on which we have not laughed.\" - Nicolas Chamfort",
-    "\"You already have every characteristic necessary for success if you recognize, claim, develop and use them.\" - Zig Ziglar",
-    "\"One never does wrong by doing right.\" - Norman Vincent Peale",
-    "\"Those who are possessed by nothing possess everything.\" - Morihei Ueshiba",
-    "\"Men go to far greater lengths to avoid what they fear than to obtain what they desire.\" - Dan Brown",
-    "\"On the other side of the clouds is a bright blue sky.\" - Unknown",
-    "\"Your setback is just a setup for a comeback.\" - Steve Harvey",
-    "\"All power is from within and therefore under our control.\" - Robert Collier",
-    "\"Change is inevitable but personal growth is a choice.\" - Bob Proctor",
-    "\"When reading, only read. When eating, only eat. When thinking, only think.\" - Seungsahn",
-    "\"Never allow a person to tell you no who doesn't have the power to say yes.\" - Eleanor Roosevelt",
-    "\"Your greatest asset is your earning ability. Your greatest resource is your time.\" - Brian Tracy",
-    "\"One must be poor to know the luxury of giving.\" - George Eliot",
-    "\"Everyone has a sense of humor. If you don't laugh at jokes, you probably laugh at opinions.\" - Criss Jami",
-    "\"Life is much more successfully looked at from a single window.\" - F. Scott Fitzgerald",
-    "\"You can't tell big dreams to small minded people.\" - Steve Harvey",
-    "\"It doesn't matter where you are coming from. All that matters is where you are going. \" - Brian Tracy",
-    "\"If the plan doesn't work, change the plan, but never the goal.\" - Unknown",
-    "\"People with clear, written goals, accomplish far more in a shorter period of time than people without them could ever imagine.\" - Brian Tracy",
-    "\"You cannot push anyone up a ladder unless he is willing to climb a little himself.\" - Andrew Carnegie",
-    "\"The price of inaction is far greater than the cost of making a mistake.\" - Meister Eckhart",
-    "\"Either get busy living or get busy dying.\" - Stephen King",
-    "\"If we learn to open our hearts, anyone, including the people who drive us crazy, can be our teacher.\" - Pema Chodron",
-    "\"Older men declare war. But it is youth that must fight and die.\" - Herbert Hoover",
-    "\"The question is not what you look at, but what you see.\" - Henry David Thoreau",
-    "\"The reward of suffering is experience.\" - Harry S. Truman",
-    "\"People don't realize that now is all there ever is; there is no past or future except as memory or anticipation in your mind.\" - Eckhart Tolle",
-    "\"Never lose hope. Storms make people stronger and never last forever.\" - Roy T. Bennett",
-    "\"Anger exceeding limits causes fear and excessive kindness eliminates respect.\" - Euripides",
-    "\"The harder you work, the harder it is to surrender.\" - Vince Lombardi",
-    "\"The biggest room in the world is the room for improvement.\" - Unknown",
-    "\"Truth is like the sun. You can shut it out for a time, but it ain't going away.\" - Elvis Presley",
-    "\"Quiet people have the loudest minds.\" - Stephen King",
-    "\"To escape from the world means that one's mind is not concerned with the opinions of the world.\" - Dogen",
-    "\"The future is not a gift. It is an achievement.\" - Robert F. Kennedy",
-    "\"Quality is not an act, it is a habit.\" - Aristotle",
-    "\"In the beginner's mind there are many possibilities, but in the expert's mind there are few.\" - Shunryu Suzuki",
-    "\"I have never let my schooling interfere with my education.\" - Mark Twain",
-    "\"If you are ever the smartest person in the room, you are in the wrong room.\" - C. Sean McGee",
-    "\"Most people spend more time and energy going around problems than in trying to solve them. \" - Henry Ford",
-    "\"Definitions belong to the definers, not the defined.\" - Toni Morrison",
-    "\"Success is an iceberg.\" - Unknown",
-    "\"A clever person turns great troubles into little ones, and little ones into none at all. \" - Chinese Proverb",
-    "\"Correct what you can. Learn from what you can't.\" - Toni Morrison",
-    "\"When we have our body and mind in order, everything else will exist in the right place, in the right way.\" - Shunryu Suzuki",
-    "\"Don't let the past steal your present.\" - Cherralea Morgen",
-    "\"If you do not push the boundaries, you will never know where they are.\" - T.S. Eliot",
-    "\"The heart wants what it wants. There's no logic. \" - Woody Allen",
-    "\"The more you are grateful for what you have the more you will have to be grateful for.  \" - Zig Ziglar",
-    "\"Pain doesn't last. And when it's gone, we have something to show for it. Growth.\" - Kamal Ravikant",
-    "\"New opinions are always suspected, and usually opposed, without any other reason but because they are not common.\" - John Locke",
-    "\"I am always doing that which I can not do, in order that I may learn how to do it.\" - Pablo Picasso",
-    "\"Stop comparing yourself to others and focus on your own improvement.\" - Lolly Daskal",
-    "\"Winning isn't always championships.\" - Michael Jordan",
-    "\"Life must be a constant education; one must learn everything, from speaking to dying.\" - Gustave Flaubert",
-    "\"Being wrong opens us up to the possibility of change.\" - Mark Manson",
-    "\"A good system shortens the road to the goal. \" - Orison Swett Marden",
-    "\"Failure is simply the opportunity to begin again, this time more intelligently.\" - Henry Ford",
-    "\"If you can't, you must. If you must, you can.\" - Tony Robbins",
-    "\"It ain't where you start in life, it's where you end up, and what you did along the way.\" - Colin Powell",
-    "\"The most difficult thing is the decision to act, the rest is merely tenacity. The fears are paper tigers.\" - Amelia Earhart",
-    "\"The scariest monsters are the ones that lurk within our souls.\" - Edgar Allan Poe",
-    "\"Intelligence is the ability to adapt to change.\" - Stephen Hawking",
-    "\"Sad thing is, you can still love someone and be wrong for them.\" - Elvis Presley",
-    "\"As you love yourself, life loves you back.\" - Kamal Ravikant",
-    "\"Instead of thinking outside the box, get rid of the box.\" - Deepak Chopra",
-    "\"To judge a man by his weakest link or deed is like judging the power of the ocean by one wave.\" - Elvis Presley",
-    "\"All human wisdom is contained in these two words - Wait and Hope\" - Alexandre Dumas",
-    "\"The secret of success lies not in doing your own work, but in recognizing the right man to do it.\" - Andrew Carnegie",
-    "\"People should pursue what they're passionate about. That will make them happier than pretty much anything else.\" - Elon Musk",
-    "\"Be brave. Take risks. Nothing can substitute experience.\" - Paulo Coelho",
-    "\"Not all readers are leaders, but all leaders are readers.\" - Harry S. Truman",
-    "\"We cannot change anything unless we accept it.\" - Carl Jung",
-    "\"The more one judges, the less one loves.\" - Honore de Balzac",
-    "\"If we know how to adapt, we end up being superior.\" - Ming-Dao Deng",
-    "\"People rarely succeed unless they have fun in what they are doing.\" - Dale Carnegie",
-    "\"The danger of an adventure is worth a thousand days of ease and comfort.\" - Paulo Coelho",
-    "\"You can calculate the worth of a man by the number of his enemies.\" - Gustave Flaubert",
-    "\"The primary cause of unhappiness is never the situation but your thoughts about it.\" - Eckhart Tolle",
-    "\"Everything that is made beautiful and fair and lovely is made for the eye of one who sees.\" - Rumi",
-    "\"Choose a job you love, and you will never have to work a day in your life. \" - Confucius",
-    "\"Truth is such a rare thing, it is delighted to tell it.\" - Emily Dickinson",
-    "\"When you know what you want, and want it bad enough, you will find a way to get it.\" - Jim Rohn",
-    "\"You cannot control the behavior of others, but you can always choose how you respond to it.\" - Roy T. Bennett",
-    "\"An inventor is a man who asks Why? of the universe and lets nothing stand between the answer and his mind.\" - Ayn Rand",
-    "\"It's the little things done consistently over time, straight from your heart, that have the greatest impact.\" - Unknown",
-    "\"What keeps life fascinating is the constant creativity of the soul.\" - Deepak Chopra",
-    "\"Everyday you can take a tiny step in the right direction.\" - Unknown",
-    "\"A person without a sense of humor is like a wagon without springs, jolted by every pebble in the road.\" - Henry Ward Beecher",
-    "\"This is the most simple and basic component of life: our struggles determine our successes.\" - Mark Manson",
-    "\"He who leaves the game wins it.\" - Nicolas Chamfort",
-    "\"The only thing we have to fear is fear itself.\" - Franklin D. Roosevelt",
-    "\"Don't wish it were easier, wish you were better.\" - Jim Rohn",
-    "\"Man suffers only because he takes seriously what the gods made for fun.\" - Alan Watts",
-    "\"To be heroic is to be courageous enough to die for something; to be inspirational is to be crazy enough to live a little.\" - Criss Jami",
-    "\"Failure is the key to success; each mistake teaches us something.\" - Morihei Ueshiba",
-    "\"The only lost cause is one we give up on before we enter the struggle.\" - Vaclav Havel",
-    "\"Lying can never save us from another lie.\" - Vaclav Havel",
-    "\"The saddest aspect of life is that there is no one on earth whose happiness is such that he won't sometimes wish he were dead rather than alive.\" - Herodotus",
-    "\"The only way it gets better for you is when you get better. Better is not something you wish, it's something you become.\" - Jim Rohn",
-    "\"Only when you can be extremely pliable and soft can you be extremely hard and strong.\" - Zen Proverb",
-    "\"Far more crucial than what we know or do not know is what we do not want to know.\" - Eric Hoffer",
-    "\"Your only obligation in any lifetime is to be true to yourself.\" - Richard Bach",
-    "\"Whether you think you can or think you can't – you are right.\" - Henry Ford",
-    "\"There is no way to happiness - happiness is the way.\" - Thich Nhat Hanh",
-    "\"Think of yourself as dead. you have lived your life. Now, take what's left, and live it properly.\" - Marcus Aurelius",
-    "\"Be yourself; everyone else is already taken \" - Oscar Wilde",
-    "\"No matter what happens, always be yourself.\" - Dale Carnegie",
-    "\"The prettiest eyes have cried the most.\" - Unknown",
-    "\"By failing to prepare, you are preparing to fail.\" - Benjamin Franklin",
-    "\"Fools resist. The wise embrace.\" - Maxime Lagace",
-    "\"There is no limit to the amount of good you can do if you don't care who gets the credit.\" - Ronald Reagan",
-    "\"At the center of your being you have the answer; you know who you are and you know what you want.\" - Lao Tzu",
-    "\"We don't stop playing because we grow old; we grow old because we stop playing.\" - George Bernard Shaw",
-    "\"If we will be quiet and ready enough, we shall find compensation in every disappointment.\" - Henry David Thoreau",
-    "\"A tiger doesn't lose sleep over the opinion of sheep.\" - Shahir Zag",
-    "\"Passion creates, addiction consumes.\" - Gabor Mate",
-    "\"The greatest mistake you can make in life is to be continually fearing you will make one.\" - Elbert Hubbard",
-    "\"Evil is whatever distracts. \" - Franz Kafka",
-    "\"The world would go on even without you. Don't take yourself so seriously.\" - Norman Vincent Peale",
-    "\"The older you get, the more you understand how your conscience works.\" - Criss Jami",
-    "\"The truth... It is a beautiful and terrible thing, and should therefore be treated with great caution.\" - Albus Dumbledore",
-    "\"When you realize you've made a mistake, take immediate steps to correct it.\" - Dalai Lama",
-    "\"You can easily judge the character of a man by how he treats those who can do nothing for him.\" - Simon Sinek",
-    "\"It is passion that makes man live; wisdom makes one only last.\" - Nicolas Chamfort",
-    "\"Storms don't last forever.\" - Unknown",
-    "\"Live, travel, adventure, bless, and don't be sorry.  \" - Jack Kerouac",
-    "\"If fear is too strong, the genius is suppressed.\" - Robert Kiyosaki",
-    "\"All know the way; few actually walk it. \" - Bodhidharma",
-    "\"All that a man achieves and all that he fails to achieve is the direct result of his own thoughts.\" - James Allen",
-    "\"Honor is the foundation of courage.\" - Amelia Earhart",
-    "\"The more you give, the more comes back to you.\" - Napoleon Hill",
-    "\"Remain calm. Be kind.\" - Colin Powell",
-    "\"When uncomfortable, my instinct is not to avoid the discomfort but to become at peace with it.\" - Josh Waitzkin",
-    "\"Happiness is the absence of the striving for happiness.\" - Zhuangzi",
-    "\"How you think is as important as as what you think.\" - Unknown",
-    "\"Be - don't try to become \" - Osho",
-    "\"Your task is not to seek for love, but merely to seek and find all the barriers within yourself that you have built against it.\" - Rumi",
-    "\"There's no scarcity of opportunity to make a living at what you love. There is only a scarcity of resolve to make it happen.\" - Wayne Dyer",
-    "\"It doesn't matter where you are, you are nowhere compared to where you can go.\" - Bob Proctor",
-    "\"The difference between winning and losing is most often not quitting.\" - Walt Disney",
-    "\"Life is really simple, but men insist on making it complicated. \" - Confucius",
-    "\"Do not look for approval except for the consciousness of doing your best.\" - Andrew Carnegie",
-    "\"If you pray for rain, be prepared to deal with some mud.\" - Mary Engelbreit",
-    "\"If life were predictable it would cease to be life, and be without flavor.\" - Eleanor Roosevelt",
-    "\"Nothing is permanent in this wicked world - not even our troubles.\" - Charlie Chaplin",
-    "\"What you seek is seeking you.\" - Rumi",
-    "\"Don't be pushed by your problems; be led by your dreams.\" - Unknown",
-    "\"The big thing is to make a winning effort. I'm not obsessed with wins.\" - Morgan Wootten",
-    "\"So long as we are being remembered, we remain alive.\" - Carlos Ruiz Zafon",
-    "\"You are what you do, not what you say you'll do.\" - Carl Jung",
-    "\"Every defeat, every heartbreak every loss, contains its own seed, its own lesson on how to improve your performance the next time.\" - Og Mandino",
-    "\"Confidence is not a guarantee of success, but a pattern of thinking that will improve your likelihood of success, a tenacious search for ways to make things work.\" - John Eliot",
-    "\"Success is like reaching an important birthday and finding you're exactly the same.\" - Audrey Hepburn",
-    "\"A poet should be so crafty with words that he is envied even for his pains.\" - Criss Jami",
-    "\"Nothing is more creative...nor destructive...than a brilliant mind with a purpose.\" - Dan Brown",
-    "\"It's not what we do once in a while that shapes our lives, but what we do consistently.\" - Tony Robbins",
-    "\"Change is hardest at the beginning, messiest in the middle and best at the end.\" - Robin Sharma",
-    "\"There is no limitations except those we create for ourselves.\" - Lolly Daskal",
-    "\"Tis not too late to seek a newer world.\" - Heraclitus",
-    "\"Don't let anyone define you. You define yourself.\" - Billie Jean King",
-    "\"The only way round is through.\" - Robert Frost",
-    "\"Contemplation often makes life miserable.  We should act more, think less, and stop watching ourselves live.\" - Nicolas Chamfort",
-    "\"All happiness depends on courage and work.\" - Honore de Balzac",
-    "\"Formal education will make you a living, self-education will make you a fortune.\" - Jim Rohn",
-    "\"Anything becomes interesting if you look at it long enough..\" - Gustave Flaubert",
-    "\"A Hero is an ordinary individual who finds the strength to persevere and endure in spite of overwhelming obstacles.\" - Christopher Reeve",
-    "\"No amount of regretting can change the past, and no amount of worrying can change the future.\" - Roy T. Bennett",
-    "\"The quality of your commitments will determine the course of your life.\" - Ralph Marston",
-    "\"Optimism, when applied to your life, develops strength and peace within you.\" - Norman Vincent Peale",
-    "\"It's necessary to get the losers out of your life if you want to live your dream.\" - Les Brown",
-    "\"The power to make and break habits and learning how to do that is really important.\" - Naval Ravikant",
-    "\"Every adversity, every failure, every heartbreak, carries with it the seed of an equal or greater benefit.\" - Napoleon Hill",
-    "\"Let us rather run the risk of wearing out than rusting out.\" - Theodore Roosevelt",
-    "\"Don't let what you cannot do interfere with what you can do.\" - John Wooden",
-    "\"One of the advantages of being disorganized is that one is always having surprising discoveries.\" - A.A. Milne",
-    "\"Live simply, expect little, give much.\" - Norman Vincent Peale",
-    "\"Those who are easily shocked should be shocked more often.\" - Mae West",
-    "\"You are not one person, but three: The one you think you are; The one others think you are; The one you really are.\" - Sathya Sai Baba",
-    "\"What are we doing here, that is the question.\" - Samuel Beckett",
-    "\"Sometimes success isn't about making the right decision, it's more about making some decision.\" - Robin Sharma",
-    "\"It does not do to dwell on dreams and forget to live, remember that.\" - Albus Dumbledore",
-    "\"Accept responsibility for your life. Know that it is you who will get you where you want to go, no one else.\" - Les Brown",
-    "\"Beware of false knowledge, it is more dangerous than ignorance.\" - George Bernard Shaw",
-    "\"Whoever is happy will make others happy too.\" - Anne Frank",
-    "\"Time and health are two precious assets that we don't recognize and appreciate until they have been depleted. \" - Denis Waitley",
-    "\"You must learn to let go. Release the stress. You were never in control anyway.\" - Steve Maraboli",
-    "\"Trust is the foundation of great leadership.\" - Lolly Daskal",
-    "\"If I cannot do great things. I can do small things in a great way.\" - Martin Luther King, Jr.",
-    "\"Learn the rules like a pro, so you can break them like an artist.\" - Pablo Picasso",
-    "\"Life has no limitations except the ones you make.\" - Les Brown",
-    "\"Your vision will become clear only when you can look into your own heart.\" - Lolly Daskal",
-    "\"The key to success is action.\" - Brian Tracy",
-    "\"If you think adventure is dangerous, try routine; it is lethal.\" - Paulo Coelho",
-    "\"Never regret your past. Rather, embrace it as the teacher that it is.\" - Robin Sharma",
-    "\"Great acts are made up of small deeds.\" - Lao Tzu",
-    "\"Tragedy is a tool for the living to gain wisdom, not a guide by which to live.\" - Robert F. Kennedy",
-    "\"Everything has beauty but not everyone sees it.\" - Confucius",
-    "\"You need a plan to build a house. To build a life, it is even more important to have a plan or goal.\" - Zig Ziglar",
-    "\"Do not wait for leaders; do it alone, person to person.\" - Mother Teresa",
-    "\"All know that the drop merges into the ocean, but few know that the ocean merges into the drop.\" - Kabir",
-    "\"Desire is suffering.\" - Naval Ravikant",
-    "\"It isn't what you have or who you are or where you are or what you are doing that makes you happy or unhappy. It is what you think about it.\" - Dale Carnegie",
-    "\"To achieve, you need thought. You have to know what you are doing and that's real power.\" - Ayn Rand",
-    "\"We will act consistently with our view of who we truly are, whether that view is accurate or not.\" - Tony Robbins",
-    "\"Do not follow the ideas of others, but learn to listen to the voice within yourself.\" - Dogen",
-    "\"For the things we have to learn before we can do them, we learn by doing them.\" - Aristotle",
-    "\"A memory is a beautiful thing, it's almost a desire that you miss.\" - Gustave Flaubert",
-    "\"He that can have patience can have what he will.\" - Benjamin Franklin",
-    "\"We must not cease from exploration. And the end of all our exploring will be to arrive where we began and to know the place for the first time.\" - T.S. Eliot",
-    "\"A hopeless man is a very desperate and dangerous man, almost a dead man.\" - Robert F. Kennedy",
-    "\"Let not your mind run on what you lack as much as on what you have already.\" - Marcus Aurelius",
-    "\"How people treat you is their karma; how you react is yours. \" - Wayne Dyer",
-    "\"There are dark shadows on the earth, but its lights are stronger in the contrast.\" - Charles Dickens",
-    "\"Remember to look up at the stars and not down at your feet.\" - Stephen Hawking",
-    "\"The journey, not the destination matters.\" - T.S. Eliot",
-    "\"Just do the best you can. No one can do more than that.\" - John Wooden",
-    "\"A different result requires doing something different.\" - Gary Keller",
-    "\"Success is the sum of small efforts, repeated day in and day out.\" - Robert Collier",
-    "\"When obstacles arise, you change your direction to reach your goal; you do not change your decision to get there.\" - Zig Ziglar",
-    "\"Whatever is begun in anger, ends in shame.\" - Benjamin Franklin",
-    "\"Take responsibility of your own happiness, never put it in other people's hands.\" - Roy T. Bennett",
-    "\"Memory is the scribe of the soul.\" - Aristotle",
-    "\"Every man desires to live long, but no man wishes to be old.\" - Jonathan Swift",
-    "\"Reality is nothing but a collective hunch.\" - Lily Tomlin",
-    "\"Be royal in your own fashion: act like a king to be treated like one.\" - Robert Greene",
-    "\"It doesn't matter what you do, only how well you do it.\" - Dan Millman",
-    "\"Never to suffer would never to have been blessed.\" - Edgar Allan Poe",
-    "\"The world makes way for the man who knows where he is going.\" - Ralph Waldo Emerson",
-    "\"Happiness is a quality of the soul...not a function of one's material circumstances.\" - Aristotle",
-    "\"We know nothing of tomorrow, our business is to be good and happy today.\" - Sydney Smith",
-    "\"The most important things are the hardest to say.\" - Stephen King",
-    "\"A moving door hinge never corrodes. Flowing water never grows stagnant.\" - Ming-Dao Deng",
-    "\"The fastest way to change is to laugh at your own folly.\" - Spencer Johnson",
-    "\"When angry count to ten before you speak. If very angry, count to one hundred.\" - Thomas Jefferson",
-    "\"Live and act within the limit of your knowledge and keep expanding it to the limit of your life.\" - Ayn Rand",
-    "\"The best way to not feel hopeless is to get up and do something.\" - Barack Obama",
-    "\"What would life be if we had no courage to attempt anything? \" - Vincent van Gogh",
-    "\"You don't have to move mountains. You'll change the world simply by being a warm, kind-hearted human being.\" - Anita Krizzan",
-    "\"Goals are the fuel in the furnace of achievement.\" - Brian Tracy",
-    "\"To be fully alive, fully human, and completely awake is to be continually thrown out of the nest.\" - Pema Chodron",
-    "\"I discovered a long time ago that if I helped enough people get what they wanted, I would always get what I wanted and I would never have to worry.\" - Tony Robbins",
-    "\"Speak when you are angry - and you'll make the best speech you'll ever regret.\" - Laurence J. Peter",
-    "\"It is the power of thought that gives man power over nature.\" - Hans Christian Andersen",
-    "\"Everyone has the right to make his own decisions, but none has the right to force his decision on others.\" - Ayn Rand",
-    "\"Don't live the same year 75 times and call it a life.\" - Robin Sharma",
-    "\"Love never keeps a man from pursuing his destiny.\" - Paulo Coelho",
-    "\"Never doubt that a small group of thoughtful, committed citizens can change the world; indeed, it's the only thing that ever has.\" - Margaret Mead",
-    "\"Never allow someone to be your priority while allowing yourself to be their option.\" - Mark Twain",
-    "\"Be where your enemy is not.\" - Sun Tzu",
-    "\"Health is the greatest of all possessions.\" - Jonathan Swift",
-    "\"What the mind can conceive, it can achieve.\" - Napoleon Hill",
-    "\"Only when one allows themself the right to die, do they then give yourself the permission to live.\" - C. Sean McGee",
-    "\"The only time you run out of chances is when you stop taking them.\" - Unknown",
-    "\"My powers are ordinary. Only my application brings me success.\" - Isaac Newton",
-    "\"Far and away the best prize that life has to offer is the chance to work hard at work worth doing.\" - Theodore Roosevelt",
-    "\"Victory is always possible for the person who refuses to stop fighting.\" - Napoleon Hill",
-    "\"Opportunity comes when you continually push forward.\" - Sonia Ricotti",
-    "\"Your mind will make you rich or poor, depending on the use you put to it.\" - Brian Tracy",
-    "\"While some of us act without thinking, too many of us think without acting.\" - Dan Millman",
-    "\"There is no value in anything until it is finished. \" - Genghis Khan",
-    "\"The primary point of this existence is to live, and all living things move and grow.\" - Ming-Dao Deng",
-    "\"We are all here for some special reason.\" - Robin Sharma",
-    "\"Sometimes good things fall apart so better things can fall together.\" - Marilyn Monroe",
-    "\"Attitude, not aptitude, determines altitude.  \" - Zig Ziglar",
-    "\"Whatever you believe with feeling becomes your reality. \" - Brian Tracy",
-    "\"Argue for your limitations and sure enough, they're yours.\" - Richard Bach",
-    "\"None but ourselves can free our minds.\" - Bob Marley",
-    "\"First say to yourself what you would be; and then do what you have to do.\" - Epictetus",
-    "\"Never stand begging for that which you have the power to earn.\" - Miguel de Cervantes",
-    "\"Don't be afraid to give up the good to go for the great.\" - John D. Rockefeller",
-    "\"Go confidently in the direction of your dreams! Live the life you've imagined.\" - Henry David Thoreau",
-    "\"Repetition does not transform a lie into a truth.\" - Franklin D. Roosevelt",
-    "\"If you throw away all thoughts of attainment, you will then come to see the real purpose of your quest.\" - Seungsahn",
-    "\"You cannot find peace avoiding life.\" - Virginia Woolf",
-    "\"Difficult roads often lead to beautiful destinations.\" - Unknown",
-    "\"Before anything else, preparation is the key to success.\" - Alexander Graham Bell",
-    "\"When you lose your desire for things that do not matter, you will be free.\" - Morihei Ueshiba",
-    "\"Silence is sometimes the best answer.\" - Dalai Lama",
-    "\"You are never too old to become younger.\" - Mae West",
-    "\"There is no path to Peace. Peace is the path.\" - Dan Millman",
-    "\"Successful people ask better questions, and as a result, they get better answers.\" - Tony Robbins",
-    "\"Don't look back - you're not going that way.\" - Mary Engelbreit",
-    "\"It takes a minute to have a crush on someone, an hour to like someone, and a day to love someone... but it takes a lifetime to forget someone.\" - Kahlil Gibran",
-    "\"Perfectionism is simply putting a limit on your future.\" - John Eliot",
-    "\"Use your smile to change the world but don't let the world change your smile.\" - Unknown",
-    "\"Life is like a beautiful melody, only the lyrics are messed up.\" - Hans Christian Andersen",
-    "\"True wealth is of the heart, not of the purse.\" - Og Mandino",
-    "\"There is nothing in the world so irresistibly contagious as laughter and good humor.\" - Charles Dickens",
-    "\"Every day is lost in which we do not learn something useful. Man has no nobler or more valuable possession than time.\" - Thomas Jefferson",
-    "\"When you reach the end of your rope, tie a knot in it and hang on.\" - Franklin D. Roosevelt",
-    "\"You have the potential for greatness.\" - Steve Harvey",
-    "\"When you know what you want, and you want it bad enough, you will find a way to get it.\" - Jim Rohn",
-    "\"Don't waste your time in anger, regrets, worries, and grudges. Life is too short to be unhappy.\" - Roy T. Bennett",
-    "\"The one who praises you is a thief. The one who criticizes you is your true friend.\" - Seungsahn",
-    "\"Tenderness and kindness are not signs of weakness and despair, but manifestations of strength and resolution.\" - Kahlil Gibran",
-    "\"Dreams are for dreamers. Goals are for achievers.\" - Arnold Schwarzenegger",
-    "\"Genius, in truth, means little more than the faculty of perceiving in an unhabitual way.\" - William James",
-    "\"Everything you've ever wanted is on the other side of fear.\" - George Addair",
-    "\"I was wise enough to never grow up while fooling most people into believing I had. \" - Margaret Mead",
-    "\"You cannot teach a crab to walk straight.\" - Aristophanes",
-    "\"In matters of style, swim with the current; in matters of principle, stand like a rock.\" - Thomas Jefferson",
-    "\"Let my soul smile through my heart and my heart smile through my eyes, that I may scatter rich smiles in sad hearts. \" - Paramahansa Yogananda",
-    "\"The dream is free, but the hustle is sold separately.\" - Steve Harvey",
-    "\"Everybody has talent, but ability takes hard work.\" - Michael Jordan",
-    "\"You never know when a moment and a few sincere words can have an impact on a life.  \" - Zig Ziglar",
-    "\"If you spend too much time thinking about a thing, you'll never get it done. \" - Bruce Lee",
-    "\"Nothing is impossible. The word itself says 'I'm possible!'\" - Audrey Hepburn",
-    "\"Confidence is contagious. So is lack of confidence.\" - Vince Lombardi",
-    "\"There are three classes of people: those who see. Those who see when they are shown. Those who do not see.\" - Leonardo da Vinci",
-    "\"Life would be tragic if it weren't funny.\" - Stephen Hawking",
-    "\"Where your talents and the needs of the world cross, there lies your vocation.\" - Aristotle",
-    "\"Never waste a minute of your precious life thinking about people you don't like.\" - Celestine Chua",
-    "\"If you do not get the chills when you set your goal you're not setting big enough goals.\" - Bob Proctor",
-    "\"Only those who dare to fail greatly can ever achieve greatly.\" - Robert F. Kennedy",
-    "\"Courage is what it takes to stand up and speak. Courage is also what it takes to sit down and listen.\" - Winston Churchill",
-    "\"Your happiness is what truly matters most. Do what you have to do in order to be happy.\" - Brian Tracy",
-    "\"You'll never find rainbows if you're looking down.  \" - Charlie Chaplin",
-    "\"Tomorrow is only found in the calendar of fools.\" - Og Mandino",
-    "\"It is best to begin at the beginning.\" - Amelia Earhart",
-    "\"Have a vision. Be demanding.\" - Colin Powell",
-    "\"Choose to be optimistic, it feels better.\" - Dalai Lama",
-    "\"Humans and prosperity never endure side by side for long.\" - Herodotus",
-    "\"The quality of your life is the quality of your relationships.\" - Tony Robbins",
-    "\"Keep your face to the sunshine and you cannot see the shadows.\" - Helen Keller",
-    "\"If what you're doing is not your passion, you have nothing to lose.\" - Celestine Chua",
-    "\"Fiction is the truth inside the lie.\" - Stephen King",
-    "\"Nothing can dim the light which shines from within.\" - Maya Angelou",
-    "\"The traveler sees what he sees. The tourist sees what he has come to see.\" - Gilbert Chesterton",
-    "\"If you done it, it ain't bragging.\" - Walt Whitman",
-    "\"Make failure your teacher, not your undertaker.  \" - Zig Ziglar",
-    "\"Courage is fear that has said its prayers and decided to go forward anyway.\" - Joyce Meyer",
-    "\"Your business is not to 'get somewhere' - it is to be here.\" - Dan Millman",
-    "\"Pleasure can be supported by an illusion; but happiness rests upon truth. \" - Nicolas Chamfort",
-    "\"The only real security that a man can have in this world is a reserve of knowledge, experience and ability. \" - Henry Ford",
-    "\"Don't ever be afraid to put yourself in a lesser position so that you can learn something from someone who knows more.\" - Steve Harvey",
-    "\"Victorious warriors win first and then go to war, while defeated warriors go to war first and then seek to win.\" - Sun Tzu",
-    "\"Be patient and calm; no one can catch fish in anger.\" - Herbert Hoover",
-    "\"Acknowledging the good that you already have in your life is the foundation for all abundance.\" - Eckhart Tolle",
-    "\"Success on the outside means nothing unless you also have success within.\" - Robin Sharma",
-    "\"Magic is believing in yourself, if you can do that, you can make anything happen.\" - Johann Wolfgang von Goethe",
-    "\"It's your outlook on life that counts.\" - Betty White",
-    "\"Only one thing is ever guaranteed, that is that you will definitely not achieve the goal if you don't take the shot.\" - Wayne Gretzky",
-    "\"If you want to be happy, set a goal that commands your thoughts, liberates your energy, and inspires your hopes.\" - Andrew Carnegie",
-    "\"Mistake is a mistake only if you make it twice.\" - Robin Sharma",
-    "\"If you always do what you've always done, you'll always get what you've always got. \" - Henry Ford",
-    "\"It isn't what you do, but how you do it.\" - John Wooden",
-    "\"I pay attention to every minute of the day.\" - Steve Harvey",
-    "\"When you want to be honored by others, you learn to honor them first.\" - Sathya Sai Baba",
-    "\"Procrastination is attitude's natural assassin. There's nothing so fatiguing as an uncompleted task.\" - William James",
-    "\"Leadership starts at the top.\" - Morgan Wootten",
-    "\"Our greatest weakness lies in giving up. The most certain way to succeed is always to try just one more time.\" - Thomas Edison",
-    "\"It's what you do in the present that will redeem the past and thereby change the future.\" - Paulo Coelho",
-    "\"Believe and you're halfway there.\" - Harry S. Truman",
-    "\"It's not the love you make. It's the love you give. \" - Nikola Tesla",
-    "\"The wise warrior avoids the battle.\" - Sun Tzu",
-    "\"Our view of the world is truly shaped by what we decide to hear.\" - William James",
-    "\"The very essence of instinct is that it's followed independently of reason.\" - Charles Darwin",
-    "\"Darkness is the absence of light.\" - Kamal Ravikant",
-    "\"Exceptional thinkers ignore their critics and go about their business making history.\" - John Eliot",
-    "\"If you're not failing every now and again, it's a sign you're not doing anything very innovative.\" - Woody Allen",
-    "\"Life can be wonderful if you're not afraid of it.\" - Charlie Chaplin",
-    "\"If you don't make things happen then things will happen to you.\" - Robert Collier",
-    "\"Nothing in this world is worth having or worth doing unless it means effort, pain, difficulty.\" - Theodore Roosevelt",
-    "\"Don't give up on your dreams, or your dreams will give up on you.\" - John Wooden",
-    "\"Life is not meant to be easy my child, but take courage: it can be delightful.\" - George Bernard Shaw",
-    "\"When you repeat a mistake, it is not a mistake anymore: it is a decision.\" - Paulo Coelho",
-    "\"The happiness of your life depends upon the quality of your thoughts; therefore guard accordingly.\" - Marcus Aurelius",
-    "\"The biggest challenge after success is shutting up about it.\" - Criss Jami",
-    "\"If you want more luck, take more chances.\" - Brian Tracy",
-    "\"Be careful the environment you choose for it will shape you; be careful the friends you choose for you will become like them.\" - W. Clement Stone",
-    "\"Nothing is particularly hard if you divide it into small jobs. \" - Henry Ford",
-    "\"Natural talent only determines the limits of your athletic potential. It's dedication and a willingness to discipline your life that makes you great.\" - Billie Jean King",
-    "\"The right thing to do and the hard thing to do are usually the same.\" - Steve Maraboli",
-    "\"Leaders think and talk about solutions. Followers think and talk about the problems.\" - Brian Tracy",
-    "\"Success is getting what you want. Happiness is wanting what you get.\" - Andrew Carnegie",
-    "\"If you want to be successful, find someone who has achieved the results you want and copy what they do and you'll achieve the same results.\" - Tony Robbins",
-    "\"As a man thinketh in his heart, so shall he be.\" - James Allen",
-];
+// This function needs to be async for poise to use it.
+#[allow(clippy::unused_async)]
+async fn author_autocomplete(_: poise::Context<'_, (), Error>, partial: &str) -> Vec<String> {
+    content::tag_values(Pool::Quotes, Tag::Author, partial)
+}
+
+// This function needs to be async for poise to use it.
+#[allow(clippy::unused_async)]
+async fn category_autocomplete(_: poise::Context<'_, (), Error>, partial: &str) -> Vec<String> {
+    content::tag_values(Pool::Quotes, Tag::Category, partial)
+}
+
+// This function needs to be async for poise to use it.
+#[allow(clippy::unused_async)]
+async fn language_autocomplete(_: poise::Context<'_, (), Error>, partial: &str) -> Vec<String> {
+    content::tag_values(Pool::Quotes, Tag::Language, partial)
+}
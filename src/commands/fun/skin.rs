@@ -1,33 +1,109 @@
-use std::vec;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
 
-use crate::commands::WARNING_EMOJI;
+use crate::commands::{ERROR_EMBED_COLOR, ERROR_EMOJI, WARNING_EMOJI};
 use crate::Error;
 
 use super::super::EMBED_COLOR;
-use poise::serenity_prelude::{CreateEmbed, CreateEmbedFooter};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use once_cell::sync::Lazy;
+use poise::serenity_prelude::{CreateActionRow, CreateButton, CreateEmbed, CreateEmbedFooter};
 use poise::CreateReply;
-use rand::Rng;
+use reqwest::StatusCode;
 
-#[derive(serde::Deserialize)]
-struct MojangResponse {
+/// How long a lookup is reused before Mojang is asked again.
+const CACHE_TTL: Duration = Duration::from_mins(5);
+
+/// Lookups keyed by the lowercase name or UUID they were made with. Players
+/// that don't exist are cached as `None` so they aren't looked up again and
+/// again either.
+static CACHE: Lazy<Mutex<HashMap<String, CachedLookup>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// When a lookup was made and what it found.
+type CachedLookup = (Instant, Option<Profile>);
+
+#[derive(Debug, Clone)]
+struct Profile {
+    /// Without dashes, like Mojang sends it.
+    uuid: String,
     name: String,
+    skin_url: Option<String>,
+    /// Whether the skin uses the slim (Alex) model.
+    slim: bool,
+    cape_url: Option<String>,
 }
 
-#[inline]
-fn generate_random_text(length: usize) -> String {
-    let mut rng = rand::thread_rng();
-    let mut text = String::new();
-    for _ in 0..length {
-        // choose either a letter or a digit
-        let c = if rng.gen::<bool>() {
-            (b'a' + rng.gen_range(0..26)) as char
-        } else {
-            (b'0' + rng.gen_range(0..10)) as char
-        };
-        text.push(c);
+impl Profile {
+    /// The UUID with dashes, the way the game shows it.
+    fn dashed_uuid(&self) -> String {
+        let uuid = &self.uuid;
+        if uuid.len() != 32 {
+            return uuid.clone();
+        }
+
+        format!(
+            "{}-{}-{}-{}-{}",
+            &uuid[..8],
+            &uuid[8..12],
+            &uuid[12..16],
+            &uuid[16..20],
+            &uuid[20..]
+        )
+    }
+
+    /// Changes whenever the skin does, so Discord doesn't keep showing an old
+    /// render of it.
+    fn skin_version(&self) -> &str {
+        self.skin_url
+            .as_deref()
+            .and_then(|url| url.rsplit('/').next())
+            .unwrap_or("default")
     }
+}
 
-    text
+#[derive(serde::Deserialize)]
+struct NameResponse {
+    id: String,
+}
+
+#[derive(serde::Deserialize)]
+struct ProfileResponse {
+    id: String,
+    name: String,
+    #[serde(default)]
+    properties: Vec<ProfileProperty>,
+}
+
+#[derive(serde::Deserialize)]
+struct ProfileProperty {
+    name: String,
+    value: String,
+}
+
+#[derive(serde::Deserialize)]
+struct TexturesProperty {
+    textures: Textures,
+}
+
+#[derive(serde::Deserialize)]
+struct Textures {
+    #[serde(rename = "SKIN")]
+    skin: Option<Texture>,
+    #[serde(rename = "CAPE")]
+    cape: Option<Texture>,
+}
+
+#[derive(serde::Deserialize)]
+struct Texture {
+    url: String,
+    metadata: Option<TextureMetadata>,
+}
+
+#[derive(serde::Deserialize)]
+struct TextureMetadata {
+    model: Option<String>,
 }
 
 /// Grab someone's Minecraft skin
@@ -38,37 +114,184 @@ pub async fn skin(
 ) -> Result<(), Error> {
     ctx.defer().await?;
 
-    let mut username = username.replace('-', "");
-    let rndm_string = generate_random_text(5);
+    let query = username.trim().replace('-', "");
+    let is_uuid = query.len() == 32 && query.chars().all(|c| c.is_ascii_hexdigit());
+    let is_name = (3..=16).contains(&query.len())
+        && query.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+
+    if !is_uuid && !is_name {
+        return send_error(
+            ctx,
+            &format!(
+                "`{}` isn't a valid Minecraft username or UUID.",
+                username.trim()
+            ),
+        )
+        .await;
+    }
+
+    let Some(profile) = lookup(&query, is_uuid).await? else {
+        return send_error(
+            ctx,
+            &format!("There's no Minecraft player called `{}`.", username.trim()),
+        )
+        .await;
+    };
+
+    let uuid = &profile.uuid;
+    let version = profile.skin_version();
+
+    let embed = CreateEmbed::default()
+        .title(format!("Skin of {}", profile.name))
+        .field("UUID", format!("`{}`", profile.dashed_uuid()), false)
+        .field(
+            "Model",
+            if profile.slim { "Slim" } else { "Classic" },
+            true,
+        )
+        .field(
+            "Cape",
+            if profile.cape_url.is_some() {
+                "Yes"
+            } else {
+                "None"
+            },
+            true,
+        )
+        .image(format!("https://mc-heads.net/body/{uuid}?v={version}"))
+        .thumbnail(format!("https://mc-heads.net/combo/{uuid}?v={version}"))
+        .color(EMBED_COLOR)
+        // Mojang took down the name history API in 2022, so past names can't
+        // be shown anymore.
+        .footer(
+            CreateEmbedFooter::new(
+                "Notice: Skin changes can take a few minutes to show up. Past names aren't available since Mojang removed them.",
+            )
+            .icon_url(WARNING_EMOJI),
+        );
+
+    let mut buttons = Vec::new();
+    if let Some(skin_url) = &profile.skin_url {
+        buttons.push(CreateButton::new_link(skin_url).label("Download skin"));
+    }
+    if let Some(cape_url) = &profile.cape_url {
+        buttons.push(CreateButton::new_link(cape_url).label("Download cape"));
+    }
+    buttons
+        .push(CreateButton::new_link(format!("https://namemc.com/profile/{uuid}")).label("NameMC"));
+
+    ctx.send(
+        CreateReply::default()
+            .embed(embed)
+            .components(vec![CreateActionRow::Buttons(buttons)]),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Gets a player's profile from the cache or from Mojang.
+///
+/// Returns `None` if the player doesn't exist.
+async fn lookup(query: &str, is_uuid: bool) -> Result<Option<Profile>, Error> {
+    let key = query.to_lowercase();
 
-    if username.len() == 32 {
-        let response: MojangResponse =
-            reqwest::get(format!("https://mc-heads.net/minecraft/profile/{username}"))
-                .await?
-                .json()
-                .await?;
+    {
+        let mut cache = CACHE.lock().expect("Skin cache lock was poisoned");
+        cache.retain(|_, (fetched_at, _)| fetched_at.elapsed() < CACHE_TTL);
 
-        username = response.name;
+        if let Some((_, profile)) = cache.get(&key) {
+            return Ok(profile.clone());
+        }
     }
 
+    let profile = fetch_profile(query, is_uuid).await?;
+
+    let mut cache = CACHE.lock().expect("Skin cache lock was poisoned");
+    cache.insert(key, (Instant::now(), profile.clone()));
+    if let Some(profile) = &profile {
+        // Whichever way the player is looked up next, it's already cached.
+        cache.insert(
+            profile.name.to_lowercase(),
+            (Instant::now(), Some(profile.clone())),
+        );
+        cache.insert(
+            profile.uuid.clone(),
+            (Instant::now(), Some(profile.clone())),
+        );
+    }
+    drop(cache);
+
+    Ok(profile)
+}
+
+async fn fetch_profile(query: &str, is_uuid: bool) -> Result<Option<Profile>, Error> {
+    let uuid = if is_uuid {
+        query.to_string()
+    } else {
+        let response = reqwest::get(format!(
+            "https://api.mojang.com/users/profiles/minecraft/{query}"
+        ))
+        .await?;
+
+        // Mojang used to answer with 204 for unknown names and now uses 404.
+        if matches!(
+            response.status(),
+            StatusCode::NOT_FOUND | StatusCode::NO_CONTENT
+        ) {
+            return Ok(None);
+        }
+
+        let response: NameResponse = response.error_for_status()?.json().await?;
+        response.id
+    };
+
+    let response = reqwest::get(format!(
+        "https://sessionserver.mojang.com/session/minecraft/profile/{uuid}"
+    ))
+    .await?;
+
+    if matches!(
+        response.status(),
+        StatusCode::NOT_FOUND | StatusCode::NO_CONTENT | StatusCode::BAD_REQUEST
+    ) {
+        return Ok(None);
+    }
+
+    let response: ProfileResponse = response.error_for_status()?.json().await?;
+
+    let textures = response
+        .properties
+        .iter()
+        .find(|property| property.name == "textures")
+        .map(|property| -> Result<Textures, Error> {
+            let decoded = STANDARD.decode(&property.value)?;
+            Ok(serde_json::from_slice::<TexturesProperty>(&decoded)?.textures)
+        })
+        .transpose()?;
+
+    let (skin, cape) = textures.map_or((None, None), |textures| (textures.skin, textures.cape));
+
+    Ok(Some(Profile {
+        uuid: response.id,
+        name: response.name,
+        slim: skin
+            .as_ref()
+            .and_then(|skin| skin.metadata.as_ref())
+            .and_then(|metadata| metadata.model.as_deref())
+            == Some("slim"),
+        skin_url: skin.map(|skin| skin.url),
+        cape_url: cape.map(|cape| cape.url),
+    }))
+}
+
+async fn send_error(ctx: poise::Context<'_, (), Error>, message: &str) -> Result<(), Error> {
     ctx.send(
         CreateReply::default().embed(
             CreateEmbed::default()
-                .title(format!("Skin of {username}"))
-                .description(format!(
-                    "Download it [here](https://mc-heads.net/download/{username})."
-                ))
-                .image(format!(
-                    "https://mc-heads.net/body/{username}/{rndm_string}"
-                ))
-                .color(EMBED_COLOR)
-                .footer(
-                    CreateEmbedFooter::new("Notice: The skin may take some time to be up-to-date.")
-                        .icon_url(WARNING_EMOJI),
-                )
-                .thumbnail(format!(
-                    "https://mc-heads.net/combo/{username}/{rndm_string}"
-                )),
+                .title("Error")
+                .description(format!("{ERROR_EMOJI} {message}"))
+                .color(ERROR_EMBED_COLOR),
         ),
     )
     .await?;
@@ -0,0 +1,475 @@
+use crate::{
+    commands::{EMBED_COLOR, ERROR_EMBED_COLOR, ERROR_EMOJI},
+    router::{self, ComponentId},
+    Error,
+};
+
+use poise::serenity_prelude::{
+    self as serenity, ComponentInteraction, ComponentInteractionDataKind, CreateActionRow,
+    CreateEmbed, CreateEmbedFooter, CreateInteractionResponse, CreateInteractionResponseMessage,
+    CreateSelectMenu, CreateSelectMenuKind, CreateSelectMenuOption, Message,
+};
+use poise::CreateReply;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// The namespace of the style picker of the "Transform text" context menu.
+pub(crate) const NAMESPACE: &str = "text";
+
+/// Discord doesn't allow embed descriptions longer than this.
+const MAX_DESCRIPTION_LENGTH: usize = 4096;
+/// Discord doesn't allow embed field values longer than this.
+const MAX_FIELD_LENGTH: usize = 1024;
+/// Discord doesn't allow embed footers longer than this.
+const MAX_FOOTER_LENGTH: usize = 2048;
+
+const OWO_FACES: [&str; 8] = ["owo", "UwU", ">w<", "^w^", "OwO", "uwu", "(・`ω´・)", ":3"];
+
+/// A way to transform text.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Style {
+    Owo,
+    Mock,
+    Vaporwave,
+    Zalgo,
+    Reverse,
+    Leet,
+    Emojify,
+    Clap,
+}
+
+impl Style {
+    pub const ALL: [Self; 8] = [
+        Self::Owo,
+        Self::Mock,
+        Self::Vaporwave,
+        Self::Zalgo,
+        Self::Reverse,
+        Self::Leet,
+        Self::Emojify,
+        Self::Clap,
+    ];
+
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Owo => "owo",
+            Self::Mock => "mock",
+            Self::Vaporwave => "vaporwave",
+            Self::Zalgo => "zalgo",
+            Self::Reverse => "reverse",
+            Self::Leet => "leet",
+            Self::Emojify => "emojify",
+            Self::Clap => "clap",
+        }
+    }
+
+    #[must_use]
+    pub const fn title(self) -> &'static str {
+        match self {
+            Self::Owo => "Owoifier 2900:",
+            Self::Mock => "mOcKiNg TeXt",
+            Self::Vaporwave => "Ｖａｐｏｒｗａｖｅ",
+            Self::Zalgo => "Zalgo",
+            Self::Reverse => "Reversed",
+            Self::Leet => "L33t",
+            Self::Emojify => "Emojified",
+            Self::Clap => "Clapped",
+        }
+    }
+
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|style| style.name().eq_ignore_ascii_case(name.trim()))
+    }
+
+    #[must_use]
+    pub fn apply(self, text: &str) -> String {
+        match self {
+            Self::Owo => owoify(text),
+            Self::Mock => mock(text),
+            Self::Vaporwave => vaporwave(text),
+            Self::Zalgo => zalgo(text),
+            Self::Reverse => text.chars().rev().collect(),
+            Self::Leet => leet(text),
+            Self::Emojify => emojify(text),
+            Self::Clap => clap(text),
+        }
+    }
+}
+
+/// Owoifies text: "r" and "l" become "w", "th" becomes "d", "n" before a
+/// vowel becomes "ny", and some words stutter and get faces after them.
+#[must_use]
+pub fn owoify(text: &str) -> String {
+    let mut rng = rand::thread_rng();
+
+    let mut words = text
+        .split(' ')
+        .map(|word| {
+            let word = replace_preserving_case(word, "th", "d");
+            let word = replace_preserving_case(&word, "ove", "uv");
+
+            let chars = word.chars().collect::<Vec<_>>();
+            let mut owo = String::with_capacity(word.len());
+            for (i, c) in chars.iter().enumerate() {
+                match c {
+                    'r' | 'l' => owo.push('w'),
+                    'R' | 'L' => owo.push('W'),
+                    'n' | 'N' if chars.get(i + 1).is_some_and(|next| is_vowel(*next)) => {
+                        owo.push(*c);
+                        let uppercase = chars[i + 1].is_uppercase();
+                        owo.push(if uppercase { 'Y' } else { 'y' });
+                    }
+                    _ => owo.push(*c),
+                }
+            }
+
+            if owo.starts_with(char::is_alphabetic) && rng.gen_ratio(1, 10) {
+                // It's safe to use .expect() since the word starts with a letter.
+                let first = owo.chars().next().expect("Unreachable");
+                owo = format!("{first}-{owo}");
+            }
+
+            if owo.ends_with(['.', '!', '?']) && rng.gen_ratio(1, 3) {
+                // It's safe to use .expect() since the array isn't empty.
+                let face = OWO_FACES.choose(&mut rng).expect("Unreachable");
+                owo = format!("{owo} {face}");
+            }
+
+            owo
+        })
+        .collect::<Vec<_>>();
+
+    // It's safe to use .expect() since the array isn't empty.
+    words.push((*OWO_FACES.choose(&mut rng).expect("Unreachable")).to_string());
+
+    words.join(" ")
+}
+
+/// Replaces every case-insensitive match of `from`, keeping the match
+/// capitalized if it was.
+fn replace_preserving_case(text: &str, from: &str, to: &str) -> String {
+    // ASCII lowercasing keeps the byte offsets the same.
+    let lowercase = text.to_ascii_lowercase();
+
+    let mut replaced = String::with_capacity(text.len());
+    let mut position = 0;
+    while let Some(found) = lowercase[position..].find(from) {
+        let start = position + found;
+        replaced.push_str(&text[position..start]);
+
+        if text[start..].starts_with(|c: char| c.is_ascii_uppercase()) {
+            let mut chars = to.chars();
+            replaced.extend(chars.next().map(|c| c.to_ascii_uppercase()));
+            replaced.extend(chars);
+        } else {
+            replaced.push_str(to);
+        }
+
+        position = start + from.len();
+    }
+    replaced.push_str(&text[position..]);
+
+    replaced
+}
+
+const fn is_vowel(c: char) -> bool {
+    matches!(c.to_ascii_lowercase(), 'a' | 'e' | 'i' | 'o' | 'u')
+}
+
+/// Alternates the case of every letter, starting with a lowercase one.
+fn mock(text: &str) -> String {
+    let mut uppercase = true;
+
+    text.chars()
+        .map(|c| {
+            if !c.is_alphabetic() {
+                return c.to_string();
+            }
+
+            uppercase = !uppercase;
+            if uppercase {
+                c.to_uppercase().to_string()
+            } else {
+                c.to_lowercase().to_string()
+            }
+        })
+        .collect()
+}
+
+/// Turns text into its full-width form.
+fn vaporwave(text: &str) -> String {
+    text.chars()
+        .map(|c| match c {
+            ' ' => '\u{3000}',
+            '!'..='~' => char::from_u32(u32::from(c) - 0x21 + 0xFF01).unwrap_or(c),
+            _ => c,
+        })
+        .collect()
+}
+
+/// Piles combining marks on top of every character.
+fn zalgo(text: &str) -> String {
+    let mut rng = rand::thread_rng();
+    let mut zalgo = String::new();
+
+    for c in text.chars() {
+        zalgo.push(c);
+        if c.is_whitespace() {
+            continue;
+        }
+
+        for _ in 0..rng.gen_range(1..=4) {
+            // It's safe to use .expect() since the range only has combining marks.
+            zalgo.push(char::from_u32(rng.gen_range(0x300..=0x36F)).expect("Unreachable"));
+        }
+    }
+
+    zalgo
+}
+
+fn leet(text: &str) -> String {
+    text.chars()
+        .map(|c| match c.to_ascii_lowercase() {
+            'a' => '4',
+            'b' => '8',
+            'e' => '3',
+            'g' => '9',
+            'i' | 'l' => '1',
+            'o' => '0',
+            's' => '5',
+            't' => '7',
+            _ => c,
+        })
+        .collect()
+}
+
+/// Spells text out with regional indicator and keycap emojis.
+fn emojify(text: &str) -> String {
+    text.chars()
+        .map(|c| match c.to_ascii_lowercase() {
+            // Regional indicators next to each other turn into flags, so
+            // they're spaced out.
+            letter @ 'a'..='z' => char::from_u32(0x1F1E6 + u32::from(letter) - u32::from('a'))
+                .map_or_else(|| c.to_string(), |indicator| format!("{indicator} ")),
+            digit @ '0'..='9' => format!("{digit}\u{FE0F}\u{20E3} "),
+            '!' => "❗ ".to_string(),
+            '?' => "❓ ".to_string(),
+            ' ' => "   ".to_string(),
+            _ => c.to_string(),
+        })
+        .collect::<String>()
+        .trim_end()
+        .to_string()
+}
+
+fn clap(text: &str) -> String {
+    let words = text.split_whitespace().collect::<Vec<_>>();
+    if words.is_empty() {
+        return String::new();
+    }
+
+    format!("👏 {} 👏", words.join(" 👏 "))
+}
+
+/// Cuts text down to `max` characters.
+fn truncate(text: &str, max: usize) -> String {
+    if text.chars().count() <= max {
+        return text.to_string();
+    }
+
+    let mut truncated = text.chars().take(max - 1).collect::<String>();
+    truncated.push('…');
+    truncated
+}
+
+fn transformed_embed(style: Style, text: &str) -> CreateEmbed {
+    let transformed = style.apply(text);
+
+    CreateEmbed::default()
+        .title(style.title())
+        .description(truncate(&transformed, MAX_DESCRIPTION_LENGTH))
+        .color(EMBED_COLOR)
+}
+
+/// Transform text into a different style
+#[poise::command(slash_command, broadcast_typing)]
+pub async fn text(
+    ctx: poise::Context<'_, (), Error>,
+    #[description = "How to transform the text."]
+    #[autocomplete = "style_autocomplete"]
+    style: String,
+    // Keeps the footer short enough that the embed stays within Discord's
+    // 6000 character total.
+    #[description = "Your text."]
+    #[max_length = 1000]
+    input: String,
+) -> Result<(), Error> {
+    let Some(style) = Style::from_name(&style) else {
+        ctx.send(
+            CreateReply::default().embed(
+                CreateEmbed::default()
+                    .title("Error")
+                    .description(format!("{ERROR_EMOJI} That style doesn't exist."))
+                    .color(ERROR_EMBED_COLOR),
+            ),
+        )
+        .await?;
+        return Ok(());
+    };
+
+    ctx.send(
+        CreateReply::default().embed(transformed_embed(style, &input).footer(
+            CreateEmbedFooter::new(truncate(
+                &format!("Translated from \"{input}\""),
+                MAX_FOOTER_LENGTH,
+            )),
+        )),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Owoify the text of a message.
+#[poise::command(context_menu_command = "Owoify")]
+pub async fn owoify_message(
+    ctx: poise::Context<'_, (), Error>,
+    #[description = "The message to owoify."] message: Message,
+) -> Result<(), Error> {
+    if message.content.trim().is_empty() {
+        return send_no_text(ctx).await;
+    }
+
+    ctx.send(
+        CreateReply::default().embed(transformed_embed(Style::Owo, &message.content).footer(
+            CreateEmbedFooter::new(format!("Translated from {}'s message", message.author.name)),
+        )),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Transform the text of a message into any style.
+#[poise::command(context_menu_command = "Transform text", ephemeral)]
+pub async fn transform_message(
+    ctx: poise::Context<'_, (), Error>,
+    #[description = "The message to transform."] message: Message,
+) -> Result<(), Error> {
+    if message.content.trim().is_empty() {
+        return send_no_text(ctx).await;
+    }
+
+    ctx.send(
+        CreateReply::default()
+            .embed(
+                CreateEmbed::default()
+                    .title("Transform text")
+                    .description("Pick a style below to transform the message with.")
+                    .field(
+                        "Original",
+                        truncate(&message.content, MAX_FIELD_LENGTH),
+                        false,
+                    )
+                    .color(EMBED_COLOR),
+            )
+            .components(vec![style_picker(None)]),
+    )
+    .await?;
+
+    Ok(())
+}
+
+fn style_picker(selected: Option<Style>) -> CreateActionRow {
+    let options = Style::ALL
+        .iter()
+        .map(|style| {
+            CreateSelectMenuOption::new(style.title(), style.name())
+                .default_selection(Some(*style) == selected)
+        })
+        .collect();
+
+    CreateActionRow::SelectMenu(
+        CreateSelectMenu::new(
+            ComponentId::new(NAMESPACE).encode(),
+            CreateSelectMenuKind::String { options },
+        )
+        .placeholder("Pick a style"),
+    )
+}
+
+pub(crate) async fn receive_interaction(
+    ctx: &serenity::Context,
+    interaction: &ComponentInteraction,
+    _id: ComponentId,
+) -> Result<(), Error> {
+    let style = match &interaction.data.kind {
+        ComponentInteractionDataKind::StringSelect { values } => {
+            values.first().and_then(|value| Style::from_name(value))
+        }
+        _ => None,
+    };
+
+    // The original text is kept in the message, since bots can't read other
+    // messages without the message content intent.
+    let original = interaction
+        .message
+        .embeds
+        .first()
+        .and_then(|embed| embed.fields.iter().find(|field| field.name == "Original"))
+        .map(|field| field.value.clone());
+
+    let (Some(style), Some(original)) = (style, original) else {
+        router::reply(ctx, interaction, "This menu doesn't do anything.").await;
+        return Ok(());
+    };
+
+    interaction
+        .create_response(
+            &ctx.http,
+            CreateInteractionResponse::UpdateMessage(
+                CreateInteractionResponseMessage::new()
+                    .embed(transformed_embed(style, &original).field(
+                        "Original",
+                        original.clone(),
+                        false,
+                    ))
+                    .components(vec![style_picker(Some(style))]),
+            ),
+        )
+        .await?;
+
+    Ok(())
+}
+
+async fn send_no_text(ctx: poise::Context<'_, (), Error>) -> Result<(), Error> {
+    ctx.send(
+        CreateReply::default()
+            .embed(
+                CreateEmbed::default()
+                    .title("Error")
+                    .description(format!("{ERROR_EMOJI} That message doesn't have any text."))
+                    .color(ERROR_EMBED_COLOR),
+            )
+            .ephemeral(true),
+    )
+    .await?;
+
+    Ok(())
+}
+
+// This function needs to be async for poise to use it.
+#[allow(clippy::unused_async)]
+async fn style_autocomplete(_: poise::Context<'_, (), Error>, partial: &str) -> Vec<String> {
+    let partial = partial.to_lowercase();
+
+    Style::ALL
+        .iter()
+        .map(|style| style.name().to_string())
+        .filter(|name| name.starts_with(&partial))
+        .collect()
+}
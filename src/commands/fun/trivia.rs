@@ -0,0 +1,358 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use log::error;
+use once_cell::sync::Lazy;
+use poise::{
+    serenity_prelude::{
+        self as serenity, ComponentInteraction, CreateActionRow, CreateButton, CreateEmbed,
+        CreateEmbedFooter, CreateInteractionResponse, CreateInteractionResponseMessage, GuildId,
+        MessageId, UserId,
+    },
+    CreateReply,
+};
+use rand::seq::SliceRandom;
+
+use crate::{
+    commands::{CONFIRM_EMOJI, EMBED_COLOR, ERROR_EMBED_COLOR, ERROR_EMOJI},
+    content::{self, Filter, Pool, Tag},
+    database,
+    router::{self, ComponentId},
+    Error,
+};
+
+/// The namespace of the answer buttons.
+pub(crate) const NAMESPACE: &str = "trivia";
+
+/// How long players have to answer a question.
+const TIME_LIMIT: Duration = Duration::from_secs(20);
+/// Points given for a right answer.
+const CORRECT_POINTS: u64 = 10;
+/// Extra points given to whoever got the right answer first.
+const FASTEST_BONUS: u64 = 5;
+/// How many players the leaderboards show.
+const LEADERBOARD_SIZE: u64 = 10;
+/// The letters the options are labeled with.
+const OPTION_LETTERS: [char; 4] = ['A', 'B', 'C', 'D'];
+
+/// The questions that can still be answered, keyed by the ID of the message
+/// they were asked in.
+static ROUNDS: Lazy<Mutex<HashMap<MessageId, Round>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// A trivia player's score.
+#[derive(Debug, Clone, Eq, PartialEq, Default)]
+pub struct TriviaScore {
+    pub(crate) user_id: UserId,
+    pub(crate) points: u64,
+    pub(crate) correct: u64,
+    pub(crate) answered: u64,
+}
+
+#[derive(Debug)]
+struct Round {
+    /// The index of the right option, in the order they're shown.
+    correct: usize,
+    /// Every player's answer and when they gave it.
+    answers: HashMap<UserId, (usize, Instant)>,
+}
+
+/// Play trivia and see who knows the most
+#[poise::command(slash_command, subcommands("play", "leaderboard"), subcommand_required)]
+// This function needs to be async for poise to use it.
+#[allow(clippy::unused_async)]
+pub async fn trivia(_: poise::Context<'_, (), Error>) -> Result<(), Error> {
+    Ok(())
+}
+
+/// Answer a multiple-choice trivia question before time runs out
+#[poise::command(slash_command, guild_only)]
+pub async fn play(
+    ctx: poise::Context<'_, (), Error>,
+    #[description = "Only get questions about this topic."]
+    #[autocomplete = "category_autocomplete"]
+    category: Option<String>,
+) -> Result<(), Error> {
+    // It's safe to use .expect() since the command is guild only.
+    let guild_id = ctx.guild_id().expect("Unreachable").get();
+
+    let filter = Filter {
+        category,
+        ..Default::default()
+    };
+    let Some(question) = content::random_entry_matching(Pool::Trivia, &filter) else {
+        ctx.send(
+            CreateReply::default().embed(
+                CreateEmbed::new()
+                    .title("Error")
+                    .description(format!("{ERROR_EMOJI} No questions match that category."))
+                    .color(ERROR_EMBED_COLOR),
+            ),
+        )
+        .await?;
+        return Ok(());
+    };
+
+    // Shuffled so the right answer isn't always in the same spot.
+    let mut order = (0..question.options.len()).collect::<Vec<_>>();
+    order.shuffle(&mut rand::thread_rng());
+    let options = order
+        .iter()
+        .map(|i| question.options[*i].clone())
+        .collect::<Vec<_>>();
+    // It's safe to use .expect() since the content loader makes sure every
+    // trivia question has a valid answer.
+    let correct = order
+        .iter()
+        .position(|i| Some(*i) == question.answer)
+        .expect("Unreachable");
+
+    let deadline = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs())
+        + TIME_LIMIT.as_secs();
+
+    let option_list = options
+        .iter()
+        .enumerate()
+        .map(|(i, option)| format!("**{}.** {option}", OPTION_LETTERS[i]))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let buttons = (0..options.len())
+        .map(|i| {
+            let id = ComponentId::new(NAMESPACE)
+                .with_arg(i)
+                .expires_in(TIME_LIMIT);
+
+            CreateButton::new(id.encode())
+                .label(OPTION_LETTERS[i].to_string())
+                .style(serenity::ButtonStyle::Primary)
+        })
+        .collect();
+
+    let footer = question.category.as_deref().map_or_else(
+        || "Trivia".to_string(),
+        |category| format!("Category: {category}"),
+    );
+
+    let reply = ctx
+        .send(
+            CreateReply::default()
+                .embed(
+                    CreateEmbed::new()
+                        .title("Trivia")
+                        .description(format!(
+                            "{}\n\n{option_list}\n\nTime's up <t:{deadline}:R>!",
+                            question.text
+                        ))
+                        .footer(CreateEmbedFooter::new(footer.clone()))
+                        .color(EMBED_COLOR),
+                )
+                .components(vec![CreateActionRow::Buttons(buttons)]),
+        )
+        .await?;
+    let message_id = reply.message().await?.id;
+
+    ROUNDS
+        .lock()
+        .expect("Trivia rounds lock was poisoned")
+        .insert(
+            message_id,
+            Round {
+                correct,
+                answers: HashMap::new(),
+            },
+        );
+
+    tokio::time::sleep(TIME_LIMIT).await;
+
+    // It's safe to use .expect() since rounds are only removed here.
+    let round = ROUNDS
+        .lock()
+        .expect("Trivia rounds lock was poisoned")
+        .remove(&message_id)
+        .expect("Unreachable");
+
+    let mut winners = round
+        .answers
+        .iter()
+        .filter(|(_, (answer, _))| *answer == round.correct)
+        .map(|(user, (_, time))| (*user, *time))
+        .collect::<Vec<_>>();
+    winners.sort_by_key(|(_, time)| *time);
+
+    for (user, (answer, _)) in &round.answers {
+        let points = match winners.first() {
+            Some((fastest, _)) if fastest == user => CORRECT_POINTS + FASTEST_BONUS,
+            _ if *answer == round.correct => CORRECT_POINTS,
+            _ => 0,
+        };
+
+        if let Err(e) =
+            database::add_trivia_result(guild_id, *user, points, *answer == round.correct)
+        {
+            error!("Failed to save trivia result: {e}");
+        }
+    }
+
+    let results = if winners.is_empty() {
+        "Nobody got it right this time!".to_string()
+    } else {
+        winners
+            .iter()
+            .enumerate()
+            .map(|(i, (user, _))| {
+                if i == 0 {
+                    format!(
+                        "<@{user}> (+{} points, fastest!)",
+                        CORRECT_POINTS + FASTEST_BONUS
+                    )
+                } else {
+                    format!("<@{user}> (+{CORRECT_POINTS} points)")
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+
+    reply
+        .edit(
+            ctx,
+            CreateReply::default()
+                .embed(
+                    CreateEmbed::new()
+                        .title("Trivia")
+                        .description(format!(
+                            "{}\n\n{option_list}\n\nThe answer was **{}. {}**",
+                            question.text, OPTION_LETTERS[correct], options[correct]
+                        ))
+                        .field(
+                            format!("Right answers ({}/{})", winners.len(), round.answers.len()),
+                            results,
+                            false,
+                        )
+                        .footer(CreateEmbedFooter::new(footer))
+                        .color(EMBED_COLOR),
+                )
+                .components(Vec::new()),
+        )
+        .await?;
+
+    Ok(())
+}
+
+/// See the best trivia players of this server or of everyone
+#[poise::command(slash_command)]
+pub async fn leaderboard(
+    ctx: poise::Context<'_, (), Error>,
+    #[description = "Whether to show this server's players or everyone's."]
+    #[autocomplete = "scope_autocomplete"]
+    scope: Option<String>,
+) -> Result<(), Error> {
+    ctx.defer().await?;
+
+    let guild_id = match scope.as_deref() {
+        Some("global") => None,
+        _ => ctx.guild_id().map(GuildId::get),
+    };
+
+    let scores = database::get_trivia_leaderboard(guild_id, LEADERBOARD_SIZE)?;
+
+    let description = if scores.is_empty() {
+        "Nobody has played trivia yet, use `/trivia play` to be the first!".to_string()
+    } else {
+        scores
+            .iter()
+            .enumerate()
+            .map(|(i, score)| {
+                format!(
+                    "{}. <@{}>: **{}** points ({}/{} right)",
+                    i + 1,
+                    score.user_id,
+                    score.points,
+                    score.correct,
+                    score.answered
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+
+    ctx.send(
+        CreateReply::default().embed(
+            CreateEmbed::new()
+                .title(if guild_id.is_some() {
+                    "Trivia leaderboard"
+                } else {
+                    "Global trivia leaderboard"
+                })
+                .description(description)
+                .color(EMBED_COLOR),
+        ),
+    )
+    .await?;
+
+    Ok(())
+}
+
+pub(crate) async fn receive_interaction(
+    ctx: &serenity::Context,
+    interaction: &ComponentInteraction,
+    id: ComponentId,
+) -> Result<(), Error> {
+    let Some(answer) = id.arg(0).and_then(|answer| answer.parse::<usize>().ok()) else {
+        router::reply(ctx, interaction, "This button doesn't do anything.").await;
+        return Ok(());
+    };
+
+    let result = {
+        let mut rounds = ROUNDS.lock().expect("Trivia rounds lock was poisoned");
+
+        match rounds.get_mut(&interaction.message.id) {
+            None => Err("Time's up for this question."),
+            Some(round) if round.answers.contains_key(&interaction.user.id) => {
+                Err("You already answered this question.")
+            }
+            Some(round) => {
+                round
+                    .answers
+                    .insert(interaction.user.id, (answer, Instant::now()));
+                Ok(())
+            }
+        }
+    };
+
+    if let Err(message) = result {
+        router::reply(ctx, interaction, message).await;
+        return Ok(());
+    }
+
+    interaction
+        .create_response(
+            &ctx.http,
+            CreateInteractionResponse::Message(
+                CreateInteractionResponseMessage::new()
+                    .content(format!(
+                        "{CONFIRM_EMOJI} You answered **{}**. The right answer will be revealed when time's up!",
+                        OPTION_LETTERS.get(answer).copied().unwrap_or('?')
+                    ))
+                    .ephemeral(true),
+            ),
+        )
+        .await?;
+
+    Ok(())
+}
+
+// This function needs to be async for poise to use it.
+#[allow(clippy::unused_async)]
+async fn category_autocomplete(_: poise::Context<'_, (), Error>, partial: &str) -> Vec<String> {
+    content::tag_values(Pool::Trivia, Tag::Category, partial)
+}
+
+// This function needs to be async for poise to use it.
+#[allow(clippy::unused_async)]
+async fn scope_autocomplete(_: poise::Context<'_, (), Error>, _partial: &str) -> Vec<String> {
+    vec!["server".to_string(), "global".to_string()]
+}
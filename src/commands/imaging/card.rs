@@ -0,0 +1,286 @@
+use crate::Error;
+
+use ab_glyph::{Font, PxScale, ScaleFont};
+use image::imageops::{self, FilterType};
+use image::{Rgba, RgbaImage};
+
+use super::effects::{draw_text, text_width, FONT};
+use super::{decode, download, encode, POOL, TIME_LIMIT};
+
+const RANK_WIDTH: u32 = 934;
+const RANK_HEIGHT: u32 = 282;
+const AVATAR_SIZE: u32 = 200;
+const GREETING_WIDTH: u32 = 1024;
+const GREETING_HEIGHT: u32 = 400;
+const GREETING_AVATAR_SIZE: u32 = 180;
+/// Space between the edges of the card and its contents.
+const PADDING: u32 = 40;
+const BAR_HEIGHT: u32 = 36;
+
+const BACKGROUND: Rgba<u8> = Rgba([35, 39, 42, 255]);
+const BAR_BACKGROUND: Rgba<u8> = Rgba([72, 75, 78, 255]);
+// The same color as `EMBED_COLOR`.
+const ACCENT: Rgba<u8> = Rgba([197, 184, 117, 255]);
+const TEXT: Rgba<u8> = Rgba([255, 255, 255, 255]);
+const MUTED_TEXT: Rgba<u8> = Rgba([185, 187, 190, 255]);
+
+/// What's shown on a rank card.
+#[derive(Debug, Clone, Default)]
+pub struct RankCard {
+    pub name: String,
+    pub avatar_url: String,
+    pub rank: u64,
+    pub level: u64,
+    /// The XP earned since reaching the current level.
+    pub xp: u64,
+    /// The XP needed to reach the next level from the current one.
+    pub xp_needed: u64,
+}
+
+/// What's shown on a welcome or goodbye card.
+#[derive(Debug, Clone, Default)]
+pub struct GreetingCard {
+    pub title: String,
+    pub name: String,
+    pub subtitle: String,
+    pub avatar_url: String,
+}
+
+/// Renders a rank card as a PNG.
+///
+/// # Errors
+///
+/// Fails if the font couldn't be loaded or rendering took too long.
+pub async fn render(card: RankCard) -> Result<Vec<u8>, Error> {
+    let avatar_url = card.avatar_url.clone();
+    render_with_avatar(&avatar_url, move |avatar| draw_rank(&card, avatar)).await
+}
+
+/// Renders a welcome or goodbye card as a PNG.
+///
+/// # Errors
+///
+/// Fails if the font couldn't be loaded or rendering took too long.
+pub async fn render_greeting(card: GreetingCard) -> Result<Vec<u8>, Error> {
+    let avatar_url = card.avatar_url.clone();
+    render_with_avatar(&avatar_url, move |avatar| draw_greeting(&card, avatar)).await
+}
+
+/// Downloads an avatar, then draws a card with it on the blocking pool.
+///
+/// The card is still drawn, with an empty circle, if the avatar can't be
+/// downloaded.
+async fn render_with_avatar<F>(avatar_url: &str, draw: F) -> Result<Vec<u8>, Error>
+where
+    F: FnOnce(Option<&RgbaImage>) -> Result<RgbaImage, Error> + Send + 'static,
+{
+    let job = async {
+        let avatar = download(avatar_url).await.ok();
+        let permit = POOL.clone().acquire_owned().await?;
+
+        tokio::task::spawn_blocking(move || {
+            let _permit = permit;
+
+            let avatar = avatar.and_then(|data| decode(&data).ok());
+            encode(draw(avatar.as_ref())?)
+        })
+        .await?
+    };
+
+    tokio::time::timeout(TIME_LIMIT, job)
+        .await
+        .map_err(|_| Error::from("The card took too long to render."))?
+}
+
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    clippy::cast_precision_loss
+)]
+fn draw_rank(card: &RankCard, avatar: Option<&RgbaImage>) -> Result<RgbaImage, Error> {
+    let font = FONT
+        .as_ref()
+        .ok_or("Rank cards aren't available right now.")?;
+
+    let mut output = RgbaImage::from_pixel(RANK_WIDTH, RANK_HEIGHT, BACKGROUND);
+
+    let avatar_y = (RANK_HEIGHT - AVATAR_SIZE) / 2;
+    let avatar = avatar.map_or_else(
+        || RgbaImage::from_pixel(AVATAR_SIZE, AVATAR_SIZE, BAR_BACKGROUND),
+        |avatar| imageops::resize(avatar, AVATAR_SIZE, AVATAR_SIZE, FilterType::Triangle),
+    );
+    overlay_circle(&mut output, &avatar, PADDING, avatar_y);
+
+    let left = (PADDING * 2 + AVATAR_SIZE) as f32;
+    let right = (RANK_WIDTH - PADDING) as f32;
+
+    // Rank and level, aligned to the right.
+    let stats = format!("RANK #{}   LEVEL {}", card.rank, card.level);
+    let stats_size = 36.0;
+    let stats_width = text_width(&font.as_scaled(PxScale::from(stats_size)), &stats);
+    draw_text(
+        &mut output,
+        font,
+        stats_size,
+        &stats,
+        right - stats_width,
+        80.0,
+        ACCENT,
+    );
+
+    // The name, shortened so it doesn't run into the XP.
+    let name_size = 40.0;
+    let name_scaled = font.as_scaled(PxScale::from(name_size));
+    let xp_text = format!("{} / {} XP", card.xp, card.xp_needed);
+    let xp_size = 28.0;
+    let xp_width = text_width(&font.as_scaled(PxScale::from(xp_size)), &xp_text);
+    let max_name_width = right - left - xp_width - 20.0;
+
+    let name = truncate(&name_scaled, &card.name, max_name_width);
+
+    let text_baseline = 165.0;
+    draw_text(
+        &mut output,
+        font,
+        name_size,
+        &name,
+        left,
+        text_baseline,
+        TEXT,
+    );
+    draw_text(
+        &mut output,
+        font,
+        xp_size,
+        &xp_text,
+        right - xp_width,
+        text_baseline,
+        MUTED_TEXT,
+    );
+
+    // The progress bar.
+    let bar_y = 190;
+    let bar_width = (right - left) as u32;
+    let progress = if card.xp_needed == 0 {
+        0.0
+    } else {
+        (card.xp as f64 / card.xp_needed as f64).clamp(0.0, 1.0)
+    };
+    fill_rounded_bar(&mut output, left as u32, bar_y, bar_width, BAR_BACKGROUND);
+    let filled = (f64::from(bar_width) * progress) as u32;
+    if filled > 0 {
+        fill_rounded_bar(
+            &mut output,
+            left as u32,
+            bar_y,
+            filled.max(BAR_HEIGHT),
+            ACCENT,
+        );
+    }
+
+    Ok(output)
+}
+
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    clippy::cast_precision_loss
+)]
+fn draw_greeting(card: &GreetingCard, avatar: Option<&RgbaImage>) -> Result<RgbaImage, Error> {
+    let font = FONT
+        .as_ref()
+        .ok_or("Greeting cards aren't available right now.")?;
+
+    let mut output = RgbaImage::from_pixel(GREETING_WIDTH, GREETING_HEIGHT, BACKGROUND);
+
+    // The avatar, centered at the top in an accent colored ring.
+    let avatar_x = (GREETING_WIDTH - GREETING_AVATAR_SIZE) / 2;
+    let avatar_y = 36;
+    let ring = RgbaImage::from_pixel(GREETING_AVATAR_SIZE + 12, GREETING_AVATAR_SIZE + 12, ACCENT);
+    overlay_circle(&mut output, &ring, avatar_x - 6, avatar_y - 6);
+    let avatar = avatar.map_or_else(
+        || RgbaImage::from_pixel(GREETING_AVATAR_SIZE, GREETING_AVATAR_SIZE, BAR_BACKGROUND),
+        |avatar| {
+            imageops::resize(
+                avatar,
+                GREETING_AVATAR_SIZE,
+                GREETING_AVATAR_SIZE,
+                FilterType::Triangle,
+            )
+        },
+    );
+    overlay_circle(&mut output, &avatar, avatar_x, avatar_y);
+
+    // The text, centered under it.
+    let max_width = (GREETING_WIDTH - PADDING * 2) as f32;
+    let lines = [
+        (card.title.as_str(), 56.0, 290.0, ACCENT),
+        (card.name.as_str(), 38.0, 337.0, TEXT),
+        (card.subtitle.as_str(), 26.0, 376.0, MUTED_TEXT),
+    ];
+    for (text, size, baseline, color) in lines {
+        let scaled = font.as_scaled(PxScale::from(size));
+        let text = truncate(&scaled, text, max_width);
+        let x = (GREETING_WIDTH as f32 - text_width(&scaled, &text)) / 2.0;
+        draw_text(&mut output, font, size, &text, x, baseline, color);
+    }
+
+    Ok(output)
+}
+
+/// Shortens `text` with an ellipsis until it fits in `max_width`.
+fn truncate<F: Font, S: ScaleFont<F>>(scaled: &S, text: &str, max_width: f32) -> String {
+    let mut text = text.to_string();
+    if text_width(scaled, &text) > max_width {
+        while !text.is_empty() && text_width(scaled, &format!("{text}…")) > max_width {
+            text.pop();
+        }
+        text.push('…');
+    }
+    text
+}
+
+/// Draws `image` cropped to a circle, with its top left corner at `x`, `y`.
+#[allow(clippy::cast_precision_loss)]
+fn overlay_circle(output: &mut RgbaImage, image: &RgbaImage, x: u32, y: u32) {
+    let radius = image.width() as f32 / 2.0;
+
+    for (ix, iy, pixel) in image.enumerate_pixels() {
+        let dx = ix as f32 + 0.5 - radius;
+        let dy = iy as f32 + 0.5 - radius;
+        // Fades the outermost pixel to smooth the edge.
+        let coverage = (radius - dx.hypot(dy)).clamp(0.0, 1.0);
+        if coverage > 0.0 {
+            blend(output.get_pixel_mut(x + ix, y + iy), *pixel, coverage);
+        }
+    }
+}
+
+/// Fills a bar of `BAR_HEIGHT` with rounded ends.
+#[allow(clippy::cast_precision_loss)]
+fn fill_rounded_bar(output: &mut RgbaImage, x: u32, y: u32, width: u32, color: Rgba<u8>) {
+    let radius = BAR_HEIGHT as f32 / 2.0;
+    let width = width.min(output.width() - x);
+
+    for px in 0..width {
+        for py in 0..BAR_HEIGHT {
+            let cx = (px as f32 + 0.5).clamp(radius, width as f32 - radius);
+            let dx = px as f32 + 0.5 - cx;
+            let dy = py as f32 + 0.5 - radius;
+            let coverage = (radius - dx.hypot(dy)).clamp(0.0, 1.0);
+            if coverage > 0.0 {
+                blend(output.get_pixel_mut(x + px, y + py), color, coverage);
+            }
+        }
+    }
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn blend(pixel: &mut Rgba<u8>, color: Rgba<u8>, coverage: f32) {
+    let alpha = coverage * f32::from(color.0[3]) / 255.0;
+    for channel in 0..3 {
+        pixel.0[channel] = f32::from(pixel.0[channel])
+            .mul_add(1.0 - alpha, f32::from(color.0[channel]) * alpha)
+            .round() as u8;
+    }
+}
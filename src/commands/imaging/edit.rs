@@ -0,0 +1,169 @@
+use crate::commands::{EMBED_COLOR, ERROR_EMBED_COLOR, ERROR_EMOJI};
+use crate::Error;
+
+use super::{effects, process, source_url};
+use ::image::RgbaImage;
+use poise::serenity_prelude::{Attachment, CreateAttachment, CreateEmbed, User};
+use poise::CreateReply;
+
+/// Edit an image or someone's avatar
+#[poise::command(
+    slash_command,
+    subcommands("magik", "deepfry", "invert", "blur", "pixelate", "caption"),
+    subcommand_required
+)]
+// This function needs to be async for poise to use it.
+#[allow(clippy::unused_async)]
+pub async fn image(_: poise::Context<'_, (), Error>) -> Result<(), Error> {
+    Ok(())
+}
+
+/// Squish and stretch an image until it's barely recognizable
+#[poise::command(slash_command)]
+pub async fn magik(
+    ctx: poise::Context<'_, (), Error>,
+    #[description = "The image to edit."] image: Option<Attachment>,
+    #[description = "Whose avatar to edit, if there's no image."] user: Option<User>,
+    #[description = "How distorted it gets, from 1 to 10."]
+    #[min = 1]
+    #[max = 10]
+    intensity: Option<u32>,
+) -> Result<(), Error> {
+    let intensity = intensity.unwrap_or(5);
+
+    apply(ctx, image, user, "magik", move |image, deadline| {
+        effects::magik(&image, intensity, deadline)
+    })
+    .await
+}
+
+/// Deepfry an image
+#[poise::command(slash_command)]
+pub async fn deepfry(
+    ctx: poise::Context<'_, (), Error>,
+    #[description = "The image to edit."] image: Option<Attachment>,
+    #[description = "Whose avatar to edit, if there's no image."] user: Option<User>,
+) -> Result<(), Error> {
+    apply(ctx, image, user, "deepfry", |image, _| {
+        effects::deepfry(&image)
+    })
+    .await
+}
+
+/// Invert the colors of an image
+#[poise::command(slash_command)]
+pub async fn invert(
+    ctx: poise::Context<'_, (), Error>,
+    #[description = "The image to edit."] image: Option<Attachment>,
+    #[description = "Whose avatar to edit, if there's no image."] user: Option<User>,
+) -> Result<(), Error> {
+    apply(ctx, image, user, "invert", |image, _| {
+        Ok(effects::invert(image))
+    })
+    .await
+}
+
+/// Blur an image
+#[poise::command(slash_command)]
+pub async fn blur(
+    ctx: poise::Context<'_, (), Error>,
+    #[description = "The image to edit."] image: Option<Attachment>,
+    #[description = "Whose avatar to edit, if there's no image."] user: Option<User>,
+    #[description = "How blurry it gets, from 1 to 20."]
+    #[min = 1]
+    #[max = 20]
+    strength: Option<u8>,
+) -> Result<(), Error> {
+    let strength = f32::from(strength.unwrap_or(5));
+
+    apply(ctx, image, user, "blur", move |image, _| {
+        Ok(effects::blur(&image, strength))
+    })
+    .await
+}
+
+/// Pixelate an image
+#[poise::command(slash_command)]
+pub async fn pixelate(
+    ctx: poise::Context<'_, (), Error>,
+    #[description = "The image to edit."] image: Option<Attachment>,
+    #[description = "Whose avatar to edit, if there's no image."] user: Option<User>,
+    #[description = "How big the pixels get, from 2 to 64."]
+    #[min = 2]
+    #[max = 64]
+    size: Option<u32>,
+) -> Result<(), Error> {
+    let size = size.unwrap_or(12).clamp(2, 64);
+
+    apply(ctx, image, user, "pixelate", move |image, _| {
+        Ok(effects::pixelate(&image, size))
+    })
+    .await
+}
+
+/// Add a caption above an image
+#[poise::command(slash_command)]
+pub async fn caption(
+    ctx: poise::Context<'_, (), Error>,
+    #[description = "The caption."]
+    #[max_length = 200]
+    text: String,
+    #[description = "The image to edit."] image: Option<Attachment>,
+    #[description = "Whose avatar to edit, if there's no image."] user: Option<User>,
+) -> Result<(), Error> {
+    apply(ctx, image, user, "caption", move |image, _| {
+        effects::caption(&image, &text)
+    })
+    .await
+}
+
+/// Applies an effect to the attachment, or to an avatar if there's none, and
+/// sends the result.
+async fn apply<F>(
+    ctx: poise::Context<'_, (), Error>,
+    attachment: Option<Attachment>,
+    user: Option<User>,
+    name: &str,
+    effect: F,
+) -> Result<(), Error>
+where
+    F: FnOnce(RgbaImage, std::time::Instant) -> Result<RgbaImage, Error> + Send + 'static,
+{
+    ctx.defer().await?;
+
+    let user = user.as_ref().unwrap_or_else(|| ctx.author());
+    let result = match source_url(attachment.as_ref(), user) {
+        Ok(url) => process(&url, effect).await,
+        Err(e) => Err(e),
+    };
+
+    let data = match result {
+        Ok(data) => data,
+        Err(e) => {
+            ctx.send(
+                CreateReply::default().embed(
+                    CreateEmbed::default()
+                        .title("Error")
+                        .description(format!("{ERROR_EMOJI} {e}"))
+                        .color(ERROR_EMBED_COLOR),
+                ),
+            )
+            .await?;
+            return Ok(());
+        }
+    };
+
+    let file_name = format!("{name}.png");
+    ctx.send(
+        CreateReply::default()
+            .attachment(CreateAttachment::bytes(data, file_name.clone()))
+            .embed(
+                CreateEmbed::default()
+                    .image(format!("attachment://{file_name}"))
+                    .color(EMBED_COLOR),
+            ),
+    )
+    .await?;
+
+    Ok(())
+}
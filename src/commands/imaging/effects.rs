@@ -0,0 +1,359 @@
+use std::time::Instant;
+
+use crate::Error;
+
+use ab_glyph::{point, Font, FontVec, PxScale, ScaleFont};
+use image::codecs::jpeg::JpegEncoder;
+use image::imageops::{self, FilterType};
+use image::{DynamicImage, ImageFormat, Rgba, RgbaImage};
+use once_cell::sync::Lazy;
+use rand::Rng;
+
+/// Where the caption font is read from when `CAPTION_FONT` isn't set.
+const DEFAULT_FONT: &str = "/usr/share/fonts/truetype/dejavu/DejaVuSans-Bold.ttf";
+
+/// Seam carving is slow, so images are shrunk further before it.
+const MAGIK_SIZE: u32 = 384;
+
+/// The caption font, or `None` if it couldn't be loaded.
+pub(super) static FONT: Lazy<Option<FontVec>> = Lazy::new(|| {
+    let path = std::env::var("CAPTION_FONT").unwrap_or_else(|_| DEFAULT_FONT.to_string());
+
+    match std::fs::read(&path)
+        .map_err(Error::from)
+        .and_then(|data| FontVec::try_from_vec(data).map_err(Error::from))
+    {
+        Ok(font) => Some(font),
+        Err(e) => {
+            log::warn!("Failed to load the caption font from {path}: {e}");
+            None
+        }
+    }
+});
+
+pub fn invert(mut image: RgbaImage) -> RgbaImage {
+    imageops::invert(&mut image);
+    image
+}
+
+pub fn blur(image: &RgbaImage, strength: f32) -> RgbaImage {
+    imageops::fast_blur(image, strength)
+}
+
+pub fn pixelate(image: &RgbaImage, block_size: u32) -> RgbaImage {
+    let (width, height) = image.dimensions();
+    let small = imageops::resize(
+        image,
+        (width / block_size).max(1),
+        (height / block_size).max(1),
+        FilterType::Triangle,
+    );
+
+    imageops::resize(&small, width, height, FilterType::Nearest)
+}
+
+/// Oversaturates the image, adds noise and runs it through a terrible JPEG
+/// encoder a few times.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub fn deepfry(image: &RgbaImage) -> Result<RgbaImage, Error> {
+    let mut rng = rand::thread_rng();
+
+    let mut image = imageops::colorops::contrast(image, 60.0);
+    for pixel in image.pixels_mut() {
+        let [r, g, b, a] = pixel.0.map(f32::from);
+        let luma = 0.114f32.mul_add(b, 0.299f32.mul_add(r, 0.587 * g));
+        let noise = rng.gen_range(-25.0..25.0);
+
+        let fry = |channel: f32| {
+            3.0f32
+                .mul_add(channel - luma, luma + noise)
+                .clamp(0.0, 255.0) as u8
+        };
+        pixel.0 = [fry(r), fry(g), fry(b), a as u8];
+    }
+
+    let mut image = DynamicImage::ImageRgba8(image);
+    for quality in [12, 6] {
+        let mut data = Vec::new();
+        JpegEncoder::new_with_quality(&mut data, quality).encode_image(&image.to_rgb8())?;
+        image = image::load_from_memory_with_format(&data, ImageFormat::Jpeg)?;
+    }
+
+    Ok(image.to_rgba8())
+}
+
+/// The classic "magik" effect: liquid rescales the image down, which squishes
+/// everything that isn't important, and then stretches it back up.
+///
+/// `intensity` goes from 1 to 10.
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    clippy::cast_precision_loss
+)]
+pub fn magik(image: &RgbaImage, intensity: u32, deadline: Instant) -> Result<RgbaImage, Error> {
+    let image = if image.width() > MAGIK_SIZE || image.height() > MAGIK_SIZE {
+        DynamicImage::ImageRgba8(image.clone())
+            .resize(MAGIK_SIZE, MAGIK_SIZE, FilterType::Triangle)
+            .to_rgba8()
+    } else {
+        image.clone()
+    };
+    let (width, height) = image.dimensions();
+
+    let removed = 0.07 * intensity.clamp(1, 10) as f32;
+    let columns = (width as f32 * removed) as u32;
+    let rows = (height as f32 * removed) as u32;
+
+    let carved = carve_columns(&image, columns, deadline)?;
+    // Rows are carved as the columns of the rotated image.
+    let carved = imageops::rotate270(&carve_columns(
+        &imageops::rotate90(&carved),
+        rows,
+        deadline,
+    )?);
+
+    Ok(imageops::resize(
+        &carved,
+        width,
+        height,
+        FilterType::Triangle,
+    ))
+}
+
+/// Removes the `count` lowest-energy vertical seams from an image.
+fn carve_columns(image: &RgbaImage, count: u32, deadline: Instant) -> Result<RgbaImage, Error> {
+    let (width, height) = image.dimensions();
+    let count = count.min(width.saturating_sub(1));
+
+    let mut rows = image
+        .rows()
+        .map(|row| row.copied().collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+
+    for _ in 0..count {
+        if Instant::now() > deadline {
+            return Err("That image took too long to edit, try a smaller one.".into());
+        }
+
+        let seam = find_seam(&rows);
+        for (row, x) in rows.iter_mut().zip(seam) {
+            row.remove(x);
+        }
+    }
+
+    let new_width = width - count;
+    Ok(RgbaImage::from_fn(new_width, height, |x, y| {
+        rows[y as usize][x as usize]
+    }))
+}
+
+/// Finds the connected top-to-bottom path of pixels that changes the image
+/// the least when it's removed. Returns its x for every row.
+fn find_seam(rows: &[Vec<Rgba<u8>>]) -> Vec<usize> {
+    let height = rows.len();
+    let width = rows[0].len();
+
+    let luma = rows
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|pixel| {
+                    let [r, g, b, _] = pixel.0.map(i32::from);
+                    (299 * r + 587 * g + 114 * b) / 1000
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let energy = |x: usize, y: usize| {
+        let left = luma[y][x.saturating_sub(1)];
+        let right = luma[y][(x + 1).min(width - 1)];
+        let up = luma[y.saturating_sub(1)][x];
+        let down = luma[(y + 1).min(height - 1)][x];
+
+        (left - right).unsigned_abs() + (up - down).unsigned_abs()
+    };
+
+    // The cheapest seam cost ending at every pixel.
+    let mut costs = vec![(0..width).map(|x| energy(x, 0)).collect::<Vec<_>>()];
+    for y in 1..height {
+        let above = &costs[y - 1];
+        let row = (0..width)
+            .map(|x| {
+                let cheapest = above[x.saturating_sub(1)..=(x + 1).min(width - 1)]
+                    .iter()
+                    .min()
+                    .copied()
+                    .unwrap_or(0);
+                cheapest + energy(x, y)
+            })
+            .collect();
+        costs.push(row);
+    }
+
+    // Walks back up from the cheapest end.
+    let mut seam = vec![0; height];
+    seam[height - 1] = (0..width)
+        .min_by_key(|x| costs[height - 1][*x])
+        .unwrap_or(0);
+    for y in (0..height - 1).rev() {
+        let x = seam[y + 1];
+        seam[y] = (x.saturating_sub(1)..=(x + 1).min(width - 1))
+            .min_by_key(|x| costs[y][*x])
+            .unwrap_or(x);
+    }
+
+    seam
+}
+
+/// Adds a white band with the text above the image, like a meme.
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    clippy::cast_precision_loss
+)]
+pub fn caption(image: &RgbaImage, text: &str) -> Result<RgbaImage, Error> {
+    let font = FONT
+        .as_ref()
+        .ok_or("Captions aren't available right now.")?;
+
+    let width = image.width();
+    let size = (width as f32 / 10.0).clamp(16.0, 48.0);
+    let scaled = font.as_scaled(PxScale::from(size));
+
+    let margin = size / 2.0;
+    let lines = wrap(&scaled, text, 2.0f32.mul_add(-margin, width as f32));
+    let line_height = scaled.height() + scaled.line_gap();
+    let band = (lines.len() as f32)
+        .mul_add(line_height, 2.0 * margin)
+        .ceil() as u32;
+
+    let mut output =
+        RgbaImage::from_pixel(width, image.height() + band, Rgba([255, 255, 255, 255]));
+    imageops::overlay(&mut output, image, 0, i64::from(band));
+
+    for (i, line) in lines.iter().enumerate() {
+        let line_width = text_width(&scaled, line);
+        let x = (width as f32 - line_width) / 2.0;
+        let baseline = (i as f32).mul_add(line_height, margin) + scaled.ascent();
+
+        draw_text(
+            &mut output,
+            font,
+            size,
+            line,
+            x,
+            baseline,
+            Rgba([0, 0, 0, 255]),
+        );
+    }
+
+    Ok(output)
+}
+
+/// Draws a line of text starting at `x`, blending it over what's already
+/// there.
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    clippy::cast_precision_loss
+)]
+pub(super) fn draw_text(
+    image: &mut RgbaImage,
+    font: &FontVec,
+    size: f32,
+    text: &str,
+    mut x: f32,
+    baseline: f32,
+    color: Rgba<u8>,
+) {
+    let scaled = font.as_scaled(PxScale::from(size));
+    let (width, height) = image.dimensions();
+
+    let mut previous = None;
+    for c in text.chars() {
+        let id = scaled.glyph_id(c);
+        if let Some(previous) = previous {
+            x += scaled.kern(previous, id);
+        }
+        previous = Some(id);
+
+        let glyph = id.with_scale_and_position(scaled.scale(), point(x, baseline));
+        x += scaled.h_advance(id);
+
+        let Some(outline) = font.outline_glyph(glyph) else {
+            continue;
+        };
+        let bounds = outline.px_bounds();
+        outline.draw(|gx, gy, coverage| {
+            let px = bounds.min.x as i64 + i64::from(gx);
+            let py = bounds.min.y as i64 + i64::from(gy);
+            let (Ok(px), Ok(py)) = (u32::try_from(px), u32::try_from(py)) else {
+                return;
+            };
+            if px >= width || py >= height {
+                return;
+            }
+
+            let alpha = coverage.clamp(0.0, 1.0) * f32::from(color.0[3]) / 255.0;
+            let pixel = image.get_pixel_mut(px, py);
+            for channel in 0..3 {
+                pixel.0[channel] = f32::from(pixel.0[channel])
+                    .mul_add(1.0 - alpha, f32::from(color.0[channel]) * alpha)
+                    .round() as u8;
+            }
+            pixel.0[3] = pixel.0[3].max((alpha * 255.0) as u8);
+        });
+    }
+}
+
+pub(super) fn text_width<F: Font, S: ScaleFont<F>>(scaled: &S, text: &str) -> f32 {
+    let mut previous = None;
+    text.chars()
+        .map(|c| {
+            let id = scaled.glyph_id(c);
+            let kern = previous.map_or(0.0, |previous| scaled.kern(previous, id));
+            previous = Some(id);
+            kern + scaled.h_advance(id)
+        })
+        .sum()
+}
+
+/// Splits text into lines that fit in `max_width`, breaking words that are
+/// too long on their own.
+fn wrap<F: Font, S: ScaleFont<F>>(scaled: &S, text: &str, max_width: f32) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut line = String::new();
+
+    for word in text.split_whitespace() {
+        let candidate = if line.is_empty() {
+            word.to_string()
+        } else {
+            format!("{line} {word}")
+        };
+
+        if text_width(scaled, &candidate) <= max_width {
+            line = candidate;
+            continue;
+        }
+
+        if !line.is_empty() {
+            lines.push(std::mem::take(&mut line));
+        }
+
+        for c in word.chars() {
+            line.push(c);
+            if text_width(scaled, &line) > max_width && line.chars().count() > 1 {
+                line.pop();
+                lines.push(std::mem::replace(&mut line, c.to_string()));
+            }
+        }
+    }
+
+    if !line.is_empty() {
+        lines.push(line);
+    }
+
+    lines
+}
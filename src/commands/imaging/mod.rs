@@ -0,0 +1,158 @@
+use std::io::Cursor;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::Error;
+
+use image::{DynamicImage, ImageFormat, ImageReader, Limits, RgbaImage};
+use once_cell::sync::Lazy;
+use poise::serenity_prelude::{Attachment, User};
+use tokio::sync::Semaphore;
+
+pub mod card;
+pub mod edit;
+mod effects;
+
+/// How many images can be processed at once. Jobs past this wait for a slot.
+const MAX_JOBS: usize = 2;
+
+/// How long a job can take, including the time spent waiting for a slot.
+const TIME_LIMIT: Duration = Duration::from_secs(20);
+
+/// The biggest file that gets downloaded.
+const MAX_DOWNLOAD_BYTES: usize = 8 * 1024 * 1024;
+
+/// The biggest width or height an image can be decoded with.
+const MAX_DIMENSION: u32 = 4096;
+
+/// The most memory the decoder can use for one image.
+const MAX_DECODE_BYTES: u64 = 128 * 1024 * 1024;
+
+/// Images are shrunk so their longest side is at most this before any effect
+/// is applied, which keeps every effect fast and the results small.
+const WORK_SIZE: u32 = 768;
+
+/// Limits the number of blocking threads busy with images, so a burst of
+/// commands can't starve the runtime.
+static POOL: Lazy<Arc<Semaphore>> = Lazy::new(|| Arc::new(Semaphore::new(MAX_JOBS)));
+
+static CLIENT: Lazy<reqwest::Client> = Lazy::new(|| {
+    reqwest::Client::builder()
+        .timeout(Duration::from_secs(10))
+        .build()
+        .expect("Failed to build the image HTTP client")
+});
+
+/// Gets the URL of the image to edit, which is the attachment if there is one
+/// and otherwise the avatar of `user`.
+fn source_url(attachment: Option<&Attachment>, user: &User) -> Result<String, Error> {
+    let Some(attachment) = attachment else {
+        return Ok(user.static_face());
+    };
+
+    let is_image = attachment
+        .content_type
+        .as_deref()
+        .is_some_and(|content_type| content_type.starts_with("image/"));
+    if !is_image {
+        return Err("That attachment isn't an image.".into());
+    }
+
+    if usize::try_from(attachment.size).map_or(true, |size| size > MAX_DOWNLOAD_BYTES) {
+        return Err(format!(
+            "That image is too big, it can be {} MB at most.",
+            MAX_DOWNLOAD_BYTES / 1024 / 1024
+        )
+        .into());
+    }
+
+    Ok(attachment.url.clone())
+}
+
+/// Downloads a file, giving up as soon as it gets bigger than
+/// [`MAX_DOWNLOAD_BYTES`].
+async fn download(url: &str) -> Result<Vec<u8>, Error> {
+    let mut response = CLIENT.get(url).send().await?.error_for_status()?;
+
+    let too_big = || Error::from("That image is too big to edit.");
+
+    if response
+        .content_length()
+        .is_some_and(|length| length > MAX_DOWNLOAD_BYTES as u64)
+    {
+        return Err(too_big());
+    }
+
+    let mut data = Vec::new();
+    while let Some(chunk) = response.chunk().await? {
+        data.extend_from_slice(&chunk);
+        if data.len() > MAX_DOWNLOAD_BYTES {
+            return Err(too_big());
+        }
+    }
+
+    Ok(data)
+}
+
+/// Decodes an image within the size limits and shrinks it down to
+/// [`WORK_SIZE`].
+fn decode(data: &[u8]) -> Result<RgbaImage, Error> {
+    let mut limits = Limits::default();
+    limits.max_image_width = Some(MAX_DIMENSION);
+    limits.max_image_height = Some(MAX_DIMENSION);
+    limits.max_alloc = Some(MAX_DECODE_BYTES);
+
+    let mut reader = ImageReader::new(Cursor::new(data)).with_guessed_format()?;
+    reader.limits(limits);
+
+    let image = reader
+        .decode()
+        .map_err(|e| format!("That image couldn't be read: {e}"))?;
+
+    let image = if image.width() > WORK_SIZE || image.height() > WORK_SIZE {
+        image.resize(WORK_SIZE, WORK_SIZE, image::imageops::FilterType::Triangle)
+    } else {
+        image
+    };
+
+    Ok(image.to_rgba8())
+}
+
+fn encode(image: RgbaImage) -> Result<Vec<u8>, Error> {
+    let mut data = Vec::new();
+    DynamicImage::ImageRgba8(image).write_to(&mut Cursor::new(&mut data), ImageFormat::Png)?;
+
+    Ok(data)
+}
+
+/// Downloads the image at `url`, applies `effect` to it on the blocking pool
+/// and returns it as a PNG.
+///
+/// `effect` gets the deadline of the job so slow effects can stop early.
+async fn process<F>(url: &str, effect: F) -> Result<Vec<u8>, Error>
+where
+    F: FnOnce(RgbaImage, Instant) -> Result<RgbaImage, Error> + Send + 'static,
+{
+    let deadline = Instant::now() + TIME_LIMIT;
+
+    let job = async {
+        let data = download(url).await?;
+
+        // The permit is moved into the blocking task so a job that timed out
+        // keeps its slot until it actually stops.
+        let permit = POOL.clone().acquire_owned().await?;
+
+        tokio::task::spawn_blocking(move || {
+            let _permit = permit;
+
+            let image = decode(&data)?;
+            let image = effect(image, deadline)?;
+            encode(image)
+        })
+        .await?
+    };
+
+    tokio::time::timeout(TIME_LIMIT, job)
+        .await
+        .map_err(|_| Error::from("That image took too long to edit, try a smaller one."))?
+}
@@ -1,6 +1,9 @@
 use std::vec;
 
-use poise::{serenity_prelude::CreateEmbed, CreateReply};
+use poise::{
+    serenity_prelude::{CreateEmbed, User},
+    CreateReply,
+};
 use rayon::prelude::*;
 
 use super::super::EMBED_COLOR;
@@ -10,18 +13,27 @@ use crate::Error;
 #[poise::command(slash_command, broadcast_typing)]
 pub async fn userinfo(
     ctx: poise::Context<'_, (), Error>,
-    #[description = "The user to get info from."] user: Option<poise::serenity_prelude::User>,
+    #[description = "The user to get info from."] user: Option<User>,
 ) -> Result<(), Error> {
     ctx.defer().await?;
 
-    let mut user = user;
-    if user.is_none() {
-        user = Some(ctx.author().clone());
-    }
+    let user = user.unwrap_or_else(|| ctx.author().clone());
 
-    // User will never be `None` since we've already assured it isn't.
-    let user = user.expect("Unreachable");
+    send_user_info(ctx, &user).await
+}
+
+/// Display info about a user
+#[poise::command(context_menu_command = "User Info")]
+pub async fn user_info_menu(
+    ctx: poise::Context<'_, (), Error>,
+    #[description = "The user to get info from."] user: User,
+) -> Result<(), Error> {
+    ctx.defer().await?;
+
+    send_user_info(ctx, &user).await
+}
 
+async fn send_user_info(ctx: poise::Context<'_, (), Error>, user: &User) -> Result<(), Error> {
     let in_a_guild = ctx.guild_id().is_some();
 
     let banner = ctx.http().get_user(user.id).await?.banner_url();
@@ -0,0 +1,48 @@
+use poise::{serenity_prelude::CreateEmbed, CreateReply};
+
+use super::guild_curve;
+use crate::{commands::EMBED_COLOR, database, Error};
+
+/// How many users the leaderboard shows.
+const LEADERBOARD_SIZE: u64 = 10;
+
+/// See the most active members of this server
+#[poise::command(slash_command, guild_only)]
+pub async fn levels(ctx: poise::Context<'_, (), Error>) -> Result<(), Error> {
+    ctx.defer().await?;
+
+    // It's safe to use .expect() since the command is guild only.
+    let guild_id = ctx.guild_id().expect("Unreachable");
+
+    let curve = guild_curve(guild_id)?;
+    let members = database::get_xp_leaderboard(guild_id.get(), LEADERBOARD_SIZE)?;
+
+    let description = if members.is_empty() {
+        "Nobody has any XP yet, chat a bit to be the first!".to_string()
+    } else {
+        members
+            .iter()
+            .enumerate()
+            .map(|(i, (user_id, xp))| {
+                format!(
+                    "{}. <@{user_id}>: level **{}** ({xp} XP)",
+                    i + 1,
+                    curve.progress(*xp).level
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+
+    ctx.send(
+        CreateReply::default().embed(
+            CreateEmbed::new()
+                .title("Levels")
+                .description(description)
+                .color(EMBED_COLOR),
+        ),
+    )
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,197 @@
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use log::warn;
+use once_cell::sync::Lazy;
+use poise::serenity_prelude::{
+    self as serenity, CreateEmbed, CreateMessage, FullEvent, GuildId, Mentionable, Message, RoleId,
+    UserId,
+};
+use rand::Rng;
+
+use crate::{
+    commands::{moderation::automod, EMBED_COLOR},
+    database, Error,
+};
+
+pub mod levels;
+pub mod rank;
+pub mod settings;
+
+/// How much XP a message can give.
+const XP_PER_MESSAGE: RangeInclusive<u64> = 15..=25;
+
+/// How long users have to wait after getting XP to get it again, so spamming
+/// doesn't level anyone up.
+const XP_COOLDOWN: Duration = Duration::from_mins(1);
+
+/// When a user last got XP in each guild.
+static COOLDOWNS: Lazy<Mutex<HashMap<(GuildId, UserId), Instant>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// How much XP each level takes to complete, which is
+/// `base + linear * level + quadratic * level²`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct LevelCurve {
+    pub(crate) base: u64,
+    pub(crate) linear: u64,
+    pub(crate) quadratic: u64,
+}
+
+impl Default for LevelCurve {
+    fn default() -> Self {
+        Self {
+            base: 100,
+            linear: 50,
+            quadratic: 5,
+        }
+    }
+}
+
+impl LevelCurve {
+    /// The XP needed to go from `level` to the next one.
+    #[must_use]
+    pub fn xp_to_next(&self, level: u64) -> u64 {
+        self.quadratic
+            .saturating_mul(level.saturating_mul(level))
+            .saturating_add(self.linear.saturating_mul(level))
+            .saturating_add(self.base)
+            // A level that takes no XP would never end.
+            .max(1)
+    }
+
+    /// Gets the level reached with `xp`, and how far into it the user is.
+    #[must_use]
+    pub fn progress(&self, mut xp: u64) -> Progress {
+        let mut level = 0;
+        while xp >= self.xp_to_next(level) {
+            xp -= self.xp_to_next(level);
+            level += 1;
+        }
+
+        Progress {
+            level,
+            xp,
+            xp_needed: self.xp_to_next(level),
+        }
+    }
+}
+
+/// How far a user is in the levels of a guild.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Progress {
+    pub level: u64,
+    /// The XP earned since reaching `level`.
+    pub xp: u64,
+    /// The XP needed to reach the next level from `level`.
+    pub xp_needed: u64,
+}
+
+/// Gets the curve a guild uses, which is the default one unless it was changed.
+fn guild_curve(guild_id: GuildId) -> Result<LevelCurve, Error> {
+    Ok(database::get_level_curve(guild_id.get())?.unwrap_or_default())
+}
+
+/// Handles the events leveling is registered for.
+///
+/// # Errors
+///
+/// Fails if the XP couldn't be saved or the level up couldn't be announced.
+pub async fn handle_event(ctx: &serenity::Context, event: &FullEvent) -> Result<(), Error> {
+    match event {
+        FullEvent::Message { new_message } => on_message(ctx, new_message).await,
+        _ => Ok(()),
+    }
+}
+
+/// Gives XP for a message, then announces level ups and hands out the roles
+/// rewarded for them.
+async fn on_message(ctx: &serenity::Context, message: &Message) -> Result<(), Error> {
+    let Some(guild_id) = message.guild_id else {
+        return Ok(());
+    };
+    if message.author.bot || message.webhook_id.is_some() {
+        return Ok(());
+    }
+    // Auto-mod runs first, and messages it deleted shouldn't count.
+    if automod::take_moderated(message.id) {
+        return Ok(());
+    }
+
+    {
+        let now = Instant::now();
+        let mut cooldowns = COOLDOWNS.lock().expect("XP cooldowns lock was poisoned");
+
+        let key = (guild_id, message.author.id);
+        if cooldowns
+            .get(&key)
+            .is_some_and(|last| now.duration_since(*last) < XP_COOLDOWN)
+        {
+            return Ok(());
+        }
+
+        // Expired cooldowns don't do anything, so they're cleaned up every
+        // now and then to keep the map small.
+        if cooldowns.len() > 10_000 {
+            cooldowns.retain(|_, last| now.duration_since(*last) < XP_COOLDOWN);
+        }
+        cooldowns.insert(key, now);
+    }
+
+    let gained = rand::thread_rng().gen_range(XP_PER_MESSAGE);
+    let xp = database::add_xp(guild_id.get(), message.author.id, gained)?;
+
+    let curve = guild_curve(guild_id)?;
+    let previous_level = curve.progress(xp.saturating_sub(gained)).level;
+    let level = curve.progress(xp).level;
+    if level <= previous_level {
+        return Ok(());
+    }
+
+    let mut rewards = Vec::new();
+    for (reward_level, role_id) in database::get_level_rewards(guild_id.get())? {
+        if reward_level <= previous_level || reward_level > level {
+            continue;
+        }
+
+        let role_id = RoleId::new(role_id);
+        match ctx
+            .http
+            .add_member_role(
+                guild_id,
+                message.author.id,
+                role_id,
+                Some(&format!("Reached level {reward_level}")),
+            )
+            .await
+        {
+            Ok(()) => rewards.push(role_id.mention().to_string()),
+            Err(e) => warn!("Failed to give level reward {role_id} in {guild_id}: {e}"),
+        }
+    }
+
+    let mut description = format!("{} reached level **{level}**!", message.author.mention());
+    if !rewards.is_empty() {
+        description.push_str("\nYou got ");
+        description.push_str(&rewards.join(", "));
+        description.push('.');
+    }
+
+    message
+        .channel_id
+        .send_message(
+            ctx,
+            CreateMessage::default().embed(
+                CreateEmbed::new()
+                    .title("Level up!")
+                    .description(description)
+                    .thumbnail(message.author.face())
+                    .color(EMBED_COLOR),
+            ),
+        )
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use log::warn;
+use poise::{
+    serenity_prelude::{CreateAttachment, CreateEmbed, User},
+    CreateReply,
+};
+
+use super::guild_curve;
+use crate::{
+    commands::{
+        imaging::card::{self, RankCard},
+        EMBED_COLOR,
+    },
+    database, Error,
+};
+
+/// See your level in this server, or someone else's
+#[poise::command(slash_command, guild_only)]
+pub async fn rank(
+    ctx: poise::Context<'_, (), Error>,
+    #[description = "Whose rank to see."] user: Option<User>,
+) -> Result<(), Error> {
+    ctx.defer().await?;
+
+    // It's safe to use .expect() since the command is guild only.
+    let guild_id = ctx.guild_id().expect("Unreachable");
+
+    let user = user.as_ref().unwrap_or_else(|| ctx.author());
+    let (xp, rank) = database::get_xp_rank(guild_id.get(), user.id)?;
+    let progress = guild_curve(guild_id)?.progress(xp);
+
+    let card = RankCard {
+        name: user.name.clone(),
+        avatar_url: user.static_face(),
+        rank,
+        level: progress.level,
+        xp: progress.xp,
+        xp_needed: progress.xp_needed,
+    };
+
+    let reply = match card::render(card).await {
+        Ok(data) => CreateReply::default().attachment(CreateAttachment::bytes(data, "rank.png")),
+        // The card is only a nicer way to show the same thing, so an embed
+        // does if it can't be rendered.
+        Err(e) => {
+            warn!("Failed to render rank card: {e}");
+            CreateReply::default().embed(
+                CreateEmbed::new()
+                    .title(format!("{}'s rank", user.name))
+                    .field("Rank", format!("#{rank}"), true)
+                    .field("Level", progress.level.to_string(), true)
+                    .field(
+                        "XP",
+                        format!("{} / {}", progress.xp, progress.xp_needed),
+                        true,
+                    )
+                    .thumbnail(user.face())
+                    .color(EMBED_COLOR),
+            )
+        }
+    };
+
+    ctx.send(reply).await?;
+
+    Ok(())
+}
@@ -0,0 +1,198 @@
+use poise::{
+    serenity_prelude::{CreateEmbed, Mentionable, Role},
+    CreateReply,
+};
+
+use super::{guild_curve, LevelCurve};
+use crate::{
+    commands::{CONFIRM_EMBED_COLOR, CONFIRM_EMOJI, EMBED_COLOR, ERROR_EMBED_COLOR, ERROR_EMOJI},
+    database, Error,
+};
+
+/// Set up how members level up in this server
+#[poise::command(
+    slash_command,
+    guild_only,
+    required_permissions = "MANAGE_GUILD",
+    subcommands("curve", "reward", "unreward", "rewards"),
+    subcommand_required
+)]
+// This function needs to be async for poise to use it.
+#[allow(clippy::unused_async)]
+pub async fn leveling(_: poise::Context<'_, (), Error>) -> Result<(), Error> {
+    Ok(())
+}
+
+/// Change how much XP each level takes. Leave everything empty to see the current curve.
+#[poise::command(slash_command, guild_only, required_permissions = "MANAGE_GUILD")]
+pub async fn curve(
+    ctx: poise::Context<'_, (), Error>,
+    #[description = "The XP every level takes."]
+    #[min = 1]
+    #[max = 1_000_000]
+    base: Option<u64>,
+    #[description = "The XP added per level."]
+    #[max = 100_000]
+    linear: Option<u64>,
+    #[description = "The XP added per level squared."]
+    #[max = 10_000]
+    quadratic: Option<u64>,
+) -> Result<(), Error> {
+    ctx.defer().await?;
+
+    // It's safe to use .expect() since the command is guild only.
+    let guild_id = ctx.guild_id().expect("Unreachable");
+
+    let current = guild_curve(guild_id)?;
+    let (title, color) = if base.is_none() && linear.is_none() && quadratic.is_none() {
+        ("Level curve", EMBED_COLOR)
+    } else {
+        let curve = LevelCurve {
+            base: base.unwrap_or(current.base),
+            linear: linear.unwrap_or(current.linear),
+            quadratic: quadratic.unwrap_or(current.quadratic),
+        };
+        database::set_level_curve(guild_id.get(), &curve)?;
+
+        ("Level curve updated", CONFIRM_EMBED_COLOR)
+    };
+
+    let curve = guild_curve(guild_id)?;
+    let examples = [1, 5, 10, 25, 50]
+        .iter()
+        .map(|level| {
+            let total = (0..*level).fold(0_u64, |total, level| {
+                total.saturating_add(curve.xp_to_next(level))
+            });
+            format!("Level {level}: **{total}** XP")
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    ctx.send(
+        CreateReply::default().embed(
+            CreateEmbed::new()
+                .title(title)
+                .description(format!(
+                    "Each level takes `{} + {} × level + {} × level²` XP.\n\n{examples}",
+                    curve.base, curve.linear, curve.quadratic
+                ))
+                .color(color),
+        ),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Give a role to members when they reach a level
+#[poise::command(slash_command, guild_only, required_permissions = "MANAGE_GUILD")]
+pub async fn reward(
+    ctx: poise::Context<'_, (), Error>,
+    #[description = "The level to give the role at."]
+    #[min = 1]
+    level: u64,
+    #[description = "The role to give."] role: Role,
+) -> Result<(), Error> {
+    ctx.defer().await?;
+
+    // It's safe to use .expect() since the command is guild only.
+    let guild_id = ctx.guild_id().expect("Unreachable");
+
+    let error = if role.id.get() == guild_id.get() {
+        Some("Everyone already has that role.")
+    } else if role.managed {
+        Some("That role is managed by an integration, so it can't be given out.")
+    } else {
+        None
+    };
+
+    let embed = if let Some(error) = error {
+        CreateEmbed::new()
+            .title("Error")
+            .description(format!("{ERROR_EMOJI} {error}"))
+            .color(ERROR_EMBED_COLOR)
+    } else {
+        database::add_level_reward(guild_id.get(), level, role.id.get())?;
+
+        CreateEmbed::new()
+            .title("Level reward added")
+            .description(format!(
+                "{CONFIRM_EMOJI} Members will get {} when they reach level **{level}**.\nMake sure my role is above it so I can give it out.",
+                role.mention()
+            ))
+            .color(CONFIRM_EMBED_COLOR)
+    };
+
+    ctx.send(CreateReply::default().embed(embed)).await?;
+
+    Ok(())
+}
+
+/// Stop giving a role to members when they level up
+#[poise::command(slash_command, guild_only, required_permissions = "MANAGE_GUILD")]
+pub async fn unreward(
+    ctx: poise::Context<'_, (), Error>,
+    #[description = "The role to stop giving."] role: Role,
+) -> Result<(), Error> {
+    ctx.defer().await?;
+
+    // It's safe to use .expect() since the command is guild only.
+    let guild_id = ctx.guild_id().expect("Unreachable");
+
+    let embed = if database::remove_level_reward(guild_id.get(), role.id.get())? {
+        CreateEmbed::new()
+            .title("Level reward removed")
+            .description(format!(
+                "{CONFIRM_EMOJI} Members won't get {} for leveling up anymore.",
+                role.mention()
+            ))
+            .color(CONFIRM_EMBED_COLOR)
+    } else {
+        CreateEmbed::new()
+            .title("Error")
+            .description(format!(
+                "{ERROR_EMOJI} {} isn't a level reward.",
+                role.mention()
+            ))
+            .color(ERROR_EMBED_COLOR)
+    };
+
+    ctx.send(CreateReply::default().embed(embed)).await?;
+
+    Ok(())
+}
+
+/// See the roles members get for leveling up
+#[poise::command(slash_command, guild_only, required_permissions = "MANAGE_GUILD")]
+pub async fn rewards(ctx: poise::Context<'_, (), Error>) -> Result<(), Error> {
+    ctx.defer().await?;
+
+    // It's safe to use .expect() since the command is guild only.
+    let guild_id = ctx.guild_id().expect("Unreachable");
+
+    let mut rewards = database::get_level_rewards(guild_id.get())?;
+    rewards.sort_unstable();
+
+    let description = if rewards.is_empty() {
+        "There are no level rewards yet, add one with `/leveling reward`.".to_string()
+    } else {
+        rewards
+            .iter()
+            .map(|(level, role_id)| format!("Level **{level}**: <@&{role_id}>"))
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+
+    ctx.send(
+        CreateReply::default().embed(
+            CreateEmbed::new()
+                .title("Level rewards")
+                .description(description)
+                .color(EMBED_COLOR),
+        ),
+    )
+    .await?;
+
+    Ok(())
+}
@@ -1,9 +1,15 @@
-use crate::Error;
+use crate::{
+    events::{self, EventKind},
+    router, Error,
+};
 use poise::serenity_prelude::Colour;
 use poise::Command;
 
+pub mod economy;
 pub mod fun;
+pub mod imaging;
 pub mod info;
+pub mod leveling;
 pub mod moderation;
 pub mod utility;
 mod time;
@@ -35,7 +41,7 @@ impl CommandList {
     #[rustfmt::skip]
     #[must_use]    
     pub fn new() -> Self {
-        use crate::commands::{fun, info, moderation, utility};
+        use crate::commands::{economy, fun, imaging, info, leveling, moderation, utility};
 
         let commands = vec![
             // Fun Commands
@@ -50,6 +56,18 @@ impl CommandList {
             fun::action::action(),
             fun::wyr::would_you_rather(),
             fun::tod::truth_or_dare(),
+            fun::tod::tod(),
+            fun::trivia::trivia(),
+            fun::text::text(),
+
+            // Economy Commands
+            economy::daily::daily(),
+            economy::balance::balance(),
+            economy::pay::pay(),
+            economy::leaderboard::leaderboard(),
+
+            // Image Commands
+            imaging::edit::image(),
 
             // Info Commands
             info::invite::invite(),
@@ -58,13 +76,34 @@ impl CommandList {
             info::userinfo::userinfo(),
             info::suggest::suggest(),
 
+            // Leveling Commands
+            leveling::rank::rank(),
+            leveling::levels::levels(),
+            leveling::settings::leveling(),
+
             // Moderation Commands
             moderation::purge::purge(),
+            moderation::safety::safety(),
+            moderation::automod::settings::automod(),
 
             // Utility Commands
             utility::poll::poll(),
+            utility::greetings::greetings(),
+            utility::roles::roles(),
             utility::add_reminder::add_reminder(),
             utility::remove_reminder::remove_reminder(),
+            utility::reload_content::reload_content(),
+
+            // Context Menu Commands
+            // Discord allows at most 5 per type (user and message).
+            // User:
+            info::userinfo::user_info_menu(),
+            fun::action::hug_user(),
+            fun::action::pat_user(),
+            // Message:
+            fun::text::owoify_message(),
+            fun::text::transform_message(),
+            utility::add_reminder::remind_about_message(),
         ];
 
         Self(commands)
@@ -78,3 +117,63 @@ impl CommandList {
         self.0
     }
 }
+
+/// Registers the handlers of the commands that send message components.
+pub fn register_component_handlers() {
+    use crate::commands::fun::{text, tod, trivia, wyr};
+    use crate::commands::utility::roles;
+
+    router::register(text::NAMESPACE, |ctx, interaction, id| {
+        Box::pin(text::receive_interaction(ctx, interaction, id))
+    });
+    router::register(tod::NAMESPACE, |ctx, interaction, id| {
+        Box::pin(tod::receive_interaction(ctx, interaction, id))
+    });
+    router::register(tod::SESSION_NAMESPACE, |ctx, interaction, id| {
+        Box::pin(tod::receive_session_interaction(ctx, interaction, id))
+    });
+    router::register(trivia::NAMESPACE, |ctx, interaction, id| {
+        Box::pin(trivia::receive_interaction(ctx, interaction, id))
+    });
+    router::register(wyr::NAMESPACE, |ctx, interaction, id| {
+        Box::pin(wyr::receive_interaction(ctx, interaction, id))
+    });
+    router::register(roles::NAMESPACE, |ctx, interaction, id| {
+        Box::pin(roles::receive_interaction(ctx, interaction, id))
+    });
+}
+
+/// Registers the handlers of the features that react to gateway events.
+pub fn register_event_handlers() {
+    use crate::commands::{
+        leveling,
+        moderation::automod,
+        utility::{greetings, roles},
+    };
+    use poise::serenity_prelude::GatewayIntents;
+
+    // Auto-mod can't check messages without reading them. It runs before
+    // everything else, so other features can skip the messages it deleted.
+    for kind in [EventKind::Message, EventKind::MessageUpdate] {
+        events::register_with_intents(
+            kind,
+            "Auto-mod",
+            GatewayIntents::MESSAGE_CONTENT,
+            |ctx, event| Box::pin(automod::handle_event(ctx, event)),
+        );
+    }
+    events::register(EventKind::Message, "Leveling", |ctx, event| {
+        Box::pin(leveling::handle_event(ctx, event))
+    });
+    events::register(EventKind::GuildMemberAddition, "Greetings", |ctx, event| {
+        Box::pin(greetings::handle_event(ctx, event))
+    });
+    events::register(EventKind::GuildMemberRemoval, "Greetings", |ctx, event| {
+        Box::pin(greetings::handle_event(ctx, event))
+    });
+    events::register(
+        EventKind::GuildMemberAddition,
+        "Auto-roles",
+        |ctx, event| Box::pin(roles::handle_event(ctx, event)),
+    );
+}
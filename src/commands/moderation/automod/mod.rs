@@ -0,0 +1,691 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt::Write;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::ops::RangeInclusive;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use log::{info, warn};
+use once_cell::sync::Lazy;
+use poise::serenity_prelude::{
+    self as serenity, ChannelId, CreateEmbed, CreateMessage, EditMember, FullEvent, GuildId,
+    Mentionable, MessageId, Permissions, Timestamp, User, UserId,
+};
+use regex::{Regex, RegexSet, RegexSetBuilder};
+
+use crate::{
+    commands::{EMBED_COLOR, ERROR_EMBED_COLOR},
+    database, Error,
+};
+
+pub mod settings;
+
+/// How long messages count towards the duplicate message rule.
+const SPAM_WINDOW: Duration = Duration::from_secs(30);
+/// The most recent messages remembered per member for the duplicate message
+/// rule.
+const SPAM_HISTORY: usize = 20;
+
+/// Messages with fewer cased letters than this are never too loud, so short
+/// ones like "OK" are fine.
+const MIN_CAPS_LETTERS: usize = 10;
+
+/// How long a trigger counts towards escalations.
+const STRIKE_WINDOW: Duration = Duration::from_hours(24);
+/// How long the timeout action times members out for, unless an escalation
+/// makes it longer.
+const RULE_TIMEOUT: Duration = Duration::from_mins(10);
+
+/// How long the rules of a guild are kept in memory before being loaded
+/// again. Changing them through `/automod` reloads them right away.
+const CONFIG_TTL: Duration = Duration::from_mins(5);
+
+/// The most memory a guild's compiled blocklist can use, so no pattern can
+/// make checking every message slow.
+const BLOCKLIST_SIZE_LIMIT: usize = 1 << 20;
+
+static INVITE_LINK: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)\b(?:discord(?:app)?\.com/invite|discord\.gg|dsc\.gg)/[a-z0-9-]+")
+        .expect("Invite link regex is invalid")
+});
+
+type CachedConfig = (Instant, Arc<GuildConfig>);
+type SentHashes = VecDeque<(Instant, u64)>;
+
+/// The rules of each guild, and when they were loaded.
+static CONFIGS: Lazy<Mutex<HashMap<GuildId, CachedConfig>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// New messages auto-mod acted on, until the features handling messages after
+/// it check them with [`take_moderated`].
+static MODERATED: Lazy<Mutex<HashSet<MessageId>>> = Lazy::new(|| Mutex::new(HashSet::new()));
+
+/// The hashes of the latest messages of each member, for the duplicate
+/// message rule.
+static RECENT_MESSAGES: Lazy<Mutex<HashMap<(GuildId, UserId), SentHashes>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Something auto-mod looks for in messages.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Default)]
+pub enum RuleKind {
+    /// The same message sent `threshold` times in `SPAM_WINDOW`.
+    #[default]
+    Spam,
+    /// Discord invite links.
+    Invites,
+    /// More than `threshold` mentions in one message.
+    Mentions,
+    /// Messages where at least `threshold` percent of the letters are capitals.
+    Caps,
+    /// Messages matching the guild's blocklist.
+    Words,
+}
+
+impl RuleKind {
+    pub const ALL: [Self; 5] = [
+        Self::Spam,
+        Self::Invites,
+        Self::Mentions,
+        Self::Caps,
+        Self::Words,
+    ];
+
+    /// The identifier used to store this rule in the database.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Spam => "spam",
+            Self::Invites => "invites",
+            Self::Mentions => "mentions",
+            Self::Caps => "caps",
+            Self::Words => "words",
+        }
+    }
+
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|kind| kind.as_str().eq_ignore_ascii_case(name.trim()))
+    }
+
+    #[must_use]
+    pub const fn title(self) -> &'static str {
+        match self {
+            Self::Spam => "Duplicate messages",
+            Self::Invites => "Invite links",
+            Self::Mentions => "Mass mentions",
+            Self::Caps => "Excessive caps",
+            Self::Words => "Blocked words",
+        }
+    }
+
+    /// The threshold used when none is given, for the rules that have one.
+    #[must_use]
+    pub const fn default_threshold(self) -> Option<u64> {
+        match self {
+            Self::Spam => Some(3),
+            Self::Mentions => Some(5),
+            Self::Caps => Some(70),
+            Self::Invites | Self::Words => None,
+        }
+    }
+
+    /// The thresholds that make sense for the rules that have one.
+    #[must_use]
+    pub const fn thresholds(self) -> Option<RangeInclusive<u64>> {
+        match self {
+            Self::Spam => Some(2..=20),
+            Self::Mentions => Some(1..=50),
+            Self::Caps => Some(1..=100),
+            Self::Invites | Self::Words => None,
+        }
+    }
+
+    /// What breaks the rule.
+    #[must_use]
+    pub fn describe(self, threshold: u64) -> String {
+        match self {
+            Self::Spam => format!(
+                "Sending the same message {threshold} times in {} seconds",
+                SPAM_WINDOW.as_secs()
+            ),
+            Self::Invites => "Sending Discord invite links".to_string(),
+            Self::Mentions => format!("Mentioning more than {threshold} users or roles at once"),
+            Self::Caps => {
+                format!("Messages where {threshold}% or more of the letters are capitals")
+            }
+            Self::Words => "Messages matching the blocklist".to_string(),
+        }
+    }
+
+    /// What members who break the rule are told.
+    const fn warning(self) -> &'static str {
+        match self {
+            Self::Spam => "please don't send the same message over and over.",
+            Self::Invites => "invite links aren't allowed here.",
+            Self::Mentions => "please don't mention so many people at once.",
+            Self::Caps => "please don't use so many capital letters.",
+            Self::Words => "that message contained a blocked word.",
+        }
+    }
+}
+
+/// What auto-mod does when a rule is broken.
+///
+/// The message is always deleted.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub enum RuleAction {
+    #[default]
+    Delete,
+    /// Tells the member why their message was deleted.
+    Warn,
+    /// Warns the member and times them out.
+    Timeout,
+}
+
+impl RuleAction {
+    pub const ALL: [Self; 3] = [Self::Delete, Self::Warn, Self::Timeout];
+
+    /// The identifier used to store this action in the database.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Delete => "delete",
+            Self::Warn => "warn",
+            Self::Timeout => "timeout",
+        }
+    }
+
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|action| action.as_str().eq_ignore_ascii_case(name.trim()))
+    }
+}
+
+/// A rule a guild turned on.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub struct AutomodRule {
+    pub(crate) guild_id: u64,
+    pub(crate) kind: RuleKind,
+    pub(crate) action: RuleAction,
+    /// What counts as breaking the rule, see [`RuleKind`]. Rules without a
+    /// threshold store 0.
+    pub(crate) threshold: u64,
+}
+
+/// A message that broke a rule, and what was done about it. What the message
+/// said is only sent to the log channel, never stored.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub struct AutomodTrigger {
+    pub(crate) guild_id: u64,
+    pub(crate) user_id: u64,
+    pub(crate) channel_id: u64,
+    pub(crate) kind: RuleKind,
+    /// The action that was taken, which is a timeout if the rule's action was
+    /// escalated.
+    pub(crate) action: RuleAction,
+    pub(crate) timestamp: u64,
+}
+
+/// Everything auto-mod needs to check the messages of a guild.
+#[derive(Debug, Default)]
+struct GuildConfig {
+    rules: Vec<AutomodRule>,
+    blocklist: Option<RegexSet>,
+    /// The timeout in minutes given once a member reaches a number of strikes.
+    escalations: Vec<(u64, u64)>,
+    log_channel_id: Option<u64>,
+}
+
+/// The parts of a new or edited message that are checked.
+struct Checked<'a> {
+    guild_id: GuildId,
+    channel_id: ChannelId,
+    message_id: MessageId,
+    author: &'a User,
+    content: &'a str,
+    mentions: usize,
+    /// Edits don't count towards the duplicate message rule, since they
+    /// aren't new messages.
+    is_edit: bool,
+}
+
+/// Compiles a guild's blocklist, matching regardless of case.
+///
+/// # Errors
+///
+/// Fails if a pattern is invalid or the blocklist is too big.
+pub fn compile_blocklist(patterns: &[String]) -> Result<RegexSet, regex::Error> {
+    RegexSetBuilder::new(patterns)
+        .case_insensitive(true)
+        .size_limit(BLOCKLIST_SIZE_LIMIT)
+        .build()
+}
+
+/// Makes the next message of a guild load its rules again, so changes apply
+/// right away.
+pub fn invalidate(guild_id: GuildId) {
+    CONFIGS
+        .lock()
+        .expect("Auto-mod config lock was poisoned")
+        .remove(&guild_id);
+}
+
+fn config(guild_id: GuildId) -> Result<Arc<GuildConfig>, Error> {
+    if let Some((loaded, config)) = CONFIGS
+        .lock()
+        .expect("Auto-mod config lock was poisoned")
+        .get(&guild_id)
+    {
+        if loaded.elapsed() < CONFIG_TTL {
+            return Ok(Arc::clone(config));
+        }
+    }
+
+    let rules = database::get_automod_rules(guild_id.get())?;
+    // The blocklist is only needed if the rule using it is on.
+    let blocklist = if rules.iter().any(|rule| rule.kind == RuleKind::Words) {
+        let patterns = database::get_blocked_patterns(guild_id.get())?;
+        if patterns.is_empty() {
+            None
+        } else {
+            compile_blocklist(&patterns)
+                .inspect_err(|e| warn!("Failed to compile the blocklist of {guild_id}: {e}"))
+                .ok()
+        }
+    } else {
+        None
+    };
+
+    let config = Arc::new(GuildConfig {
+        rules,
+        blocklist,
+        escalations: database::get_automod_escalations(guild_id.get())?,
+        log_channel_id: database::get_automod_log_channel(guild_id.get())?,
+    });
+
+    CONFIGS
+        .lock()
+        .expect("Auto-mod config lock was poisoned")
+        .insert(guild_id, (Instant::now(), Arc::clone(&config)));
+
+    Ok(config)
+}
+
+/// Whether auto-mod acted on a new message, so features like leveling don't
+/// reward it. Each message is only reported once.
+pub fn take_moderated(message_id: MessageId) -> bool {
+    MODERATED
+        .lock()
+        .expect("Moderated messages lock was poisoned")
+        .remove(&message_id)
+}
+
+/// Handles the events auto-mod is registered for.
+///
+/// # Errors
+///
+/// Fails if the rules couldn't be loaded or the trigger couldn't be saved.
+pub async fn handle_event(ctx: &serenity::Context, event: &FullEvent) -> Result<(), Error> {
+    let checked = match event {
+        FullEvent::Message { new_message } => {
+            let Some(guild_id) = new_message.guild_id else {
+                return Ok(());
+            };
+            if new_message.webhook_id.is_some() {
+                return Ok(());
+            }
+
+            Checked {
+                guild_id,
+                channel_id: new_message.channel_id,
+                message_id: new_message.id,
+                author: &new_message.author,
+                content: &new_message.content,
+                mentions: new_message.mentions.len()
+                    + new_message.mention_roles.len()
+                    + usize::from(new_message.mention_everyone),
+                is_edit: false,
+            }
+        }
+        // Edits are checked too, or rules could be dodged by editing a
+        // message after sending it. Updates without content, like embeds
+        // loading, aren't edits.
+        FullEvent::MessageUpdate { event, .. } => {
+            let (Some(guild_id), Some(author), Some(content)) = (
+                event.guild_id,
+                event.author.as_ref(),
+                event.content.as_deref(),
+            ) else {
+                return Ok(());
+            };
+            if matches!(event.webhook_id, Some(Some(_))) {
+                return Ok(());
+            }
+
+            Checked {
+                guild_id,
+                channel_id: event.channel_id,
+                message_id: event.id,
+                author,
+                content,
+                mentions: event.mentions.as_ref().map_or(0, Vec::len)
+                    + event.mention_roles.as_ref().map_or(0, Vec::len)
+                    + usize::from(event.mention_everyone.unwrap_or_default()),
+                is_edit: true,
+            }
+        }
+        _ => return Ok(()),
+    };
+
+    if checked.author.bot {
+        return Ok(());
+    }
+
+    check(ctx, &checked).await
+}
+
+async fn check(ctx: &serenity::Context, message: &Checked<'_>) -> Result<(), Error> {
+    let config = config(message.guild_id)?;
+    if config.rules.is_empty() {
+        return Ok(());
+    }
+
+    let duplicates =
+        if message.is_edit || !config.rules.iter().any(|rule| rule.kind == RuleKind::Spam) {
+            0
+        } else {
+            count_duplicates(message)
+        };
+
+    // Only the first rule broken is enforced, in the order of `RuleKind::ALL`.
+    let broken = RuleKind::ALL
+        .iter()
+        .filter_map(|kind| config.rules.iter().find(|rule| rule.kind == *kind))
+        .find(|rule| breaks(rule, message, duplicates, config.blocklist.as_ref()));
+    let Some(rule) = broken else {
+        return Ok(());
+    };
+
+    // Moderators can break the rules, which only happens rarely enough that
+    // fetching their permissions each time is fine.
+    let guild = message.guild_id.to_partial_guild(ctx).await?;
+    let member = message.guild_id.member(ctx, message.author.id).await?;
+    if guild.owner_id == message.author.id
+        || guild
+            .member_permissions(&member)
+            .contains(Permissions::MANAGE_MESSAGES)
+    {
+        return Ok(());
+    }
+
+    // Edits have already been handled by everything else.
+    if !message.is_edit {
+        let mut moderated = MODERATED
+            .lock()
+            .expect("Moderated messages lock was poisoned");
+        // Entries are only left behind if no feature checks them, so this
+        // never grows far, but it's bounded anyway.
+        if moderated.len() > 10_000 {
+            moderated.clear();
+        }
+        moderated.insert(message.message_id);
+    }
+
+    enforce(ctx, &config, rule, message).await
+}
+
+fn breaks(
+    rule: &AutomodRule,
+    message: &Checked<'_>,
+    duplicates: usize,
+    blocklist: Option<&RegexSet>,
+) -> bool {
+    match rule.kind {
+        RuleKind::Spam => duplicates as u64 >= rule.threshold,
+        RuleKind::Invites => INVITE_LINK.is_match(message.content),
+        RuleKind::Mentions => message.mentions as u64 > rule.threshold,
+        RuleKind::Caps => {
+            let (letters, capitals) = message
+                .content
+                .chars()
+                .filter(|c| c.is_lowercase() || c.is_uppercase())
+                .fold((0, 0), |(letters, capitals), c| {
+                    (letters + 1, capitals + usize::from(c.is_uppercase()))
+                });
+
+            letters >= MIN_CAPS_LETTERS && capitals as u64 * 100 >= letters as u64 * rule.threshold
+        }
+        RuleKind::Words => blocklist.is_some_and(|blocklist| blocklist.is_match(message.content)),
+    }
+}
+
+/// Remembers a message, and counts how many times the member sent it in
+/// `SPAM_WINDOW`, including this time.
+fn count_duplicates(message: &Checked<'_>) -> usize {
+    let content = message.content.trim().to_lowercase();
+    // Messages with only attachments or stickers have no text to compare.
+    if content.is_empty() {
+        return 0;
+    }
+
+    let mut hasher = DefaultHasher::new();
+    content.hash(&mut hasher);
+    let hash = hasher.finish();
+
+    let now = Instant::now();
+    let mut recent = RECENT_MESSAGES
+        .lock()
+        .expect("Recent messages lock was poisoned");
+
+    // Members who stopped talking don't need to be remembered, so they're
+    // cleaned up every now and then to keep the map small.
+    if recent.len() > 10_000 {
+        recent.retain(|_, messages| {
+            messages
+                .back()
+                .is_some_and(|(sent, _)| now.duration_since(*sent) < SPAM_WINDOW)
+        });
+    }
+
+    let messages = recent
+        .entry((message.guild_id, message.author.id))
+        .or_default();
+    messages.retain(|(sent, _)| now.duration_since(*sent) < SPAM_WINDOW);
+    if messages.len() >= SPAM_HISTORY {
+        messages.pop_front();
+    }
+    messages.push_back((now, hash));
+
+    let count = messages.iter().filter(|(_, sent)| *sent == hash).count();
+    drop(recent);
+    count
+}
+
+/// Deletes a message that broke a rule, then warns or times out its author
+/// depending on the rule and how often they broke rules lately.
+async fn enforce(
+    ctx: &serenity::Context,
+    config: &GuildConfig,
+    rule: &AutomodRule,
+    message: &Checked<'_>,
+) -> Result<(), Error> {
+    let reason = format!("Auto-mod: {}", rule.kind.title());
+
+    if let Err(e) = ctx
+        .http
+        .delete_message(message.channel_id, message.message_id, Some(&reason))
+        .await
+    {
+        warn!(
+            "Failed to delete a message breaking {} in {}: {e}",
+            rule.kind.as_str(),
+            message.guild_id
+        );
+    }
+
+    let now = now();
+    let window_start = now.saturating_sub(STRIKE_WINDOW.as_secs());
+    let strikes = database::count_automod_triggers(
+        message.guild_id.get(),
+        message.author.id.get(),
+        window_start,
+    )? + 1;
+
+    // The highest escalation reached replaces the rule's own timeout if it's
+    // longer.
+    let escalation = config
+        .escalations
+        .iter()
+        .filter(|(reached, _)| *reached <= strikes)
+        .max_by_key(|(reached, _)| *reached)
+        .map(|(_, minutes)| Duration::from_mins(*minutes));
+    let timeout = match (rule.action, escalation) {
+        (RuleAction::Timeout, escalation) => {
+            Some(escalation.map_or(RULE_TIMEOUT, |e| e.max(RULE_TIMEOUT)))
+        }
+        (_, escalation) => escalation,
+    };
+    let action = if timeout.is_some() {
+        RuleAction::Timeout
+    } else {
+        rule.action
+    };
+
+    // Triggers older than the strike window don't count anymore, so they're
+    // pruned before logging a new one.
+    database::remove_automod_triggers_before(window_start)?;
+    database::add_automod_trigger(&AutomodTrigger {
+        guild_id: message.guild_id.get(),
+        user_id: message.author.id.get(),
+        channel_id: message.channel_id.get(),
+        kind: rule.kind,
+        action,
+        timestamp: now,
+    })?;
+    info!(
+        "Auto-mod took action {} against {} in {} for {} ({strikes} strikes)",
+        action.as_str(),
+        message.author.id,
+        message.guild_id,
+        rule.kind.as_str()
+    );
+
+    let outcome = if let Some(timeout) = timeout {
+        let until = Timestamp::from_unix_timestamp(i64::try_from(now + timeout.as_secs())?)?;
+        match message
+            .guild_id
+            .edit_member(
+                ctx,
+                message.author.id,
+                EditMember::new()
+                    .disable_communication_until_datetime(until)
+                    .audit_log_reason(&reason),
+            )
+            .await
+        {
+            Ok(_) => format!(
+                "Deleted the message and timed the member out for {} minutes",
+                timeout.as_secs() / 60
+            ),
+            Err(e) => {
+                warn!(
+                    "Failed to time out {} in {}: {e}",
+                    message.author.id, message.guild_id
+                );
+                "Deleted the message, but couldn't time the member out".to_string()
+            }
+        }
+    } else if action == RuleAction::Warn {
+        "Deleted the message and warned the member".to_string()
+    } else {
+        "Deleted the message".to_string()
+    };
+
+    if action != RuleAction::Delete {
+        let mut warning = format!("{}, {}", message.author.mention(), rule.kind.warning());
+        if let Some(timeout) = timeout {
+            let _ = write!(
+                warning,
+                "\nYou've been timed out for {} minutes.",
+                timeout.as_secs() / 60
+            );
+        }
+
+        if let Err(e) = message
+            .channel_id
+            .send_message(
+                ctx,
+                CreateMessage::new().embed(
+                    CreateEmbed::new()
+                        .title(rule.kind.title())
+                        .description(warning)
+                        .color(ERROR_EMBED_COLOR),
+                ),
+            )
+            .await
+        {
+            warn!("Failed to warn a member in {}: {e}", message.guild_id);
+        }
+    }
+
+    if let Some(log_channel_id) = config.log_channel_id {
+        let content = if message.content.is_empty() {
+            "*No text*".to_string()
+        } else {
+            message.content.chars().take(1024).collect()
+        };
+
+        if let Err(e) = ChannelId::new(log_channel_id)
+            .send_message(
+                ctx,
+                CreateMessage::new().embed(
+                    CreateEmbed::new()
+                        .title(format!("Auto-mod: {}", rule.kind.title()))
+                        .field(
+                            "Member",
+                            format!("{} ({})", message.author.mention(), message.author.id),
+                            true,
+                        )
+                        .field("Channel", message.channel_id.mention().to_string(), true)
+                        .field(
+                            "Strikes",
+                            format!(
+                                "{strikes} in the last {} hours",
+                                STRIKE_WINDOW.as_secs() / 3600
+                            ),
+                            true,
+                        )
+                        .field("Action", outcome, false)
+                        .field(
+                            if message.is_edit {
+                                "Edited message"
+                            } else {
+                                "Message"
+                            },
+                            content,
+                            false,
+                        )
+                        .color(EMBED_COLOR),
+                ),
+            )
+            .await
+        {
+            warn!(
+                "Failed to send to the auto-mod log channel of {}: {e}",
+                message.guild_id
+            );
+        }
+    }
+
+    Ok(())
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs())
+}
@@ -0,0 +1,461 @@
+use log::error;
+use poise::{
+    serenity_prelude::{CreateEmbed, GuildChannel, Mentionable},
+    CreateReply,
+};
+
+use super::{compile_blocklist, invalidate, AutomodRule, RuleAction, RuleKind};
+use crate::{
+    commands::{CONFIRM_EMBED_COLOR, CONFIRM_EMOJI, EMBED_COLOR, ERROR_EMBED_COLOR, ERROR_EMOJI},
+    database, Error,
+};
+
+/// The most patterns a guild's blocklist can have.
+const MAX_PATTERNS: usize = 50;
+
+/// Set up automatic moderation for this server
+#[poise::command(
+    slash_command,
+    guild_only,
+    required_permissions = "MANAGE_GUILD",
+    subcommands("enable", "disable", "block", "unblock", "escalate", "log", "settings"),
+    subcommand_required
+)]
+// This function needs to be async for poise to use it.
+#[allow(clippy::unused_async)]
+pub async fn automod(_: poise::Context<'_, (), Error>) -> Result<(), Error> {
+    Ok(())
+}
+
+/// Turn on a rule, or change what it does
+#[poise::command(slash_command, guild_only, required_permissions = "MANAGE_GUILD")]
+pub async fn enable(
+    ctx: poise::Context<'_, (), Error>,
+    #[description = "The rule to turn on."]
+    #[autocomplete = "rule_autocomplete"]
+    rule: String,
+    #[description = "What to do with messages breaking the rule."]
+    #[autocomplete = "action_autocomplete"]
+    action: String,
+    #[description = "How many duplicates, mentions or percent of capitals break the rule."]
+    threshold: Option<u64>,
+) -> Result<(), Error> {
+    ctx.defer().await?;
+
+    // It's safe to use .expect() since the command is guild only.
+    let guild_id = ctx.guild_id().expect("Unreachable");
+
+    let (Some(kind), Some(action)) = (RuleKind::from_name(&rule), RuleAction::from_name(&action))
+    else {
+        ctx.send(error_reply(
+            "Unknown rule or action. Valid rules are `spam`, `invites`, `mentions`, `caps` and `words`, and valid actions are `delete`, `warn` and `timeout`.",
+        ))
+        .await?;
+        return Ok(());
+    };
+
+    let threshold = match (kind.thresholds(), threshold) {
+        (Some(range), Some(threshold)) if !range.contains(&threshold) => {
+            ctx.send(error_reply(&format!(
+                "The threshold of `{}` has to be between {} and {}.",
+                kind.as_str(),
+                range.start(),
+                range.end()
+            )))
+            .await?;
+            return Ok(());
+        }
+        (Some(_), threshold) => threshold
+            .or_else(|| kind.default_threshold())
+            .unwrap_or_default(),
+        (None, _) => 0,
+    };
+
+    database::set_automod_rule(&AutomodRule {
+        guild_id: guild_id.get(),
+        kind,
+        action,
+        threshold,
+    })?;
+    invalidate(guild_id);
+
+    let mut description = format!(
+        "{CONFIRM_EMOJI} {}: messages breaking it get the `{}` action.",
+        kind.describe(threshold),
+        action.as_str()
+    );
+    if kind == RuleKind::Words && database::get_blocked_patterns(guild_id.get())?.is_empty() {
+        description.push_str("\nThe blocklist is empty, add words to it with `/automod block`.");
+    }
+
+    ctx.send(
+        CreateReply::default().embed(
+            CreateEmbed::new()
+                .title(format!("{} rule turned on", kind.title()))
+                .description(description)
+                .color(CONFIRM_EMBED_COLOR),
+        ),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Turn off a rule
+#[poise::command(slash_command, guild_only, required_permissions = "MANAGE_GUILD")]
+pub async fn disable(
+    ctx: poise::Context<'_, (), Error>,
+    #[description = "The rule to turn off."]
+    #[autocomplete = "rule_autocomplete"]
+    rule: String,
+) -> Result<(), Error> {
+    ctx.defer().await?;
+
+    // It's safe to use .expect() since the command is guild only.
+    let guild_id = ctx.guild_id().expect("Unreachable");
+
+    let reply = match RuleKind::from_name(&rule) {
+        None => error_reply(
+            "Unknown rule. Valid rules are `spam`, `invites`, `mentions`, `caps` and `words`.",
+        ),
+        Some(kind) if database::remove_automod_rule(guild_id.get(), kind)? => {
+            invalidate(guild_id);
+
+            CreateReply::default().embed(
+                CreateEmbed::new()
+                    .title(format!("{} rule turned off", kind.title()))
+                    .description(format!(
+                        "{CONFIRM_EMOJI} Messages won't be checked for it anymore."
+                    ))
+                    .color(CONFIRM_EMBED_COLOR),
+            )
+        }
+        Some(kind) => error_reply(&format!("The `{}` rule isn't on.", kind.as_str())),
+    };
+
+    ctx.send(reply).await?;
+
+    Ok(())
+}
+
+/// Add a word or regular expression to the blocklist
+#[poise::command(slash_command, guild_only, required_permissions = "MANAGE_GUILD")]
+pub async fn block(
+    ctx: poise::Context<'_, (), Error>,
+    #[description = "A word, or a regular expression like `free\\s*nitro`. Case doesn't matter."]
+    // Longer entries couldn't be picked in /automod unblock.
+    #[max_length = 100]
+    pattern: String,
+) -> Result<(), Error> {
+    ctx.defer().await?;
+
+    // It's safe to use .expect() since the command is guild only.
+    let guild_id = ctx.guild_id().expect("Unreachable");
+
+    let mut patterns = database::get_blocked_patterns(guild_id.get())?;
+    let error = if patterns.contains(&pattern) {
+        Some("That's already on the blocklist.".to_string())
+    } else if patterns.len() >= MAX_PATTERNS {
+        Some(format!(
+            "The blocklist can't have more than {MAX_PATTERNS} entries, try combining some with `|`."
+        ))
+    } else {
+        // The whole blocklist is compiled so it can't grow past the size limit.
+        patterns.push(pattern.clone());
+        compile_blocklist(&patterns)
+            .err()
+            .map(|e| format!("That isn't a valid regular expression:\n```\n{e}\n```"))
+    };
+
+    if let Some(error) = error {
+        ctx.send(error_reply(&error)).await?;
+        return Ok(());
+    }
+
+    database::add_blocked_pattern(guild_id.get(), &pattern)?;
+    invalidate(guild_id);
+
+    let mut description = format!("{CONFIRM_EMOJI} Added `{pattern}` to the blocklist.");
+    if !database::get_automod_rules(guild_id.get())?
+        .iter()
+        .any(|rule| rule.kind == RuleKind::Words)
+    {
+        description.push_str("\nTurn on the `words` rule with `/automod enable` to use it.");
+    }
+
+    ctx.send(
+        CreateReply::default().embed(
+            CreateEmbed::new()
+                .title("Blocklist updated")
+                .description(description)
+                .color(CONFIRM_EMBED_COLOR),
+        ),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Remove a word or regular expression from the blocklist
+#[poise::command(slash_command, guild_only, required_permissions = "MANAGE_GUILD")]
+pub async fn unblock(
+    ctx: poise::Context<'_, (), Error>,
+    #[description = "The entry to remove."]
+    #[autocomplete = "pattern_autocomplete"]
+    pattern: String,
+) -> Result<(), Error> {
+    ctx.defer().await?;
+
+    // It's safe to use .expect() since the command is guild only.
+    let guild_id = ctx.guild_id().expect("Unreachable");
+
+    let reply = if database::remove_blocked_pattern(guild_id.get(), &pattern)? {
+        invalidate(guild_id);
+
+        CreateReply::default().embed(
+            CreateEmbed::new()
+                .title("Blocklist updated")
+                .description(format!(
+                    "{CONFIRM_EMOJI} Removed `{pattern}` from the blocklist."
+                ))
+                .color(CONFIRM_EMBED_COLOR),
+        )
+    } else {
+        error_reply("That isn't on the blocklist.")
+    };
+
+    ctx.send(reply).await?;
+
+    Ok(())
+}
+
+/// Time out members who break rules too often. Leave the minutes empty to remove a step.
+#[poise::command(slash_command, guild_only, required_permissions = "MANAGE_GUILD")]
+pub async fn escalate(
+    ctx: poise::Context<'_, (), Error>,
+    #[description = "How many times a member has to break rules in a day."]
+    #[min = 1]
+    #[max = 100]
+    strikes: u64,
+    #[description = "How many minutes to time them out for."]
+    #[min = 1]
+    // Discord doesn't allow timeouts longer than 28 days.
+    #[max = 40320]
+    minutes: Option<u64>,
+) -> Result<(), Error> {
+    ctx.defer().await?;
+
+    // It's safe to use .expect() since the command is guild only.
+    let guild_id = ctx.guild_id().expect("Unreachable");
+
+    let reply = match minutes {
+        Some(minutes) => {
+            database::set_automod_escalation(guild_id.get(), strikes, minutes)?;
+            invalidate(guild_id);
+
+            CreateReply::default().embed(
+                CreateEmbed::new()
+                    .title("Escalation updated")
+                    .description(format!(
+                        "{CONFIRM_EMOJI} Members breaking rules {strikes} times in a day will be timed out for {minutes} minutes."
+                    ))
+                    .color(CONFIRM_EMBED_COLOR),
+            )
+        }
+        None if database::remove_automod_escalation(guild_id.get(), strikes)? => {
+            invalidate(guild_id);
+
+            CreateReply::default().embed(
+                CreateEmbed::new()
+                    .title("Escalation removed")
+                    .description(format!(
+                        "{CONFIRM_EMOJI} Members won't be timed out for breaking rules {strikes} times anymore."
+                    ))
+                    .color(CONFIRM_EMBED_COLOR),
+            )
+        }
+        None => error_reply(&format!("There's no escalation at {strikes} strikes.")),
+    };
+
+    ctx.send(reply).await?;
+
+    Ok(())
+}
+
+/// Choose where auto-mod logs what it does. Leave the channel empty to stop logging.
+#[poise::command(slash_command, guild_only, required_permissions = "MANAGE_GUILD")]
+pub async fn log(
+    ctx: poise::Context<'_, (), Error>,
+    #[description = "The channel to log to."]
+    #[channel_types("Text")]
+    channel: Option<GuildChannel>,
+) -> Result<(), Error> {
+    ctx.defer().await?;
+
+    // It's safe to use .expect() since the command is guild only.
+    let guild_id = ctx.guild_id().expect("Unreachable");
+
+    let reply = match channel {
+        Some(channel) if channel.guild_id != guild_id => {
+            error_reply("That channel isn't in this server.")
+        }
+        Some(channel) => {
+            database::set_automod_log_channel(guild_id.get(), channel.id.get())?;
+            invalidate(guild_id);
+
+            CreateReply::default().embed(
+                CreateEmbed::new()
+                    .title("Log channel updated")
+                    .description(format!(
+                        "{CONFIRM_EMOJI} Auto-mod will log what it does in {}.",
+                        channel.mention()
+                    ))
+                    .color(CONFIRM_EMBED_COLOR),
+            )
+        }
+        None => {
+            database::remove_automod_log_channel(guild_id.get())?;
+            invalidate(guild_id);
+
+            CreateReply::default().embed(
+                CreateEmbed::new()
+                    .title("Log channel removed")
+                    .description(format!(
+                        "{CONFIRM_EMOJI} Auto-mod won't log to a channel anymore."
+                    ))
+                    .color(CONFIRM_EMBED_COLOR),
+            )
+        }
+    };
+
+    ctx.send(reply).await?;
+
+    Ok(())
+}
+
+/// See this server's auto-mod rules
+#[poise::command(slash_command, guild_only, required_permissions = "MANAGE_GUILD")]
+pub async fn settings(ctx: poise::Context<'_, (), Error>) -> Result<(), Error> {
+    ctx.defer().await?;
+
+    // It's safe to use .expect() since the command is guild only.
+    let guild_id = ctx.guild_id().expect("Unreachable");
+
+    let rules = database::get_automod_rules(guild_id.get())?;
+    let rules = RuleKind::ALL
+        .iter()
+        .map(|kind| {
+            rules.iter().find(|rule| rule.kind == *kind).map_or_else(
+                || format!("**{}**: off", kind.title()),
+                |rule| {
+                    format!(
+                        "**{}**: {}, `{}`",
+                        kind.title(),
+                        kind.describe(rule.threshold),
+                        rule.action.as_str()
+                    )
+                },
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let patterns = database::get_blocked_patterns(guild_id.get())?;
+    let blocklist = if patterns.is_empty() {
+        "Empty".to_string()
+    } else {
+        let list = patterns
+            .iter()
+            .map(|pattern| format!("`{pattern}`"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        // Embed fields can't be longer than 1024 characters.
+        if list.chars().count() > 1024 {
+            format!("{} entries", patterns.len())
+        } else {
+            list
+        }
+    };
+
+    let mut escalations = database::get_automod_escalations(guild_id.get())?;
+    escalations.sort_unstable();
+    let escalations = if escalations.is_empty() {
+        "None".to_string()
+    } else {
+        escalations
+            .iter()
+            .map(|(strikes, minutes)| format!("{strikes} strikes: {minutes} minute timeout"))
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+
+    let log_channel = database::get_automod_log_channel(guild_id.get())?.map_or_else(
+        || "None".to_string(),
+        |channel_id| format!("<#{channel_id}>"),
+    );
+
+    ctx.send(
+        CreateReply::default().embed(
+            CreateEmbed::new()
+                .title("Auto-mod")
+                .description(rules)
+                .field("Blocklist", blocklist, false)
+                .field("Escalations", escalations, false)
+                .field("Log channel", log_channel, false)
+                .color(EMBED_COLOR),
+        ),
+    )
+    .await?;
+
+    Ok(())
+}
+
+fn error_reply(error: &str) -> CreateReply {
+    CreateReply::default().embed(
+        CreateEmbed::new()
+            .title("Error")
+            .description(format!("{ERROR_EMOJI} {error}"))
+            .color(ERROR_EMBED_COLOR),
+    )
+}
+
+// This function needs to be async for poise to use it.
+#[allow(clippy::unused_async)]
+async fn rule_autocomplete(_: poise::Context<'_, (), Error>, partial: &str) -> Vec<String> {
+    let partial = partial.to_lowercase();
+
+    RuleKind::ALL
+        .iter()
+        .map(|kind| kind.as_str().to_string())
+        .filter(|kind| kind.starts_with(&partial))
+        .collect()
+}
+
+// This function needs to be async for poise to use it.
+#[allow(clippy::unused_async)]
+async fn action_autocomplete(_: poise::Context<'_, (), Error>, partial: &str) -> Vec<String> {
+    let partial = partial.to_lowercase();
+
+    RuleAction::ALL
+        .iter()
+        .map(|action| action.as_str().to_string())
+        .filter(|action| action.starts_with(&partial))
+        .collect()
+}
+
+// This function needs to be async for poise to use it.
+#[allow(clippy::unused_async)]
+async fn pattern_autocomplete(ctx: poise::Context<'_, (), Error>, partial: &str) -> Vec<String> {
+    let Some(guild_id) = ctx.guild_id() else {
+        return Vec::new();
+    };
+
+    let partial = partial.to_lowercase();
+    database::get_blocked_patterns(guild_id.get())
+        .map_err(|e| error!("Failed to get the blocklist: {e}"))
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|pattern| pattern.to_lowercase().contains(&partial))
+        .take(25)
+        .collect()
+}
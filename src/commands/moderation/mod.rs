@@ -1 +1,3 @@
+pub mod automod;
 pub mod purge;
+pub mod safety;
@@ -0,0 +1,201 @@
+use crate::{
+    commands::{CONFIRM_EMBED_COLOR, CONFIRM_EMOJI, EMBED_COLOR, ERROR_EMBED_COLOR, ERROR_EMOJI},
+    content::Rating,
+    database, Error,
+};
+
+use log::error;
+use poise::serenity_prelude::{Channel, ChannelId, CreateEmbed, GuildId, Http, Permissions};
+use poise::CreateReply;
+
+/// How mature the truths, dares and would you rather questions of a guild
+/// can be.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub enum SafetyLevel {
+    Kid,
+    #[default]
+    Teen,
+    Adult,
+}
+
+impl SafetyLevel {
+    pub const ALL: [Self; 3] = [Self::Kid, Self::Teen, Self::Adult];
+
+    /// The identifier used to store this level in the database.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Kid => "kid",
+            Self::Teen => "teen",
+            Self::Adult => "adult",
+        }
+    }
+
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|level| level.as_str().eq_ignore_ascii_case(name.trim()))
+    }
+
+    /// The highest rated content this level allows.
+    #[must_use]
+    pub const fn max_rating(self) -> Rating {
+        match self {
+            Self::Kid => Rating::Pg,
+            Self::Teen => Rating::Pg13,
+            Self::Adult => Rating::Nsfw,
+        }
+    }
+
+    const fn description(self) -> &'static str {
+        match self {
+            Self::Kid => "Only kid-friendly content.",
+            Self::Teen => "Kid-friendly and teen content.",
+            Self::Adult => "Everything, with adult content limited to NSFW channels.",
+        }
+    }
+}
+
+/// Gets the highest rated content that can be sent in a channel, taking the
+/// guild's safety level into account.
+///
+/// Adult content is never allowed outside of NSFW channels, including DMs.
+pub async fn allowed_rating(
+    http: &Http,
+    guild_id: Option<GuildId>,
+    channel_id: ChannelId,
+) -> Rating {
+    let level = guild_id.map_or_else(SafetyLevel::default, |guild_id| {
+        database::get_safety_level(guild_id.get())
+            .map_err(|e| error!("Failed to get safety level: {e}"))
+            .ok()
+            .flatten()
+            .unwrap_or_default()
+    });
+
+    if level.max_rating() > Rating::Pg13 && !is_nsfw_channel(http, channel_id).await {
+        return Rating::Pg13;
+    }
+
+    level.max_rating()
+}
+
+async fn is_nsfw_channel(http: &Http, channel_id: ChannelId) -> bool {
+    let Ok(Channel::Guild(channel)) = channel_id.to_channel(http).await else {
+        return false;
+    };
+
+    // Threads inherit the NSFW setting of the channel they're in.
+    match channel.parent_id {
+        Some(parent_id) if channel.thread_metadata.is_some() => {
+            matches!(parent_id.to_channel(http).await, Ok(Channel::Guild(parent)) if parent.nsfw)
+        }
+        _ => channel.nsfw,
+    }
+}
+
+/// Set how mature truth or dare and would you rather can get in this server
+#[poise::command(
+    slash_command,
+    broadcast_typing,
+    guild_only,
+    required_permissions = "MANAGE_GUILD"
+)]
+pub async fn safety(
+    ctx: poise::Context<'_, (), Error>,
+    #[description = "The safety level to use. Leave empty to see the current one."]
+    #[autocomplete = "level_autocomplete"]
+    level: Option<String>,
+) -> Result<(), Error> {
+    ctx.defer().await?;
+
+    // It's safe to use .expect() since the command is guild only.
+    let guild_id = ctx.guild_id().expect("Unreachable").get();
+
+    let perms = ctx
+        .author_member()
+        .await
+        .expect("Couldn't get author member")
+        .permissions
+        .expect("Couldn't get permissions")
+        .contains(Permissions::MANAGE_GUILD);
+    if !perms {
+        ctx.send(
+            CreateReply::default().embed(
+                CreateEmbed::default()
+                    .title("Error")
+                    .description(format!(
+                        "{ERROR_EMOJI} You don't have permission to use this command.\nPermission needed: `MANAGE_GUILD`.",
+                    ))
+                    .color(ERROR_EMBED_COLOR),
+            ),
+        )
+        .await?;
+        return Ok(());
+    }
+
+    let Some(level) = level else {
+        let level = database::get_safety_level(guild_id)?.unwrap_or_default();
+
+        ctx.send(
+            CreateReply::default().embed(
+                CreateEmbed::default()
+                    .title("Safety level")
+                    .description(format!(
+                        "This server's safety level is `{}`.\n{}",
+                        level.as_str(),
+                        level.description()
+                    ))
+                    .color(EMBED_COLOR),
+            ),
+        )
+        .await?;
+        return Ok(());
+    };
+
+    let Some(level) = SafetyLevel::from_name(&level) else {
+        ctx.send(
+            CreateReply::default().embed(
+                CreateEmbed::default()
+                    .title("Error")
+                    .description(format!(
+                        "{ERROR_EMOJI} Unknown safety level. Valid levels are `kid`, `teen` and `adult`."
+                    ))
+                    .color(ERROR_EMBED_COLOR),
+            ),
+        )
+        .await?;
+        return Ok(());
+    };
+
+    database::set_safety_level(guild_id, level)?;
+
+    ctx.send(
+        CreateReply::default().embed(
+            CreateEmbed::default()
+                .title("Safety level updated")
+                .description(format!(
+                    "{CONFIRM_EMOJI} This server's safety level is now `{}`.\n{}",
+                    level.as_str(),
+                    level.description()
+                ))
+                .color(CONFIRM_EMBED_COLOR),
+        ),
+    )
+    .await?;
+
+    Ok(())
+}
+
+// This function needs to be async for poise to use it.
+#[allow(clippy::unused_async)]
+async fn level_autocomplete(_: poise::Context<'_, (), Error>, partial: &str) -> Vec<String> {
+    let partial = partial.to_lowercase();
+
+    SafetyLevel::ALL
+        .iter()
+        .map(|level| level.as_str().to_string())
+        .filter(|level| level.starts_with(&partial))
+        .collect()
+}
@@ -1,7 +1,7 @@
 use log::error;
 use poise::{
-    serenity_prelude::{CreateEmbed, CreateEmbedFooter, UserId},
-    CreateReply,
+    serenity_prelude::{CreateEmbed, CreateEmbedFooter, Message, UserId},
+    CreateReply, Modal,
 };
 use reqwest::Client;
 
@@ -13,6 +13,20 @@ use crate::{
     database, Error,
 };
 
+type ApplicationContext<'a> = poise::ApplicationContext<'a, (), Error>;
+
+/// How much of a message's text is quoted in reminders about it.
+const MAX_EXCERPT_LENGTH: usize = 200;
+
+#[derive(Modal)]
+#[name = "Remind me about this"]
+struct ReminderTimeModal {
+    #[name = "When should we remind you?"]
+    #[placeholder = "10 minutes, 2h, 1 day"]
+    #[max_length = 30]
+    time: String,
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, Default)]
 pub struct Reminder {
     pub(crate) message: String,
@@ -48,6 +62,40 @@ pub async fn add_reminder(
 ) -> Result<(), Error> {
     ctx.defer().await?;
 
+    schedule_reminder(ctx, message, time).await
+}
+
+/// Get reminded about a message later
+#[poise::command(context_menu_command = "Remind me about this")]
+pub async fn remind_about_message(
+    ctx: ApplicationContext<'_>,
+    #[description = "The message to be reminded about."] message: Message,
+) -> Result<(), Error> {
+    // Can't defer since the modal has to be the first response.
+    let Some(modal) = ReminderTimeModal::execute(ctx).await? else {
+        return Ok(());
+    };
+
+    let link = message.id.link(message.channel_id, ctx.guild_id());
+    let excerpt = message.content.trim();
+    let reminder = if excerpt.is_empty() {
+        link
+    } else if excerpt.chars().count() > MAX_EXCERPT_LENGTH {
+        let excerpt = excerpt.chars().take(MAX_EXCERPT_LENGTH).collect::<String>();
+        format!("{link}\n> {excerpt}…")
+    } else {
+        format!("{link}\n> {excerpt}")
+    };
+
+    schedule_reminder(poise::Context::Application(ctx), reminder, modal.time).await
+}
+
+/// Parses when to remind the author and saves the reminder.
+async fn schedule_reminder(
+    ctx: poise::Context<'_, (), Error>,
+    message: String,
+    time: String,
+) -> Result<(), Error> {
     super::init_reminder_cache();
 
     let parsed_timestamp = parse_timestamp(&time);
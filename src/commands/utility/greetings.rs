@@ -0,0 +1,432 @@
+use log::warn;
+use poise::{
+    serenity_prelude::{
+        self as serenity, ChannelId, CreateAttachment, CreateEmbed, CreateMessage, FullEvent,
+        GuildChannel, GuildId, Http, Mentionable, User,
+    },
+    CreateReply,
+};
+
+use crate::{
+    commands::{
+        imaging::card::{self, GreetingCard},
+        CONFIRM_EMBED_COLOR, CONFIRM_EMOJI, EMBED_COLOR, ERROR_EMBED_COLOR, ERROR_EMOJI,
+    },
+    database, Error,
+};
+
+const DEFAULT_WELCOME: &str = "Welcome to **{server}**, {user}! You're member #{member_count}.";
+const DEFAULT_GOODBYE: &str =
+    "**{username}** left **{server}**. We're down to {member_count} members.";
+
+const CARD_FILE_NAME: &str = "greeting.png";
+
+/// The placeholders templates can use, and what they're replaced with.
+const PLACEHOLDERS: &str = "`{user}` mentions the member, `{username}` is their name, `{server}` is the server's name and `{member_count}` is how many members it has.";
+
+/// Whether a greeting is sent when members join or when they leave.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub enum GreetingKind {
+    #[default]
+    Welcome,
+    Goodbye,
+}
+
+impl GreetingKind {
+    pub const ALL: [Self; 2] = [Self::Welcome, Self::Goodbye];
+
+    /// The identifier used to store this kind in the database.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Welcome => "welcome",
+            Self::Goodbye => "goodbye",
+        }
+    }
+
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|kind| kind.as_str().eq_ignore_ascii_case(name.trim()))
+    }
+}
+
+/// How a guild greets members joining or leaving it.
+#[derive(Debug, Clone, Eq, PartialEq, Default)]
+pub struct Greeting {
+    pub(crate) guild_id: u64,
+    pub(crate) kind: GreetingKind,
+    pub(crate) channel_id: u64,
+    pub(crate) message: String,
+    /// Whether a card with the member's avatar is attached.
+    pub(crate) card: bool,
+    /// Whether the message is also sent to the member. Only welcomes do this,
+    /// since members who left usually can't be messaged anymore.
+    pub(crate) dm: bool,
+}
+
+/// Set up the messages sent when members join or leave
+#[poise::command(
+    slash_command,
+    guild_only,
+    required_permissions = "MANAGE_GUILD",
+    subcommands("welcome", "goodbye", "disable", "preview"),
+    subcommand_required
+)]
+// This function needs to be async for poise to use it.
+#[allow(clippy::unused_async)]
+pub async fn greetings(_: poise::Context<'_, (), Error>) -> Result<(), Error> {
+    Ok(())
+}
+
+/// Greet members when they join this server
+#[poise::command(slash_command, guild_only, required_permissions = "MANAGE_GUILD")]
+pub async fn welcome(
+    ctx: poise::Context<'_, (), Error>,
+    #[description = "The channel to send the welcome in."]
+    #[channel_types("Text", "News")]
+    channel: GuildChannel,
+    #[description = "The welcome. Can use {user}, {username}, {server} and {member_count}."]
+    #[max_length = 1000]
+    message: Option<String>,
+    #[description = "Whether to attach a card with the member's avatar. Defaults to yes."]
+    card: Option<bool>,
+    #[description = "Whether to send the welcome to the member as well. Defaults to no."]
+    dm: Option<bool>,
+) -> Result<(), Error> {
+    configure(
+        ctx,
+        GreetingKind::Welcome,
+        &channel,
+        message,
+        card.unwrap_or(true),
+        dm.unwrap_or(false),
+    )
+    .await
+}
+
+/// Say goodbye to members when they leave this server
+#[poise::command(slash_command, guild_only, required_permissions = "MANAGE_GUILD")]
+pub async fn goodbye(
+    ctx: poise::Context<'_, (), Error>,
+    #[description = "The channel to send the goodbye in."]
+    #[channel_types("Text", "News")]
+    channel: GuildChannel,
+    #[description = "The goodbye. Can use {user}, {username}, {server} and {member_count}."]
+    #[max_length = 1000]
+    message: Option<String>,
+    #[description = "Whether to attach a card with the member's avatar. Defaults to yes."]
+    card: Option<bool>,
+) -> Result<(), Error> {
+    configure(
+        ctx,
+        GreetingKind::Goodbye,
+        &channel,
+        message,
+        card.unwrap_or(true),
+        false,
+    )
+    .await
+}
+
+async fn configure(
+    ctx: poise::Context<'_, (), Error>,
+    kind: GreetingKind,
+    channel: &GuildChannel,
+    message: Option<String>,
+    card: bool,
+    dm: bool,
+) -> Result<(), Error> {
+    ctx.defer().await?;
+
+    // It's safe to use .expect() since the command is guild only.
+    let guild_id = ctx.guild_id().expect("Unreachable");
+
+    if channel.guild_id != guild_id {
+        ctx.send(
+            CreateReply::default().embed(
+                CreateEmbed::new()
+                    .title("Error")
+                    .description(format!("{ERROR_EMOJI} That channel isn't in this server."))
+                    .color(ERROR_EMBED_COLOR),
+            ),
+        )
+        .await?;
+        return Ok(());
+    }
+
+    let greeting = Greeting {
+        guild_id: guild_id.get(),
+        kind,
+        channel_id: channel.id.get(),
+        message: message.unwrap_or_else(|| default_message(kind).to_string()),
+        card,
+        dm,
+    };
+    database::set_greeting(&greeting)?;
+
+    ctx.send(
+        CreateReply::default().embed(
+            CreateEmbed::new()
+                .title(format!("{} message updated", title(kind)))
+                .description(format!(
+                    "{CONFIRM_EMOJI} It'll be sent in {}. See what it looks like with `/greetings preview`.\n\n{PLACEHOLDERS}",
+                    channel.mention()
+                ))
+                .field("Message", &greeting.message, false)
+                .color(CONFIRM_EMBED_COLOR),
+        ),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Stop sending a welcome or goodbye message
+#[poise::command(slash_command, guild_only, required_permissions = "MANAGE_GUILD")]
+pub async fn disable(
+    ctx: poise::Context<'_, (), Error>,
+    #[description = "The message to stop sending."]
+    #[autocomplete = "kind_autocomplete"]
+    kind: String,
+) -> Result<(), Error> {
+    ctx.defer().await?;
+
+    // It's safe to use .expect() since the command is guild only.
+    let guild_id = ctx.guild_id().expect("Unreachable");
+
+    let embed = match GreetingKind::from_name(&kind) {
+        None => unknown_kind_embed(),
+        Some(kind) if database::remove_greeting(guild_id.get(), kind)? => CreateEmbed::new()
+            .title(format!("{} message disabled", title(kind)))
+            .description(format!(
+                "{CONFIRM_EMOJI} Members won't get a {} message anymore.",
+                kind.as_str()
+            ))
+            .color(CONFIRM_EMBED_COLOR),
+        Some(kind) => CreateEmbed::new()
+            .title("Error")
+            .description(format!(
+                "{ERROR_EMOJI} This server doesn't have a {} message.",
+                kind.as_str()
+            ))
+            .color(ERROR_EMBED_COLOR),
+    };
+
+    ctx.send(CreateReply::default().embed(embed)).await?;
+
+    Ok(())
+}
+
+/// See a welcome or goodbye message as if you had just joined or left
+#[poise::command(slash_command, guild_only, required_permissions = "MANAGE_GUILD")]
+pub async fn preview(
+    ctx: poise::Context<'_, (), Error>,
+    #[description = "The message to preview."]
+    #[autocomplete = "kind_autocomplete"]
+    kind: String,
+) -> Result<(), Error> {
+    ctx.defer().await?;
+
+    // It's safe to use .expect() since the command is guild only.
+    let guild_id = ctx.guild_id().expect("Unreachable");
+
+    let Some(kind) = GreetingKind::from_name(&kind) else {
+        ctx.send(CreateReply::default().embed(unknown_kind_embed()))
+            .await?;
+        return Ok(());
+    };
+
+    let Some(greeting) = database::get_greeting(guild_id.get(), kind)? else {
+        ctx.send(
+            CreateReply::default().embed(
+                CreateEmbed::new()
+                    .title("Error")
+                    .description(format!(
+                        "{ERROR_EMOJI} This server doesn't have a {0} message, set one up with `/greetings {0}`.",
+                        kind.as_str()
+                    ))
+                    .color(ERROR_EMBED_COLOR),
+            ),
+        )
+        .await?;
+        return Ok(());
+    };
+
+    let (description, attachment) = compose(ctx.http(), &greeting, ctx.author()).await?;
+    let mut reply = CreateReply::default()
+        .content(format!("This will be sent in <#{}>:", greeting.channel_id))
+        .embed(embed(kind, description, attachment.is_some()));
+    if let Some(attachment) = attachment {
+        reply = reply.attachment(attachment);
+    }
+    ctx.send(reply).await?;
+
+    Ok(())
+}
+
+/// Handles the events greetings are registered for.
+///
+/// # Errors
+///
+/// Fails if the greeting couldn't be loaded or sent.
+pub async fn handle_event(ctx: &serenity::Context, event: &FullEvent) -> Result<(), Error> {
+    match event {
+        FullEvent::GuildMemberAddition { new_member } => {
+            greet(
+                ctx,
+                new_member.guild_id,
+                &new_member.user,
+                GreetingKind::Welcome,
+            )
+            .await
+        }
+        FullEvent::GuildMemberRemoval { guild_id, user, .. } => {
+            greet(ctx, *guild_id, user, GreetingKind::Goodbye).await
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Sends a guild's greeting for `user`, if it has one set up.
+async fn greet(
+    ctx: &serenity::Context,
+    guild_id: GuildId,
+    user: &User,
+    kind: GreetingKind,
+) -> Result<(), Error> {
+    if user.bot {
+        return Ok(());
+    }
+    let Some(greeting) = database::get_greeting(guild_id.get(), kind)? else {
+        return Ok(());
+    };
+
+    let (description, attachment) = compose(&ctx.http, &greeting, user).await?;
+
+    let mut message =
+        CreateMessage::new().embed(embed(kind, description.clone(), attachment.is_some()));
+    if let Some(attachment) = attachment {
+        message = message.add_file(attachment);
+    }
+    ChannelId::new(greeting.channel_id)
+        .send_message(ctx, message)
+        .await?;
+
+    // The card is only attached in the guild, a DM just gets the text.
+    if greeting.dm {
+        if let Err(e) = user
+            .direct_message(
+                ctx,
+                CreateMessage::new().embed(embed(kind, description, false)),
+            )
+            .await
+        {
+            // Members can have DMs from servers turned off.
+            warn!(
+                "Failed to DM the {} message of {guild_id}: {e}",
+                kind.as_str()
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Fills in a greeting's template for `user`, and renders its card if it has
+/// one.
+///
+/// A card that can't be rendered is left out, so the greeting is still sent.
+async fn compose(
+    http: &Http,
+    greeting: &Greeting,
+    user: &User,
+) -> Result<(String, Option<CreateAttachment>), Error> {
+    let guild = http
+        .get_guild_with_counts(GuildId::new(greeting.guild_id))
+        .await?;
+    let member_count = guild.approximate_member_count.unwrap_or_default();
+
+    let description = fill_template(&greeting.message, user, &guild.name, member_count);
+
+    if !greeting.card {
+        return Ok((description, None));
+    }
+
+    let card = GreetingCard {
+        title: title(greeting.kind).to_uppercase(),
+        name: user.display_name().to_string(),
+        subtitle: match greeting.kind {
+            GreetingKind::Welcome => format!("Member #{member_count} of {}", guild.name),
+            GreetingKind::Goodbye => format!("{} now has {member_count} members", guild.name),
+        },
+        avatar_url: user.static_face(),
+    };
+    let attachment = match card::render_greeting(card).await {
+        Ok(data) => Some(CreateAttachment::bytes(data, CARD_FILE_NAME)),
+        Err(e) => {
+            warn!("Failed to render greeting card: {e}");
+            None
+        }
+    };
+
+    Ok((description, attachment))
+}
+
+/// Replaces the placeholders listed in `PLACEHOLDERS`.
+fn fill_template(template: &str, member: &User, guild_name: &str, count: u64) -> String {
+    template
+        .replace("{user}", &member.mention().to_string())
+        .replace("{username}", member.display_name())
+        .replace("{server}", guild_name)
+        .replace("{member_count}", &count.to_string())
+}
+
+fn embed(kind: GreetingKind, description: String, with_card: bool) -> CreateEmbed {
+    let embed = CreateEmbed::new()
+        .title(title(kind))
+        .description(description)
+        .color(EMBED_COLOR);
+
+    if with_card {
+        embed.image(format!("attachment://{CARD_FILE_NAME}"))
+    } else {
+        embed
+    }
+}
+
+const fn title(kind: GreetingKind) -> &'static str {
+    match kind {
+        GreetingKind::Welcome => "Welcome",
+        GreetingKind::Goodbye => "Goodbye",
+    }
+}
+
+const fn default_message(kind: GreetingKind) -> &'static str {
+    match kind {
+        GreetingKind::Welcome => DEFAULT_WELCOME,
+        GreetingKind::Goodbye => DEFAULT_GOODBYE,
+    }
+}
+
+fn unknown_kind_embed() -> CreateEmbed {
+    CreateEmbed::new()
+        .title("Error")
+        .description(format!(
+            "{ERROR_EMOJI} Unknown message. Valid messages are `welcome` and `goodbye`."
+        ))
+        .color(ERROR_EMBED_COLOR)
+}
+
+// This function needs to be async for poise to use it.
+#[allow(clippy::unused_async)]
+async fn kind_autocomplete(_: poise::Context<'_, (), Error>, partial: &str) -> Vec<String> {
+    let partial = partial.to_lowercase();
+
+    GreetingKind::ALL
+        .iter()
+        .map(|kind| kind.as_str().to_string())
+        .filter(|kind| kind.starts_with(&partial))
+        .collect()
+}
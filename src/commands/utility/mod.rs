@@ -6,8 +6,11 @@ use poise::serenity_prelude::UserId;
 use self::add_reminder::Reminder;
 
 pub mod add_reminder;
+pub mod greetings;
 pub mod poll;
+pub mod reload_content;
 pub mod remove_reminder;
+pub mod roles;
 
 static mut REMINDER_CACHE: OnceCell<HashMap<UserId, Vec<Reminder>>> = OnceCell::new();
 static mut REMINDER_CACHE_INITIALIZED: bool = false;
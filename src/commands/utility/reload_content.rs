@@ -0,0 +1,40 @@
+use crate::commands::{CONFIRM_EMBED_COLOR, CONFIRM_EMOJI, ERROR_EMBED_COLOR, ERROR_EMOJI};
+use crate::{content, Error};
+
+use log::error;
+use poise::serenity_prelude::CreateEmbed;
+use poise::CreateReply;
+
+/// Reload the built-in quotes, facts, questions, truths, dares and trivia
+#[poise::command(slash_command, owners_only, hide_in_help)]
+pub async fn reload_content(ctx: poise::Context<'_, (), Error>) -> Result<(), Error> {
+    ctx.defer_ephemeral().await?;
+
+    let embed = match content::load() {
+        Ok(counts) => CreateEmbed::default()
+            .title("Content reloaded")
+            .description(format!(
+                "{CONFIRM_EMOJI} All content pools were reloaded.\n\n{}",
+                counts
+                    .iter()
+                    .map(|(pool, count)| format!("**{}**: {count}", pool.name()))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            ))
+            .color(CONFIRM_EMBED_COLOR),
+        Err(e) => {
+            error!("Failed to reload content: {e}");
+
+            CreateEmbed::default()
+                .title("Error")
+                .description(format!(
+                    "{ERROR_EMOJI} The content couldn't be reloaded, the current pools were kept.\n```\n{e}\n```"
+                ))
+                .color(ERROR_EMBED_COLOR)
+        }
+    };
+
+    ctx.send(CreateReply::default().embed(embed)).await?;
+
+    Ok(())
+}
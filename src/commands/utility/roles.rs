@@ -0,0 +1,670 @@
+use std::collections::HashSet;
+
+use log::warn;
+use poise::{
+    serenity_prelude::{
+        self as serenity, ButtonStyle, ComponentInteraction, ComponentInteractionDataKind,
+        CreateActionRow, CreateButton, CreateEmbed, CreateEmbedFooter, CreateInteractionResponse,
+        CreateInteractionResponseMessage, CreateMessage, CreateSelectMenu, CreateSelectMenuKind,
+        CreateSelectMenuOption, FullEvent, GuildChannel, Member, Mentionable, Role, RoleId,
+    },
+    CreateReply,
+};
+
+use crate::{
+    commands::{CONFIRM_EMBED_COLOR, CONFIRM_EMOJI, EMBED_COLOR, ERROR_EMBED_COLOR, ERROR_EMOJI},
+    database,
+    router::{self, ComponentId},
+    Error,
+};
+
+/// The namespace of the buttons and select menus of role menus.
+pub(crate) const NAMESPACE: &str = "roles";
+
+/// Discord doesn't allow more buttons than this in a row.
+const BUTTONS_PER_ROW: usize = 5;
+
+/// Discord doesn't allow button labels longer than this.
+const MAX_BUTTON_LABEL_LENGTH: usize = 80;
+/// Discord doesn't allow select menu option labels longer than this.
+const MAX_OPTION_LABEL_LENGTH: usize = 100;
+
+/// Whether members can pick one or several roles of a menu.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub enum RoleMenuMode {
+    /// Picking a role removes the other roles of the menu.
+    Single,
+    #[default]
+    Multi,
+}
+
+impl RoleMenuMode {
+    pub const ALL: [Self; 2] = [Self::Single, Self::Multi];
+
+    /// The identifier used to store this mode in the database.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Single => "single",
+            Self::Multi => "multi",
+        }
+    }
+
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|mode| mode.as_str().eq_ignore_ascii_case(name.trim()))
+    }
+}
+
+/// How the roles of a menu are picked. It's only needed to build the message,
+/// so it isn't stored.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+enum MenuStyle {
+    #[default]
+    Buttons,
+    Select,
+}
+
+impl MenuStyle {
+    const ALL: [Self; 2] = [Self::Buttons, Self::Select];
+
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::Buttons => "buttons",
+            Self::Select => "select",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|style| style.as_str().eq_ignore_ascii_case(name.trim()))
+    }
+}
+
+/// A message members can pick roles from.
+#[derive(Debug, Clone, Eq, PartialEq, Default)]
+pub struct RoleMenu {
+    pub(crate) message_id: u64,
+    pub(crate) guild_id: u64,
+    pub(crate) channel_id: u64,
+    pub(crate) mode: RoleMenuMode,
+    pub(crate) roles: Vec<u64>,
+}
+
+/// Let members pick their own roles, and give roles to new members
+#[poise::command(
+    slash_command,
+    guild_only,
+    required_permissions = "MANAGE_ROLES",
+    subcommands("menu", "auto"),
+    subcommand_required
+)]
+// This function needs to be async for poise to use it.
+#[allow(clippy::unused_async)]
+pub async fn roles(_: poise::Context<'_, (), Error>) -> Result<(), Error> {
+    Ok(())
+}
+
+/// Messages members can pick roles from
+#[poise::command(
+    slash_command,
+    guild_only,
+    required_permissions = "MANAGE_ROLES",
+    subcommands("create"),
+    subcommand_required
+)]
+// This function needs to be async for poise to use it.
+#[allow(clippy::unused_async)]
+pub async fn menu(_: poise::Context<'_, (), Error>) -> Result<(), Error> {
+    Ok(())
+}
+
+/// Post a message members can pick roles from
+#[poise::command(slash_command, guild_only, required_permissions = "MANAGE_ROLES")]
+#[allow(clippy::too_many_arguments)]
+pub async fn create(
+    ctx: poise::Context<'_, (), Error>,
+    #[description = "The title of the menu."]
+    #[max_length = 256]
+    title: String,
+    #[description = "A role members can pick."] role1: Role,
+    #[description = "A role members can pick."] role2: Option<Role>,
+    #[description = "A role members can pick."] role3: Option<Role>,
+    #[description = "A role members can pick."] role4: Option<Role>,
+    #[description = "A role members can pick."] role5: Option<Role>,
+    #[description = "A role members can pick."] role6: Option<Role>,
+    #[description = "A role members can pick."] role7: Option<Role>,
+    #[description = "A role members can pick."] role8: Option<Role>,
+    #[description = "A role members can pick."] role9: Option<Role>,
+    #[description = "A role members can pick."] role10: Option<Role>,
+    #[description = "Whether roles are picked with buttons or a select menu. Defaults to buttons."]
+    #[autocomplete = "style_autocomplete"]
+    style: Option<String>,
+    #[description = "Whether members can pick one role or several. Defaults to several."]
+    #[autocomplete = "mode_autocomplete"]
+    mode: Option<String>,
+    #[description = "Text shown above the roles."]
+    #[max_length = 2000]
+    description: Option<String>,
+    #[description = "The channel to post the menu in. Defaults to this one."]
+    #[channel_types("Text", "News")]
+    channel: Option<GuildChannel>,
+) -> Result<(), Error> {
+    ctx.defer().await?;
+
+    // It's safe to use .expect() since the command is guild only.
+    let guild_id = ctx.guild_id().expect("Unreachable");
+
+    // Roles picked twice are only shown once.
+    let mut seen = HashSet::new();
+    let menu_roles = [
+        Some(role1),
+        role2,
+        role3,
+        role4,
+        role5,
+        role6,
+        role7,
+        role8,
+        role9,
+        role10,
+    ]
+    .into_iter()
+    .flatten()
+    .filter(|role| seen.insert(role.id))
+    .collect::<Vec<_>>();
+
+    let style = style.as_deref().map(MenuStyle::from_name);
+    let mode = mode.as_deref().map(RoleMenuMode::from_name);
+    let channel_id = channel
+        .as_ref()
+        .map_or_else(|| ctx.channel_id(), |channel| channel.id);
+
+    let error = if matches!(style, Some(None)) {
+        Some("Unknown style. Valid styles are `buttons` and `select`.".to_string())
+    } else if matches!(mode, Some(None)) {
+        Some("Unknown mode. Valid modes are `single` and `multi`.".to_string())
+    } else if channel.is_some_and(|channel| channel.guild_id != guild_id) {
+        Some("That channel isn't in this server.".to_string())
+    } else {
+        check_assignable(ctx, &menu_roles).await?
+    };
+
+    if let Some(error) = error {
+        ctx.send(error_reply(&error)).await?;
+        return Ok(());
+    }
+
+    let style = style.flatten().unwrap_or_default();
+    let mode = mode.flatten().unwrap_or_default();
+
+    let footer = match (style, mode) {
+        (MenuStyle::Buttons, RoleMenuMode::Single) => {
+            "Press a button to get its role, or press it again to remove it. You can only have one of these roles."
+        }
+        (MenuStyle::Buttons, RoleMenuMode::Multi) => {
+            "Press a button to get its role, or press it again to remove it."
+        }
+        (MenuStyle::Select, RoleMenuMode::Single) => {
+            "Pick a role to get it, or pick nothing to remove it."
+        }
+        (MenuStyle::Select, RoleMenuMode::Multi) => {
+            "You'll have exactly the roles you pick, so pick nothing to remove them all."
+        }
+    };
+
+    let mut embed = CreateEmbed::new()
+        .title(title)
+        .field(
+            "Roles",
+            menu_roles
+                .iter()
+                .map(|role| role.mention().to_string())
+                .collect::<Vec<_>>()
+                .join("\n"),
+            false,
+        )
+        .footer(CreateEmbedFooter::new(footer))
+        .color(EMBED_COLOR);
+    if let Some(description) = description {
+        embed = embed.description(description);
+    }
+
+    let message = channel_id
+        .send_message(
+            ctx,
+            CreateMessage::new()
+                .embed(embed)
+                .components(components(style, mode, &menu_roles)),
+        )
+        .await?;
+
+    database::add_role_menu(&RoleMenu {
+        message_id: message.id.get(),
+        guild_id: guild_id.get(),
+        channel_id: channel_id.get(),
+        mode,
+        roles: menu_roles.iter().map(|role| role.id.get()).collect(),
+    })?;
+
+    ctx.send(
+        CreateReply::default().embed(
+            CreateEmbed::new()
+                .title("Role menu created")
+                .description(format!(
+                    "{CONFIRM_EMOJI} Members can pick their roles [here]({}).\nMake sure my role is above these roles so I can give them out.",
+                    message.link()
+                ))
+                .color(CONFIRM_EMBED_COLOR),
+        ),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Roles given to members when they join
+#[poise::command(
+    slash_command,
+    guild_only,
+    required_permissions = "MANAGE_ROLES",
+    subcommands("add", "remove", "list"),
+    subcommand_required
+)]
+// This function needs to be async for poise to use it.
+#[allow(clippy::unused_async)]
+pub async fn auto(_: poise::Context<'_, (), Error>) -> Result<(), Error> {
+    Ok(())
+}
+
+/// Give a role to members when they join
+#[poise::command(slash_command, guild_only, required_permissions = "MANAGE_ROLES")]
+pub async fn add(
+    ctx: poise::Context<'_, (), Error>,
+    #[description = "The role to give."] role: Role,
+) -> Result<(), Error> {
+    ctx.defer().await?;
+
+    // It's safe to use .expect() since the command is guild only.
+    let guild_id = ctx.guild_id().expect("Unreachable");
+
+    if let Some(error) = check_assignable(ctx, std::slice::from_ref(&role)).await? {
+        ctx.send(error_reply(&error)).await?;
+        return Ok(());
+    }
+
+    database::add_auto_role(guild_id.get(), role.id.get())?;
+
+    ctx.send(
+        CreateReply::default().embed(
+            CreateEmbed::new()
+                .title("Auto-role added")
+                .description(format!(
+                    "{CONFIRM_EMOJI} Members will get {} when they join.",
+                    role.mention()
+                ))
+                .color(CONFIRM_EMBED_COLOR),
+        ),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Stop giving a role to members when they join
+#[poise::command(slash_command, guild_only, required_permissions = "MANAGE_ROLES")]
+pub async fn remove(
+    ctx: poise::Context<'_, (), Error>,
+    #[description = "The role to stop giving."] role: Role,
+) -> Result<(), Error> {
+    ctx.defer().await?;
+
+    // It's safe to use .expect() since the command is guild only.
+    let guild_id = ctx.guild_id().expect("Unreachable");
+
+    let reply = if database::remove_auto_role(guild_id.get(), role.id.get())? {
+        CreateReply::default().embed(
+            CreateEmbed::new()
+                .title("Auto-role removed")
+                .description(format!(
+                    "{CONFIRM_EMOJI} Members won't get {} when they join anymore.",
+                    role.mention()
+                ))
+                .color(CONFIRM_EMBED_COLOR),
+        )
+    } else {
+        error_reply(&format!("{} isn't an auto-role.", role.mention()))
+    };
+
+    ctx.send(reply).await?;
+
+    Ok(())
+}
+
+/// See the roles given to members when they join
+#[poise::command(slash_command, guild_only, required_permissions = "MANAGE_ROLES")]
+pub async fn list(ctx: poise::Context<'_, (), Error>) -> Result<(), Error> {
+    ctx.defer().await?;
+
+    // It's safe to use .expect() since the command is guild only.
+    let guild_id = ctx.guild_id().expect("Unreachable");
+
+    let roles = database::get_auto_roles(guild_id.get())?;
+    let description = if roles.is_empty() {
+        "There are no auto-roles yet, add one with `/roles auto add`.".to_string()
+    } else {
+        roles
+            .iter()
+            .map(|role_id| format!("<@&{role_id}>"))
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+
+    ctx.send(
+        CreateReply::default().embed(
+            CreateEmbed::new()
+                .title("Auto-roles")
+                .description(description)
+                .color(EMBED_COLOR),
+        ),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Checks that roles can be handed out by the bot, and that the author isn't
+/// handing out roles above their own.
+///
+/// Returns why a role can't be used, if one can't.
+async fn check_assignable(
+    ctx: poise::Context<'_, (), Error>,
+    roles: &[Role],
+) -> Result<Option<String>, Error> {
+    // It's safe to use .expect() since the command is guild only.
+    let guild_id = ctx.guild_id().expect("Unreachable");
+
+    let guild = guild_id.to_partial_guild(ctx).await?;
+    let bot = guild_id.member(ctx, ctx.framework().bot_id).await?;
+    let highest_position = |member: &Member| {
+        member
+            .roles
+            .iter()
+            .filter_map(|role_id| guild.roles.get(role_id))
+            .map(|role| role.position)
+            .max()
+            .unwrap_or_default()
+    };
+
+    // The owner can hand out any role, everyone else only ones below their
+    // highest role, the same way Discord limits giving out roles by hand.
+    let author_position = if ctx.author().id == guild.owner_id {
+        None
+    } else {
+        let author = ctx
+            .author_member()
+            .await
+            .ok_or("Couldn't get the author's member")?;
+        Some(highest_position(&author))
+    };
+    let bot_position = highest_position(&bot);
+
+    for role in roles {
+        let error = if role.id.get() == guild_id.get() {
+            "Everyone already has @everyone.".to_string()
+        } else if role.managed {
+            format!(
+                "{} is managed by an integration, so it can't be given out.",
+                role.mention()
+            )
+        } else if author_position.is_some_and(|position| role.position >= position) {
+            format!(
+                "{} is above your highest role, so you can't give it out.",
+                role.mention()
+            )
+        } else if role.position >= bot_position {
+            format!(
+                "{} is above my highest role, so I can't give it out. Move my role above it first.",
+                role.mention()
+            )
+        } else {
+            continue;
+        };
+
+        return Ok(Some(error));
+    }
+
+    Ok(None)
+}
+
+fn error_reply(error: &str) -> CreateReply {
+    CreateReply::default().embed(
+        CreateEmbed::new()
+            .title("Error")
+            .description(format!("{ERROR_EMOJI} {error}"))
+            .color(ERROR_EMBED_COLOR),
+    )
+}
+
+/// Cuts a role's name down to fit in a label of `max` characters.
+fn label(role: &Role, max: usize) -> String {
+    if role.name.chars().count() > max {
+        format!("{}…", role.name.chars().take(max - 1).collect::<String>())
+    } else {
+        role.name.clone()
+    }
+}
+
+fn components(style: MenuStyle, mode: RoleMenuMode, roles: &[Role]) -> Vec<CreateActionRow> {
+    match style {
+        MenuStyle::Buttons => roles
+            .chunks(BUTTONS_PER_ROW)
+            .map(|roles| {
+                let buttons = roles
+                    .iter()
+                    .map(|role| {
+                        let id = ComponentId::new(NAMESPACE).with_arg(role.id);
+
+                        CreateButton::new(id.encode())
+                            .label(label(role, MAX_BUTTON_LABEL_LENGTH))
+                            .style(ButtonStyle::Secondary)
+                    })
+                    .collect();
+
+                CreateActionRow::Buttons(buttons)
+            })
+            .collect(),
+        MenuStyle::Select => {
+            let options = roles
+                .iter()
+                .map(|role| {
+                    CreateSelectMenuOption::new(
+                        label(role, MAX_OPTION_LABEL_LENGTH),
+                        role.id.to_string(),
+                    )
+                })
+                .collect();
+            let max_values = match mode {
+                RoleMenuMode::Single => 1,
+                // There are at most 10 roles, so this can't truncate.
+                #[allow(clippy::cast_possible_truncation)]
+                RoleMenuMode::Multi => roles.len() as u8,
+            };
+
+            vec![CreateActionRow::SelectMenu(
+                CreateSelectMenu::new(
+                    ComponentId::new(NAMESPACE).encode(),
+                    CreateSelectMenuKind::String { options },
+                )
+                .placeholder("Pick your roles")
+                .min_values(0)
+                .max_values(max_values),
+            )]
+        }
+    }
+}
+
+pub(crate) async fn receive_interaction(
+    ctx: &serenity::Context,
+    interaction: &ComponentInteraction,
+    id: ComponentId,
+) -> Result<(), Error> {
+    let menu = database::get_role_menu(interaction.message.id.get())?;
+    let (Some(menu), Some(member)) = (menu, interaction.member.as_ref()) else {
+        router::reply(ctx, interaction, "This menu doesn't do anything anymore.").await;
+        return Ok(());
+    };
+
+    let has = |role_id: &u64| member.roles.contains(&RoleId::new(*role_id));
+
+    // The roles the member should have out of the menu's roles.
+    let wanted = match (&interaction.data.kind, id.arg(0)) {
+        (ComponentInteractionDataKind::Button, Some(role_id)) => {
+            let role_id = role_id.parse::<u64>()?;
+            if !menu.roles.contains(&role_id) {
+                router::reply(ctx, interaction, "This role can't be picked anymore.").await;
+                return Ok(());
+            }
+
+            // Pressing the button of a role the member has removes it.
+            let mut wanted = menu
+                .roles
+                .iter()
+                .copied()
+                .filter(|menu_role| has(menu_role) && *menu_role != role_id)
+                .collect::<Vec<_>>();
+            if !has(&role_id) {
+                if menu.mode == RoleMenuMode::Single {
+                    wanted.clear();
+                }
+                wanted.push(role_id);
+            }
+            wanted
+        }
+        (ComponentInteractionDataKind::StringSelect { values }, None) => values
+            .iter()
+            .filter_map(|value| value.parse::<u64>().ok())
+            .filter(|role_id| menu.roles.contains(role_id))
+            .take(match menu.mode {
+                RoleMenuMode::Single => 1,
+                RoleMenuMode::Multi => usize::MAX,
+            })
+            .collect(),
+        _ => {
+            router::reply(ctx, interaction, "This menu doesn't do anything anymore.").await;
+            return Ok(());
+        }
+    };
+
+    let mut added = Vec::new();
+    let mut removed = Vec::new();
+    for role_id in &menu.roles {
+        let role = RoleId::new(*role_id);
+        let result = match (has(role_id), wanted.contains(role_id)) {
+            (false, true) => {
+                added.push(role.mention().to_string());
+                member.add_role(ctx, role).await
+            }
+            (true, false) => {
+                removed.push(role.mention().to_string());
+                member.remove_role(ctx, role).await
+            }
+            _ => continue,
+        };
+
+        if let Err(e) = result {
+            warn!(
+                "Failed to update role {role} from a role menu in {}: {e}",
+                member.guild_id
+            );
+            router::reply(
+                ctx,
+                interaction,
+                "I couldn't update your roles, ask a moderator to move my role above the roles of this menu.",
+            )
+            .await;
+            return Ok(());
+        }
+    }
+
+    let mut changes = Vec::new();
+    if !added.is_empty() {
+        changes.push(format!("You got {}.", added.join(", ")));
+    }
+    if !removed.is_empty() {
+        changes.push(format!("You no longer have {}.", removed.join(", ")));
+    }
+    if changes.is_empty() {
+        changes.push("Your roles didn't change.".to_string());
+    }
+
+    interaction
+        .create_response(
+            &ctx.http,
+            CreateInteractionResponse::Message(
+                CreateInteractionResponseMessage::new()
+                    .embed(
+                        CreateEmbed::new()
+                            .title("Roles updated")
+                            .description(format!("{CONFIRM_EMOJI} {}", changes.join("\n")))
+                            .color(CONFIRM_EMBED_COLOR),
+                    )
+                    .ephemeral(true),
+            ),
+        )
+        .await?;
+
+    Ok(())
+}
+
+/// Handles the events auto-roles are registered for.
+///
+/// # Errors
+///
+/// Fails if the auto-roles couldn't be loaded.
+pub async fn handle_event(ctx: &serenity::Context, event: &FullEvent) -> Result<(), Error> {
+    let FullEvent::GuildMemberAddition { new_member } = event else {
+        return Ok(());
+    };
+
+    for role_id in database::get_auto_roles(new_member.guild_id.get())? {
+        let role_id = RoleId::new(role_id);
+        // A role that can't be given, like one that was moved above the bot's,
+        // shouldn't stop the others from being given.
+        if let Err(e) = new_member.add_role(ctx, role_id).await {
+            warn!(
+                "Failed to give auto-role {role_id} in {}: {e}",
+                new_member.guild_id
+            );
+        }
+    }
+
+    Ok(())
+}
+
+// This function needs to be async for poise to use it.
+#[allow(clippy::unused_async)]
+async fn style_autocomplete(_: poise::Context<'_, (), Error>, partial: &str) -> Vec<String> {
+    let partial = partial.to_lowercase();
+
+    MenuStyle::ALL
+        .iter()
+        .map(|style| style.as_str().to_string())
+        .filter(|style| style.starts_with(&partial))
+        .collect()
+}
+
+// This function needs to be async for poise to use it.
+#[allow(clippy::unused_async)]
+async fn mode_autocomplete(_: poise::Context<'_, (), Error>, partial: &str) -> Vec<String> {
+    let partial = partial.to_lowercase();
+
+    RoleMenuMode::ALL
+        .iter()
+        .map(|mode| mode.as_str().to_string())
+        .filter(|mode| mode.starts_with(&partial))
+        .collect()
+}
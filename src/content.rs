@@ -0,0 +1,391 @@
+use std::collections::{HashMap, HashSet};
+use std::env::var;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+
+use crate::Error;
+
+use log::info;
+use once_cell::sync::Lazy;
+use rand::seq::SliceRandom;
+use serde::Deserialize;
+
+/// Where the content files are read from when `CONTENT_DIR` isn't set.
+const DEFAULT_CONTENT_DIR: &str = "data";
+
+/// The only content file format this version of Shiba understands.
+/// Bump it whenever the layout of the files changes.
+const SUPPORTED_VERSION: u32 = 3;
+
+/// The language entries are assumed to be in when their file doesn't say.
+const DEFAULT_LANGUAGE: &str = "en";
+
+/// The most autocomplete choices Discord will show.
+const MAX_CHOICES: usize = 25;
+
+/// The built-in content pools, keyed by pool.
+///
+/// They're empty until [`load`] is called at startup.
+static POOLS: Lazy<RwLock<HashMap<Pool, Vec<Entry>>>> = Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// A pool of built-in content, each backed by its own file.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Pool {
+    Quotes,
+    Facts,
+    Questions,
+    Truths,
+    Dares,
+    WouldYouRather,
+    Trivia,
+}
+
+impl Pool {
+    pub const ALL: [Self; 7] = [
+        Self::Quotes,
+        Self::Facts,
+        Self::Questions,
+        Self::Truths,
+        Self::Dares,
+        Self::WouldYouRather,
+        Self::Trivia,
+    ];
+
+    #[must_use]
+    pub const fn file_name(self) -> &'static str {
+        match self {
+            Self::Quotes => "quotes.json",
+            Self::Facts => "facts.json",
+            Self::Questions => "questions.json",
+            Self::Truths => "truths.json",
+            Self::Dares => "dares.json",
+            Self::WouldYouRather => "would_you_rather.json",
+            Self::Trivia => "trivia.json",
+        }
+    }
+
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Quotes => "Quotes",
+            Self::Facts => "Facts of the day",
+            Self::Questions => "Questions of the day",
+            Self::Truths => "Truths",
+            Self::Dares => "Dares",
+            Self::WouldYouRather => "Would you rather",
+            Self::Trivia => "Trivia",
+        }
+    }
+
+    /// The longest an entry can be and still fit where it's sent.
+    const fn max_length(self) -> usize {
+        match self {
+            // Daily posts are sent as embed fields.
+            Self::Facts | Self::Questions => 1024,
+            // Everything else goes in an embed description.
+            Self::Quotes | Self::Truths | Self::Dares | Self::WouldYouRather | Self::Trivia => 4096,
+        }
+    }
+}
+
+/// How suitable a piece of content is for younger audiences, from most to
+/// least suitable.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Rating {
+    #[default]
+    Pg,
+    Pg13,
+    Nsfw,
+}
+
+impl Rating {
+    pub const ALL: [Self; 3] = [Self::Pg, Self::Pg13, Self::Nsfw];
+
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Pg => "pg",
+            Self::Pg13 => "pg13",
+            Self::Nsfw => "nsfw",
+        }
+    }
+
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|rating| rating.as_str().eq_ignore_ascii_case(name.trim()))
+    }
+}
+
+/// A single piece of built-in content and its tags.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Entry {
+    pub text: String,
+    #[serde(default)]
+    pub category: Option<String>,
+    #[serde(default)]
+    pub author: Option<String>,
+    #[serde(default)]
+    pub rating: Rating,
+    #[serde(default = "default_language")]
+    pub language: String,
+    /// The choices of a would you rather or trivia question.
+    #[serde(default)]
+    pub options: Vec<String>,
+    /// The index of the right option of a trivia question.
+    #[serde(default)]
+    pub answer: Option<usize>,
+}
+
+impl Entry {
+    /// An ID for the entry that stays the same as long as its text does, so
+    /// it can be stored and looked up again after the pools are reloaded.
+    #[must_use]
+    pub fn id(&self) -> u64 {
+        // FNV-1a, since std's hashers aren't guaranteed to be stable across
+        // Rust versions.
+        self.text.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        })
+    }
+}
+
+fn default_language() -> String {
+    DEFAULT_LANGUAGE.to_string()
+}
+
+/// A tag that content can be filtered by.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Tag {
+    Category,
+    Author,
+    Language,
+}
+
+impl Tag {
+    fn value(self, entry: &Entry) -> Option<&str> {
+        match self {
+            Self::Category => entry.category.as_deref(),
+            Self::Author => entry.author.as_deref(),
+            Self::Language => Some(&entry.language),
+        }
+    }
+}
+
+/// Narrows down which entries of a pool can be picked.
+///
+/// Tags are compared case-insensitively, and `max_rating` lets through
+/// everything up to and including that rating.
+#[derive(Debug, Clone, Default)]
+pub struct Filter {
+    pub category: Option<String>,
+    pub author: Option<String>,
+    pub language: Option<String>,
+    pub max_rating: Option<Rating>,
+}
+
+impl Filter {
+    fn matches(&self, entry: &Entry) -> bool {
+        let tag_matches = |wanted: &Option<String>, tag: Tag| {
+            wanted.as_deref().is_none_or(|wanted| {
+                tag.value(entry)
+                    .is_some_and(|value| value.eq_ignore_ascii_case(wanted.trim()))
+            })
+        };
+
+        tag_matches(&self.category, Tag::Category)
+            && tag_matches(&self.author, Tag::Author)
+            && tag_matches(&self.language, Tag::Language)
+            && self.max_rating.is_none_or(|max| entry.rating <= max)
+    }
+}
+
+#[derive(Deserialize)]
+struct ContentFile {
+    version: u32,
+    entries: Vec<Entry>,
+}
+
+/// Reads and validates every pool, then swaps them in all at once.
+///
+/// If any of the files is invalid nothing is replaced, so a bad edit
+/// can't take down the pools that are already loaded.
+///
+/// Returns how many entries were loaded for each pool.
+#[allow(clippy::missing_errors_doc)]
+pub fn load() -> Result<Vec<(Pool, usize)>, Error> {
+    let dir = PathBuf::from(var("CONTENT_DIR").unwrap_or_else(|_| DEFAULT_CONTENT_DIR.into()));
+
+    let mut pools = HashMap::new();
+    for pool in Pool::ALL {
+        let entries = read_pool(&dir, pool)?;
+        pools.insert(pool, entries);
+    }
+
+    let counts = Pool::ALL
+        .iter()
+        .map(|pool| (*pool, pools[pool].len()))
+        .collect();
+
+    *POOLS
+        .write()
+        .map_err(|_| Error::from("Content pools lock was poisoned"))? = pools;
+
+    info!("Loaded content pools from {}", dir.display());
+
+    Ok(counts)
+}
+
+fn read_pool(dir: &Path, pool: Pool) -> Result<Vec<Entry>, Error> {
+    let path = dir.join(pool.file_name());
+    let file = std::fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read {}: {e}", path.display()))?;
+    let file: ContentFile = serde_json::from_str(&file)
+        .map_err(|e| format!("Failed to parse {}: {e}", path.display()))?;
+
+    if file.version != SUPPORTED_VERSION {
+        return Err(format!(
+            "{} has version {}, expected {SUPPORTED_VERSION}",
+            path.display(),
+            file.version
+        )
+        .into());
+    }
+
+    if file.entries.is_empty() {
+        return Err(format!("{} has no entries", path.display()).into());
+    }
+
+    let mut seen = HashSet::new();
+    for (i, entry) in file.entries.iter().enumerate() {
+        let text = entry.text.trim();
+
+        if text.is_empty() {
+            return Err(format!("{} entry {i} is empty", path.display()).into());
+        }
+
+        if text.chars().count() > pool.max_length() {
+            return Err(format!(
+                "{} entry {i} is longer than {} characters",
+                path.display(),
+                pool.max_length()
+            )
+            .into());
+        }
+
+        if entry.language.trim().is_empty() {
+            return Err(format!("{} entry {i} has an empty language", path.display()).into());
+        }
+
+        // Each option gets its own button, and a row can't hold more than 5.
+        if pool == Pool::WouldYouRather
+            && (!(2..=5).contains(&entry.options.len())
+                || entry.options.iter().any(|option| option.trim().is_empty()))
+        {
+            return Err(format!(
+                "{} entry {i} needs between 2 and 5 non-empty options",
+                path.display()
+            )
+            .into());
+        }
+
+        if pool == Pool::Trivia
+            && (!(2..=4).contains(&entry.options.len())
+                || entry.options.iter().any(|option| option.trim().is_empty())
+                || entry
+                    .answer
+                    .is_none_or(|answer| answer >= entry.options.len()))
+        {
+            return Err(format!(
+                "{} entry {i} needs between 2 and 4 non-empty options and the index of the right one",
+                path.display()
+            )
+            .into());
+        }
+
+        if !seen.insert(text.to_lowercase()) {
+            return Err(format!("{} entry {i} is a duplicate: {text}", path.display()).into());
+        }
+    }
+
+    Ok(file.entries)
+}
+
+/// Gets a random entry from a pool.
+#[must_use]
+pub fn random(pool: Pool) -> String {
+    let pools = POOLS.read().expect("Content pools lock was poisoned");
+
+    // It's safe to use .expect() since `load` is called at startup and it
+    // never stores an empty pool. .choose() will only return `None` if it is empty.
+    pools
+        .get(&pool)
+        .and_then(|entries| entries.choose(&mut rand::thread_rng()))
+        .map(|entry| entry.text.clone())
+        .expect("Unreachable, content pools are loaded at startup")
+}
+
+/// Gets a random entry from a pool out of the ones matching `filter`.
+///
+/// Returns `None` if nothing matches.
+#[must_use]
+pub fn random_matching(pool: Pool, filter: &Filter) -> Option<String> {
+    random_entry_matching(pool, filter).map(|entry| entry.text)
+}
+
+/// Like [`random_matching`], but gets the whole entry instead of its text.
+#[must_use]
+pub fn random_entry_matching(pool: Pool, filter: &Filter) -> Option<Entry> {
+    let pools = POOLS.read().expect("Content pools lock was poisoned");
+
+    let entries = pools
+        .get(&pool)?
+        .iter()
+        .filter(|entry| filter.matches(entry))
+        .collect::<Vec<_>>();
+
+    let entry = entries
+        .choose(&mut rand::thread_rng())
+        .map(|entry| (*entry).clone());
+    drop(pools);
+
+    entry
+}
+
+/// Gets the entry of a pool with the given [`Entry::id`].
+#[must_use]
+pub fn find(pool: Pool, id: u64) -> Option<Entry> {
+    POOLS
+        .read()
+        .expect("Content pools lock was poisoned")
+        .get(&pool)?
+        .iter()
+        .find(|entry| entry.id() == id)
+        .cloned()
+}
+
+/// Lists the values of a tag used in a pool that contain `partial`, for use
+/// in autocompletes.
+#[must_use]
+pub fn tag_values(pool: Pool, tag: Tag, partial: &str) -> Vec<String> {
+    let pools = POOLS.read().expect("Content pools lock was poisoned");
+    let partial = partial.to_lowercase();
+
+    let mut values = pools
+        .get(&pool)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| tag.value(entry))
+        .filter(|value| value.to_lowercase().contains(&partial))
+        .map(str::to_string)
+        .collect::<Vec<_>>();
+    drop(pools);
+
+    values.sort_unstable();
+    values.dedup();
+    values.truncate(MAX_CHOICES);
+
+    values
+}
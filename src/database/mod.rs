@@ -1,4 +1,25 @@
-use crate::{commands::utility::add_reminder::Reminder, Error as Err};
+use crate::{
+    commands::{
+        economy::{DailyClaim, Transaction},
+        fun::{
+            action::{ActionPair, ActionPreferences, ActionRole, ActionStats},
+            custom_content::{ContentKind, CustomContent},
+            qotd::QotdPost,
+            trivia::TriviaScore,
+        },
+        leveling::LevelCurve,
+        moderation::{
+            automod::{AutomodRule, AutomodTrigger, RuleKind},
+            safety::SafetyLevel,
+        },
+        utility::{
+            add_reminder::Reminder,
+            greetings::{Greeting, GreetingKind},
+            roles::RoleMenu,
+        },
+    },
+    Error as Err,
+};
 use once_cell::sync::OnceCell;
 use poise::serenity_prelude::UserId;
 
@@ -354,55 +375,1052 @@ pub fn get_reminders_of_user(user_id: UserId) -> Result<Vec<Reminder>, Err> {
     }
 }
 
-pub fn get_action_count(
+/// Adds one to how many times a user performed an action on another, and
+/// returns the new count.
+pub fn increment_action_count(
     action: &str,
     from_user_id: UserId,
     to_user_id: UserId,
-) -> Result<i32, Err> {
+) -> Result<u64, Err> {
     #[cfg(feature = "mysql")]
     {
-        sql::get_action_count(action, from_user_id, to_user_id)
+        sql::increment_action_count(action, from_user_id, to_user_id)
     }
     #[cfg(feature = "mongodb")]
     {
         let action = action.to_string();
-        spawn_thread!(mongo::get_action_count(&action, from_user_id, to_user_id))
+        spawn_thread!(mongo::increment_action_count(
+            &action,
+            from_user_id,
+            to_user_id
+        ))
     }
     #[cfg(feature = "surrealdb")]
     {
         let action = action.to_string();
-        spawn_thread!(surreal::get_action_count(&action, from_user_id, to_user_id))
+        spawn_thread!(surreal::increment_action_count(
+            &action,
+            from_user_id,
+            to_user_id
+        ))
+    }
+}
+
+pub fn add_custom_content(content: &CustomContent) -> Result<(), Err> {
+    #[cfg(feature = "mysql")]
+    {
+        sql::add_custom_content(content)
+    }
+    #[cfg(feature = "mongodb")]
+    {
+        let content = content.clone();
+        spawn_thread!(mongo::add_custom_content(&content))
+    }
+    #[cfg(feature = "surrealdb")]
+    {
+        let content = content.clone();
+        spawn_thread!(surreal::add_custom_content(&content))
+    }
+}
+
+pub fn get_custom_content(
+    guild_id: u64,
+    kind: ContentKind,
+    approved: bool,
+) -> Result<Vec<CustomContent>, Err> {
+    #[cfg(feature = "mysql")]
+    {
+        sql::get_custom_content(guild_id, kind, approved)
+    }
+    #[cfg(feature = "mongodb")]
+    {
+        spawn_thread!(mongo::get_custom_content(guild_id, kind, approved))
+    }
+    #[cfg(feature = "surrealdb")]
+    {
+        spawn_thread!(surreal::get_custom_content(guild_id, kind, approved))
+    }
+}
+
+/// Returns whether a pending submission with that ID existed in the guild.
+pub fn approve_custom_content(id: u64, guild_id: u64) -> Result<bool, Err> {
+    #[cfg(feature = "mysql")]
+    {
+        sql::approve_custom_content(id, guild_id)
+    }
+    #[cfg(feature = "mongodb")]
+    {
+        spawn_thread!(mongo::approve_custom_content(id, guild_id))
+    }
+    #[cfg(feature = "surrealdb")]
+    {
+        spawn_thread!(surreal::approve_custom_content(id, guild_id))
+    }
+}
+
+/// Returns whether a submission with that ID existed in the guild.
+pub fn remove_custom_content(id: u64, guild_id: u64) -> Result<bool, Err> {
+    #[cfg(feature = "mysql")]
+    {
+        sql::remove_custom_content(id, guild_id)
+    }
+    #[cfg(feature = "mongodb")]
+    {
+        spawn_thread!(mongo::remove_custom_content(id, guild_id))
+    }
+    #[cfg(feature = "surrealdb")]
+    {
+        spawn_thread!(surreal::remove_custom_content(id, guild_id))
+    }
+}
+
+/// Returns `None` if the guild never configured a ratio.
+pub fn get_custom_content_ratio(guild_id: u64, kind: ContentKind) -> Result<Option<u8>, Err> {
+    #[cfg(feature = "mysql")]
+    {
+        sql::get_custom_content_ratio(guild_id, kind)
+    }
+    #[cfg(feature = "mongodb")]
+    {
+        spawn_thread!(mongo::get_custom_content_ratio(guild_id, kind))
+    }
+    #[cfg(feature = "surrealdb")]
+    {
+        spawn_thread!(surreal::get_custom_content_ratio(guild_id, kind))
+    }
+}
+
+pub fn set_custom_content_ratio(guild_id: u64, kind: ContentKind, ratio: u8) -> Result<(), Err> {
+    #[cfg(feature = "mysql")]
+    {
+        sql::set_custom_content_ratio(guild_id, kind, ratio)
+    }
+    #[cfg(feature = "mongodb")]
+    {
+        spawn_thread!(mongo::set_custom_content_ratio(guild_id, kind, ratio))
+    }
+    #[cfg(feature = "surrealdb")]
+    {
+        spawn_thread!(surreal::set_custom_content_ratio(guild_id, kind, ratio))
+    }
+}
+
+pub fn add_qotd_thread_channel(channel_id: u64) -> Result<(), Err> {
+    #[cfg(feature = "mysql")]
+    {
+        sql::add_qotd_thread_channel(channel_id)
+    }
+    #[cfg(feature = "mongodb")]
+    {
+        spawn_thread!(mongo::add_qotd_thread_channel(channel_id))
+    }
+    #[cfg(feature = "surrealdb")]
+    {
+        spawn_thread!(surreal::add_qotd_thread_channel(channel_id))
+    }
+}
+
+pub fn remove_qotd_thread_channel(channel_id: u64) -> Result<(), Err> {
+    #[cfg(feature = "mysql")]
+    {
+        sql::remove_qotd_thread_channel(channel_id)
+    }
+    #[cfg(feature = "mongodb")]
+    {
+        spawn_thread!(mongo::remove_qotd_thread_channel(channel_id))
+    }
+    #[cfg(feature = "surrealdb")]
+    {
+        spawn_thread!(surreal::remove_qotd_thread_channel(channel_id))
+    }
+}
+
+pub fn qotd_threads_enabled(channel_id: u64) -> Result<bool, Err> {
+    #[cfg(feature = "mysql")]
+    {
+        sql::qotd_threads_enabled(channel_id)
+    }
+    #[cfg(feature = "mongodb")]
+    {
+        spawn_thread!(mongo::qotd_threads_enabled(channel_id))
+    }
+    #[cfg(feature = "surrealdb")]
+    {
+        spawn_thread!(surreal::qotd_threads_enabled(channel_id))
+    }
+}
+
+pub fn add_qotd_post(post: &QotdPost) -> Result<(), Err> {
+    #[cfg(feature = "mysql")]
+    {
+        sql::add_qotd_post(post)
+    }
+    #[cfg(feature = "mongodb")]
+    {
+        let post = post.clone();
+        spawn_thread!(mongo::add_qotd_post(&post))
+    }
+    #[cfg(feature = "surrealdb")]
+    {
+        let post = post.clone();
+        spawn_thread!(surreal::add_qotd_post(&post))
+    }
+}
+
+/// Gets the posts sent to a channel at or after `timestamp`.
+pub fn get_qotd_posts_since(channel_id: u64, timestamp: u64) -> Result<Vec<QotdPost>, Err> {
+    #[cfg(feature = "mysql")]
+    {
+        sql::get_qotd_posts_since(channel_id, timestamp)
+    }
+    #[cfg(feature = "mongodb")]
+    {
+        spawn_thread!(mongo::get_qotd_posts_since(channel_id, timestamp))
+    }
+    #[cfg(feature = "surrealdb")]
+    {
+        spawn_thread!(surreal::get_qotd_posts_since(channel_id, timestamp))
+    }
+}
+
+pub fn update_qotd_post_answers(thread_id: u64, answers: u64) -> Result<(), Err> {
+    #[cfg(feature = "mysql")]
+    {
+        sql::update_qotd_post_answers(thread_id, answers)
+    }
+    #[cfg(feature = "mongodb")]
+    {
+        spawn_thread!(mongo::update_qotd_post_answers(thread_id, answers))
+    }
+    #[cfg(feature = "surrealdb")]
+    {
+        spawn_thread!(surreal::update_qotd_post_answers(thread_id, answers))
+    }
+}
+
+pub fn get_safety_level(guild_id: u64) -> Result<Option<SafetyLevel>, Err> {
+    #[cfg(feature = "mysql")]
+    {
+        sql::get_safety_level(guild_id)
+    }
+    #[cfg(feature = "mongodb")]
+    {
+        spawn_thread!(mongo::get_safety_level(guild_id))
+    }
+    #[cfg(feature = "surrealdb")]
+    {
+        spawn_thread!(surreal::get_safety_level(guild_id))
+    }
+}
+
+pub fn set_safety_level(guild_id: u64, level: SafetyLevel) -> Result<(), Err> {
+    #[cfg(feature = "mysql")]
+    {
+        sql::set_safety_level(guild_id, level)
+    }
+    #[cfg(feature = "mongodb")]
+    {
+        spawn_thread!(mongo::set_safety_level(guild_id, level))
+    }
+    #[cfg(feature = "surrealdb")]
+    {
+        spawn_thread!(surreal::set_safety_level(guild_id, level))
+    }
+}
+
+/// Sets a user's vote on a would you rather message, replacing their previous one.
+pub fn set_wyr_vote(
+    message_id: u64,
+    prompt_id: u64,
+    user_id: UserId,
+    choice: u8,
+) -> Result<(), Err> {
+    #[cfg(feature = "mysql")]
+    {
+        sql::set_wyr_vote(message_id, prompt_id, user_id, choice)
+    }
+    #[cfg(feature = "mongodb")]
+    {
+        spawn_thread!(mongo::set_wyr_vote(message_id, prompt_id, user_id, choice))
+    }
+    #[cfg(feature = "surrealdb")]
+    {
+        spawn_thread!(surreal::set_wyr_vote(
+            message_id, prompt_id, user_id, choice
+        ))
+    }
+}
+
+/// Gets how many votes each choice of a would you rather message got.
+pub fn get_wyr_message_votes(message_id: u64) -> Result<Vec<(u8, u64)>, Err> {
+    #[cfg(feature = "mysql")]
+    {
+        sql::get_wyr_message_votes(message_id)
+    }
+    #[cfg(feature = "mongodb")]
+    {
+        spawn_thread!(mongo::get_wyr_message_votes(message_id))
+    }
+    #[cfg(feature = "surrealdb")]
+    {
+        spawn_thread!(surreal::get_wyr_message_votes(message_id))
+    }
+}
+
+/// Gets how many votes each choice of a would you rather question got, across
+/// every time it was asked.
+pub fn get_wyr_prompt_votes(prompt_id: u64) -> Result<Vec<(u8, u64)>, Err> {
+    #[cfg(feature = "mysql")]
+    {
+        sql::get_wyr_prompt_votes(prompt_id)
+    }
+    #[cfg(feature = "mongodb")]
+    {
+        spawn_thread!(mongo::get_wyr_prompt_votes(prompt_id))
+    }
+    #[cfg(feature = "surrealdb")]
+    {
+        spawn_thread!(surreal::get_wyr_prompt_votes(prompt_id))
+    }
+}
+
+/// Adds the result of a trivia question to a user's score in a guild.
+pub fn add_trivia_result(
+    guild_id: u64,
+    user_id: UserId,
+    points: u64,
+    correct: bool,
+) -> Result<(), Err> {
+    #[cfg(feature = "mysql")]
+    {
+        sql::add_trivia_result(guild_id, user_id, points, correct)
+    }
+    #[cfg(feature = "mongodb")]
+    {
+        spawn_thread!(mongo::add_trivia_result(guild_id, user_id, points, correct))
+    }
+    #[cfg(feature = "surrealdb")]
+    {
+        spawn_thread!(surreal::add_trivia_result(
+            guild_id, user_id, points, correct
+        ))
     }
 }
 
-pub fn update_action_count(
+/// Gets the best trivia players of a guild, or of every guild if `guild_id`
+/// is `None`.
+pub fn get_trivia_leaderboard(guild_id: Option<u64>, limit: u64) -> Result<Vec<TriviaScore>, Err> {
+    #[cfg(feature = "mysql")]
+    {
+        sql::get_trivia_leaderboard(guild_id, limit)
+    }
+    #[cfg(feature = "mongodb")]
+    {
+        spawn_thread!(mongo::get_trivia_leaderboard(guild_id, limit))
+    }
+    #[cfg(feature = "surrealdb")]
+    {
+        spawn_thread!(surreal::get_trivia_leaderboard(guild_id, limit))
+    }
+}
+
+/// Adds one to how many times a user performed an action on another in a guild.
+pub fn add_guild_action(
+    guild_id: u64,
     action: &str,
     from_user_id: UserId,
     to_user_id: UserId,
-    amount: i32,
 ) -> Result<(), Err> {
     #[cfg(feature = "mysql")]
     {
-        sql::update_action_count(action, from_user_id, to_user_id, amount)
+        sql::add_guild_action(guild_id, action, from_user_id, to_user_id)
     }
     #[cfg(feature = "mongodb")]
     {
         let action = action.to_string();
-        spawn_thread!(mongo::update_action_count(
+        spawn_thread!(mongo::add_guild_action(
+            guild_id,
             &action,
             from_user_id,
-            to_user_id,
-            amount
+            to_user_id
         ))
     }
     #[cfg(feature = "surrealdb")]
     {
         let action = action.to_string();
-        spawn_thread!(surreal::update_action_count(
+        spawn_thread!(surreal::add_guild_action(
+            guild_id,
             &action,
             from_user_id,
-            to_user_id,
-            amount
+            to_user_id
+        ))
+    }
+}
+
+/// Gets how many times a user gave and received each action in a guild, or
+/// everywhere if `guild_id` is `None`.
+pub fn get_action_stats(guild_id: Option<u64>, user_id: UserId) -> Result<Vec<ActionStats>, Err> {
+    #[cfg(feature = "mysql")]
+    {
+        sql::get_action_stats(guild_id, user_id)
+    }
+    #[cfg(feature = "mongodb")]
+    {
+        spawn_thread!(mongo::get_action_stats(guild_id, user_id))
+    }
+    #[cfg(feature = "surrealdb")]
+    {
+        spawn_thread!(surreal::get_action_stats(guild_id, user_id))
+    }
+}
+
+/// Gets the users who performed or received an action the most in a guild, or
+/// everywhere if `guild_id` is `None`.
+pub fn get_action_leaderboard(
+    guild_id: Option<u64>,
+    action: &str,
+    role: ActionRole,
+    limit: u64,
+) -> Result<Vec<(UserId, u64)>, Err> {
+    #[cfg(feature = "mysql")]
+    {
+        sql::get_action_leaderboard(guild_id, action, role, limit)
+    }
+    #[cfg(feature = "mongodb")]
+    {
+        let action = action.to_string();
+        spawn_thread!(mongo::get_action_leaderboard(
+            guild_id, &action, role, limit
+        ))
+    }
+    #[cfg(feature = "surrealdb")]
+    {
+        let action = action.to_string();
+        spawn_thread!(surreal::get_action_leaderboard(
+            guild_id, &action, role, limit
         ))
     }
 }
+
+/// Gets the pairs of users who performed an action on each other the most in a
+/// guild, or everywhere if `guild_id` is `None`.
+pub fn get_mutual_action_pairs(
+    guild_id: Option<u64>,
+    action: &str,
+    limit: u64,
+) -> Result<Vec<ActionPair>, Err> {
+    #[cfg(feature = "mysql")]
+    {
+        sql::get_mutual_action_pairs(guild_id, action, limit)
+    }
+    #[cfg(feature = "mongodb")]
+    {
+        let action = action.to_string();
+        spawn_thread!(mongo::get_mutual_action_pairs(guild_id, &action, limit))
+    }
+    #[cfg(feature = "surrealdb")]
+    {
+        let action = action.to_string();
+        spawn_thread!(surreal::get_mutual_action_pairs(guild_id, &action, limit))
+    }
+}
+
+/// Gets what a user lets others do to them with actions, if they ever changed it.
+pub fn get_action_preferences(user_id: UserId) -> Result<Option<ActionPreferences>, Err> {
+    #[cfg(feature = "mysql")]
+    {
+        sql::get_action_preferences(user_id)
+    }
+    #[cfg(feature = "mongodb")]
+    {
+        spawn_thread!(mongo::get_action_preferences(user_id))
+    }
+    #[cfg(feature = "surrealdb")]
+    {
+        spawn_thread!(surreal::get_action_preferences(user_id))
+    }
+}
+
+pub fn set_action_preferences(preferences: &ActionPreferences) -> Result<(), Err> {
+    #[cfg(feature = "mysql")]
+    {
+        sql::set_action_preferences(preferences)
+    }
+    #[cfg(feature = "mongodb")]
+    {
+        let preferences = preferences.clone();
+        spawn_thread!(mongo::set_action_preferences(&preferences))
+    }
+    #[cfg(feature = "surrealdb")]
+    {
+        let preferences = preferences.clone();
+        spawn_thread!(surreal::set_action_preferences(&preferences))
+    }
+}
+
+/// Gets how much money a user has.
+pub fn get_balance(user_id: UserId) -> Result<u64, Err> {
+    #[cfg(feature = "mysql")]
+    {
+        sql::get_balance(user_id)
+    }
+    #[cfg(feature = "mongodb")]
+    {
+        spawn_thread!(mongo::get_balance(user_id))
+    }
+    #[cfg(feature = "surrealdb")]
+    {
+        spawn_thread!(surreal::get_balance(user_id))
+    }
+}
+
+/// Moves money and records it in the ledger, all at once.
+///
+/// Returns `false` without changing anything if the sender doesn't have enough
+/// money.
+pub fn apply_transaction(transaction: &Transaction) -> Result<bool, Err> {
+    #[cfg(feature = "mysql")]
+    {
+        sql::apply_transaction(transaction)
+    }
+    #[cfg(feature = "mongodb")]
+    {
+        let transaction = transaction.clone();
+        spawn_thread!(mongo::apply_transaction(&transaction))
+    }
+    #[cfg(feature = "surrealdb")]
+    {
+        let transaction = transaction.clone();
+        spawn_thread!(surreal::apply_transaction(&transaction))
+    }
+}
+
+/// Gives the recipient of `transaction` their daily reward, unless they already
+/// claimed it less than `cooldown` seconds ago.
+pub fn claim_daily(transaction: &Transaction, cooldown: u64) -> Result<DailyClaim, Err> {
+    #[cfg(feature = "mysql")]
+    {
+        sql::claim_daily(transaction, cooldown)
+    }
+    #[cfg(feature = "mongodb")]
+    {
+        let transaction = transaction.clone();
+        spawn_thread!(mongo::claim_daily(&transaction, cooldown))
+    }
+    #[cfg(feature = "surrealdb")]
+    {
+        let transaction = transaction.clone();
+        spawn_thread!(surreal::claim_daily(&transaction, cooldown))
+    }
+}
+
+/// Gets the richest users and their balances.
+pub fn get_balance_leaderboard(limit: u64) -> Result<Vec<(UserId, u64)>, Err> {
+    #[cfg(feature = "mysql")]
+    {
+        sql::get_balance_leaderboard(limit)
+    }
+    #[cfg(feature = "mongodb")]
+    {
+        spawn_thread!(mongo::get_balance_leaderboard(limit))
+    }
+    #[cfg(feature = "surrealdb")]
+    {
+        spawn_thread!(surreal::get_balance_leaderboard(limit))
+    }
+}
+
+/// Adds XP to a user in a guild and returns how much they have now.
+pub fn add_xp(guild_id: u64, user_id: UserId, xp: u64) -> Result<u64, Err> {
+    #[cfg(feature = "mysql")]
+    {
+        sql::add_xp(guild_id, user_id, xp)
+    }
+    #[cfg(feature = "mongodb")]
+    {
+        spawn_thread!(mongo::add_xp(guild_id, user_id, xp))
+    }
+    #[cfg(feature = "surrealdb")]
+    {
+        spawn_thread!(surreal::add_xp(guild_id, user_id, xp))
+    }
+}
+
+/// Gets how much XP a user has in a guild, and their position among its
+/// members.
+pub fn get_xp_rank(guild_id: u64, user_id: UserId) -> Result<(u64, u64), Err> {
+    #[cfg(feature = "mysql")]
+    {
+        sql::get_xp_rank(guild_id, user_id)
+    }
+    #[cfg(feature = "mongodb")]
+    {
+        spawn_thread!(mongo::get_xp_rank(guild_id, user_id))
+    }
+    #[cfg(feature = "surrealdb")]
+    {
+        spawn_thread!(surreal::get_xp_rank(guild_id, user_id))
+    }
+}
+
+/// Gets the members of a guild with the most XP.
+pub fn get_xp_leaderboard(guild_id: u64, limit: u64) -> Result<Vec<(UserId, u64)>, Err> {
+    #[cfg(feature = "mysql")]
+    {
+        sql::get_xp_leaderboard(guild_id, limit)
+    }
+    #[cfg(feature = "mongodb")]
+    {
+        spawn_thread!(mongo::get_xp_leaderboard(guild_id, limit))
+    }
+    #[cfg(feature = "surrealdb")]
+    {
+        spawn_thread!(surreal::get_xp_leaderboard(guild_id, limit))
+    }
+}
+
+/// Gets the level curve of a guild, if it ever changed it.
+pub fn get_level_curve(guild_id: u64) -> Result<Option<LevelCurve>, Err> {
+    #[cfg(feature = "mysql")]
+    {
+        sql::get_level_curve(guild_id)
+    }
+    #[cfg(feature = "mongodb")]
+    {
+        spawn_thread!(mongo::get_level_curve(guild_id))
+    }
+    #[cfg(feature = "surrealdb")]
+    {
+        spawn_thread!(surreal::get_level_curve(guild_id))
+    }
+}
+
+pub fn set_level_curve(guild_id: u64, curve: &LevelCurve) -> Result<(), Err> {
+    #[cfg(feature = "mysql")]
+    {
+        sql::set_level_curve(guild_id, curve)
+    }
+    #[cfg(feature = "mongodb")]
+    {
+        let curve = *curve;
+        spawn_thread!(mongo::set_level_curve(guild_id, &curve))
+    }
+    #[cfg(feature = "surrealdb")]
+    {
+        let curve = *curve;
+        spawn_thread!(surreal::set_level_curve(guild_id, &curve))
+    }
+}
+
+/// Makes a role be given at a level, replacing the level it was given at before.
+pub fn add_level_reward(guild_id: u64, level: u64, role_id: u64) -> Result<(), Err> {
+    #[cfg(feature = "mysql")]
+    {
+        sql::add_level_reward(guild_id, level, role_id)
+    }
+    #[cfg(feature = "mongodb")]
+    {
+        spawn_thread!(mongo::add_level_reward(guild_id, level, role_id))
+    }
+    #[cfg(feature = "surrealdb")]
+    {
+        spawn_thread!(surreal::add_level_reward(guild_id, level, role_id))
+    }
+}
+
+/// Returns `false` if the role wasn't a level reward.
+pub fn remove_level_reward(guild_id: u64, role_id: u64) -> Result<bool, Err> {
+    #[cfg(feature = "mysql")]
+    {
+        sql::remove_level_reward(guild_id, role_id)
+    }
+    #[cfg(feature = "mongodb")]
+    {
+        spawn_thread!(mongo::remove_level_reward(guild_id, role_id))
+    }
+    #[cfg(feature = "surrealdb")]
+    {
+        spawn_thread!(surreal::remove_level_reward(guild_id, role_id))
+    }
+}
+
+/// Gets the level every reward role of a guild is given at.
+pub fn get_level_rewards(guild_id: u64) -> Result<Vec<(u64, u64)>, Err> {
+    #[cfg(feature = "mysql")]
+    {
+        sql::get_level_rewards(guild_id)
+    }
+    #[cfg(feature = "mongodb")]
+    {
+        spawn_thread!(mongo::get_level_rewards(guild_id))
+    }
+    #[cfg(feature = "surrealdb")]
+    {
+        spawn_thread!(surreal::get_level_rewards(guild_id))
+    }
+}
+
+pub fn get_greeting(guild_id: u64, kind: GreetingKind) -> Result<Option<Greeting>, Err> {
+    #[cfg(feature = "mysql")]
+    {
+        sql::get_greeting(guild_id, kind)
+    }
+    #[cfg(feature = "mongodb")]
+    {
+        spawn_thread!(mongo::get_greeting(guild_id, kind))
+    }
+    #[cfg(feature = "surrealdb")]
+    {
+        spawn_thread!(surreal::get_greeting(guild_id, kind))
+    }
+}
+
+/// Sets up a greeting, replacing the guild's greeting of the same kind.
+pub fn set_greeting(greeting: &Greeting) -> Result<(), Err> {
+    #[cfg(feature = "mysql")]
+    {
+        sql::set_greeting(greeting)
+    }
+    #[cfg(feature = "mongodb")]
+    {
+        let greeting = greeting.clone();
+        spawn_thread!(mongo::set_greeting(&greeting))
+    }
+    #[cfg(feature = "surrealdb")]
+    {
+        let greeting = greeting.clone();
+        spawn_thread!(surreal::set_greeting(&greeting))
+    }
+}
+
+pub fn remove_greeting(guild_id: u64, kind: GreetingKind) -> Result<bool, Err> {
+    #[cfg(feature = "mysql")]
+    {
+        sql::remove_greeting(guild_id, kind)
+    }
+    #[cfg(feature = "mongodb")]
+    {
+        spawn_thread!(mongo::remove_greeting(guild_id, kind))
+    }
+    #[cfg(feature = "surrealdb")]
+    {
+        spawn_thread!(surreal::remove_greeting(guild_id, kind))
+    }
+}
+
+pub fn add_role_menu(menu: &RoleMenu) -> Result<(), Err> {
+    #[cfg(feature = "mysql")]
+    {
+        sql::add_role_menu(menu)
+    }
+    #[cfg(feature = "mongodb")]
+    {
+        let menu = menu.clone();
+        spawn_thread!(mongo::add_role_menu(&menu))
+    }
+    #[cfg(feature = "surrealdb")]
+    {
+        let menu = menu.clone();
+        spawn_thread!(surreal::add_role_menu(&menu))
+    }
+}
+
+pub fn get_role_menu(message_id: u64) -> Result<Option<RoleMenu>, Err> {
+    #[cfg(feature = "mysql")]
+    {
+        sql::get_role_menu(message_id)
+    }
+    #[cfg(feature = "mongodb")]
+    {
+        spawn_thread!(mongo::get_role_menu(message_id))
+    }
+    #[cfg(feature = "surrealdb")]
+    {
+        spawn_thread!(surreal::get_role_menu(message_id))
+    }
+}
+
+pub fn add_auto_role(guild_id: u64, role_id: u64) -> Result<(), Err> {
+    #[cfg(feature = "mysql")]
+    {
+        sql::add_auto_role(guild_id, role_id)
+    }
+    #[cfg(feature = "mongodb")]
+    {
+        spawn_thread!(mongo::add_auto_role(guild_id, role_id))
+    }
+    #[cfg(feature = "surrealdb")]
+    {
+        spawn_thread!(surreal::add_auto_role(guild_id, role_id))
+    }
+}
+
+pub fn remove_auto_role(guild_id: u64, role_id: u64) -> Result<bool, Err> {
+    #[cfg(feature = "mysql")]
+    {
+        sql::remove_auto_role(guild_id, role_id)
+    }
+    #[cfg(feature = "mongodb")]
+    {
+        spawn_thread!(mongo::remove_auto_role(guild_id, role_id))
+    }
+    #[cfg(feature = "surrealdb")]
+    {
+        spawn_thread!(surreal::remove_auto_role(guild_id, role_id))
+    }
+}
+
+pub fn get_auto_roles(guild_id: u64) -> Result<Vec<u64>, Err> {
+    #[cfg(feature = "mysql")]
+    {
+        sql::get_auto_roles(guild_id)
+    }
+    #[cfg(feature = "mongodb")]
+    {
+        spawn_thread!(mongo::get_auto_roles(guild_id))
+    }
+    #[cfg(feature = "surrealdb")]
+    {
+        spawn_thread!(surreal::get_auto_roles(guild_id))
+    }
+}
+
+/// Turns on a rule, replacing the guild's rule of the same kind.
+pub fn set_automod_rule(rule: &AutomodRule) -> Result<(), Err> {
+    #[cfg(feature = "mysql")]
+    {
+        sql::set_automod_rule(rule)
+    }
+    #[cfg(feature = "mongodb")]
+    {
+        let rule = *rule;
+        spawn_thread!(mongo::set_automod_rule(&rule))
+    }
+    #[cfg(feature = "surrealdb")]
+    {
+        let rule = *rule;
+        spawn_thread!(surreal::set_automod_rule(&rule))
+    }
+}
+
+pub fn remove_automod_rule(guild_id: u64, kind: RuleKind) -> Result<bool, Err> {
+    #[cfg(feature = "mysql")]
+    {
+        sql::remove_automod_rule(guild_id, kind)
+    }
+    #[cfg(feature = "mongodb")]
+    {
+        spawn_thread!(mongo::remove_automod_rule(guild_id, kind))
+    }
+    #[cfg(feature = "surrealdb")]
+    {
+        spawn_thread!(surreal::remove_automod_rule(guild_id, kind))
+    }
+}
+
+pub fn get_automod_rules(guild_id: u64) -> Result<Vec<AutomodRule>, Err> {
+    #[cfg(feature = "mysql")]
+    {
+        sql::get_automod_rules(guild_id)
+    }
+    #[cfg(feature = "mongodb")]
+    {
+        spawn_thread!(mongo::get_automod_rules(guild_id))
+    }
+    #[cfg(feature = "surrealdb")]
+    {
+        spawn_thread!(surreal::get_automod_rules(guild_id))
+    }
+}
+
+pub fn add_blocked_pattern(guild_id: u64, pattern: &str) -> Result<(), Err> {
+    #[cfg(feature = "mysql")]
+    {
+        sql::add_blocked_pattern(guild_id, &pattern)
+    }
+    #[cfg(feature = "mongodb")]
+    {
+        let pattern = pattern.to_string();
+        spawn_thread!(mongo::add_blocked_pattern(guild_id, &pattern))
+    }
+    #[cfg(feature = "surrealdb")]
+    {
+        let pattern = pattern.to_string();
+        spawn_thread!(surreal::add_blocked_pattern(guild_id, &pattern))
+    }
+}
+
+pub fn remove_blocked_pattern(guild_id: u64, pattern: &str) -> Result<bool, Err> {
+    #[cfg(feature = "mysql")]
+    {
+        sql::remove_blocked_pattern(guild_id, &pattern)
+    }
+    #[cfg(feature = "mongodb")]
+    {
+        let pattern = pattern.to_string();
+        spawn_thread!(mongo::remove_blocked_pattern(guild_id, &pattern))
+    }
+    #[cfg(feature = "surrealdb")]
+    {
+        let pattern = pattern.to_string();
+        spawn_thread!(surreal::remove_blocked_pattern(guild_id, &pattern))
+    }
+}
+
+pub fn get_blocked_patterns(guild_id: u64) -> Result<Vec<String>, Err> {
+    #[cfg(feature = "mysql")]
+    {
+        sql::get_blocked_patterns(guild_id)
+    }
+    #[cfg(feature = "mongodb")]
+    {
+        spawn_thread!(mongo::get_blocked_patterns(guild_id))
+    }
+    #[cfg(feature = "surrealdb")]
+    {
+        spawn_thread!(surreal::get_blocked_patterns(guild_id))
+    }
+}
+
+/// Times members out for `minutes` once they reach `strikes`, replacing the
+/// timeout set for that many strikes before.
+pub fn set_automod_escalation(guild_id: u64, strikes: u64, minutes: u64) -> Result<(), Err> {
+    #[cfg(feature = "mysql")]
+    {
+        sql::set_automod_escalation(guild_id, strikes, minutes)
+    }
+    #[cfg(feature = "mongodb")]
+    {
+        spawn_thread!(mongo::set_automod_escalation(guild_id, strikes, minutes))
+    }
+    #[cfg(feature = "surrealdb")]
+    {
+        spawn_thread!(surreal::set_automod_escalation(guild_id, strikes, minutes))
+    }
+}
+
+pub fn remove_automod_escalation(guild_id: u64, strikes: u64) -> Result<bool, Err> {
+    #[cfg(feature = "mysql")]
+    {
+        sql::remove_automod_escalation(guild_id, strikes)
+    }
+    #[cfg(feature = "mongodb")]
+    {
+        spawn_thread!(mongo::remove_automod_escalation(guild_id, strikes))
+    }
+    #[cfg(feature = "surrealdb")]
+    {
+        spawn_thread!(surreal::remove_automod_escalation(guild_id, strikes))
+    }
+}
+
+/// Gets the timeout in minutes given at each number of strikes.
+pub fn get_automod_escalations(guild_id: u64) -> Result<Vec<(u64, u64)>, Err> {
+    #[cfg(feature = "mysql")]
+    {
+        sql::get_automod_escalations(guild_id)
+    }
+    #[cfg(feature = "mongodb")]
+    {
+        spawn_thread!(mongo::get_automod_escalations(guild_id))
+    }
+    #[cfg(feature = "surrealdb")]
+    {
+        spawn_thread!(surreal::get_automod_escalations(guild_id))
+    }
+}
+
+pub fn set_automod_log_channel(guild_id: u64, channel_id: u64) -> Result<(), Err> {
+    #[cfg(feature = "mysql")]
+    {
+        sql::set_automod_log_channel(guild_id, channel_id)
+    }
+    #[cfg(feature = "mongodb")]
+    {
+        spawn_thread!(mongo::set_automod_log_channel(guild_id, channel_id))
+    }
+    #[cfg(feature = "surrealdb")]
+    {
+        spawn_thread!(surreal::set_automod_log_channel(guild_id, channel_id))
+    }
+}
+
+pub fn remove_automod_log_channel(guild_id: u64) -> Result<(), Err> {
+    #[cfg(feature = "mysql")]
+    {
+        sql::remove_automod_log_channel(guild_id)
+    }
+    #[cfg(feature = "mongodb")]
+    {
+        spawn_thread!(mongo::remove_automod_log_channel(guild_id))
+    }
+    #[cfg(feature = "surrealdb")]
+    {
+        spawn_thread!(surreal::remove_automod_log_channel(guild_id))
+    }
+}
+
+pub fn get_automod_log_channel(guild_id: u64) -> Result<Option<u64>, Err> {
+    #[cfg(feature = "mysql")]
+    {
+        sql::get_automod_log_channel(guild_id)
+    }
+    #[cfg(feature = "mongodb")]
+    {
+        spawn_thread!(mongo::get_automod_log_channel(guild_id))
+    }
+    #[cfg(feature = "surrealdb")]
+    {
+        spawn_thread!(surreal::get_automod_log_channel(guild_id))
+    }
+}
+
+pub fn add_automod_trigger(trigger: &AutomodTrigger) -> Result<(), Err> {
+    #[cfg(feature = "mysql")]
+    {
+        sql::add_automod_trigger(trigger)
+    }
+    #[cfg(feature = "mongodb")]
+    {
+        let trigger = *trigger;
+        spawn_thread!(mongo::add_automod_trigger(&trigger))
+    }
+    #[cfg(feature = "surrealdb")]
+    {
+        let trigger = *trigger;
+        spawn_thread!(surreal::add_automod_trigger(&trigger))
+    }
+}
+
+/// Removes the triggers logged before the UNIX timestamp `before` in every
+/// guild.
+pub fn remove_automod_triggers_before(before: u64) -> Result<(), Err> {
+    #[cfg(feature = "mysql")]
+    {
+        sql::remove_automod_triggers_before(before)
+    }
+    #[cfg(feature = "mongodb")]
+    {
+        spawn_thread!(mongo::remove_automod_triggers_before(before))
+    }
+    #[cfg(feature = "surrealdb")]
+    {
+        spawn_thread!(surreal::remove_automod_triggers_before(before))
+    }
+}
+
+/// Counts the rules a member broke since the UNIX timestamp `since`.
+pub fn count_automod_triggers(guild_id: u64, user_id: u64, since: u64) -> Result<u64, Err> {
+    #[cfg(feature = "mysql")]
+    {
+        sql::count_automod_triggers(guild_id, user_id, since)
+    }
+    #[cfg(feature = "mongodb")]
+    {
+        spawn_thread!(mongo::count_automod_triggers(guild_id, user_id, since))
+    }
+    #[cfg(feature = "surrealdb")]
+    {
+        spawn_thread!(surreal::count_automod_triggers(guild_id, user_id, since))
+    }
+}
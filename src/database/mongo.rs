@@ -1,9 +1,33 @@
 use std::env::var;
 
-use crate::{commands::utility::add_reminder::Reminder, Error as Err};
+use crate::{
+    commands::{
+        economy::{DailyClaim, Transaction},
+        fun::{
+            action::{ActionPair, ActionPreferences, ActionRole, ActionStats},
+            custom_content::{ContentKind, CustomContent},
+            qotd::QotdPost,
+            trivia::TriviaScore,
+        },
+        leveling::LevelCurve,
+        moderation::{
+            automod::{AutomodRule, AutomodTrigger, RuleAction, RuleKind},
+            safety::SafetyLevel,
+        },
+        utility::{
+            add_reminder::Reminder,
+            greetings::{Greeting, GreetingKind},
+            roles::{RoleMenu, RoleMenuMode},
+        },
+    },
+    Error as Err,
+};
+use log::warn;
 use mongodb::{
-    bson::{doc, Document},
-    sync::Client,
+    bson::{doc, Bson, Document},
+    error::{TRANSIENT_TRANSACTION_ERROR, UNKNOWN_TRANSACTION_COMMIT_RESULT},
+    options::{FindOneAndUpdateOptions, ReturnDocument, UpdateOptions},
+    sync::{Client, ClientSession},
 };
 use poise::serenity_prelude::UserId;
 
@@ -11,9 +35,26 @@ use super::DB_CONN;
 
 const DB_NAME: &str = "ShibaBot";
 
+/// How many times a transaction is tried before giving up on errors that are
+/// safe to retry, like write conflicts with concurrent transactions.
+const TRANSACTION_ATTEMPTS: u32 = 5;
+
 pub fn initialize_db() -> Result<(), Err> {
     let uri = var("MONGODB_URI")?;
     let client = Client::with_uri_str(uri)?;
+
+    // Transactions only work on replica sets and sharded clusters, and the
+    // economy can't work without them.
+    match client
+        .database("admin")
+        .run_command(doc! { "hello": 1 }, None)
+    {
+        Ok(hello) if hello.contains_key("setName") || hello.get_str("msg") == Ok("isdbgrid") => {}
+        Ok(_) => warn!(
+            "MongoDB isn't running as a replica set, so economy commands won't work. Convert it to a single node replica set to use them."
+        ),
+        Err(e) => warn!("Couldn't check whether MongoDB is running as a replica set: {e}"),
+    }
     unsafe {
         if DB_CONN.get().is_none() {
             DB_CONN.set(client).expect("Could not set DB_CONN.");
@@ -370,75 +411,1307 @@ pub fn get_reminders_of_user(user_id: UserId) -> Result<Vec<Reminder>, Err> {
     Ok(reminders)
 }
 
-pub fn get_action_count(
+pub fn increment_action_count(
     action: &str,
     from_user_id: UserId,
     to_user_id: UserId,
-) -> Result<i32, Err> {
+) -> Result<u64, Err> {
     let conn = get_db_conn()?;
 
-    let count: Vec<i32> = conn
+    let doc = conn
         .database(DB_NAME)
         .collection::<Document>("actions")
-        .find(
+        .find_one_and_update(
             doc! {
                 "action": action,
                 "from_user_id": from_user_id.to_string(),
                 "to_user_id": to_user_id.to_string()
             },
+            // Counts have always been stored as 32 bit integers.
+            doc! { "$inc": { "amount": 1_i32 } },
+            FindOneAndUpdateOptions::builder()
+                .upsert(true)
+                .return_document(ReturnDocument::After)
+                .build(),
+        )?
+        .ok_or("Error when trying to increment action count.")?;
+
+    get_count(&doc, "amount")
+}
+
+fn custom_content_from_doc(doc: &Document) -> Result<CustomContent, Err> {
+    Ok(CustomContent {
+        id: doc.get_str("id")?.parse::<u64>()?,
+        guild_id: doc.get_str("guild_id")?.parse::<u64>()?,
+        kind: ContentKind::from_db(doc.get_str("kind")?),
+        content: doc.get_str("content")?.to_string(),
+        author_id: UserId::new(doc.get_str("author_id")?.parse::<u64>()?),
+        approved: doc.get_bool("approved")?,
+    })
+}
+
+pub fn add_custom_content(content: &CustomContent) -> Result<(), Err> {
+    let conn = get_db_conn()?;
+
+    conn.database(DB_NAME)
+        .collection::<Document>("custom_content")
+        .insert_one(
+            doc! {
+                "id": content.id.to_string(),
+                "guild_id": content.guild_id.to_string(),
+                "kind": content.kind.as_str(),
+                "content": &content.content,
+                "author_id": content.author_id.to_string(),
+                "approved": content.approved
+            },
+            None,
+        )?;
+
+    Ok(())
+}
+
+pub fn get_custom_content(
+    guild_id: u64,
+    kind: ContentKind,
+    approved: bool,
+) -> Result<Vec<CustomContent>, Err> {
+    let conn = get_db_conn()?;
+
+    conn.database(DB_NAME)
+        .collection::<Document>("custom_content")
+        .find(
+            doc! {
+                "guild_id": guild_id.to_string(),
+                "kind": kind.as_str(),
+                "approved": approved
+            },
+            None,
+        )?
+        .map(|doc| custom_content_from_doc(&doc?))
+        .collect()
+}
+
+pub fn approve_custom_content(id: u64, guild_id: u64) -> Result<bool, Err> {
+    let conn = get_db_conn()?;
+
+    let result = conn
+        .database(DB_NAME)
+        .collection::<Document>("custom_content")
+        .update_one(
+            doc! { "id": id.to_string(), "guild_id": guild_id.to_string(), "approved": false },
+            doc! { "$set": { "approved": true } },
+            None,
+        )?;
+
+    Ok(result.matched_count > 0)
+}
+
+pub fn remove_custom_content(id: u64, guild_id: u64) -> Result<bool, Err> {
+    let conn = get_db_conn()?;
+
+    let result = conn
+        .database(DB_NAME)
+        .collection::<Document>("custom_content")
+        .delete_one(
+            doc! { "id": id.to_string(), "guild_id": guild_id.to_string() },
+            None,
+        )?;
+
+    Ok(result.deleted_count > 0)
+}
+
+pub fn get_custom_content_ratio(guild_id: u64, kind: ContentKind) -> Result<Option<u8>, Err> {
+    let conn = get_db_conn()?;
+
+    conn.database(DB_NAME)
+        .collection::<Document>("custom_content_settings")
+        .find_one(
+            doc! { "guild_id": guild_id.to_string(), "kind": kind.as_str() },
+            None,
+        )?
+        .map(|doc| Ok(u8::try_from(doc.get_i32("ratio")?)?))
+        .transpose()
+}
+
+pub fn set_custom_content_ratio(guild_id: u64, kind: ContentKind, ratio: u8) -> Result<(), Err> {
+    let conn = get_db_conn()?;
+
+    conn.database(DB_NAME)
+        .collection::<Document>("custom_content_settings")
+        .update_one(
+            doc! { "guild_id": guild_id.to_string(), "kind": kind.as_str() },
+            doc! { "$set": { "ratio": i32::from(ratio) } },
+            UpdateOptions::builder().upsert(true).build(),
+        )?;
+
+    Ok(())
+}
+
+pub fn add_qotd_thread_channel(channel_id: u64) -> Result<(), Err> {
+    let conn = get_db_conn()?;
+
+    conn.database(DB_NAME)
+        .collection::<Document>("qotd_threads")
+        .update_one(
+            doc! { "channel_id": channel_id.to_string() },
+            doc! { "$set": { "channel_id": channel_id.to_string() } },
+            UpdateOptions::builder().upsert(true).build(),
+        )?;
+
+    Ok(())
+}
+
+pub fn remove_qotd_thread_channel(channel_id: u64) -> Result<(), Err> {
+    let conn = get_db_conn()?;
+
+    conn.database(DB_NAME)
+        .collection::<Document>("qotd_threads")
+        .delete_one(doc! { "channel_id": channel_id.to_string() }, None)?;
+
+    Ok(())
+}
+
+pub fn qotd_threads_enabled(channel_id: u64) -> Result<bool, Err> {
+    let conn = get_db_conn()?;
+
+    Ok(conn
+        .database(DB_NAME)
+        .collection::<Document>("qotd_threads")
+        .find_one(doc! { "channel_id": channel_id.to_string() }, None)?
+        .is_some())
+}
+
+pub fn add_qotd_post(post: &QotdPost) -> Result<(), Err> {
+    let conn = get_db_conn()?;
+
+    conn.database(DB_NAME)
+        .collection::<Document>("qotd_posts")
+        .insert_one(
+            doc! {
+                "channel_id": post.channel_id.to_string(),
+                "thread_id": post.thread_id.to_string(),
+                "question": &post.question,
+                "timestamp": i64::try_from(post.timestamp)?,
+                "answers": i64::try_from(post.answers)?
+            },
+            None,
+        )?;
+
+    Ok(())
+}
+
+pub fn get_qotd_posts_since(channel_id: u64, timestamp: u64) -> Result<Vec<QotdPost>, Err> {
+    let conn = get_db_conn()?;
+
+    conn.database(DB_NAME)
+        .collection::<Document>("qotd_posts")
+        .find(
+            doc! {
+                "channel_id": channel_id.to_string(),
+                "timestamp": { "$gte": i64::try_from(timestamp)? }
+            },
             None,
         )?
         .map(|doc| {
-            doc.expect("Error when trying to get action count.")
-                .get_i32("amount")
-                .expect("Error when trying to get action count.")
+            let doc = doc?;
+            Ok(QotdPost {
+                channel_id: doc.get_str("channel_id")?.parse::<u64>()?,
+                thread_id: doc.get_str("thread_id")?.parse::<u64>()?,
+                question: doc.get_str("question")?.to_string(),
+                timestamp: u64::try_from(doc.get_i64("timestamp")?)?,
+                answers: u64::try_from(doc.get_i64("answers")?)?,
+            })
         })
-        .collect();
+        .collect()
+}
 
-    if count.is_empty() {
-        // First time these two users performed this action, create a new entry
-        conn.database(DB_NAME)
-            .collection::<Document>("actions")
-            .insert_one(
+pub fn update_qotd_post_answers(thread_id: u64, answers: u64) -> Result<(), Err> {
+    let conn = get_db_conn()?;
+
+    conn.database(DB_NAME)
+        .collection::<Document>("qotd_posts")
+        .update_one(
+            doc! { "thread_id": thread_id.to_string() },
+            doc! { "$set": { "answers": i64::try_from(answers)? } },
+            None,
+        )?;
+
+    Ok(())
+}
+
+pub fn get_safety_level(guild_id: u64) -> Result<Option<SafetyLevel>, Err> {
+    let conn = get_db_conn()?;
+
+    conn.database(DB_NAME)
+        .collection::<Document>("safety_levels")
+        .find_one(doc! { "guild_id": guild_id.to_string() }, None)?
+        .map(|doc| Ok(SafetyLevel::from_name(doc.get_str("level")?)))
+        .transpose()
+        .map(Option::flatten)
+}
+
+pub fn set_safety_level(guild_id: u64, level: SafetyLevel) -> Result<(), Err> {
+    let conn = get_db_conn()?;
+
+    conn.database(DB_NAME)
+        .collection::<Document>("safety_levels")
+        .update_one(
+            doc! { "guild_id": guild_id.to_string() },
+            doc! { "$set": { "level": level.as_str() } },
+            UpdateOptions::builder().upsert(true).build(),
+        )?;
+
+    Ok(())
+}
+
+pub fn set_wyr_vote(
+    message_id: u64,
+    prompt_id: u64,
+    user_id: UserId,
+    choice: u8,
+) -> Result<(), Err> {
+    let conn = get_db_conn()?;
+
+    conn.database(DB_NAME)
+        .collection::<Document>("wyr_votes")
+        .update_one(
+            doc! { "message_id": message_id.to_string(), "user_id": user_id.to_string() },
+            doc! { "$set": { "prompt_id": prompt_id.to_string(), "choice": i32::from(choice) } },
+            UpdateOptions::builder().upsert(true).build(),
+        )?;
+
+    Ok(())
+}
+
+pub fn get_wyr_message_votes(message_id: u64) -> Result<Vec<(u8, u64)>, Err> {
+    count_wyr_votes(doc! { "message_id": message_id.to_string() })
+}
+
+pub fn get_wyr_prompt_votes(prompt_id: u64) -> Result<Vec<(u8, u64)>, Err> {
+    count_wyr_votes(doc! { "prompt_id": prompt_id.to_string() })
+}
+
+fn count_wyr_votes(filter: Document) -> Result<Vec<(u8, u64)>, Err> {
+    let conn = get_db_conn()?;
+
+    conn.database(DB_NAME)
+        .collection::<Document>("wyr_votes")
+        .aggregate(
+            [
+                doc! { "$match": filter },
+                doc! { "$group": { "_id": "$choice", "votes": { "$sum": 1 } } },
+            ],
+            None,
+        )?
+        .map(|doc| {
+            let doc = doc?;
+            Ok((
+                u8::try_from(doc.get_i32("_id")?)?,
+                u64::try_from(doc.get_i32("votes")?)?,
+            ))
+        })
+        .collect()
+}
+
+pub fn add_trivia_result(
+    guild_id: u64,
+    user_id: UserId,
+    points: u64,
+    correct: bool,
+) -> Result<(), Err> {
+    let conn = get_db_conn()?;
+
+    conn.database(DB_NAME)
+        .collection::<Document>("trivia_scores")
+        .update_one(
+            doc! { "guild_id": guild_id.to_string(), "user_id": user_id.to_string() },
+            doc! {
+                "$inc": {
+                    "points": i64::try_from(points)?,
+                    "correct": i64::from(correct),
+                    "answered": 1_i64
+                }
+            },
+            UpdateOptions::builder().upsert(true).build(),
+        )?;
+
+    Ok(())
+}
+
+pub fn get_trivia_leaderboard(guild_id: Option<u64>, limit: u64) -> Result<Vec<TriviaScore>, Err> {
+    let conn = get_db_conn()?;
+
+    let filter = guild_id.map_or_else(Document::new, |guild_id| {
+        doc! { "guild_id": guild_id.to_string() }
+    });
+
+    conn.database(DB_NAME)
+        .collection::<Document>("trivia_scores")
+        .aggregate(
+            [
+                doc! { "$match": filter },
                 doc! {
-                    "action": action,
-                    "from_user_id": from_user_id.to_string(),
-                    "to_user_id": to_user_id.to_string(),
-                    "amount": 0
+                    "$group": {
+                        "_id": "$user_id",
+                        "points": { "$sum": "$points" },
+                        "correct": { "$sum": "$correct" },
+                        "answered": { "$sum": "$answered" }
+                    }
                 },
-                None,
-            )?;
-
-        Ok(0)
-    } else {
-        Ok(count[0])
-    }
+                doc! { "$sort": { "points": -1 } },
+                doc! { "$limit": i64::try_from(limit)? },
+            ],
+            None,
+        )?
+        .map(|doc| {
+            let doc = doc?;
+            Ok(TriviaScore {
+                user_id: UserId::new(doc.get_str("_id")?.parse::<u64>()?),
+                points: u64::try_from(doc.get_i64("points")?)?,
+                correct: u64::try_from(doc.get_i64("correct")?)?,
+                answered: u64::try_from(doc.get_i64("answered")?)?,
+            })
+        })
+        .collect()
 }
 
-pub fn update_action_count(
+pub fn add_guild_action(
+    guild_id: u64,
     action: &str,
     from_user_id: UserId,
     to_user_id: UserId,
-    amount: i32,
 ) -> Result<(), Err> {
     let conn = get_db_conn()?;
 
     conn.database(DB_NAME)
-        .collection::<Document>("actions")
+        .collection::<Document>("guild_actions")
         .update_one(
             doc! {
+                "guild_id": guild_id.to_string(),
                 "action": action,
                 "from_user_id": from_user_id.to_string(),
                 "to_user_id": to_user_id.to_string()
             },
+            doc! { "$inc": { "amount": 1_i64 } },
+            UpdateOptions::builder().upsert(true).build(),
+        )?;
+
+    Ok(())
+}
+
+/// Gets the collection the actions of a scope are counted in, and the filter
+/// that limits a query to that scope.
+///
+/// `actions` has every action ever performed, while `guild_actions` only has
+/// the ones performed since guilds started being tracked.
+fn action_scope(guild_id: Option<u64>) -> (&'static str, Document) {
+    guild_id.map_or_else(
+        || ("actions", Document::new()),
+        |guild_id| ("guild_actions", doc! { "guild_id": guild_id.to_string() }),
+    )
+}
+
+/// Gets a count that might have been stored as either a 32 or 64 bit integer.
+fn get_count(doc: &Document, key: &str) -> Result<u64, Err> {
+    match doc.get(key) {
+        Some(Bson::Int32(count)) => Ok(u64::try_from(*count)?),
+        Some(Bson::Int64(count)) => Ok(u64::try_from(*count)?),
+        _ => Err(format!("Error when trying to get {key}.").into()),
+    }
+}
+
+pub fn get_action_stats(guild_id: Option<u64>, user_id: UserId) -> Result<Vec<ActionStats>, Err> {
+    let conn = get_db_conn()?;
+    let (collection, scope) = action_scope(guild_id);
+
+    let totals = |role: ActionRole| -> Result<Vec<(String, u64)>, Err> {
+        let mut filter = scope.clone();
+        filter.insert(role.column(), user_id.to_string());
+
+        conn.database(DB_NAME)
+            .collection::<Document>(collection)
+            .aggregate(
+                [
+                    doc! { "$match": filter },
+                    doc! { "$group": { "_id": "$action", "amount": { "$sum": "$amount" } } },
+                ],
+                None,
+            )?
+            .map(|doc| {
+                let doc = doc?;
+                Ok((doc.get_str("_id")?.to_string(), get_count(&doc, "amount")?))
+            })
+            .collect()
+    };
+
+    Ok(ActionStats::merge(
+        totals(ActionRole::Performer)?,
+        totals(ActionRole::Receiver)?,
+    ))
+}
+
+pub fn get_action_leaderboard(
+    guild_id: Option<u64>,
+    action: &str,
+    role: ActionRole,
+    limit: u64,
+) -> Result<Vec<(UserId, u64)>, Err> {
+    let conn = get_db_conn()?;
+    let (collection, mut filter) = action_scope(guild_id);
+    filter.insert("action", action);
+
+    conn.database(DB_NAME)
+        .collection::<Document>(collection)
+        .aggregate(
+            [
+                doc! { "$match": filter },
+                doc! {
+                    "$group": {
+                        "_id": format!("${}", role.column()),
+                        "amount": { "$sum": "$amount" }
+                    }
+                },
+                doc! { "$sort": { "amount": -1 } },
+                doc! { "$limit": i64::try_from(limit)? },
+            ],
+            None,
+        )?
+        .map(|doc| {
+            let doc = doc?;
+            Ok((
+                UserId::new(doc.get_str("_id")?.parse::<u64>()?),
+                get_count(&doc, "amount")?,
+            ))
+        })
+        .collect()
+}
+
+pub fn get_mutual_action_pairs(
+    guild_id: Option<u64>,
+    action: &str,
+    limit: u64,
+) -> Result<Vec<ActionPair>, Err> {
+    let conn = get_db_conn()?;
+    let (collection, mut filter) = action_scope(guild_id);
+    filter.insert("action", action);
+
+    // Matches the action going the other way, in the same scope.
+    let mut reverse = vec![
+        doc! { "$eq": ["$action", action] },
+        doc! { "$eq": ["$from_user_id", "$$to_user_id"] },
+        doc! { "$eq": ["$to_user_id", "$$from_user_id"] },
+    ];
+    if let Some(guild_id) = guild_id {
+        reverse.push(doc! { "$eq": ["$guild_id", guild_id.to_string()] });
+    }
+
+    conn.database(DB_NAME)
+        .collection::<Document>(collection)
+        .aggregate(
+            [
+                doc! { "$match": filter },
+                // Each pair is in the collection twice, once for each
+                // direction, so only the one where the first user has the
+                // smaller ID is kept.
+                doc! { "$match": { "$expr": { "$lt": ["$from_user_id", "$to_user_id"] } } },
+                doc! {
+                    "$lookup": {
+                        "from": collection,
+                        "let": { "from_user_id": "$from_user_id", "to_user_id": "$to_user_id" },
+                        "pipeline": [{ "$match": { "$expr": { "$and": reverse } } }],
+                        "as": "reverse"
+                    }
+                },
+                doc! { "$unwind": "$reverse" },
+                doc! {
+                    "$project": {
+                        "from_user_id": 1,
+                        "to_user_id": 1,
+                        "amount": 1,
+                        "reverse_amount": "$reverse.amount",
+                        "total": { "$add": ["$amount", "$reverse.amount"] }
+                    }
+                },
+                doc! { "$sort": { "total": -1 } },
+                doc! { "$limit": i64::try_from(limit)? },
+            ],
+            None,
+        )?
+        .map(|doc| {
+            let doc = doc?;
+            Ok(ActionPair {
+                first: UserId::new(doc.get_str("from_user_id")?.parse::<u64>()?),
+                second: UserId::new(doc.get_str("to_user_id")?.parse::<u64>()?),
+                first_to_second: get_count(&doc, "amount")?,
+                second_to_first: get_count(&doc, "reverse_amount")?,
+            })
+        })
+        .collect()
+}
+
+pub fn get_action_preferences(user_id: UserId) -> Result<Option<ActionPreferences>, Err> {
+    let conn = get_db_conn()?;
+
+    conn.database(DB_NAME)
+        .collection::<Document>("action_preferences")
+        .find_one(doc! { "user_id": user_id.to_string() }, None)?
+        .map(|doc| {
+            Ok(ActionPreferences {
+                user_id,
+                opted_out_all: doc.get_bool("opted_out_all")?,
+                opted_out: doc
+                    .get_array("opted_out")?
+                    .iter()
+                    .filter_map(|action| action.as_str().map(str::to_string))
+                    .collect(),
+                blocked: doc
+                    .get_array("blocked")?
+                    .iter()
+                    .filter_map(Bson::as_str)
+                    .map(|blocked| Ok(UserId::new(blocked.parse::<u64>()?)))
+                    .collect::<Result<_, Err>>()?,
+                ping: doc.get_bool("ping")?,
+            })
+        })
+        .transpose()
+}
+
+pub fn set_action_preferences(preferences: &ActionPreferences) -> Result<(), Err> {
+    let conn = get_db_conn()?;
+
+    conn.database(DB_NAME)
+        .collection::<Document>("action_preferences")
+        .update_one(
+            doc! { "user_id": preferences.user_id.to_string() },
             doc! {
                 "$set": {
-                    "amount": amount
+                    "opted_out_all": preferences.opted_out_all,
+                    "opted_out": &preferences.opted_out,
+                    "blocked": preferences
+                        .blocked
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<_>>(),
+                    "ping": preferences.ping
                 }
             },
-            None,
-        )
-        .expect("Error when trying to update action count.");
+            UpdateOptions::builder().upsert(true).build(),
+        )?;
 
     Ok(())
 }
+
+pub fn get_balance(user_id: UserId) -> Result<u64, Err> {
+    let conn = get_db_conn()?;
+
+    conn.database(DB_NAME)
+        .collection::<Document>("balances")
+        .find_one(doc! { "user_id": user_id.to_string() }, None)?
+        .map_or(Ok(0), |doc| Ok(u64::try_from(doc.get_i64("balance")?)?))
+}
+
+fn ledger_entry(transaction: &Transaction) -> Result<Document, Err> {
+    Ok(doc! {
+        "from_user_id": transaction.from_user_id.map(|user_id| user_id.to_string()),
+        "to_user_id": transaction.to_user_id.map(|user_id| user_id.to_string()),
+        "amount": i64::try_from(transaction.amount)?,
+        "kind": transaction.kind.as_str(),
+        "timestamp": i64::try_from(transaction.timestamp)?,
+    })
+}
+
+/// How a transaction run by [`run_transaction`] ends.
+enum Outcome<T> {
+    Commit(T),
+    Abort(T),
+}
+
+fn has_label(e: &Err, label: &str) -> bool {
+    e.downcast_ref::<mongodb::error::Error>()
+        .is_some_and(|e| e.contains_label(label))
+}
+
+/// Runs `body` inside a transaction, then commits or aborts it depending on
+/// what `body` returns.
+///
+/// The whole transaction is run again when the server says it failed in a way
+/// that's safe to retry, and the commit alone when the server doesn't know if it
+/// went through, up to [`TRANSACTION_ATTEMPTS`] times.
+fn run_transaction<T>(
+    conn: &Client,
+    mut body: impl FnMut(&mut ClientSession) -> Result<Outcome<T>, Err>,
+) -> Result<T, Err> {
+    let mut session = conn.start_session(None)?;
+    let mut attempt = 1;
+
+    'transaction: loop {
+        session.start_transaction(None)?;
+
+        let value = match body(&mut session) {
+            Ok(Outcome::Commit(value)) => value,
+            Ok(Outcome::Abort(value)) => {
+                session.abort_transaction()?;
+                return Ok(value);
+            }
+            Err(e) => {
+                // The server may have aborted the transaction already, in
+                // which case there's nothing left to abort.
+                let _ = session.abort_transaction();
+                if attempt < TRANSACTION_ATTEMPTS && has_label(&e, TRANSIENT_TRANSACTION_ERROR) {
+                    attempt += 1;
+                    continue 'transaction;
+                }
+                return Err(e);
+            }
+        };
+
+        loop {
+            let Err(e) = session.commit_transaction() else {
+                return Ok(value);
+            };
+            if attempt >= TRANSACTION_ATTEMPTS {
+                return Err(e.into());
+            }
+            attempt += 1;
+
+            if e.contains_label(UNKNOWN_TRANSACTION_COMMIT_RESULT) {
+                continue;
+            }
+            if e.contains_label(TRANSIENT_TRANSACTION_ERROR) {
+                continue 'transaction;
+            }
+            return Err(e.into());
+        }
+    }
+}
+
+// Writes to more than one document only happen together inside a transaction,
+// which MongoDB only supports on replica sets.
+pub fn apply_transaction(transaction: &Transaction) -> Result<bool, Err> {
+    let conn = get_db_conn()?;
+    let db = conn.database(DB_NAME);
+    let balances = db.collection::<Document>("balances");
+    let amount = i64::try_from(transaction.amount)?;
+    let entry = ledger_entry(transaction)?;
+
+    run_transaction(conn, |session| {
+        if let Some(from_user_id) = transaction.from_user_id {
+            // Checking the balance in the filter makes the check and the
+            // withdrawal a single operation, so money can't be spent twice.
+            let result = balances.update_one_with_session(
+                doc! { "user_id": from_user_id.to_string(), "balance": { "$gte": amount } },
+                doc! { "$inc": { "balance": -amount } },
+                None,
+                &mut *session,
+            )?;
+
+            if result.modified_count == 0 {
+                return Ok(Outcome::Abort(false));
+            }
+        }
+
+        if let Some(to_user_id) = transaction.to_user_id {
+            balances.update_one_with_session(
+                doc! { "user_id": to_user_id.to_string() },
+                doc! { "$inc": { "balance": amount } },
+                UpdateOptions::builder().upsert(true).build(),
+                &mut *session,
+            )?;
+        }
+
+        db.collection::<Document>("economy_ledger")
+            .insert_one_with_session(entry.clone(), None, &mut *session)?;
+
+        Ok(Outcome::Commit(true))
+    })
+}
+
+pub fn claim_daily(transaction: &Transaction, cooldown: u64) -> Result<DailyClaim, Err> {
+    let user_id = transaction
+        .to_user_id
+        .ok_or("Daily rewards need a recipient.")?
+        .to_string();
+
+    let conn = get_db_conn()?;
+    let db = conn.database(DB_NAME);
+    let balances = db.collection::<Document>("balances");
+    let claimable_since = i64::try_from(transaction.timestamp.saturating_sub(cooldown))?;
+    let amount = i64::try_from(transaction.amount)?;
+    let timestamp = i64::try_from(transaction.timestamp)?;
+    let entry = ledger_entry(transaction)?;
+
+    run_transaction(conn, |session| {
+        balances.update_one_with_session(
+            doc! { "user_id": &user_id },
+            doc! { "$setOnInsert": { "balance": 0_i64 } },
+            UpdateOptions::builder().upsert(true).build(),
+            &mut *session,
+        )?;
+        let result = balances.update_one_with_session(
+            doc! {
+                "user_id": &user_id,
+                "$or": [
+                    { "last_daily": { "$exists": false } },
+                    { "last_daily": { "$lte": claimable_since } }
+                ]
+            },
+            doc! {
+                "$inc": { "balance": amount },
+                "$set": { "last_daily": timestamp }
+            },
+            None,
+            &mut *session,
+        )?;
+
+        if result.modified_count == 0 {
+            let last_daily = balances
+                .find_one_with_session(doc! { "user_id": &user_id }, None, &mut *session)?
+                .map(|doc| doc.get_i64("last_daily"))
+                .transpose()?
+                .unwrap_or_default();
+
+            return Ok(Outcome::Abort(DailyClaim::OnCooldown(u64::try_from(
+                last_daily,
+            )?)));
+        }
+
+        db.collection::<Document>("economy_ledger")
+            .insert_one_with_session(entry.clone(), None, &mut *session)?;
+
+        Ok(Outcome::Commit(DailyClaim::Claimed))
+    })
+}
+
+pub fn get_balance_leaderboard(limit: u64) -> Result<Vec<(UserId, u64)>, Err> {
+    let conn = get_db_conn()?;
+
+    conn.database(DB_NAME)
+        .collection::<Document>("balances")
+        .aggregate(
+            [
+                doc! { "$match": { "balance": { "$gt": 0_i64 } } },
+                doc! { "$sort": { "balance": -1 } },
+                doc! { "$limit": i64::try_from(limit)? },
+            ],
+            None,
+        )?
+        .map(|doc| {
+            let doc = doc?;
+            Ok((
+                UserId::new(doc.get_str("user_id")?.parse::<u64>()?),
+                u64::try_from(doc.get_i64("balance")?)?,
+            ))
+        })
+        .collect()
+}
+
+pub fn add_xp(guild_id: u64, user_id: UserId, xp: u64) -> Result<u64, Err> {
+    let conn = get_db_conn()?;
+
+    let doc = conn
+        .database(DB_NAME)
+        .collection::<Document>("member_xp")
+        .find_one_and_update(
+            doc! { "guild_id": guild_id.to_string(), "user_id": user_id.to_string() },
+            doc! { "$inc": { "xp": i64::try_from(xp)? } },
+            FindOneAndUpdateOptions::builder()
+                .upsert(true)
+                .return_document(ReturnDocument::After)
+                .build(),
+        )?
+        .ok_or("Error when adding XP.")?;
+
+    Ok(u64::try_from(doc.get_i64("xp")?)?)
+}
+
+pub fn get_xp_rank(guild_id: u64, user_id: UserId) -> Result<(u64, u64), Err> {
+    let conn = get_db_conn()?;
+    let collection = conn.database(DB_NAME).collection::<Document>("member_xp");
+
+    let xp = collection
+        .find_one(
+            doc! { "guild_id": guild_id.to_string(), "user_id": user_id.to_string() },
+            None,
+        )?
+        .map(|doc| doc.get_i64("xp"))
+        .transpose()?
+        .unwrap_or_default();
+
+    let ahead = collection.count_documents(
+        doc! { "guild_id": guild_id.to_string(), "xp": { "$gt": xp } },
+        None,
+    )?;
+
+    Ok((u64::try_from(xp)?, ahead + 1))
+}
+
+pub fn get_xp_leaderboard(guild_id: u64, limit: u64) -> Result<Vec<(UserId, u64)>, Err> {
+    let conn = get_db_conn()?;
+
+    conn.database(DB_NAME)
+        .collection::<Document>("member_xp")
+        .aggregate(
+            [
+                doc! { "$match": { "guild_id": guild_id.to_string() } },
+                doc! { "$sort": { "xp": -1 } },
+                doc! { "$limit": i64::try_from(limit)? },
+            ],
+            None,
+        )?
+        .map(|doc| {
+            let doc = doc?;
+            Ok((
+                UserId::new(doc.get_str("user_id")?.parse::<u64>()?),
+                u64::try_from(doc.get_i64("xp")?)?,
+            ))
+        })
+        .collect()
+}
+
+pub fn get_level_curve(guild_id: u64) -> Result<Option<LevelCurve>, Err> {
+    let conn = get_db_conn()?;
+
+    conn.database(DB_NAME)
+        .collection::<Document>("level_curves")
+        .find_one(doc! { "guild_id": guild_id.to_string() }, None)?
+        .map(|doc| {
+            Ok(LevelCurve {
+                base: u64::try_from(doc.get_i64("base")?)?,
+                linear: u64::try_from(doc.get_i64("linear")?)?,
+                quadratic: u64::try_from(doc.get_i64("quadratic")?)?,
+            })
+        })
+        .transpose()
+}
+
+pub fn set_level_curve(guild_id: u64, curve: &LevelCurve) -> Result<(), Err> {
+    let conn = get_db_conn()?;
+
+    conn.database(DB_NAME)
+        .collection::<Document>("level_curves")
+        .update_one(
+            doc! { "guild_id": guild_id.to_string() },
+            doc! {
+                "$set": {
+                    "base": i64::try_from(curve.base)?,
+                    "linear": i64::try_from(curve.linear)?,
+                    "quadratic": i64::try_from(curve.quadratic)?
+                }
+            },
+            UpdateOptions::builder().upsert(true).build(),
+        )?;
+
+    Ok(())
+}
+
+pub fn add_level_reward(guild_id: u64, level: u64, role_id: u64) -> Result<(), Err> {
+    let conn = get_db_conn()?;
+
+    conn.database(DB_NAME)
+        .collection::<Document>("level_rewards")
+        .update_one(
+            doc! { "guild_id": guild_id.to_string(), "role_id": role_id.to_string() },
+            doc! { "$set": { "level": i64::try_from(level)? } },
+            UpdateOptions::builder().upsert(true).build(),
+        )?;
+
+    Ok(())
+}
+
+pub fn remove_level_reward(guild_id: u64, role_id: u64) -> Result<bool, Err> {
+    let conn = get_db_conn()?;
+
+    let result = conn
+        .database(DB_NAME)
+        .collection::<Document>("level_rewards")
+        .delete_one(
+            doc! { "guild_id": guild_id.to_string(), "role_id": role_id.to_string() },
+            None,
+        )?;
+
+    Ok(result.deleted_count > 0)
+}
+
+pub fn get_level_rewards(guild_id: u64) -> Result<Vec<(u64, u64)>, Err> {
+    let conn = get_db_conn()?;
+
+    conn.database(DB_NAME)
+        .collection::<Document>("level_rewards")
+        .find(doc! { "guild_id": guild_id.to_string() }, None)?
+        .map(|doc| {
+            let doc = doc?;
+            Ok((
+                u64::try_from(doc.get_i64("level")?)?,
+                doc.get_str("role_id")?.parse::<u64>()?,
+            ))
+        })
+        .collect()
+}
+
+pub fn get_greeting(guild_id: u64, kind: GreetingKind) -> Result<Option<Greeting>, Err> {
+    let conn = get_db_conn()?;
+
+    conn.database(DB_NAME)
+        .collection::<Document>("greetings")
+        .find_one(
+            doc! { "guild_id": guild_id.to_string(), "kind": kind.as_str() },
+            None,
+        )?
+        .map(|doc| {
+            Ok(Greeting {
+                guild_id,
+                kind,
+                channel_id: doc.get_str("channel_id")?.parse::<u64>()?,
+                message: doc.get_str("message")?.to_string(),
+                card: doc.get_bool("card")?,
+                dm: doc.get_bool("dm")?,
+            })
+        })
+        .transpose()
+}
+
+pub fn set_greeting(greeting: &Greeting) -> Result<(), Err> {
+    let conn = get_db_conn()?;
+
+    conn.database(DB_NAME)
+        .collection::<Document>("greetings")
+        .update_one(
+            doc! {
+                "guild_id": greeting.guild_id.to_string(),
+                "kind": greeting.kind.as_str()
+            },
+            doc! {
+                "$set": {
+                    "channel_id": greeting.channel_id.to_string(),
+                    "message": &greeting.message,
+                    "card": greeting.card,
+                    "dm": greeting.dm
+                }
+            },
+            UpdateOptions::builder().upsert(true).build(),
+        )?;
+
+    Ok(())
+}
+
+pub fn remove_greeting(guild_id: u64, kind: GreetingKind) -> Result<bool, Err> {
+    let conn = get_db_conn()?;
+
+    let result = conn
+        .database(DB_NAME)
+        .collection::<Document>("greetings")
+        .delete_one(
+            doc! { "guild_id": guild_id.to_string(), "kind": kind.as_str() },
+            None,
+        )?;
+
+    Ok(result.deleted_count > 0)
+}
+
+pub fn add_role_menu(menu: &RoleMenu) -> Result<(), Err> {
+    let conn = get_db_conn()?;
+
+    conn.database(DB_NAME)
+        .collection::<Document>("role_menus")
+        .insert_one(
+            doc! {
+                "message_id": menu.message_id.to_string(),
+                "guild_id": menu.guild_id.to_string(),
+                "channel_id": menu.channel_id.to_string(),
+                "mode": menu.mode.as_str(),
+                "roles": menu
+                    .roles
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+            },
+            None,
+        )?;
+
+    Ok(())
+}
+
+pub fn get_role_menu(message_id: u64) -> Result<Option<RoleMenu>, Err> {
+    let conn = get_db_conn()?;
+
+    conn.database(DB_NAME)
+        .collection::<Document>("role_menus")
+        .find_one(doc! { "message_id": message_id.to_string() }, None)?
+        .map(|doc| {
+            Ok(RoleMenu {
+                message_id,
+                guild_id: doc.get_str("guild_id")?.parse::<u64>()?,
+                channel_id: doc.get_str("channel_id")?.parse::<u64>()?,
+                mode: RoleMenuMode::from_name(doc.get_str("mode")?).unwrap_or_default(),
+                roles: doc
+                    .get_array("roles")?
+                    .iter()
+                    .filter_map(Bson::as_str)
+                    .map(|role_id| Ok(role_id.parse::<u64>()?))
+                    .collect::<Result<_, Err>>()?,
+            })
+        })
+        .transpose()
+}
+
+pub fn add_auto_role(guild_id: u64, role_id: u64) -> Result<(), Err> {
+    let conn = get_db_conn()?;
+
+    conn.database(DB_NAME)
+        .collection::<Document>("auto_roles")
+        .update_one(
+            doc! { "guild_id": guild_id.to_string(), "role_id": role_id.to_string() },
+            doc! { "$set": { "guild_id": guild_id.to_string(), "role_id": role_id.to_string() } },
+            UpdateOptions::builder().upsert(true).build(),
+        )?;
+
+    Ok(())
+}
+
+pub fn remove_auto_role(guild_id: u64, role_id: u64) -> Result<bool, Err> {
+    let conn = get_db_conn()?;
+
+    let result = conn
+        .database(DB_NAME)
+        .collection::<Document>("auto_roles")
+        .delete_one(
+            doc! { "guild_id": guild_id.to_string(), "role_id": role_id.to_string() },
+            None,
+        )?;
+
+    Ok(result.deleted_count > 0)
+}
+
+pub fn get_auto_roles(guild_id: u64) -> Result<Vec<u64>, Err> {
+    let conn = get_db_conn()?;
+
+    conn.database(DB_NAME)
+        .collection::<Document>("auto_roles")
+        .find(doc! { "guild_id": guild_id.to_string() }, None)?
+        .map(|doc| Ok(doc?.get_str("role_id")?.parse::<u64>()?))
+        .collect()
+}
+
+pub fn set_automod_rule(rule: &AutomodRule) -> Result<(), Err> {
+    let conn = get_db_conn()?;
+
+    conn.database(DB_NAME)
+        .collection::<Document>("automod_rules")
+        .update_one(
+            doc! { "guild_id": rule.guild_id.to_string(), "kind": rule.kind.as_str() },
+            doc! {
+                "$set": {
+                    "action": rule.action.as_str(),
+                    "threshold": i64::try_from(rule.threshold)?
+                }
+            },
+            UpdateOptions::builder().upsert(true).build(),
+        )?;
+
+    Ok(())
+}
+
+pub fn remove_automod_rule(guild_id: u64, kind: RuleKind) -> Result<bool, Err> {
+    let conn = get_db_conn()?;
+
+    let result = conn
+        .database(DB_NAME)
+        .collection::<Document>("automod_rules")
+        .delete_one(
+            doc! { "guild_id": guild_id.to_string(), "kind": kind.as_str() },
+            None,
+        )?;
+
+    Ok(result.deleted_count > 0)
+}
+
+pub fn get_automod_rules(guild_id: u64) -> Result<Vec<AutomodRule>, Err> {
+    let conn = get_db_conn()?;
+
+    let mut rules = Vec::new();
+    for doc in conn
+        .database(DB_NAME)
+        .collection::<Document>("automod_rules")
+        .find(doc! { "guild_id": guild_id.to_string() }, None)?
+    {
+        let doc = doc?;
+        // Rules that were removed from the bot are skipped.
+        let Some(kind) = RuleKind::from_name(doc.get_str("kind")?) else {
+            continue;
+        };
+
+        rules.push(AutomodRule {
+            guild_id,
+            kind,
+            action: RuleAction::from_name(doc.get_str("action")?).unwrap_or_default(),
+            threshold: u64::try_from(doc.get_i64("threshold")?)?,
+        });
+    }
+
+    Ok(rules)
+}
+
+pub fn add_blocked_pattern(guild_id: u64, pattern: &str) -> Result<(), Err> {
+    let conn = get_db_conn()?;
+
+    conn.database(DB_NAME)
+        .collection::<Document>("automod_blocklist")
+        .update_one(
+            doc! { "guild_id": guild_id.to_string(), "pattern": pattern },
+            doc! { "$set": { "guild_id": guild_id.to_string(), "pattern": pattern } },
+            UpdateOptions::builder().upsert(true).build(),
+        )?;
+
+    Ok(())
+}
+
+pub fn remove_blocked_pattern(guild_id: u64, pattern: &str) -> Result<bool, Err> {
+    let conn = get_db_conn()?;
+
+    let result = conn
+        .database(DB_NAME)
+        .collection::<Document>("automod_blocklist")
+        .delete_one(
+            doc! { "guild_id": guild_id.to_string(), "pattern": pattern },
+            None,
+        )?;
+
+    Ok(result.deleted_count > 0)
+}
+
+pub fn get_blocked_patterns(guild_id: u64) -> Result<Vec<String>, Err> {
+    let conn = get_db_conn()?;
+
+    conn.database(DB_NAME)
+        .collection::<Document>("automod_blocklist")
+        .find(doc! { "guild_id": guild_id.to_string() }, None)?
+        .map(|doc| Ok(doc?.get_str("pattern")?.to_string()))
+        .collect()
+}
+
+pub fn set_automod_escalation(guild_id: u64, strikes: u64, minutes: u64) -> Result<(), Err> {
+    let conn = get_db_conn()?;
+
+    conn.database(DB_NAME)
+        .collection::<Document>("automod_escalations")
+        .update_one(
+            doc! { "guild_id": guild_id.to_string(), "strikes": i64::try_from(strikes)? },
+            doc! { "$set": { "minutes": i64::try_from(minutes)? } },
+            UpdateOptions::builder().upsert(true).build(),
+        )?;
+
+    Ok(())
+}
+
+pub fn remove_automod_escalation(guild_id: u64, strikes: u64) -> Result<bool, Err> {
+    let conn = get_db_conn()?;
+
+    let result = conn
+        .database(DB_NAME)
+        .collection::<Document>("automod_escalations")
+        .delete_one(
+            doc! { "guild_id": guild_id.to_string(), "strikes": i64::try_from(strikes)? },
+            None,
+        )?;
+
+    Ok(result.deleted_count > 0)
+}
+
+pub fn get_automod_escalations(guild_id: u64) -> Result<Vec<(u64, u64)>, Err> {
+    let conn = get_db_conn()?;
+
+    conn.database(DB_NAME)
+        .collection::<Document>("automod_escalations")
+        .find(doc! { "guild_id": guild_id.to_string() }, None)?
+        .map(|doc| {
+            let doc = doc?;
+            Ok((
+                u64::try_from(doc.get_i64("strikes")?)?,
+                u64::try_from(doc.get_i64("minutes")?)?,
+            ))
+        })
+        .collect()
+}
+
+pub fn set_automod_log_channel(guild_id: u64, channel_id: u64) -> Result<(), Err> {
+    let conn = get_db_conn()?;
+
+    conn.database(DB_NAME)
+        .collection::<Document>("automod_log_channels")
+        .update_one(
+            doc! { "guild_id": guild_id.to_string() },
+            doc! { "$set": { "channel_id": channel_id.to_string() } },
+            UpdateOptions::builder().upsert(true).build(),
+        )?;
+
+    Ok(())
+}
+
+pub fn remove_automod_log_channel(guild_id: u64) -> Result<(), Err> {
+    let conn = get_db_conn()?;
+
+    conn.database(DB_NAME)
+        .collection::<Document>("automod_log_channels")
+        .delete_one(doc! { "guild_id": guild_id.to_string() }, None)?;
+
+    Ok(())
+}
+
+pub fn get_automod_log_channel(guild_id: u64) -> Result<Option<u64>, Err> {
+    let conn = get_db_conn()?;
+
+    conn.database(DB_NAME)
+        .collection::<Document>("automod_log_channels")
+        .find_one(doc! { "guild_id": guild_id.to_string() }, None)?
+        .map(|doc| Ok(doc.get_str("channel_id")?.parse::<u64>()?))
+        .transpose()
+}
+
+pub fn add_automod_trigger(trigger: &AutomodTrigger) -> Result<(), Err> {
+    let conn = get_db_conn()?;
+
+    conn.database(DB_NAME)
+        .collection::<Document>("automod_log")
+        .insert_one(
+            doc! {
+                "guild_id": trigger.guild_id.to_string(),
+                "user_id": trigger.user_id.to_string(),
+                "channel_id": trigger.channel_id.to_string(),
+                "kind": trigger.kind.as_str(),
+                "action": trigger.action.as_str(),
+                "timestamp": i64::try_from(trigger.timestamp)?
+            },
+            None,
+        )?;
+
+    Ok(())
+}
+
+pub fn remove_automod_triggers_before(before: u64) -> Result<(), Err> {
+    let conn = get_db_conn()?;
+
+    conn.database(DB_NAME)
+        .collection::<Document>("automod_log")
+        .delete_many(
+            doc! { "timestamp": { "$lt": i64::try_from(before)? } },
+            None,
+        )?;
+
+    Ok(())
+}
+
+pub fn count_automod_triggers(guild_id: u64, user_id: u64, since: u64) -> Result<u64, Err> {
+    let conn = get_db_conn()?;
+
+    Ok(conn
+        .database(DB_NAME)
+        .collection::<Document>("automod_log")
+        .count_documents(
+            doc! {
+                "guild_id": guild_id.to_string(),
+                "user_id": user_id.to_string(),
+                "timestamp": { "$gte": i64::try_from(since)? }
+            },
+            None,
+        )?)
+}
@@ -2,13 +2,23 @@ use std::env::var;
 use std::time::{Duration, SystemTime};
 
 use log::info;
-use mysql::{params, Pool, Result, Row};
+use mysql::{params, Pool, Result, Row, Transaction, TxOpts};
 use mysql::{prelude::*, PooledConn};
 use poise::serenity_prelude::UserId;
 
 use crate::Error as Err;
 
+use crate::commands::economy::{DailyClaim, Transaction as EconomyTransaction};
+use crate::commands::fun::action::{ActionPair, ActionPreferences, ActionRole, ActionStats};
+use crate::commands::fun::custom_content::{ContentKind, CustomContent};
+use crate::commands::fun::qotd::QotdPost;
+use crate::commands::fun::trivia::TriviaScore;
+use crate::commands::leveling::LevelCurve;
+use crate::commands::moderation::automod::{AutomodRule, AutomodTrigger, RuleAction, RuleKind};
+use crate::commands::moderation::safety::SafetyLevel;
 use crate::commands::utility::add_reminder::Reminder;
+use crate::commands::utility::greetings::{Greeting, GreetingKind};
+use crate::commands::utility::roles::{RoleMenu, RoleMenuMode};
 use crate::VOLATILE_TIME;
 
 use super::DB_CONN;
@@ -345,15 +355,18 @@ pub fn get_reminders_of_user(user_id: UserId) -> Result<Vec<Reminder>, Err> {
     Ok(reminders)
 }
 
-pub fn get_action_count(
+pub fn increment_action_count(
     action: &str,
     from_user_id: UserId,
     to_user_id: UserId,
-) -> Result<i32, Err> {
+) -> Result<u64, Err> {
     let conn = get_db_conn()?;
 
-    let count: Vec<i32> = conn.exec(
-        "SELECT amount FROM actions WHERE action = :action AND from_user_id = :from_user_id AND to_user_id = :to_user_id",
+    // (action, from_user_id, to_user_id) is the table's unique key.
+    // LAST_INSERT_ID(expr) hands the updated count back without a second
+    // query, so nothing can change it in between.
+    conn.exec_drop(
+        "INSERT INTO actions (action, from_user_id, to_user_id, amount) VALUES (:action, :from_user_id, :to_user_id, 1) ON DUPLICATE KEY UPDATE amount = LAST_INSERT_ID(amount + 1)",
         params! {
             "action" => action,
             "from_user_id" => from_user_id.to_string(),
@@ -361,41 +374,1177 @@ pub fn get_action_count(
         },
     )?;
 
-    if count.is_empty() {
-        // thanks rust-analyzer <3
-        // Insert new row into actions cause there isn't one yet, this is the first time these two users performed this action
+    // MySQL reports 1 affected row for an insert and 2 for an update.
+    if conn.affected_rows() == 1 {
+        Ok(1)
+    } else {
+        Ok(conn.last_insert_id())
+    }
+}
+
+fn custom_content_from_row(row: &Row) -> CustomContent {
+    CustomContent {
+        id: row.get::<u64, _>("id").expect("Could not get ID."),
+        guild_id: row
+            .get::<String, _>("guild_id")
+            .expect("Could not get guild ID.")
+            .parse::<u64>()
+            .expect("Could not parse guild ID."),
+        kind: ContentKind::from_db(&row.get::<String, _>("kind").expect("Could not get kind.")),
+        content: row
+            .get::<String, _>("content")
+            .expect("Could not get content."),
+        author_id: row
+            .get::<String, _>("author_id")
+            .expect("Could not get author ID.")
+            .parse::<u64>()
+            .expect("Could not parse author ID.")
+            .into(),
+        approved: row
+            .get::<bool, _>("approved")
+            .expect("Could not get approval state."),
+    }
+}
+
+pub fn add_custom_content(content: &CustomContent) -> Result<(), Err> {
+    let conn = get_db_conn()?;
+
+    conn.exec_drop(
+        "INSERT INTO custom_content (id, guild_id, kind, content, author_id, approved) VALUES (:id, :guild_id, :kind, :content, :author_id, :approved)",
+        params! {
+            "id" => content.id,
+            "guild_id" => content.guild_id.to_string(),
+            "kind" => content.kind.as_str(),
+            "content" => &content.content,
+            "author_id" => content.author_id.to_string(),
+            "approved" => content.approved,
+        },
+    )?;
+
+    Ok(())
+}
+
+pub fn get_custom_content(
+    guild_id: u64,
+    kind: ContentKind,
+    approved: bool,
+) -> Result<Vec<CustomContent>, Err> {
+    let conn = get_db_conn()?;
+    let rows: Vec<Row> = conn.exec(
+        "SELECT * FROM custom_content WHERE guild_id = :guild_id AND kind = :kind AND approved = :approved",
+        params! {
+            "guild_id" => guild_id.to_string(),
+            "kind" => kind.as_str(),
+            "approved" => approved,
+        },
+    )?;
+
+    Ok(rows.iter().map(custom_content_from_row).collect())
+}
+
+pub fn approve_custom_content(id: u64, guild_id: u64) -> Result<bool, Err> {
+    let conn = get_db_conn()?;
+
+    conn.exec_drop(
+        "UPDATE custom_content SET approved = TRUE WHERE id = :id AND guild_id = :guild_id AND approved = FALSE",
+        params! {
+            "id" => id,
+            "guild_id" => guild_id.to_string(),
+        },
+    )?;
+
+    Ok(conn.affected_rows() > 0)
+}
+
+pub fn remove_custom_content(id: u64, guild_id: u64) -> Result<bool, Err> {
+    let conn = get_db_conn()?;
+
+    conn.exec_drop(
+        "DELETE FROM custom_content WHERE id = :id AND guild_id = :guild_id",
+        params! {
+            "id" => id,
+            "guild_id" => guild_id.to_string(),
+        },
+    )?;
+
+    Ok(conn.affected_rows() > 0)
+}
+
+pub fn get_custom_content_ratio(guild_id: u64, kind: ContentKind) -> Result<Option<u8>, Err> {
+    let conn = get_db_conn()?;
+
+    Ok(conn.exec_first(
+        "SELECT ratio FROM custom_content_settings WHERE guild_id = :guild_id AND kind = :kind",
+        params! {
+            "guild_id" => guild_id.to_string(),
+            "kind" => kind.as_str(),
+        },
+    )?)
+}
+
+pub fn set_custom_content_ratio(guild_id: u64, kind: ContentKind, ratio: u8) -> Result<(), Err> {
+    let conn = get_db_conn()?;
+
+    // (guild_id, kind) is the table's unique key.
+    conn.exec_drop(
+        "INSERT INTO custom_content_settings (guild_id, kind, ratio) VALUES (:guild_id, :kind, :ratio) ON DUPLICATE KEY UPDATE ratio = :ratio",
+        params! {
+            "guild_id" => guild_id.to_string(),
+            "kind" => kind.as_str(),
+            "ratio" => ratio,
+        },
+    )?;
+
+    Ok(())
+}
+
+pub fn add_qotd_thread_channel(channel_id: u64) -> Result<(), Err> {
+    let conn = get_db_conn()?;
+
+    if !qotd_threads_enabled(channel_id)? {
         conn.exec_drop(
-            "INSERT INTO actions (action, from_user_id, to_user_id, amount) VALUES (:action, :from_user_id, :to_user_id, 1)",
+            "INSERT INTO qotd_threads (channel_id) VALUES (:channel_id)",
             params! {
-                "action" => action,
-                "from_user_id" => from_user_id.to_string(),
-                "to_user_id" => to_user_id.to_string(),
-                "amount" => 0,
+                "channel_id" => channel_id.to_string(),
             },
         )?;
-        Ok(0)
-    } else {
-        Ok(count[0])
     }
+
+    Ok(())
+}
+
+pub fn remove_qotd_thread_channel(channel_id: u64) -> Result<(), Err> {
+    let conn = get_db_conn()?;
+
+    conn.exec_drop(
+        "DELETE FROM qotd_threads WHERE channel_id = :channel_id",
+        params! {
+            "channel_id" => channel_id.to_string(),
+        },
+    )?;
+
+    Ok(())
+}
+
+pub fn qotd_threads_enabled(channel_id: u64) -> Result<bool, Err> {
+    let conn = get_db_conn()?;
+    let enabled: Option<bool> = conn.exec_first(
+        "SELECT EXISTS(SELECT 1 FROM qotd_threads WHERE channel_id = :channel_id)",
+        params! {
+            "channel_id" => channel_id.to_string(),
+        },
+    )?;
+
+    enabled.map_or_else(
+        || {
+            Err(Err::from(
+                "Error when checking if QOTD threads are enabled.",
+            ))
+        },
+        Ok,
+    )
+}
+
+pub fn add_qotd_post(post: &QotdPost) -> Result<(), Err> {
+    let conn = get_db_conn()?;
+
+    conn.exec_drop(
+        "INSERT INTO qotd_posts (channel_id, thread_id, question, timestamp, answers) VALUES (:channel_id, :thread_id, :question, :timestamp, :answers)",
+        params! {
+            "channel_id" => post.channel_id.to_string(),
+            "thread_id" => post.thread_id.to_string(),
+            "question" => &post.question,
+            "timestamp" => post.timestamp,
+            "answers" => post.answers,
+        },
+    )?;
+
+    Ok(())
+}
+
+pub fn get_qotd_posts_since(channel_id: u64, timestamp: u64) -> Result<Vec<QotdPost>, Err> {
+    let conn = get_db_conn()?;
+    let rows: Vec<Row> = conn.exec(
+        "SELECT * FROM qotd_posts WHERE channel_id = :channel_id AND timestamp >= :timestamp",
+        params! {
+            "channel_id" => channel_id.to_string(),
+            "timestamp" => timestamp,
+        },
+    )?;
+
+    Ok(rows
+        .iter()
+        .map(|row| QotdPost {
+            channel_id,
+            thread_id: row
+                .get::<String, _>("thread_id")
+                .expect("Could not get thread ID.")
+                .parse::<u64>()
+                .expect("Could not parse thread ID."),
+            question: row
+                .get::<String, _>("question")
+                .expect("Could not get question."),
+            timestamp: row
+                .get::<u64, _>("timestamp")
+                .expect("Could not get timestamp."),
+            answers: row
+                .get::<u64, _>("answers")
+                .expect("Could not get answers."),
+        })
+        .collect())
+}
+
+pub fn update_qotd_post_answers(thread_id: u64, answers: u64) -> Result<(), Err> {
+    let conn = get_db_conn()?;
+
+    conn.exec_drop(
+        "UPDATE qotd_posts SET answers = :answers WHERE thread_id = :thread_id",
+        params! {
+            "thread_id" => thread_id.to_string(),
+            "answers" => answers,
+        },
+    )?;
+
+    Ok(())
+}
+
+pub fn get_safety_level(guild_id: u64) -> Result<Option<SafetyLevel>, Err> {
+    let conn = get_db_conn()?;
+    let level: Option<String> = conn.exec_first(
+        "SELECT level FROM safety_levels WHERE guild_id = :guild_id",
+        params! {
+            "guild_id" => guild_id.to_string(),
+        },
+    )?;
+
+    Ok(level.as_deref().and_then(SafetyLevel::from_name))
+}
+
+pub fn set_safety_level(guild_id: u64, level: SafetyLevel) -> Result<(), Err> {
+    let conn = get_db_conn()?;
+
+    // guild_id is the table's primary key.
+    conn.exec_drop(
+        "INSERT INTO safety_levels (guild_id, level) VALUES (:guild_id, :level) ON DUPLICATE KEY UPDATE level = :level",
+        params! {
+            "guild_id" => guild_id.to_string(),
+            "level" => level.as_str(),
+        },
+    )?;
+
+    Ok(())
+}
+
+pub fn set_wyr_vote(
+    message_id: u64,
+    prompt_id: u64,
+    user_id: UserId,
+    choice: u8,
+) -> Result<(), Err> {
+    let conn = get_db_conn()?;
+
+    // (message_id, user_id) is the table's unique key.
+    conn.exec_drop(
+        "INSERT INTO wyr_votes (message_id, prompt_id, user_id, choice) VALUES (:message_id, :prompt_id, :user_id, :choice) ON DUPLICATE KEY UPDATE choice = :choice",
+        params! {
+            "message_id" => message_id.to_string(),
+            "prompt_id" => prompt_id.to_string(),
+            "user_id" => user_id.to_string(),
+            "choice" => choice,
+        },
+    )?;
+
+    Ok(())
+}
+
+pub fn get_wyr_message_votes(message_id: u64) -> Result<Vec<(u8, u64)>, Err> {
+    let conn = get_db_conn()?;
+
+    Ok(conn.exec(
+        "SELECT choice, COUNT(*) FROM wyr_votes WHERE message_id = :message_id GROUP BY choice",
+        params! {
+            "message_id" => message_id.to_string(),
+        },
+    )?)
+}
+
+pub fn get_wyr_prompt_votes(prompt_id: u64) -> Result<Vec<(u8, u64)>, Err> {
+    let conn = get_db_conn()?;
+
+    Ok(conn.exec(
+        "SELECT choice, COUNT(*) FROM wyr_votes WHERE prompt_id = :prompt_id GROUP BY choice",
+        params! {
+            "prompt_id" => prompt_id.to_string(),
+        },
+    )?)
+}
+
+pub fn add_trivia_result(
+    guild_id: u64,
+    user_id: UserId,
+    points: u64,
+    correct: bool,
+) -> Result<(), Err> {
+    let conn = get_db_conn()?;
+
+    // (guild_id, user_id) is the table's unique key.
+    conn.exec_drop(
+        "INSERT INTO trivia_scores (guild_id, user_id, points, correct, answered) VALUES (:guild_id, :user_id, :points, :correct, 1) ON DUPLICATE KEY UPDATE points = points + :points, correct = correct + :correct, answered = answered + 1",
+        params! {
+            "guild_id" => guild_id.to_string(),
+            "user_id" => user_id.to_string(),
+            "points" => points,
+            "correct" => u64::from(correct),
+        },
+    )?;
+
+    Ok(())
 }
 
-pub fn update_action_count(
+pub fn get_trivia_leaderboard(guild_id: Option<u64>, limit: u64) -> Result<Vec<TriviaScore>, Err> {
+    let conn = get_db_conn()?;
+
+    let rows: Vec<(String, u64, u64, u64)> = conn.exec(
+        "SELECT user_id, CAST(SUM(points) AS UNSIGNED) AS total_points, CAST(SUM(correct) AS UNSIGNED), CAST(SUM(answered) AS UNSIGNED) FROM trivia_scores WHERE :guild_id IS NULL OR guild_id = :guild_id GROUP BY user_id ORDER BY total_points DESC LIMIT :limit",
+        params! {
+            "guild_id" => guild_id.map(|guild_id| guild_id.to_string()),
+            "limit" => limit,
+        },
+    )?;
+
+    rows.into_iter()
+        .map(|(user_id, points, correct, answered)| {
+            Ok(TriviaScore {
+                user_id: UserId::new(user_id.parse::<u64>()?),
+                points,
+                correct,
+                answered,
+            })
+        })
+        .collect()
+}
+
+pub fn add_guild_action(
+    guild_id: u64,
     action: &str,
     from_user_id: UserId,
     to_user_id: UserId,
-    amount: i32,
 ) -> Result<(), Err> {
     let conn = get_db_conn()?;
 
+    // (guild_id, action, from_user_id, to_user_id) is the table's unique key.
     conn.exec_drop(
-        "UPDATE actions SET amount = :amount WHERE action = :action AND from_user_id = :from_user_id AND to_user_id = :to_user_id", 
+        "INSERT INTO guild_actions (guild_id, action, from_user_id, to_user_id, amount) VALUES (:guild_id, :action, :from_user_id, :to_user_id, 1) ON DUPLICATE KEY UPDATE amount = amount + 1",
         params! {
+            "guild_id" => guild_id.to_string(),
             "action" => action,
             "from_user_id" => from_user_id.to_string(),
             "to_user_id" => to_user_id.to_string(),
-            "amount" => amount
-        }
+        },
+    )?;
+
+    Ok(())
+}
+
+/// Gets the table the actions of a scope are counted in, and the condition
+/// that limits `alias` to that scope.
+///
+/// `actions` has every action ever performed, while `guild_actions` only has
+/// the ones performed since guilds started being tracked.
+fn action_scope(guild_id: Option<u64>, alias: &str) -> (&'static str, String) {
+    if guild_id.is_some() {
+        ("guild_actions", format!("{alias}.guild_id = :guild_id"))
+    } else {
+        // Still uses the parameter so both scopes can be given the same ones.
+        ("actions", ":guild_id IS NULL".to_string())
+    }
+}
+
+pub fn get_action_stats(guild_id: Option<u64>, user_id: UserId) -> Result<Vec<ActionStats>, Err> {
+    let conn = get_db_conn()?;
+    let (table, scope) = action_scope(guild_id, "a");
+
+    let query = |role: ActionRole| {
+        format!(
+            "SELECT a.action, CAST(SUM(a.amount) AS UNSIGNED) FROM {table} a WHERE a.{} = :user_id AND {scope} GROUP BY a.action",
+            role.column()
+        )
+    };
+
+    let given: Vec<(String, u64)> = conn.exec(
+        query(ActionRole::Performer),
+        params! {
+            "guild_id" => guild_id.map(|guild_id| guild_id.to_string()),
+            "user_id" => user_id.to_string(),
+        },
+    )?;
+    let received: Vec<(String, u64)> = conn.exec(
+        query(ActionRole::Receiver),
+        params! {
+            "guild_id" => guild_id.map(|guild_id| guild_id.to_string()),
+            "user_id" => user_id.to_string(),
+        },
+    )?;
+
+    Ok(ActionStats::merge(given, received))
+}
+
+pub fn get_action_leaderboard(
+    guild_id: Option<u64>,
+    action: &str,
+    role: ActionRole,
+    limit: u64,
+) -> Result<Vec<(UserId, u64)>, Err> {
+    let conn = get_db_conn()?;
+    let (table, scope) = action_scope(guild_id, "a");
+
+    let rows: Vec<(String, u64)> = conn.exec(
+        format!(
+            "SELECT a.{column}, CAST(SUM(a.amount) AS UNSIGNED) AS total FROM {table} a WHERE a.action = :action AND {scope} GROUP BY a.{column} ORDER BY total DESC LIMIT :limit",
+            column = role.column()
+        ),
+        params! {
+            "guild_id" => guild_id.map(|guild_id| guild_id.to_string()),
+            "action" => action,
+            "limit" => limit,
+        },
+    )?;
+
+    rows.into_iter()
+        .map(|(user_id, amount)| Ok((UserId::new(user_id.parse::<u64>()?), amount)))
+        .collect()
+}
+
+pub fn get_mutual_action_pairs(
+    guild_id: Option<u64>,
+    action: &str,
+    limit: u64,
+) -> Result<Vec<ActionPair>, Err> {
+    let conn = get_db_conn()?;
+    let (table, scope) = action_scope(guild_id, "a");
+    let (_, other_scope) = action_scope(guild_id, "b");
+
+    // Each pair is in the table twice, once for each direction, so only the
+    // one where the first user has the smaller ID is kept.
+    let rows: Vec<(String, String, u64, u64)> = conn.exec(
+        format!(
+            "SELECT a.from_user_id, a.to_user_id, a.amount, b.amount FROM {table} a JOIN {table} b ON b.action = a.action AND b.from_user_id = a.to_user_id AND b.to_user_id = a.from_user_id AND {other_scope} WHERE a.action = :action AND a.from_user_id < a.to_user_id AND {scope} ORDER BY a.amount + b.amount DESC LIMIT :limit"
+        ),
+        params! {
+            "guild_id" => guild_id.map(|guild_id| guild_id.to_string()),
+            "action" => action,
+            "limit" => limit,
+        },
+    )?;
+
+    rows.into_iter()
+        .map(|(first, second, first_to_second, second_to_first)| {
+            Ok(ActionPair {
+                first: UserId::new(first.parse::<u64>()?),
+                second: UserId::new(second.parse::<u64>()?),
+                first_to_second,
+                second_to_first,
+            })
+        })
+        .collect()
+}
+
+pub fn get_action_preferences(user_id: UserId) -> Result<Option<ActionPreferences>, Err> {
+    let conn = get_db_conn()?;
+
+    let row: Option<(bool, String, String, bool)> = conn.exec_first(
+        "SELECT opted_out_all, opted_out, blocked, ping FROM action_preferences WHERE user_id = :user_id",
+        params! {
+            "user_id" => user_id.to_string(),
+        },
+    )?;
+
+    row.map(|(opted_out_all, opted_out, blocked, ping)| {
+        Ok(ActionPreferences {
+            user_id,
+            opted_out_all,
+            opted_out: opted_out
+                .split(',')
+                .filter(|action| !action.is_empty())
+                .map(str::to_string)
+                .collect(),
+            blocked: blocked
+                .split(',')
+                .filter(|blocked| !blocked.is_empty())
+                .map(|blocked| Ok(UserId::new(blocked.parse::<u64>()?)))
+                .collect::<Result<_, Err>>()?,
+            ping,
+        })
+    })
+    .transpose()
+}
+
+pub fn set_action_preferences(preferences: &ActionPreferences) -> Result<(), Err> {
+    let conn = get_db_conn()?;
+
+    // user_id is the table's primary key. The lists are stored comma
+    // separated, since neither action names nor IDs can contain commas.
+    conn.exec_drop(
+        "INSERT INTO action_preferences (user_id, opted_out_all, opted_out, blocked, ping) VALUES (:user_id, :opted_out_all, :opted_out, :blocked, :ping) ON DUPLICATE KEY UPDATE opted_out_all = :opted_out_all, opted_out = :opted_out, blocked = :blocked, ping = :ping",
+        params! {
+            "user_id" => preferences.user_id.to_string(),
+            "opted_out_all" => preferences.opted_out_all,
+            "opted_out" => preferences.opted_out.join(","),
+            "blocked" => preferences
+                .blocked
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(","),
+            "ping" => preferences.ping,
+        },
     )?;
 
     Ok(())
 }
+
+pub fn get_balance(user_id: UserId) -> Result<u64, Err> {
+    let conn = get_db_conn()?;
+
+    let balance: Option<u64> = conn.exec_first(
+        "SELECT balance FROM balances WHERE user_id = :user_id",
+        params! {
+            "user_id" => user_id.to_string(),
+        },
+    )?;
+
+    Ok(balance.unwrap_or_default())
+}
+
+/// Adds `transaction` to the ledger. Must be called as part of the
+/// transaction that moves the money.
+fn add_ledger_entry(tx: &mut Transaction<'_>, transaction: &EconomyTransaction) -> Result<(), Err> {
+    tx.exec_drop(
+        "INSERT INTO economy_ledger (from_user_id, to_user_id, amount, kind, timestamp) VALUES (:from_user_id, :to_user_id, :amount, :kind, :timestamp)",
+        params! {
+            "from_user_id" => transaction.from_user_id.map(|user_id| user_id.to_string()),
+            "to_user_id" => transaction.to_user_id.map(|user_id| user_id.to_string()),
+            "amount" => transaction.amount,
+            "kind" => transaction.kind.as_str(),
+            "timestamp" => transaction.timestamp,
+        },
+    )?;
+
+    Ok(())
+}
+
+pub fn apply_transaction(transaction: &EconomyTransaction) -> Result<bool, Err> {
+    let conn = get_db_conn()?;
+    let mut tx = conn.start_transaction(TxOpts::default())?;
+
+    if let Some(from_user_id) = transaction.from_user_id {
+        // The balance check and the withdrawal have to be the same statement,
+        // or two transactions could both spend the same money.
+        tx.exec_drop(
+            "UPDATE balances SET balance = balance - :amount WHERE user_id = :user_id AND balance >= :amount",
+            params! {
+                "user_id" => from_user_id.to_string(),
+                "amount" => transaction.amount,
+            },
+        )?;
+
+        if tx.affected_rows() == 0 {
+            tx.rollback()?;
+            return Ok(false);
+        }
+    }
+
+    if let Some(to_user_id) = transaction.to_user_id {
+        // user_id is the table's primary key.
+        tx.exec_drop(
+            "INSERT INTO balances (user_id, balance) VALUES (:user_id, :amount) ON DUPLICATE KEY UPDATE balance = balance + :amount",
+            params! {
+                "user_id" => to_user_id.to_string(),
+                "amount" => transaction.amount,
+            },
+        )?;
+    }
+
+    add_ledger_entry(&mut tx, transaction)?;
+    tx.commit()?;
+
+    Ok(true)
+}
+
+pub fn claim_daily(transaction: &EconomyTransaction, cooldown: u64) -> Result<DailyClaim, Err> {
+    let user_id = transaction
+        .to_user_id
+        .ok_or_else(|| Err::from("Daily rewards need a recipient."))?
+        .to_string();
+
+    let conn = get_db_conn()?;
+    let mut tx = conn.start_transaction(TxOpts::default())?;
+
+    tx.exec_drop(
+        "INSERT IGNORE INTO balances (user_id, balance) VALUES (:user_id, 0)",
+        params! {
+            "user_id" => &user_id,
+        },
+    )?;
+    // Locks the row, so a second claim waits for this one and then sees the
+    // new `last_daily`.
+    tx.exec_drop(
+        "UPDATE balances SET balance = balance + :amount, last_daily = :timestamp WHERE user_id = :user_id AND (last_daily IS NULL OR last_daily + :cooldown <= :timestamp)",
+        params! {
+            "user_id" => &user_id,
+            "amount" => transaction.amount,
+            "timestamp" => transaction.timestamp,
+            "cooldown" => cooldown,
+        },
+    )?;
+
+    if tx.affected_rows() == 0 {
+        let last_daily: Option<u64> = tx.exec_first(
+            "SELECT last_daily FROM balances WHERE user_id = :user_id",
+            params! {
+                "user_id" => &user_id,
+            },
+        )?;
+        tx.rollback()?;
+
+        return Ok(DailyClaim::OnCooldown(last_daily.unwrap_or_default()));
+    }
+
+    add_ledger_entry(&mut tx, transaction)?;
+    tx.commit()?;
+
+    Ok(DailyClaim::Claimed)
+}
+
+pub fn get_balance_leaderboard(limit: u64) -> Result<Vec<(UserId, u64)>, Err> {
+    let conn = get_db_conn()?;
+
+    let rows: Vec<(String, u64)> = conn.exec(
+        "SELECT user_id, balance FROM balances WHERE balance > 0 ORDER BY balance DESC LIMIT :limit",
+        params! {
+            "limit" => limit,
+        },
+    )?;
+
+    rows.into_iter()
+        .map(|(user_id, balance)| Ok((UserId::new(user_id.parse::<u64>()?), balance)))
+        .collect()
+}
+
+pub fn add_xp(guild_id: u64, user_id: UserId, xp: u64) -> Result<u64, Err> {
+    let conn = get_db_conn()?;
+    let mut tx = conn.start_transaction(TxOpts::default())?;
+
+    // (guild_id, user_id) is the table's unique key.
+    tx.exec_drop(
+        "INSERT INTO member_xp (guild_id, user_id, xp) VALUES (:guild_id, :user_id, :xp) ON DUPLICATE KEY UPDATE xp = xp + :xp",
+        params! {
+            "guild_id" => guild_id.to_string(),
+            "user_id" => user_id.to_string(),
+            "xp" => xp,
+        },
+    )?;
+    let total: Option<u64> = tx.exec_first(
+        "SELECT xp FROM member_xp WHERE guild_id = :guild_id AND user_id = :user_id",
+        params! {
+            "guild_id" => guild_id.to_string(),
+            "user_id" => user_id.to_string(),
+        },
+    )?;
+    tx.commit()?;
+
+    total.map_or_else(|| Err(Err::from("Error when adding XP.")), Ok)
+}
+
+pub fn get_xp_rank(guild_id: u64, user_id: UserId) -> Result<(u64, u64), Err> {
+    let conn = get_db_conn()?;
+
+    let xp: Option<u64> = conn.exec_first(
+        "SELECT xp FROM member_xp WHERE guild_id = :guild_id AND user_id = :user_id",
+        params! {
+            "guild_id" => guild_id.to_string(),
+            "user_id" => user_id.to_string(),
+        },
+    )?;
+    let xp = xp.unwrap_or_default();
+
+    let ahead: Option<u64> = conn.exec_first(
+        "SELECT COUNT(*) FROM member_xp WHERE guild_id = :guild_id AND xp > :xp",
+        params! {
+            "guild_id" => guild_id.to_string(),
+            "xp" => xp,
+        },
+    )?;
+
+    Ok((xp, ahead.unwrap_or_default() + 1))
+}
+
+pub fn get_xp_leaderboard(guild_id: u64, limit: u64) -> Result<Vec<(UserId, u64)>, Err> {
+    let conn = get_db_conn()?;
+
+    let rows: Vec<(String, u64)> = conn.exec(
+        "SELECT user_id, xp FROM member_xp WHERE guild_id = :guild_id ORDER BY xp DESC LIMIT :limit",
+        params! {
+            "guild_id" => guild_id.to_string(),
+            "limit" => limit,
+        },
+    )?;
+
+    rows.into_iter()
+        .map(|(user_id, xp)| Ok((UserId::new(user_id.parse::<u64>()?), xp)))
+        .collect()
+}
+
+pub fn get_level_curve(guild_id: u64) -> Result<Option<LevelCurve>, Err> {
+    let conn = get_db_conn()?;
+
+    let curve: Option<(u64, u64, u64)> = conn.exec_first(
+        "SELECT base, `linear`, quadratic FROM level_curves WHERE guild_id = :guild_id",
+        params! {
+            "guild_id" => guild_id.to_string(),
+        },
+    )?;
+
+    Ok(curve.map(|(base, linear, quadratic)| LevelCurve {
+        base,
+        linear,
+        quadratic,
+    }))
+}
+
+pub fn set_level_curve(guild_id: u64, curve: &LevelCurve) -> Result<(), Err> {
+    let conn = get_db_conn()?;
+
+    // guild_id is the table's primary key. `linear` is a reserved word in
+    // MySQL, so it has to be quoted.
+    conn.exec_drop(
+        "INSERT INTO level_curves (guild_id, base, `linear`, quadratic) VALUES (:guild_id, :base, :linear, :quadratic) ON DUPLICATE KEY UPDATE base = :base, `linear` = :linear, quadratic = :quadratic",
+        params! {
+            "guild_id" => guild_id.to_string(),
+            "base" => curve.base,
+            "linear" => curve.linear,
+            "quadratic" => curve.quadratic,
+        },
+    )?;
+
+    Ok(())
+}
+
+pub fn add_level_reward(guild_id: u64, level: u64, role_id: u64) -> Result<(), Err> {
+    let conn = get_db_conn()?;
+
+    // (guild_id, role_id) is the table's unique key.
+    conn.exec_drop(
+        "INSERT INTO level_rewards (guild_id, role_id, level) VALUES (:guild_id, :role_id, :level) ON DUPLICATE KEY UPDATE level = :level",
+        params! {
+            "guild_id" => guild_id.to_string(),
+            "role_id" => role_id.to_string(),
+            "level" => level,
+        },
+    )?;
+
+    Ok(())
+}
+
+pub fn remove_level_reward(guild_id: u64, role_id: u64) -> Result<bool, Err> {
+    let conn = get_db_conn()?;
+
+    conn.exec_drop(
+        "DELETE FROM level_rewards WHERE guild_id = :guild_id AND role_id = :role_id",
+        params! {
+            "guild_id" => guild_id.to_string(),
+            "role_id" => role_id.to_string(),
+        },
+    )?;
+
+    Ok(conn.affected_rows() > 0)
+}
+
+pub fn get_level_rewards(guild_id: u64) -> Result<Vec<(u64, u64)>, Err> {
+    let conn = get_db_conn()?;
+
+    let rows: Vec<(u64, String)> = conn.exec(
+        "SELECT level, role_id FROM level_rewards WHERE guild_id = :guild_id",
+        params! {
+            "guild_id" => guild_id.to_string(),
+        },
+    )?;
+
+    rows.into_iter()
+        .map(|(level, role_id)| Ok((level, role_id.parse::<u64>()?)))
+        .collect()
+}
+
+pub fn get_greeting(guild_id: u64, kind: GreetingKind) -> Result<Option<Greeting>, Err> {
+    let conn = get_db_conn()?;
+
+    let greeting: Option<(String, String, bool, bool)> = conn.exec_first(
+        "SELECT channel_id, message, card, dm FROM greetings WHERE guild_id = :guild_id AND kind = :kind",
+        params! {
+            "guild_id" => guild_id.to_string(),
+            "kind" => kind.as_str(),
+        },
+    )?;
+
+    greeting
+        .map(|(channel_id, message, card, dm)| {
+            Ok(Greeting {
+                guild_id,
+                kind,
+                channel_id: channel_id.parse::<u64>()?,
+                message,
+                card,
+                dm,
+            })
+        })
+        .transpose()
+}
+
+pub fn set_greeting(greeting: &Greeting) -> Result<(), Err> {
+    let conn = get_db_conn()?;
+
+    // (guild_id, kind) is the table's unique key.
+    conn.exec_drop(
+        "INSERT INTO greetings (guild_id, kind, channel_id, message, card, dm) VALUES (:guild_id, :kind, :channel_id, :message, :card, :dm) ON DUPLICATE KEY UPDATE channel_id = :channel_id, message = :message, card = :card, dm = :dm",
+        params! {
+            "guild_id" => greeting.guild_id.to_string(),
+            "kind" => greeting.kind.as_str(),
+            "channel_id" => greeting.channel_id.to_string(),
+            "message" => &greeting.message,
+            "card" => greeting.card,
+            "dm" => greeting.dm,
+        },
+    )?;
+
+    Ok(())
+}
+
+pub fn remove_greeting(guild_id: u64, kind: GreetingKind) -> Result<bool, Err> {
+    let conn = get_db_conn()?;
+
+    conn.exec_drop(
+        "DELETE FROM greetings WHERE guild_id = :guild_id AND kind = :kind",
+        params! {
+            "guild_id" => guild_id.to_string(),
+            "kind" => kind.as_str(),
+        },
+    )?;
+
+    Ok(conn.affected_rows() > 0)
+}
+
+pub fn add_role_menu(menu: &RoleMenu) -> Result<(), Err> {
+    let conn = get_db_conn()?;
+
+    // The roles are stored comma separated, since IDs can't contain commas.
+    conn.exec_drop(
+        "INSERT INTO role_menus (message_id, guild_id, channel_id, mode, roles) VALUES (:message_id, :guild_id, :channel_id, :mode, :roles)",
+        params! {
+            "message_id" => menu.message_id.to_string(),
+            "guild_id" => menu.guild_id.to_string(),
+            "channel_id" => menu.channel_id.to_string(),
+            "mode" => menu.mode.as_str(),
+            "roles" => menu
+                .roles
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(","),
+        },
+    )?;
+
+    Ok(())
+}
+
+pub fn get_role_menu(message_id: u64) -> Result<Option<RoleMenu>, Err> {
+    let conn = get_db_conn()?;
+
+    let menu: Option<(String, String, String, String)> = conn.exec_first(
+        "SELECT guild_id, channel_id, mode, roles FROM role_menus WHERE message_id = :message_id",
+        params! {
+            "message_id" => message_id.to_string(),
+        },
+    )?;
+
+    menu.map(|(guild_id, channel_id, mode, roles)| {
+        Ok(RoleMenu {
+            message_id,
+            guild_id: guild_id.parse::<u64>()?,
+            channel_id: channel_id.parse::<u64>()?,
+            mode: RoleMenuMode::from_name(&mode).unwrap_or_default(),
+            roles: roles
+                .split(',')
+                .filter(|role_id| !role_id.is_empty())
+                .map(|role_id| Ok(role_id.parse::<u64>()?))
+                .collect::<Result<_, Err>>()?,
+        })
+    })
+    .transpose()
+}
+
+pub fn add_auto_role(guild_id: u64, role_id: u64) -> Result<(), Err> {
+    let conn = get_db_conn()?;
+
+    // (guild_id, role_id) is the table's unique key.
+    conn.exec_drop(
+        "INSERT IGNORE INTO auto_roles (guild_id, role_id) VALUES (:guild_id, :role_id)",
+        params! {
+            "guild_id" => guild_id.to_string(),
+            "role_id" => role_id.to_string(),
+        },
+    )?;
+
+    Ok(())
+}
+
+pub fn remove_auto_role(guild_id: u64, role_id: u64) -> Result<bool, Err> {
+    let conn = get_db_conn()?;
+
+    conn.exec_drop(
+        "DELETE FROM auto_roles WHERE guild_id = :guild_id AND role_id = :role_id",
+        params! {
+            "guild_id" => guild_id.to_string(),
+            "role_id" => role_id.to_string(),
+        },
+    )?;
+
+    Ok(conn.affected_rows() > 0)
+}
+
+pub fn get_auto_roles(guild_id: u64) -> Result<Vec<u64>, Err> {
+    let conn = get_db_conn()?;
+
+    let rows: Vec<String> = conn.exec(
+        "SELECT role_id FROM auto_roles WHERE guild_id = :guild_id",
+        params! {
+            "guild_id" => guild_id.to_string(),
+        },
+    )?;
+
+    rows.into_iter()
+        .map(|role_id| Ok(role_id.parse::<u64>()?))
+        .collect()
+}
+
+pub fn set_automod_rule(rule: &AutomodRule) -> Result<(), Err> {
+    let conn = get_db_conn()?;
+
+    // (guild_id, kind) is the table's unique key.
+    conn.exec_drop(
+        "INSERT INTO automod_rules (guild_id, kind, action, threshold) VALUES (:guild_id, :kind, :action, :threshold) ON DUPLICATE KEY UPDATE action = :action, threshold = :threshold",
+        params! {
+            "guild_id" => rule.guild_id.to_string(),
+            "kind" => rule.kind.as_str(),
+            "action" => rule.action.as_str(),
+            "threshold" => rule.threshold,
+        },
+    )?;
+
+    Ok(())
+}
+
+pub fn remove_automod_rule(guild_id: u64, kind: RuleKind) -> Result<bool, Err> {
+    let conn = get_db_conn()?;
+
+    conn.exec_drop(
+        "DELETE FROM automod_rules WHERE guild_id = :guild_id AND kind = :kind",
+        params! {
+            "guild_id" => guild_id.to_string(),
+            "kind" => kind.as_str(),
+        },
+    )?;
+
+    Ok(conn.affected_rows() > 0)
+}
+
+pub fn get_automod_rules(guild_id: u64) -> Result<Vec<AutomodRule>, Err> {
+    let conn = get_db_conn()?;
+
+    let rows: Vec<(String, String, u64)> = conn.exec(
+        "SELECT kind, action, threshold FROM automod_rules WHERE guild_id = :guild_id",
+        params! {
+            "guild_id" => guild_id.to_string(),
+        },
+    )?;
+
+    // Rules that were removed from the bot are skipped.
+    Ok(rows
+        .into_iter()
+        .filter_map(|(kind, action, threshold)| {
+            Some(AutomodRule {
+                guild_id,
+                kind: RuleKind::from_name(&kind)?,
+                action: RuleAction::from_name(&action).unwrap_or_default(),
+                threshold,
+            })
+        })
+        .collect())
+}
+
+pub fn add_blocked_pattern(guild_id: u64, pattern: &str) -> Result<(), Err> {
+    let conn = get_db_conn()?;
+
+    // (guild_id, pattern) is the table's unique key.
+    conn.exec_drop(
+        "INSERT IGNORE INTO automod_blocklist (guild_id, pattern) VALUES (:guild_id, :pattern)",
+        params! {
+            "guild_id" => guild_id.to_string(),
+            "pattern" => pattern,
+        },
+    )?;
+
+    Ok(())
+}
+
+pub fn remove_blocked_pattern(guild_id: u64, pattern: &str) -> Result<bool, Err> {
+    let conn = get_db_conn()?;
+
+    conn.exec_drop(
+        "DELETE FROM automod_blocklist WHERE guild_id = :guild_id AND pattern = :pattern",
+        params! {
+            "guild_id" => guild_id.to_string(),
+            "pattern" => pattern,
+        },
+    )?;
+
+    Ok(conn.affected_rows() > 0)
+}
+
+pub fn get_blocked_patterns(guild_id: u64) -> Result<Vec<String>, Err> {
+    let conn = get_db_conn()?;
+
+    Ok(conn.exec(
+        "SELECT pattern FROM automod_blocklist WHERE guild_id = :guild_id",
+        params! {
+            "guild_id" => guild_id.to_string(),
+        },
+    )?)
+}
+
+pub fn set_automod_escalation(guild_id: u64, strikes: u64, minutes: u64) -> Result<(), Err> {
+    let conn = get_db_conn()?;
+
+    // (guild_id, strikes) is the table's unique key.
+    conn.exec_drop(
+        "INSERT INTO automod_escalations (guild_id, strikes, minutes) VALUES (:guild_id, :strikes, :minutes) ON DUPLICATE KEY UPDATE minutes = :minutes",
+        params! {
+            "guild_id" => guild_id.to_string(),
+            "strikes" => strikes,
+            "minutes" => minutes,
+        },
+    )?;
+
+    Ok(())
+}
+
+pub fn remove_automod_escalation(guild_id: u64, strikes: u64) -> Result<bool, Err> {
+    let conn = get_db_conn()?;
+
+    conn.exec_drop(
+        "DELETE FROM automod_escalations WHERE guild_id = :guild_id AND strikes = :strikes",
+        params! {
+            "guild_id" => guild_id.to_string(),
+            "strikes" => strikes,
+        },
+    )?;
+
+    Ok(conn.affected_rows() > 0)
+}
+
+pub fn get_automod_escalations(guild_id: u64) -> Result<Vec<(u64, u64)>, Err> {
+    let conn = get_db_conn()?;
+
+    Ok(conn.exec(
+        "SELECT strikes, minutes FROM automod_escalations WHERE guild_id = :guild_id",
+        params! {
+            "guild_id" => guild_id.to_string(),
+        },
+    )?)
+}
+
+pub fn set_automod_log_channel(guild_id: u64, channel_id: u64) -> Result<(), Err> {
+    let conn = get_db_conn()?;
+
+    // guild_id is the table's primary key.
+    conn.exec_drop(
+        "INSERT INTO automod_log_channels (guild_id, channel_id) VALUES (:guild_id, :channel_id) ON DUPLICATE KEY UPDATE channel_id = :channel_id",
+        params! {
+            "guild_id" => guild_id.to_string(),
+            "channel_id" => channel_id.to_string(),
+        },
+    )?;
+
+    Ok(())
+}
+
+pub fn remove_automod_log_channel(guild_id: u64) -> Result<(), Err> {
+    let conn = get_db_conn()?;
+
+    conn.exec_drop(
+        "DELETE FROM automod_log_channels WHERE guild_id = :guild_id",
+        params! {
+            "guild_id" => guild_id.to_string(),
+        },
+    )?;
+
+    Ok(())
+}
+
+pub fn get_automod_log_channel(guild_id: u64) -> Result<Option<u64>, Err> {
+    let conn = get_db_conn()?;
+
+    let channel_id: Option<String> = conn.exec_first(
+        "SELECT channel_id FROM automod_log_channels WHERE guild_id = :guild_id",
+        params! {
+            "guild_id" => guild_id.to_string(),
+        },
+    )?;
+
+    Ok(channel_id.map(|id| id.parse::<u64>()).transpose()?)
+}
+
+pub fn add_automod_trigger(trigger: &AutomodTrigger) -> Result<(), Err> {
+    let conn = get_db_conn()?;
+
+    conn.exec_drop(
+        "INSERT INTO automod_log (guild_id, user_id, channel_id, kind, action, timestamp) VALUES (:guild_id, :user_id, :channel_id, :kind, :action, :timestamp)",
+        params! {
+            "guild_id" => trigger.guild_id.to_string(),
+            "user_id" => trigger.user_id.to_string(),
+            "channel_id" => trigger.channel_id.to_string(),
+            "kind" => trigger.kind.as_str(),
+            "action" => trigger.action.as_str(),
+            "timestamp" => trigger.timestamp,
+        },
+    )?;
+
+    Ok(())
+}
+
+pub fn remove_automod_triggers_before(before: u64) -> Result<(), Err> {
+    let conn = get_db_conn()?;
+
+    conn.exec_drop(
+        "DELETE FROM automod_log WHERE timestamp < :before",
+        params! {
+            "before" => before,
+        },
+    )?;
+
+    Ok(())
+}
+
+pub fn count_automod_triggers(guild_id: u64, user_id: u64, since: u64) -> Result<u64, Err> {
+    let conn = get_db_conn()?;
+
+    let count: Option<u64> = conn.exec_first(
+        "SELECT COUNT(*) FROM automod_log WHERE guild_id = :guild_id AND user_id = :user_id AND timestamp >= :since",
+        params! {
+            "guild_id" => guild_id.to_string(),
+            "user_id" => user_id.to_string(),
+            "since" => since,
+        },
+    )?;
+
+    Ok(count.unwrap_or_default())
+}
@@ -3,7 +3,28 @@ use std::env::var;
 use poise::serenity_prelude::UserId;
 use surrealdb::{engine::remote::ws::Ws, opt::auth::Root, Surreal};
 
-use crate::{commands::utility::add_reminder::Reminder, database::Err};
+use crate::{
+    commands::{
+        economy::{DailyClaim, Transaction},
+        fun::{
+            action::{ActionPair, ActionPreferences, ActionRole, ActionStats},
+            custom_content::{ContentKind, CustomContent},
+            qotd::QotdPost,
+            trivia::TriviaScore,
+        },
+        leveling::LevelCurve,
+        moderation::{
+            automod::{AutomodRule, AutomodTrigger, RuleAction, RuleKind},
+            safety::SafetyLevel,
+        },
+        utility::{
+            add_reminder::Reminder,
+            greetings::{Greeting, GreetingKind},
+            roles::{RoleMenu, RoleMenuMode},
+        },
+    },
+    database::Err,
+};
 
 use super::DB_CONN;
 
@@ -340,13 +361,20 @@ pub async fn get_reminders_of_user(user_id: UserId) -> Result<Vec<Reminder>, Err
         )
 }
 
-pub async fn get_action_count(
+pub async fn increment_action_count(
     action: &str,
     from_user_id: UserId,
     to_user_id: UserId,
-) -> Result<i32, Err> {
+) -> Result<u64, Err> {
+    // UPDATE creates the record when it's given a record ID that doesn't exist
+    // yet, so every pair gets a fixed ID to increment it in a single statement.
+    // Rows from before that had random IDs, so they're folded in and removed
+    // the first time the pair is incremented again.
     get_db_conn()?
-        .query("SELECT amount FROM actions WHERE action = $action AND from_user_id = $from_user_id AND to_user_id = $to_user_id")
+        .query("BEGIN TRANSACTION")
+        .query("UPDATE type::thing('actions', [$action, $from_user_id, $to_user_id]) SET action = $action, from_user_id = $from_user_id, to_user_id = $to_user_id, amount = (amount OR 0) + math::sum((SELECT VALUE amount FROM actions WHERE action = $action AND from_user_id = $from_user_id AND to_user_id = $to_user_id AND id != type::thing('actions', [$action, $from_user_id, $to_user_id]))) + 1 RETURN amount")
+        .query("DELETE actions WHERE action = $action AND from_user_id = $from_user_id AND to_user_id = $to_user_id AND id != type::thing('actions', [$action, $from_user_id, $to_user_id])")
+        .query("COMMIT TRANSACTION")
         .bind(("action", action))
         .bind(("from_user_id", from_user_id.get()))
         .bind(("to_user_id", to_user_id.get()))
@@ -354,26 +382,1226 @@ pub async fn get_action_count(
         .take::<surrealdb::sql::Value>(0)?
         .first()
         .into_json()["amount"]
-        .as_i64()
+        .as_u64()
+        .map_or_else(
+            || Err(Err::from("Error when incrementing action count.")),
+            Ok,
+        )
+}
+
+fn custom_content_from_json(v: &serde_json::Value) -> CustomContent {
+    CustomContent {
+        // `id` is the record ID in SurrealDB, so the submission ID lives in `content_id`.
+        id: v["content_id"]
+            .as_u64()
+            .expect("Error when trying to get content ID."),
+        guild_id: v["guild_id"]
+            .as_u64()
+            .expect("Error when trying to get guild ID."),
+        kind: ContentKind::from_db(v["kind"].as_str().expect("Error when trying to get kind.")),
+        content: v["content"]
+            .as_str()
+            .expect("Error when trying to get content.")
+            .to_string(),
+        author_id: UserId::new(
+            v["author_id"]
+                .as_u64()
+                .expect("Error when trying to get author ID."),
+        ),
+        approved: v["approved"]
+            .as_bool()
+            .expect("Error when trying to get approval state."),
+    }
+}
+
+pub async fn add_custom_content(content: &CustomContent) -> Result<(), Err> {
+    get_db_conn()?
+        .query("INSERT INTO custom_content (content_id, guild_id, kind, content, author_id, approved) VALUES ($content_id, $guild_id, $kind, $content, $author_id, $approved)")
+        .bind(("content_id", content.id))
+        .bind(("guild_id", content.guild_id))
+        .bind(("kind", content.kind.as_str()))
+        .bind(("content", content.content.clone()))
+        .bind(("author_id", content.author_id.get()))
+        .bind(("approved", content.approved))
+        .await?;
+
+    Ok(())
+}
+
+pub async fn get_custom_content(
+    guild_id: u64,
+    kind: ContentKind,
+    approved: bool,
+) -> Result<Vec<CustomContent>, Err> {
+    get_db_conn()?
+        .query("SELECT * FROM custom_content WHERE guild_id = $guild_id AND kind = $kind AND approved = $approved")
+        .bind(("guild_id", guild_id))
+        .bind(("kind", kind.as_str()))
+        .bind(("approved", approved))
+        .await?
+        .take::<surrealdb::sql::Value>(0)?
+        .into_json()
+        .as_array()
+        .map(|arr| arr.iter().map(custom_content_from_json).collect())
+        .map_or_else(
+            || Err(Err::from("Error when getting custom content.")),
+            Ok,
+        )
+}
+
+pub async fn approve_custom_content(id: u64, guild_id: u64) -> Result<bool, Err> {
+    Ok(get_db_conn()?
+        .query("UPDATE custom_content SET approved = true WHERE content_id = $content_id AND guild_id = $guild_id AND approved = false")
+        .bind(("content_id", id))
+        .bind(("guild_id", guild_id))
+        .await?
+        .take::<surrealdb::sql::Value>(0)?
+        .into_json()
+        .as_array()
+        .is_some_and(|arr| !arr.is_empty()))
+}
+
+pub async fn remove_custom_content(id: u64, guild_id: u64) -> Result<bool, Err> {
+    Ok(get_db_conn()?
+        .query("DELETE FROM custom_content WHERE content_id = $content_id AND guild_id = $guild_id RETURN BEFORE")
+        .bind(("content_id", id))
+        .bind(("guild_id", guild_id))
+        .await?
+        .take::<surrealdb::sql::Value>(0)?
+        .into_json()
+        .as_array()
+        .is_some_and(|arr| !arr.is_empty()))
+}
+
+pub async fn get_custom_content_ratio(guild_id: u64, kind: ContentKind) -> Result<Option<u8>, Err> {
+    Ok(get_db_conn()?
+        .query(
+            "SELECT ratio FROM custom_content_settings WHERE guild_id = $guild_id AND kind = $kind",
+        )
+        .bind(("guild_id", guild_id))
+        .bind(("kind", kind.as_str()))
+        .await?
+        .take::<surrealdb::sql::Value>(0)?
+        .first()
+        .into_json()["ratio"]
+        .as_u64()
+        .and_then(|ratio| u8::try_from(ratio).ok()))
+}
+
+pub async fn set_custom_content_ratio(
+    guild_id: u64,
+    kind: ContentKind,
+    ratio: u8,
+) -> Result<(), Err> {
+    let exists = get_db_conn()?
+        .query("UPDATE custom_content_settings SET ratio = $ratio WHERE guild_id = $guild_id AND kind = $kind")
+        .bind(("guild_id", guild_id))
+        .bind(("kind", kind.as_str()))
+        .bind(("ratio", ratio))
+        .await?
+        .take::<surrealdb::sql::Value>(0)?
+        .into_json()
+        .as_array()
+        .is_some_and(|arr| !arr.is_empty());
+
+    if !exists {
+        get_db_conn()?
+            .query("INSERT INTO custom_content_settings (guild_id, kind, ratio) VALUES ($guild_id, $kind, $ratio)")
+            .bind(("guild_id", guild_id))
+            .bind(("kind", kind.as_str()))
+            .bind(("ratio", ratio))
+            .await?;
+    }
+
+    Ok(())
+}
+
+pub async fn add_qotd_thread_channel(channel_id: u64) -> Result<(), Err> {
+    if !qotd_threads_enabled(channel_id).await? {
+        get_db_conn()?
+            .query("INSERT INTO qotd_threads (channel_id) VALUES ($channel_id)")
+            .bind(("channel_id", channel_id))
+            .await?;
+    }
+
+    Ok(())
+}
+
+pub async fn remove_qotd_thread_channel(channel_id: u64) -> Result<(), Err> {
+    get_db_conn()?
+        .query("DELETE FROM qotd_threads WHERE channel_id = $channel_id")
+        .bind(("channel_id", channel_id))
+        .await?;
+
+    Ok(())
+}
+
+pub async fn qotd_threads_enabled(channel_id: u64) -> Result<bool, Err> {
+    Ok(!get_db_conn()?
+        .query("SELECT * FROM qotd_threads WHERE channel_id = $channel_id")
+        .bind(("channel_id", channel_id))
+        .await?
+        .take::<surrealdb::sql::Value>(0)?
+        .first()
+        .into_json()
+        .is_null())
+}
+
+pub async fn add_qotd_post(post: &QotdPost) -> Result<(), Err> {
+    get_db_conn()?
+        .query("INSERT INTO qotd_posts (channel_id, thread_id, question, timestamp, answers) VALUES ($channel_id, $thread_id, $question, $timestamp, $answers)")
+        .bind(("channel_id", post.channel_id))
+        .bind(("thread_id", post.thread_id))
+        .bind(("question", post.question.clone()))
+        .bind(("timestamp", post.timestamp))
+        .bind(("answers", post.answers))
+        .await?;
+
+    Ok(())
+}
+
+pub async fn get_qotd_posts_since(channel_id: u64, timestamp: u64) -> Result<Vec<QotdPost>, Err> {
+    get_db_conn()?
+        .query(
+            "SELECT * FROM qotd_posts WHERE channel_id = $channel_id AND timestamp >= $timestamp",
+        )
+        .bind(("channel_id", channel_id))
+        .bind(("timestamp", timestamp))
+        .await?
+        .take::<surrealdb::sql::Value>(0)?
+        .into_json()
+        .as_array()
+        .map(|arr| {
+            arr.iter()
+                .map(|v| QotdPost {
+                    channel_id,
+                    thread_id: v["thread_id"]
+                        .as_u64()
+                        .expect("Error when trying to get thread ID."),
+                    question: v["question"]
+                        .as_str()
+                        .expect("Error when trying to get question.")
+                        .to_string(),
+                    timestamp: v["timestamp"]
+                        .as_u64()
+                        .expect("Error when trying to get timestamp."),
+                    answers: v["answers"]
+                        .as_u64()
+                        .expect("Error when trying to get answers."),
+                })
+                .collect()
+        })
+        .map_or_else(|| Err(Err::from("Error when getting QOTD posts.")), Ok)
+}
+
+pub async fn update_qotd_post_answers(thread_id: u64, answers: u64) -> Result<(), Err> {
+    get_db_conn()?
+        .query("UPDATE qotd_posts SET answers = $answers WHERE thread_id = $thread_id")
+        .bind(("thread_id", thread_id))
+        .bind(("answers", answers))
+        .await?;
+
+    Ok(())
+}
+
+pub async fn get_safety_level(guild_id: u64) -> Result<Option<SafetyLevel>, Err> {
+    Ok(get_db_conn()?
+        .query("SELECT level FROM safety_levels WHERE guild_id = $guild_id")
+        .bind(("guild_id", guild_id))
+        .await?
+        .take::<surrealdb::sql::Value>(0)?
+        .first()
+        .into_json()["level"]
+        .as_str()
+        .and_then(SafetyLevel::from_name))
+}
+
+pub async fn set_safety_level(guild_id: u64, level: SafetyLevel) -> Result<(), Err> {
+    let exists = get_db_conn()?
+        .query("UPDATE safety_levels SET level = $level WHERE guild_id = $guild_id")
+        .bind(("guild_id", guild_id))
+        .bind(("level", level.as_str()))
+        .await?
+        .take::<surrealdb::sql::Value>(0)?
+        .into_json()
+        .as_array()
+        .is_some_and(|arr| !arr.is_empty());
+
+    if !exists {
+        get_db_conn()?
+            .query("INSERT INTO safety_levels (guild_id, level) VALUES ($guild_id, $level)")
+            .bind(("guild_id", guild_id))
+            .bind(("level", level.as_str()))
+            .await?;
+    }
+
+    Ok(())
+}
+
+pub async fn set_wyr_vote(
+    message_id: u64,
+    prompt_id: u64,
+    user_id: UserId,
+    choice: u8,
+) -> Result<(), Err> {
+    let exists = get_db_conn()?
+        .query("UPDATE wyr_votes SET choice = $choice WHERE message_id = $message_id AND user_id = $user_id")
+        .bind(("message_id", message_id))
+        .bind(("user_id", user_id.get()))
+        .bind(("choice", choice))
+        .await?
+        .take::<surrealdb::sql::Value>(0)?
+        .into_json()
+        .as_array()
+        .is_some_and(|arr| !arr.is_empty());
+
+    if !exists {
+        // Prompt IDs can go past the largest number SurrealDB can store.
+        get_db_conn()?
+            .query("INSERT INTO wyr_votes (message_id, prompt_id, user_id, choice) VALUES ($message_id, $prompt_id, $user_id, $choice)")
+            .bind(("message_id", message_id))
+            .bind(("prompt_id", prompt_id.to_string()))
+            .bind(("user_id", user_id.get()))
+            .bind(("choice", choice))
+            .await?;
+    }
+
+    Ok(())
+}
+
+pub async fn get_wyr_message_votes(message_id: u64) -> Result<Vec<(u8, u64)>, Err> {
+    let result = get_db_conn()?
+        .query("SELECT choice, count() AS votes FROM wyr_votes WHERE message_id = $message_id GROUP BY choice")
+        .bind(("message_id", message_id))
+        .await?
+        .take::<surrealdb::sql::Value>(0)?
+        .into_json();
+
+    Ok(wyr_votes_from_json(&result))
+}
+
+pub async fn get_wyr_prompt_votes(prompt_id: u64) -> Result<Vec<(u8, u64)>, Err> {
+    let result = get_db_conn()?
+        .query("SELECT choice, count() AS votes FROM wyr_votes WHERE prompt_id = $prompt_id GROUP BY choice")
+        .bind(("prompt_id", prompt_id.to_string()))
+        .await?
+        .take::<surrealdb::sql::Value>(0)?
+        .into_json();
+
+    Ok(wyr_votes_from_json(&result))
+}
+
+fn wyr_votes_from_json(v: &serde_json::Value) -> Vec<(u8, u64)> {
+    v.as_array()
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|v| {
+                    Some((
+                        u8::try_from(v["choice"].as_u64()?).ok()?,
+                        v["votes"].as_u64()?,
+                    ))
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+pub async fn add_trivia_result(
+    guild_id: u64,
+    user_id: UserId,
+    points: u64,
+    correct: bool,
+) -> Result<(), Err> {
+    let exists = get_db_conn()?
+        .query("UPDATE trivia_scores SET points += $points, correct += $correct, answered += 1 WHERE guild_id = $guild_id AND user_id = $user_id")
+        .bind(("guild_id", guild_id))
+        .bind(("user_id", user_id.get()))
+        .bind(("points", points))
+        .bind(("correct", u64::from(correct)))
+        .await?
+        .take::<surrealdb::sql::Value>(0)?
+        .into_json()
+        .as_array()
+        .is_some_and(|arr| !arr.is_empty());
+
+    if !exists {
+        get_db_conn()?
+            .query("INSERT INTO trivia_scores (guild_id, user_id, points, correct, answered) VALUES ($guild_id, $user_id, $points, $correct, 1)")
+            .bind(("guild_id", guild_id))
+            .bind(("user_id", user_id.get()))
+            .bind(("points", points))
+            .bind(("correct", u64::from(correct)))
+            .await?;
+    }
+
+    Ok(())
+}
+
+pub async fn get_trivia_leaderboard(
+    guild_id: Option<u64>,
+    limit: u64,
+) -> Result<Vec<TriviaScore>, Err> {
+    let query = if guild_id.is_some() {
+        "SELECT user_id, math::sum(points) AS points, math::sum(correct) AS correct, math::sum(answered) AS answered FROM trivia_scores WHERE guild_id = $guild_id GROUP BY user_id ORDER BY points DESC LIMIT $limit"
+    } else {
+        "SELECT user_id, math::sum(points) AS points, math::sum(correct) AS correct, math::sum(answered) AS answered FROM trivia_scores GROUP BY user_id ORDER BY points DESC LIMIT $limit"
+    };
+
+    get_db_conn()?
+        .query(query)
+        .bind(("guild_id", guild_id))
+        .bind(("limit", limit))
+        .await?
+        .take::<surrealdb::sql::Value>(0)?
+        .into_json()
+        .as_array()
+        .map(|arr| {
+            arr.iter()
+                .map(|v| TriviaScore {
+                    user_id: UserId::new(
+                        v["user_id"]
+                            .as_u64()
+                            .expect("Error when trying to get user ID."),
+                    ),
+                    points: v["points"].as_u64().unwrap_or_default(),
+                    correct: v["correct"].as_u64().unwrap_or_default(),
+                    answered: v["answered"].as_u64().unwrap_or_default(),
+                })
+                .collect()
+        })
         .map_or_else(
-            || Err(Err::from("Error when getting action count.")),
+            || Err(Err::from("Error when getting the trivia leaderboard.")),
             Ok,
-        ).map(|i| i.try_into().expect("Error when converting action count: overflow."))
+        )
 }
 
-pub async fn update_action_count(
+pub async fn add_guild_action(
+    guild_id: u64,
     action: &str,
     from_user_id: UserId,
     to_user_id: UserId,
-    amount: i32,
 ) -> Result<(), Err> {
+    // Like `actions`, every pair has a fixed ID so UPDATE can create it.
     get_db_conn()?
-        .query("UPDATE actions SET amount = $amount WHERE action = $action AND from_user_id = $from_user_id AND to_user_id = $to_user_id")
+        .query("UPDATE type::thing('guild_actions', [$guild_id, $action, $from_user_id, $to_user_id]) SET guild_id = $guild_id, action = $action, from_user_id = $from_user_id, to_user_id = $to_user_id, amount = (amount OR 0) + 1")
+        .bind(("guild_id", guild_id))
         .bind(("action", action))
         .bind(("from_user_id", from_user_id.get()))
         .bind(("to_user_id", to_user_id.get()))
-        .bind(("amount", amount))
         .await?;
 
     Ok(())
 }
+
+/// Gets the table the actions of a scope are counted in, and the condition
+/// that limits a query to that scope.
+///
+/// `actions` has every action ever performed, while `guild_actions` only has
+/// the ones performed since guilds started being tracked.
+const fn action_scope(guild_id: Option<u64>) -> (&'static str, &'static str) {
+    if guild_id.is_some() {
+        ("guild_actions", "guild_id = $guild_id")
+    } else {
+        ("actions", "true")
+    }
+}
+
+async fn get_action_totals(
+    guild_id: Option<u64>,
+    user_id: UserId,
+    role: ActionRole,
+) -> Result<Vec<(String, u64)>, Err> {
+    let (table, scope) = action_scope(guild_id);
+
+    get_db_conn()?
+        .query(format!(
+            "SELECT action, math::sum(amount) AS amount FROM {table} WHERE {} = $user_id AND {scope} GROUP BY action",
+            role.column()
+        ))
+        .bind(("guild_id", guild_id))
+        .bind(("user_id", user_id.get()))
+        .await?
+        .take::<surrealdb::sql::Value>(0)?
+        .into_json()
+        .as_array()
+        .map(|arr| {
+            arr.iter()
+                .map(|v| {
+                    (
+                        v["action"]
+                            .as_str()
+                            .expect("Error when trying to get action.")
+                            .to_string(),
+                        v["amount"].as_u64().unwrap_or_default(),
+                    )
+                })
+                .collect()
+        })
+        .map_or_else(
+            || Err(Err::from("Error when getting action stats.")),
+            Ok,
+        )
+}
+
+pub async fn get_action_stats(
+    guild_id: Option<u64>,
+    user_id: UserId,
+) -> Result<Vec<ActionStats>, Err> {
+    Ok(ActionStats::merge(
+        get_action_totals(guild_id, user_id, ActionRole::Performer).await?,
+        get_action_totals(guild_id, user_id, ActionRole::Receiver).await?,
+    ))
+}
+
+pub async fn get_action_leaderboard(
+    guild_id: Option<u64>,
+    action: &str,
+    role: ActionRole,
+    limit: u64,
+) -> Result<Vec<(UserId, u64)>, Err> {
+    let (table, scope) = action_scope(guild_id);
+
+    get_db_conn()?
+        .query(format!(
+            "SELECT {} AS user_id, math::sum(amount) AS amount FROM {table} WHERE action = $action AND {scope} GROUP BY user_id ORDER BY amount DESC LIMIT $limit",
+            role.column()
+        ))
+        .bind(("guild_id", guild_id))
+        .bind(("action", action))
+        .bind(("limit", limit))
+        .await?
+        .take::<surrealdb::sql::Value>(0)?
+        .into_json()
+        .as_array()
+        .map(|arr| {
+            arr.iter()
+                .map(|v| {
+                    (
+                        UserId::new(
+                            v["user_id"]
+                                .as_u64()
+                                .expect("Error when trying to get user ID."),
+                        ),
+                        v["amount"].as_u64().unwrap_or_default(),
+                    )
+                })
+                .collect()
+        })
+        .map_or_else(
+            || Err(Err::from("Error when getting the action leaderboard.")),
+            Ok,
+        )
+}
+
+pub async fn get_mutual_action_pairs(
+    guild_id: Option<u64>,
+    action: &str,
+    limit: u64,
+) -> Result<Vec<ActionPair>, Err> {
+    let (table, scope) = action_scope(guild_id);
+
+    // Each pair is in the table twice, once for each direction, so only the
+    // one where the first user has the smaller ID is kept.
+    let mut pairs = get_db_conn()?
+        .query(format!(
+            "SELECT from_user_id, to_user_id, amount, (SELECT VALUE amount FROM {table} WHERE action = $action AND from_user_id = $parent.to_user_id AND to_user_id = $parent.from_user_id AND {scope})[0] AS reverse_amount FROM {table} WHERE action = $action AND from_user_id < to_user_id AND {scope}"
+        ))
+        .bind(("guild_id", guild_id))
+        .bind(("action", action))
+        .await?
+        .take::<surrealdb::sql::Value>(0)?
+        .into_json()
+        .as_array()
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|v| {
+                    Some(ActionPair {
+                        first: UserId::new(v["from_user_id"].as_u64()?),
+                        second: UserId::new(v["to_user_id"].as_u64()?),
+                        first_to_second: v["amount"].as_u64()?,
+                        // Not set when the action was never performed back.
+                        second_to_first: v["reverse_amount"].as_u64()?,
+                    })
+                })
+                .collect::<Vec<_>>()
+        })
+        .map_or_else(
+            || Err(Err::from("Error when getting mutual action pairs.")),
+            Ok,
+        )?;
+
+    pairs.sort_by_key(|pair| std::cmp::Reverse(pair.first_to_second + pair.second_to_first));
+    pairs.truncate(usize::try_from(limit)?);
+
+    Ok(pairs)
+}
+
+pub async fn get_action_preferences(user_id: UserId) -> Result<Option<ActionPreferences>, Err> {
+    let preferences = get_db_conn()?
+        .query("SELECT * FROM action_preferences WHERE user_id = $user_id")
+        .bind(("user_id", user_id.get()))
+        .await?
+        .take::<surrealdb::sql::Value>(0)?
+        .first()
+        .into_json();
+
+    if preferences.is_null() {
+        return Ok(None);
+    }
+
+    Ok(Some(ActionPreferences {
+        user_id,
+        opted_out_all: preferences["opted_out_all"].as_bool().unwrap_or_default(),
+        opted_out: preferences["opted_out"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|action| action.as_str().map(str::to_string))
+            .collect(),
+        blocked: preferences["blocked"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(serde_json::Value::as_u64)
+            .map(UserId::new)
+            .collect(),
+        ping: preferences["ping"].as_bool().unwrap_or(true),
+    }))
+}
+
+pub async fn set_action_preferences(preferences: &ActionPreferences) -> Result<(), Err> {
+    let blocked = preferences
+        .blocked
+        .iter()
+        .map(|blocked| blocked.get())
+        .collect::<Vec<_>>();
+
+    let exists = get_db_conn()?
+        .query("UPDATE action_preferences SET opted_out_all = $opted_out_all, opted_out = $opted_out, blocked = $blocked, ping = $ping WHERE user_id = $user_id")
+        .bind(("user_id", preferences.user_id.get()))
+        .bind(("opted_out_all", preferences.opted_out_all))
+        .bind(("opted_out", &preferences.opted_out))
+        .bind(("blocked", &blocked))
+        .bind(("ping", preferences.ping))
+        .await?
+        .take::<surrealdb::sql::Value>(0)?
+        .into_json()
+        .as_array()
+        .is_some_and(|arr| !arr.is_empty());
+
+    if !exists {
+        get_db_conn()?
+            .query("INSERT INTO action_preferences (user_id, opted_out_all, opted_out, blocked, ping) VALUES ($user_id, $opted_out_all, $opted_out, $blocked, $ping)")
+            .bind(("user_id", preferences.user_id.get()))
+            .bind(("opted_out_all", preferences.opted_out_all))
+            .bind(("opted_out", &preferences.opted_out))
+            .bind(("blocked", &blocked))
+            .bind(("ping", preferences.ping))
+            .await?;
+    }
+
+    Ok(())
+}
+
+/// What the transactions below throw to cancel themselves when the money can't
+/// be moved, so it can be told apart from other errors.
+const TRANSACTION_REFUSED: &str = "Transaction refused";
+
+/// Runs a query that contains a transaction, and returns `false` if it threw
+/// `TRANSACTION_REFUSED`.
+async fn run_transaction(
+    query: surrealdb::method::Query<'_, surrealdb::engine::remote::ws::Client>,
+) -> Result<bool, Err> {
+    let errors = query.await?.take_errors();
+
+    if errors
+        .values()
+        .any(|error| error.to_string().contains(TRANSACTION_REFUSED))
+    {
+        return Ok(false);
+    }
+
+    errors
+        .into_values()
+        .next()
+        .map_or(Ok(true), |error| Err(error.into()))
+}
+
+pub async fn get_balance(user_id: UserId) -> Result<u64, Err> {
+    Ok(get_db_conn()?
+        .query("SELECT VALUE balance FROM type::thing('balances', $user_id)")
+        .bind(("user_id", user_id.get()))
+        .await?
+        .take::<surrealdb::sql::Value>(0)?
+        .first()
+        .into_json()
+        .as_u64()
+        .unwrap_or_default())
+}
+
+pub async fn apply_transaction(transaction: &Transaction) -> Result<bool, Err> {
+    // Every user's balance has their ID as its record ID, so UPDATE can
+    // create it.
+    let withdrawal = if transaction.from_user_id.is_some() {
+        // Checking the balance in WHERE makes the check and the withdrawal a
+        // single statement, so money can't be spent twice.
+        "IF array::len((UPDATE type::thing('balances', $from_user_id) SET balance -= $amount WHERE balance >= $amount)) = 0 { THROW $refused };"
+    } else {
+        ""
+    };
+    let deposit = if transaction.to_user_id.is_some() {
+        "UPDATE type::thing('balances', $to_user_id) SET user_id = $to_user_id, balance = (balance OR 0) + $amount;"
+    } else {
+        ""
+    };
+
+    let query = format!("BEGIN TRANSACTION; {withdrawal} {deposit} CREATE economy_ledger SET from_user_id = $from_user_id, to_user_id = $to_user_id, amount = $amount, kind = $kind, timestamp = $timestamp; COMMIT TRANSACTION;");
+
+    run_transaction(
+        get_db_conn()?
+            .query(query)
+            .bind(("from_user_id", transaction.from_user_id.map(UserId::get)))
+            .bind(("to_user_id", transaction.to_user_id.map(UserId::get)))
+            .bind(("amount", transaction.amount))
+            .bind(("kind", transaction.kind.as_str()))
+            .bind(("timestamp", transaction.timestamp))
+            .bind(("refused", TRANSACTION_REFUSED)),
+    )
+    .await
+}
+
+pub async fn claim_daily(transaction: &Transaction, cooldown: u64) -> Result<DailyClaim, Err> {
+    let user_id = transaction
+        .to_user_id
+        .ok_or("Daily rewards need a recipient.")?
+        .get();
+
+    let claimed = run_transaction(
+        get_db_conn()?
+            .query("BEGIN TRANSACTION")
+            .query("IF array::len((UPDATE type::thing('balances', $user_id) SET user_id = $user_id, balance = (balance OR 0) + $amount, last_daily = $timestamp WHERE last_daily = NONE OR last_daily + $cooldown <= $timestamp)) = 0 { THROW $refused }")
+            .query("CREATE economy_ledger SET from_user_id = NONE, to_user_id = $user_id, amount = $amount, kind = $kind, timestamp = $timestamp")
+            .query("COMMIT TRANSACTION")
+            .bind(("user_id", user_id))
+            .bind(("amount", transaction.amount))
+            .bind(("kind", transaction.kind.as_str()))
+            .bind(("timestamp", transaction.timestamp))
+            .bind(("cooldown", cooldown))
+            .bind(("refused", TRANSACTION_REFUSED)),
+    )
+    .await?;
+
+    if claimed {
+        return Ok(DailyClaim::Claimed);
+    }
+
+    let last_daily = get_db_conn()?
+        .query("SELECT VALUE last_daily FROM type::thing('balances', $user_id)")
+        .bind(("user_id", user_id))
+        .await?
+        .take::<surrealdb::sql::Value>(0)?
+        .first()
+        .into_json()
+        .as_u64()
+        .unwrap_or_default();
+
+    Ok(DailyClaim::OnCooldown(last_daily))
+}
+
+pub async fn get_balance_leaderboard(limit: u64) -> Result<Vec<(UserId, u64)>, Err> {
+    get_db_conn()?
+        .query("SELECT user_id, balance FROM balances WHERE balance > 0 ORDER BY balance DESC LIMIT $limit")
+        .bind(("limit", limit))
+        .await?
+        .take::<surrealdb::sql::Value>(0)?
+        .into_json()
+        .as_array()
+        .map(|arr| {
+            arr.iter()
+                .map(|v| {
+                    (
+                        UserId::new(
+                            v["user_id"]
+                                .as_u64()
+                                .expect("Error when trying to get user ID."),
+                        ),
+                        v["balance"].as_u64().unwrap_or_default(),
+                    )
+                })
+                .collect()
+        })
+        .map_or_else(
+            || Err(Err::from("Error when getting the balance leaderboard.")),
+            Ok,
+        )
+}
+
+pub async fn add_xp(guild_id: u64, user_id: UserId, xp: u64) -> Result<u64, Err> {
+    // Every member has a fixed ID so UPDATE can create it.
+    get_db_conn()?
+        .query("UPDATE type::thing('member_xp', [$guild_id, $user_id]) SET guild_id = $guild_id, user_id = $user_id, xp = (xp OR 0) + $xp RETURN xp")
+        .bind(("guild_id", guild_id))
+        .bind(("user_id", user_id.get()))
+        .bind(("xp", xp))
+        .await?
+        .take::<surrealdb::sql::Value>(0)?
+        .first()
+        .into_json()["xp"]
+        .as_u64()
+        .map_or_else(|| Err(Err::from("Error when adding XP.")), Ok)
+}
+
+pub async fn get_xp_rank(guild_id: u64, user_id: UserId) -> Result<(u64, u64), Err> {
+    let xp = get_db_conn()?
+        .query("SELECT VALUE xp FROM type::thing('member_xp', [$guild_id, $user_id])")
+        .bind(("guild_id", guild_id))
+        .bind(("user_id", user_id.get()))
+        .await?
+        .take::<surrealdb::sql::Value>(0)?
+        .first()
+        .into_json()
+        .as_u64()
+        .unwrap_or_default();
+
+    let ahead = get_db_conn()?
+        .query("SELECT count() FROM member_xp WHERE guild_id = $guild_id AND xp > $xp GROUP ALL")
+        .bind(("guild_id", guild_id))
+        .bind(("xp", xp))
+        .await?
+        .take::<surrealdb::sql::Value>(0)?
+        .first()
+        .into_json()["count"]
+        .as_u64()
+        .unwrap_or_default();
+
+    Ok((xp, ahead + 1))
+}
+
+pub async fn get_xp_leaderboard(guild_id: u64, limit: u64) -> Result<Vec<(UserId, u64)>, Err> {
+    get_db_conn()?
+        .query("SELECT user_id, xp FROM member_xp WHERE guild_id = $guild_id ORDER BY xp DESC LIMIT $limit")
+        .bind(("guild_id", guild_id))
+        .bind(("limit", limit))
+        .await?
+        .take::<surrealdb::sql::Value>(0)?
+        .into_json()
+        .as_array()
+        .map(|arr| {
+            arr.iter()
+                .map(|v| {
+                    (
+                        UserId::new(
+                            v["user_id"]
+                                .as_u64()
+                                .expect("Error when trying to get user ID."),
+                        ),
+                        v["xp"].as_u64().unwrap_or_default(),
+                    )
+                })
+                .collect()
+        })
+        .map_or_else(
+            || Err(Err::from("Error when getting the XP leaderboard.")),
+            Ok,
+        )
+}
+
+pub async fn get_level_curve(guild_id: u64) -> Result<Option<LevelCurve>, Err> {
+    let curve = get_db_conn()?
+        .query("SELECT * FROM type::thing('level_curves', $guild_id)")
+        .bind(("guild_id", guild_id))
+        .await?
+        .take::<surrealdb::sql::Value>(0)?
+        .first()
+        .into_json();
+
+    Ok(curve["base"].as_u64().map(|base| LevelCurve {
+        base,
+        linear: curve["linear"].as_u64().unwrap_or_default(),
+        quadratic: curve["quadratic"].as_u64().unwrap_or_default(),
+    }))
+}
+
+pub async fn set_level_curve(guild_id: u64, curve: &LevelCurve) -> Result<(), Err> {
+    get_db_conn()?
+        .query("UPDATE type::thing('level_curves', $guild_id) SET guild_id = $guild_id, base = $base, linear = $linear, quadratic = $quadratic")
+        .bind(("guild_id", guild_id))
+        .bind(("base", curve.base))
+        .bind(("linear", curve.linear))
+        .bind(("quadratic", curve.quadratic))
+        .await?;
+
+    Ok(())
+}
+
+pub async fn add_level_reward(guild_id: u64, level: u64, role_id: u64) -> Result<(), Err> {
+    get_db_conn()?
+        .query("UPDATE type::thing('level_rewards', [$guild_id, $role_id]) SET guild_id = $guild_id, role_id = $role_id, level = $level")
+        .bind(("guild_id", guild_id))
+        .bind(("role_id", role_id))
+        .bind(("level", level))
+        .await?;
+
+    Ok(())
+}
+
+pub async fn remove_level_reward(guild_id: u64, role_id: u64) -> Result<bool, Err> {
+    Ok(get_db_conn()?
+        .query("DELETE type::thing('level_rewards', [$guild_id, $role_id]) RETURN BEFORE")
+        .bind(("guild_id", guild_id))
+        .bind(("role_id", role_id))
+        .await?
+        .take::<surrealdb::sql::Value>(0)?
+        .into_json()
+        .as_array()
+        .is_some_and(|arr| !arr.is_empty()))
+}
+
+pub async fn get_level_rewards(guild_id: u64) -> Result<Vec<(u64, u64)>, Err> {
+    get_db_conn()?
+        .query("SELECT level, role_id FROM level_rewards WHERE guild_id = $guild_id")
+        .bind(("guild_id", guild_id))
+        .await?
+        .take::<surrealdb::sql::Value>(0)?
+        .into_json()
+        .as_array()
+        .map(|arr| {
+            arr.iter()
+                .map(|v| {
+                    (
+                        v["level"].as_u64().unwrap_or_default(),
+                        v["role_id"]
+                            .as_u64()
+                            .expect("Error when trying to get role ID."),
+                    )
+                })
+                .collect()
+        })
+        .map_or_else(|| Err(Err::from("Error when getting level rewards.")), Ok)
+}
+
+pub async fn get_greeting(guild_id: u64, kind: GreetingKind) -> Result<Option<Greeting>, Err> {
+    let greeting = get_db_conn()?
+        .query("SELECT * FROM type::thing('greetings', [$guild_id, $kind])")
+        .bind(("guild_id", guild_id))
+        .bind(("kind", kind.as_str()))
+        .await?
+        .take::<surrealdb::sql::Value>(0)?
+        .first()
+        .into_json();
+
+    Ok(greeting["channel_id"].as_u64().map(|channel_id| Greeting {
+        guild_id,
+        kind,
+        channel_id,
+        message: greeting["message"].as_str().unwrap_or_default().to_string(),
+        card: greeting["card"].as_bool().unwrap_or_default(),
+        dm: greeting["dm"].as_bool().unwrap_or_default(),
+    }))
+}
+
+pub async fn set_greeting(greeting: &Greeting) -> Result<(), Err> {
+    get_db_conn()?
+        .query("UPDATE type::thing('greetings', [$guild_id, $kind]) SET guild_id = $guild_id, kind = $kind, channel_id = $channel_id, message = $message, card = $card, dm = $dm")
+        .bind(("guild_id", greeting.guild_id))
+        .bind(("kind", greeting.kind.as_str()))
+        .bind(("channel_id", greeting.channel_id))
+        .bind(("message", greeting.message.clone()))
+        .bind(("card", greeting.card))
+        .bind(("dm", greeting.dm))
+        .await?;
+
+    Ok(())
+}
+
+pub async fn remove_greeting(guild_id: u64, kind: GreetingKind) -> Result<bool, Err> {
+    Ok(get_db_conn()?
+        .query("DELETE type::thing('greetings', [$guild_id, $kind]) RETURN BEFORE")
+        .bind(("guild_id", guild_id))
+        .bind(("kind", kind.as_str()))
+        .await?
+        .take::<surrealdb::sql::Value>(0)?
+        .into_json()
+        .as_array()
+        .is_some_and(|arr| !arr.is_empty()))
+}
+
+pub async fn add_role_menu(menu: &RoleMenu) -> Result<(), Err> {
+    get_db_conn()?
+        .query("CREATE type::thing('role_menus', $message_id) SET message_id = $message_id, guild_id = $guild_id, channel_id = $channel_id, mode = $mode, roles = $roles")
+        .bind(("message_id", menu.message_id))
+        .bind(("guild_id", menu.guild_id))
+        .bind(("channel_id", menu.channel_id))
+        .bind(("mode", menu.mode.as_str()))
+        .bind(("roles", menu.roles.clone()))
+        .await?;
+
+    Ok(())
+}
+
+pub async fn get_role_menu(message_id: u64) -> Result<Option<RoleMenu>, Err> {
+    let menu = get_db_conn()?
+        .query("SELECT * FROM type::thing('role_menus', $message_id)")
+        .bind(("message_id", message_id))
+        .await?
+        .take::<surrealdb::sql::Value>(0)?
+        .first()
+        .into_json();
+
+    if menu.is_null() {
+        return Ok(None);
+    }
+
+    Ok(Some(RoleMenu {
+        message_id,
+        guild_id: menu["guild_id"].as_u64().unwrap_or_default(),
+        channel_id: menu["channel_id"].as_u64().unwrap_or_default(),
+        mode: menu["mode"]
+            .as_str()
+            .and_then(RoleMenuMode::from_name)
+            .unwrap_or_default(),
+        roles: menu["roles"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(serde_json::Value::as_u64)
+            .collect(),
+    }))
+}
+
+pub async fn add_auto_role(guild_id: u64, role_id: u64) -> Result<(), Err> {
+    get_db_conn()?
+        .query("UPDATE type::thing('auto_roles', [$guild_id, $role_id]) SET guild_id = $guild_id, role_id = $role_id")
+        .bind(("guild_id", guild_id))
+        .bind(("role_id", role_id))
+        .await?;
+
+    Ok(())
+}
+
+pub async fn remove_auto_role(guild_id: u64, role_id: u64) -> Result<bool, Err> {
+    Ok(get_db_conn()?
+        .query("DELETE type::thing('auto_roles', [$guild_id, $role_id]) RETURN BEFORE")
+        .bind(("guild_id", guild_id))
+        .bind(("role_id", role_id))
+        .await?
+        .take::<surrealdb::sql::Value>(0)?
+        .into_json()
+        .as_array()
+        .is_some_and(|arr| !arr.is_empty()))
+}
+
+pub async fn get_auto_roles(guild_id: u64) -> Result<Vec<u64>, Err> {
+    get_db_conn()?
+        .query("SELECT role_id FROM auto_roles WHERE guild_id = $guild_id")
+        .bind(("guild_id", guild_id))
+        .await?
+        .take::<surrealdb::sql::Value>(0)?
+        .into_json()
+        .as_array()
+        .map(|arr| arr.iter().filter_map(|v| v["role_id"].as_u64()).collect())
+        .map_or_else(|| Err(Err::from("Error when getting auto-roles.")), Ok)
+}
+
+pub async fn set_automod_rule(rule: &AutomodRule) -> Result<(), Err> {
+    get_db_conn()?
+        .query("UPDATE type::thing('automod_rules', [$guild_id, $kind]) SET guild_id = $guild_id, kind = $kind, action = $action, threshold = $threshold")
+        .bind(("guild_id", rule.guild_id))
+        .bind(("kind", rule.kind.as_str()))
+        .bind(("action", rule.action.as_str()))
+        .bind(("threshold", rule.threshold))
+        .await?;
+
+    Ok(())
+}
+
+pub async fn remove_automod_rule(guild_id: u64, kind: RuleKind) -> Result<bool, Err> {
+    Ok(get_db_conn()?
+        .query("DELETE type::thing('automod_rules', [$guild_id, $kind]) RETURN BEFORE")
+        .bind(("guild_id", guild_id))
+        .bind(("kind", kind.as_str()))
+        .await?
+        .take::<surrealdb::sql::Value>(0)?
+        .into_json()
+        .as_array()
+        .is_some_and(|arr| !arr.is_empty()))
+}
+
+pub async fn get_automod_rules(guild_id: u64) -> Result<Vec<AutomodRule>, Err> {
+    get_db_conn()?
+        .query("SELECT kind, action, threshold FROM automod_rules WHERE guild_id = $guild_id")
+        .bind(("guild_id", guild_id))
+        .await?
+        .take::<surrealdb::sql::Value>(0)?
+        .into_json()
+        .as_array()
+        .map(|arr| {
+            // Rules that were removed from the bot are skipped.
+            arr.iter()
+                .filter_map(|v| {
+                    Some(AutomodRule {
+                        guild_id,
+                        kind: RuleKind::from_name(v["kind"].as_str()?)?,
+                        action: v["action"]
+                            .as_str()
+                            .and_then(RuleAction::from_name)
+                            .unwrap_or_default(),
+                        threshold: v["threshold"].as_u64().unwrap_or_default(),
+                    })
+                })
+                .collect()
+        })
+        .map_or_else(|| Err(Err::from("Error when getting auto-mod rules.")), Ok)
+}
+
+pub async fn add_blocked_pattern(guild_id: u64, pattern: &str) -> Result<(), Err> {
+    get_db_conn()?
+        .query("UPDATE type::thing('automod_blocklist', [$guild_id, $pattern]) SET guild_id = $guild_id, pattern = $pattern")
+        .bind(("guild_id", guild_id))
+        .bind(("pattern", pattern))
+        .await?;
+
+    Ok(())
+}
+
+pub async fn remove_blocked_pattern(guild_id: u64, pattern: &str) -> Result<bool, Err> {
+    Ok(get_db_conn()?
+        .query("DELETE type::thing('automod_blocklist', [$guild_id, $pattern]) RETURN BEFORE")
+        .bind(("guild_id", guild_id))
+        .bind(("pattern", pattern))
+        .await?
+        .take::<surrealdb::sql::Value>(0)?
+        .into_json()
+        .as_array()
+        .is_some_and(|arr| !arr.is_empty()))
+}
+
+pub async fn get_blocked_patterns(guild_id: u64) -> Result<Vec<String>, Err> {
+    get_db_conn()?
+        .query("SELECT pattern FROM automod_blocklist WHERE guild_id = $guild_id")
+        .bind(("guild_id", guild_id))
+        .await?
+        .take::<surrealdb::sql::Value>(0)?
+        .into_json()
+        .as_array()
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|v| v["pattern"].as_str().map(str::to_string))
+                .collect()
+        })
+        .map_or_else(|| Err(Err::from("Error when getting the blocklist.")), Ok)
+}
+
+pub async fn set_automod_escalation(guild_id: u64, strikes: u64, minutes: u64) -> Result<(), Err> {
+    get_db_conn()?
+        .query("UPDATE type::thing('automod_escalations', [$guild_id, $strikes]) SET guild_id = $guild_id, strikes = $strikes, minutes = $minutes")
+        .bind(("guild_id", guild_id))
+        .bind(("strikes", strikes))
+        .bind(("minutes", minutes))
+        .await?;
+
+    Ok(())
+}
+
+pub async fn remove_automod_escalation(guild_id: u64, strikes: u64) -> Result<bool, Err> {
+    Ok(get_db_conn()?
+        .query("DELETE type::thing('automod_escalations', [$guild_id, $strikes]) RETURN BEFORE")
+        .bind(("guild_id", guild_id))
+        .bind(("strikes", strikes))
+        .await?
+        .take::<surrealdb::sql::Value>(0)?
+        .into_json()
+        .as_array()
+        .is_some_and(|arr| !arr.is_empty()))
+}
+
+pub async fn get_automod_escalations(guild_id: u64) -> Result<Vec<(u64, u64)>, Err> {
+    get_db_conn()?
+        .query("SELECT strikes, minutes FROM automod_escalations WHERE guild_id = $guild_id")
+        .bind(("guild_id", guild_id))
+        .await?
+        .take::<surrealdb::sql::Value>(0)?
+        .into_json()
+        .as_array()
+        .map(|arr| {
+            arr.iter()
+                .map(|v| {
+                    (
+                        v["strikes"].as_u64().unwrap_or_default(),
+                        v["minutes"].as_u64().unwrap_or_default(),
+                    )
+                })
+                .collect()
+        })
+        .map_or_else(
+            || Err(Err::from("Error when getting auto-mod escalations.")),
+            Ok,
+        )
+}
+
+pub async fn set_automod_log_channel(guild_id: u64, channel_id: u64) -> Result<(), Err> {
+    get_db_conn()?
+        .query("UPDATE type::thing('automod_log_channels', $guild_id) SET guild_id = $guild_id, channel_id = $channel_id")
+        .bind(("guild_id", guild_id))
+        .bind(("channel_id", channel_id))
+        .await?;
+
+    Ok(())
+}
+
+pub async fn remove_automod_log_channel(guild_id: u64) -> Result<(), Err> {
+    get_db_conn()?
+        .query("DELETE type::thing('automod_log_channels', $guild_id)")
+        .bind(("guild_id", guild_id))
+        .await?;
+
+    Ok(())
+}
+
+pub async fn get_automod_log_channel(guild_id: u64) -> Result<Option<u64>, Err> {
+    Ok(get_db_conn()?
+        .query("SELECT channel_id FROM type::thing('automod_log_channels', $guild_id)")
+        .bind(("guild_id", guild_id))
+        .await?
+        .take::<surrealdb::sql::Value>(0)?
+        .first()
+        .into_json()["channel_id"]
+        .as_u64())
+}
+
+pub async fn add_automod_trigger(trigger: &AutomodTrigger) -> Result<(), Err> {
+    get_db_conn()?
+        .query("CREATE automod_log SET guild_id = $guild_id, user_id = $user_id, channel_id = $channel_id, kind = $kind, action = $action, timestamp = $timestamp")
+        .bind(("guild_id", trigger.guild_id))
+        .bind(("user_id", trigger.user_id))
+        .bind(("channel_id", trigger.channel_id))
+        .bind(("kind", trigger.kind.as_str()))
+        .bind(("action", trigger.action.as_str()))
+        .bind(("timestamp", trigger.timestamp))
+        .await?;
+
+    Ok(())
+}
+
+pub async fn remove_automod_triggers_before(before: u64) -> Result<(), Err> {
+    get_db_conn()?
+        .query("DELETE automod_log WHERE timestamp < $before")
+        .bind(("before", before))
+        .await?;
+
+    Ok(())
+}
+
+pub async fn count_automod_triggers(guild_id: u64, user_id: u64, since: u64) -> Result<u64, Err> {
+    Ok(get_db_conn()?
+        .query("SELECT count() AS strikes FROM automod_log WHERE guild_id = $guild_id AND user_id = $user_id AND timestamp >= $since GROUP ALL")
+        .bind(("guild_id", guild_id))
+        .bind(("user_id", user_id))
+        .bind(("since", since))
+        .await?
+        .take::<surrealdb::sql::Value>(0)?
+        .first()
+        .into_json()["strikes"]
+        .as_u64()
+        .unwrap_or_default())
+}
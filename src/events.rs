@@ -0,0 +1,176 @@
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use crate::Error;
+
+use log::{error, info, warn};
+use once_cell::sync::Lazy;
+use poise::serenity_prelude::{self as serenity, FullEvent, GatewayIntents};
+use poise::BoxFuture;
+
+/// Handles the events a feature registered for.
+///
+/// It's given the whole event, so one handler can be registered for several
+/// kinds of events and match on them.
+pub type Handler =
+    for<'a> fn(&'a serenity::Context, &'a FullEvent) -> BoxFuture<'a, Result<(), Error>>;
+
+/// The features registered for a kind of event, in the order they were
+/// registered in, with the intents they need on top of the event's.
+type Registered = Vec<(&'static str, GatewayIntents, Handler)>;
+
+static HANDLERS: Lazy<RwLock<HashMap<EventKind, Registered>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// The gateway events features can register handlers for.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum EventKind {
+    Message,
+    MessageUpdate,
+    MessageDelete,
+    GuildMemberAddition,
+    GuildMemberRemoval,
+    GuildMemberUpdate,
+    ReactionAdd,
+    ReactionRemove,
+}
+
+impl EventKind {
+    #[must_use]
+    pub const fn of(event: &FullEvent) -> Option<Self> {
+        match event {
+            FullEvent::Message { .. } => Some(Self::Message),
+            FullEvent::MessageUpdate { .. } => Some(Self::MessageUpdate),
+            FullEvent::MessageDelete { .. } => Some(Self::MessageDelete),
+            FullEvent::GuildMemberAddition { .. } => Some(Self::GuildMemberAddition),
+            FullEvent::GuildMemberRemoval { .. } => Some(Self::GuildMemberRemoval),
+            FullEvent::GuildMemberUpdate { .. } => Some(Self::GuildMemberUpdate),
+            FullEvent::ReactionAdd { .. } => Some(Self::ReactionAdd),
+            FullEvent::ReactionRemove { .. } => Some(Self::ReactionRemove),
+            _ => None,
+        }
+    }
+
+    /// The intents Discord needs to send this event.
+    #[must_use]
+    pub const fn intents(self) -> GatewayIntents {
+        match self {
+            Self::Message | Self::MessageUpdate | Self::MessageDelete => {
+                GatewayIntents::GUILD_MESSAGES
+            }
+            Self::GuildMemberAddition | Self::GuildMemberRemoval | Self::GuildMemberUpdate => {
+                GatewayIntents::GUILD_MEMBERS
+            }
+            Self::ReactionAdd | Self::ReactionRemove => GatewayIntents::GUILD_MESSAGE_REACTIONS,
+        }
+    }
+}
+
+/// Runs `handler` on every event of `kind`. `name` identifies the feature in
+/// the logs.
+pub fn register(kind: EventKind, name: &'static str, handler: Handler) {
+    register_with_intents(kind, name, GatewayIntents::empty(), handler);
+}
+
+/// Like [`register`], for features that need more than the event itself, like
+/// `MESSAGE_CONTENT` to read what messages say.
+pub fn register_with_intents(
+    kind: EventKind,
+    name: &'static str,
+    intents: GatewayIntents,
+    handler: Handler,
+) {
+    HANDLERS
+        .write()
+        .expect("Event handlers lock was poisoned")
+        .entry(kind)
+        .or_default()
+        .push((name, intents, handler));
+}
+
+/// Gets the intents to connect with.
+///
+/// By default those are the intents every registered feature needs. Setting
+/// `GATEWAY_INTENTS` to a comma separated list, like
+/// `GUILD_MESSAGES,GUILD_MEMBERS`, uses exactly those instead, which is useful
+/// when the bot isn't allowed to use some privileged intents. Features that
+/// need intents that were left out are logged and never receive their events.
+#[must_use]
+pub fn intents() -> GatewayIntents {
+    // Copied out so the lock isn't held while logging.
+    let registered = HANDLERS
+        .read()
+        .expect("Event handlers lock was poisoned")
+        .iter()
+        .flat_map(|(kind, handlers)| {
+            handlers
+                .iter()
+                .map(|(name, intents, _)| (*kind, *name, kind.intents() | *intents))
+        })
+        .collect::<Vec<_>>();
+
+    let needed = registered
+        .iter()
+        .fold(GatewayIntents::empty(), |intents, (_, _, needs)| {
+            intents | *needs
+        });
+
+    let Ok(configured) = std::env::var("GATEWAY_INTENTS") else {
+        info!("Using the gateway intents {needed:?}");
+        return needed;
+    };
+
+    let intents = configured
+        .split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .filter_map(|name| {
+            let intent = GatewayIntents::from_name(&name.to_ascii_uppercase());
+            if intent.is_none() {
+                warn!("Unknown gateway intent in GATEWAY_INTENTS: {name}");
+            }
+            intent
+        })
+        .fold(GatewayIntents::empty(), |intents, intent| intents | intent);
+
+    for (kind, name, needs) in &registered {
+        if !intents.contains(kind.intents()) {
+            warn!(
+                "{name} won't receive {kind:?} events without the {:?} intent",
+                kind.intents()
+            );
+        } else if !intents.contains(*needs) {
+            warn!(
+                "{name} won't work properly without the {:?} intent",
+                needs.difference(intents)
+            );
+        }
+    }
+
+    info!("Using the gateway intents {intents:?} from GATEWAY_INTENTS");
+    intents
+}
+
+/// Sends an event to every handler registered for its kind, one after the
+/// other.
+///
+/// A handler failing is only logged, so it doesn't stop the ones after it.
+pub async fn dispatch(ctx: &serenity::Context, event: &FullEvent) {
+    let Some(kind) = EventKind::of(event) else {
+        return;
+    };
+
+    // Copied out so the lock isn't held across the handlers.
+    let handlers = HANDLERS
+        .read()
+        .expect("Event handlers lock was poisoned")
+        .get(&kind)
+        .cloned()
+        .unwrap_or_default();
+
+    for (name, _, handler) in handlers {
+        if let Err(e) = handler(ctx, event).await {
+            error!("{name} failed to handle a {kind:?} event: {e}");
+        }
+    }
+}
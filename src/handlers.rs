@@ -1,8 +1,8 @@
 use core::panic;
 use std::sync::Arc;
 
-use crate::{commands::fun::tod, configuration::ACTIVITIES, database, Error};
-use log::{error, info, warn};
+use crate::{configuration::ACTIVITIES, database, events, router, Error};
+use log::{error, info};
 use poise::serenity_prelude::{self as serenity, ActivityData as Data};
 use rand::seq::SliceRandom;
 
@@ -76,23 +76,15 @@ pub async fn event_handler(
         }
         serenity::FullEvent::InteractionCreate { interaction } => {
             if let Some(interaction_data) = interaction.as_message_component() {
-                match interaction_data.data.custom_id.as_str() {
-                    "tod_truth" | "tod_dare" | "tod_random" => {
-                        tod::receive_interaction(ctx, interaction_data).await;
-                    }
-                    _ => {
-                        warn!(
-                            "Unhandled interaction: {:?}",
-                            interaction_data.data.custom_id
-                        );
-                    }
-                }
+                router::dispatch(ctx, interaction_data).await;
             }
         }
 
         _ => {}
     }
 
+    events::dispatch(ctx, event).await;
+
     Ok(())
 }
 
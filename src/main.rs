@@ -3,10 +3,14 @@
 
 pub mod commands;
 mod configuration;
+mod content;
 mod database;
 mod environment;
+mod events;
 mod handlers;
 mod logger;
+mod media_cache;
+mod router;
 mod utils;
 
 use commands::CommandList;
@@ -31,8 +35,6 @@ pub static mut VERSION: OnceCell<String> = OnceCell::new();
 pub static mut STARTUP_TIME: Option<SystemTime> = None;
 pub static mut VOLATILE_TIME: Option<SystemTime> = None;
 
-pub static mut ACTION_COUNTS: Option<self::commands::fun::action::Counts> = None;
-
 #[tokio::main]
 async fn main() {
     #[cfg(debug_assertions)]
@@ -54,6 +56,11 @@ async fn main() {
 
     Others it can accept but doesn't depend on:
     - RUST_LOG
+    - CONTENT_DIR
+    - MEDIA_CACHE_DIR
+    - MEDIA_CACHE_MAX_MB
+    - CAPTION_FONT
+    - GATEWAY_INTENTS
     */
     environment::parse();
 
@@ -77,6 +84,16 @@ async fn main() {
 
     info!("Initialized the database connection");
 
+    // Load the built-in quotes, facts, questions, truths, dares and trivia.
+    content::load()
+        .map_err(|e| error!("{e}"))
+        .expect("Failed to load content pools");
+
+    // The media cache is optional, so failing to set it up isn't fatal.
+    if let Err(e) = media_cache::initialize() {
+        error!("Failed to initialize the media cache: {e}");
+    }
+
     std::thread::spawn(|| {
         let reminders = database::get_all_reminders().expect("Failed to get all reminders from DB");
         commands::utility::add_reminders_to_cache(reminders.clone());
@@ -108,14 +125,12 @@ async fn main() {
         }
     });
 
-    let result = commands::fun::action::initialize_action_count()
+    commands::fun::action::load_actions()
         .await
         .map_err(|e| error!("{}", e))
-        .expect("Failed to initialize action count");
+        .expect("Failed to load actions");
 
-    unsafe {
-        ACTION_COUNTS = Some(result);
-    }
+    commands::fun::action::prefetch_gifs();
 
     #[cfg(feature = "prod")]
     let discord_token = var("PROD_TOKEN").expect("You must provide a production token");
@@ -129,6 +144,8 @@ async fn main() {
     );
 
     let command_list = CommandList::new();
+    commands::register_component_handlers();
+    commands::register_event_handlers();
 
     let framework = poise::Framework::builder()
         .options(poise::FrameworkOptions {
@@ -149,17 +166,20 @@ async fn main() {
     #[cfg(not(debug_assertions))]
     info!("Starting bot in release mode...");
 
-    let mut client =
-        match serenity::ClientBuilder::new(discord_token, serenity::GatewayIntents::empty())
-            .framework(framework)
-            .await
-        {
-            Ok(client) => client,
-            Err(error) => {
-                error!("Error creating client: {:?}", error);
-                std::process::exit(1);
-            }
-        };
+    // Has to be done after the event handlers are registered, since they
+    // decide which intents are needed.
+    let intents = events::intents();
+
+    let mut client = match serenity::ClientBuilder::new(discord_token, intents)
+        .framework(framework)
+        .await
+    {
+        Ok(client) => client,
+        Err(error) => {
+            error!("Error creating client: {:?}", error);
+            std::process::exit(1);
+        }
+    };
 
     if let Err(why) = client.start().await {
         error!("Client error: {:?}", why);
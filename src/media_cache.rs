@@ -0,0 +1,417 @@
+use std::collections::HashMap;
+use std::env::var;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use crate::Error;
+
+use log::{info, warn};
+use once_cell::sync::Lazy;
+use poise::serenity_prelude::CreateAttachment;
+use rand::seq::IteratorRandom;
+
+/// How big the cache can get when `MEDIA_CACHE_MAX_MB` isn't set.
+const DEFAULT_MAX_MB: u64 = 256;
+
+/// The biggest file that gets cached, since Discord won't take bigger
+/// attachments from bots in servers without boosts.
+const MAX_FILE_BYTES: u64 = 8 * 1024 * 1024;
+
+/// How long a download can take before it's given up on.
+const DOWNLOAD_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The cache, or `None` if it's disabled.
+///
+/// It stays disabled until [`initialize`] is called with `MEDIA_CACHE_DIR` set.
+static CACHE: Lazy<Mutex<Option<Cache>>> = Lazy::new(|| Mutex::new(None));
+
+static CLIENT: Lazy<reqwest::Client> = Lazy::new(|| {
+    reqwest::Client::builder()
+        .timeout(DOWNLOAD_TIMEOUT)
+        .build()
+        .expect("Failed to build the media cache HTTP client")
+});
+
+/// An image or video that's been downloaded, ready to be uploaded as an
+/// attachment.
+#[derive(Debug, Clone)]
+pub struct Media {
+    pub file_name: String,
+    pub data: Vec<u8>,
+}
+
+impl Media {
+    pub fn attachment(&self) -> CreateAttachment {
+        CreateAttachment::bytes(self.data.clone(), self.file_name.clone())
+    }
+
+    /// The URL that makes an embed show the attachment.
+    #[must_use]
+    pub fn attachment_url(&self) -> String {
+        format!("attachment://{}", self.file_name)
+    }
+}
+
+struct Cache {
+    dir: PathBuf,
+    max_bytes: u64,
+    size: u64,
+    /// Bumped every time something is used, so the lowest `last_used` is the
+    /// least recently used file.
+    clock: u64,
+    /// Keyed by file name.
+    files: HashMap<String, CachedFile>,
+}
+
+struct CachedFile {
+    namespace: String,
+    size: u64,
+    last_used: u64,
+}
+
+impl Cache {
+    fn touch(&mut self, file_name: &str) {
+        self.clock += 1;
+        if let Some(file) = self.files.get_mut(file_name) {
+            file.last_used = self.clock;
+        }
+    }
+
+    fn insert(&mut self, file_name: String, namespace: String, size: u64) {
+        self.clock += 1;
+        self.size += size;
+        if let Some(replaced) = self.files.insert(
+            file_name,
+            CachedFile {
+                namespace,
+                size,
+                last_used: self.clock,
+            },
+        ) {
+            self.size -= replaced.size;
+        }
+
+        self.evict();
+    }
+
+    /// Removes the least recently used files until the cache fits its limit.
+    fn evict(&mut self) {
+        while self.size > self.max_bytes {
+            let Some(oldest) = self
+                .files
+                .iter()
+                .min_by_key(|(_, file)| file.last_used)
+                .map(|(file_name, _)| file_name.clone())
+            else {
+                break;
+            };
+
+            // It's safe to use .expect() since the name was just taken from the map.
+            let file = self.files.remove(&oldest).expect("Unreachable");
+            self.size -= file.size;
+
+            if let Err(e) = std::fs::remove_file(self.dir.join(&oldest)) {
+                warn!("Failed to evict {oldest} from the media cache: {e}");
+            }
+        }
+    }
+}
+
+/// Sets up the cache in `MEDIA_CACHE_DIR` and indexes what's already in it.
+///
+/// Does nothing if `MEDIA_CACHE_DIR` isn't set, leaving the cache disabled.
+#[allow(clippy::missing_errors_doc)]
+pub fn initialize() -> Result<(), Error> {
+    let Ok(dir) = var("MEDIA_CACHE_DIR") else {
+        info!("MEDIA_CACHE_DIR isn't set, the media cache is disabled");
+        return Ok(());
+    };
+
+    let max_mb = var("MEDIA_CACHE_MAX_MB")
+        .ok()
+        .map(|max_mb| max_mb.parse::<u64>())
+        .transpose()
+        .map_err(|e| format!("Invalid MEDIA_CACHE_MAX_MB: {e}"))?
+        .unwrap_or(DEFAULT_MAX_MB);
+
+    let dir = PathBuf::from(dir);
+    std::fs::create_dir_all(&dir)?;
+
+    // The files that were modified last count as the most recently used.
+    let mut existing = Vec::new();
+    for entry in std::fs::read_dir(&dir)? {
+        let entry = entry?;
+        let metadata = entry.metadata()?;
+        let file_name = entry.file_name().to_string_lossy().to_string();
+
+        let Some((namespace, _)) = file_name.split_once('.') else {
+            continue;
+        };
+
+        if metadata.is_file() {
+            existing.push((
+                metadata.modified()?,
+                file_name.clone(),
+                namespace.to_string(),
+                metadata.len(),
+            ));
+        }
+    }
+    existing.sort_by_key(|(modified, ..)| *modified);
+
+    let mut cache = Cache {
+        dir,
+        max_bytes: max_mb * 1024 * 1024,
+        size: 0,
+        clock: 0,
+        files: HashMap::new(),
+    };
+
+    for (_, file_name, namespace, size) in existing {
+        cache.insert(file_name, namespace, size);
+    }
+
+    info!(
+        "Media cache enabled in {} with {} files ({} of {max_mb} MB used)",
+        cache.dir.display(),
+        cache.files.len(),
+        cache.size / 1024 / 1024
+    );
+
+    *CACHE
+        .lock()
+        .map_err(|_| Error::from("Media cache lock was poisoned"))? = Some(cache);
+
+    Ok(())
+}
+
+#[must_use]
+pub fn is_enabled() -> bool {
+    CACHE
+        .lock()
+        .expect("Media cache lock was poisoned")
+        .is_some()
+}
+
+/// Gets the file at `url`, from the cache if it's there or by downloading and
+/// caching it otherwise.
+///
+/// `namespace` groups files that can stand in for each other, see [`random`].
+#[allow(clippy::missing_errors_doc)]
+pub async fn fetch(namespace: &str, url: &str) -> Result<Media, Error> {
+    let namespace = sanitize(namespace);
+    let hash = hash(url);
+
+    let cached = {
+        let mut guard = CACHE.lock().expect("Media cache lock was poisoned");
+        let cache = guard.as_mut().ok_or("The media cache is disabled")?;
+
+        let prefix = format!("{namespace}.{hash:016x}.");
+        let file_name = cache
+            .files
+            .keys()
+            .find(|file_name| file_name.starts_with(&prefix))
+            .cloned();
+
+        let cached = file_name.map(|file_name| {
+            cache.touch(&file_name);
+            (cache.dir.join(&file_name), file_name)
+        });
+        drop(guard);
+
+        cached
+    };
+
+    if let Some((path, file_name)) = cached {
+        match tokio::fs::read(&path).await {
+            Ok(data) => return Ok(Media { file_name, data }),
+            // Someone probably deleted it, so it's downloaded again below.
+            Err(e) => warn!(
+                "Failed to read {} from the media cache: {e}",
+                path.display()
+            ),
+        }
+    }
+
+    let response = CLIENT.get(url).send().await?.error_for_status()?;
+
+    if response
+        .content_length()
+        .is_some_and(|length| length > MAX_FILE_BYTES)
+    {
+        return Err(format!("{url} is too big to cache").into());
+    }
+
+    let extension = extension(url, response.headers().get(reqwest::header::CONTENT_TYPE))
+        .ok_or_else(|| format!("{url} isn't an image or video"))?;
+    let data = response.bytes().await?.to_vec();
+
+    let size = u64::try_from(data.len())?;
+    if size > MAX_FILE_BYTES {
+        return Err(format!("{url} is too big to cache").into());
+    }
+
+    let file_name = format!("{namespace}.{hash:016x}.{extension}");
+    let path = CACHE
+        .lock()
+        .expect("Media cache lock was poisoned")
+        .as_ref()
+        .ok_or("The media cache is disabled")?
+        .dir
+        .join(&file_name);
+
+    tokio::fs::write(&path, &data).await?;
+
+    if let Some(cache) = CACHE
+        .lock()
+        .expect("Media cache lock was poisoned")
+        .as_mut()
+    {
+        cache.insert(file_name.clone(), namespace, size);
+    }
+
+    Ok(Media { file_name, data })
+}
+
+/// Gets a random cached file of a namespace, to stand in for one that
+/// couldn't be downloaded.
+pub async fn random(namespace: &str) -> Option<Media> {
+    let namespace = sanitize(namespace);
+
+    let (path, file_name) = {
+        let mut guard = CACHE.lock().expect("Media cache lock was poisoned");
+        let cache = guard.as_mut()?;
+
+        let file_name = cache
+            .files
+            .iter()
+            .filter(|(_, file)| file.namespace == namespace)
+            .map(|(file_name, _)| file_name.clone())
+            .choose(&mut rand::thread_rng())?;
+
+        cache.touch(&file_name);
+        let path = cache.dir.join(&file_name);
+        drop(guard);
+
+        (path, file_name)
+    };
+
+    match tokio::fs::read(&path).await {
+        Ok(data) => Some(Media { file_name, data }),
+        Err(e) => {
+            warn!(
+                "Failed to read {} from the media cache: {e}",
+                path.display()
+            );
+            None
+        }
+    }
+}
+
+/// Gets the file at `url` through the cache, or a random cached file of the
+/// same namespace if it can't be downloaded.
+///
+/// Returns `None` if the cache is disabled or has nothing to offer, in which
+/// case `url` should be linked directly.
+pub async fn fetch_or_fallback(namespace: &str, url: &str) -> Option<Media> {
+    if !is_enabled() {
+        return None;
+    }
+
+    match fetch(namespace, url).await {
+        Ok(media) => Some(media),
+        Err(e) => {
+            warn!("Failed to fetch {url} through the media cache: {e}");
+            random(namespace).await
+        }
+    }
+}
+
+/// Like [`fetch_or_fallback`], but only waits `wait` for the download, for
+/// interactions that have to be answered within Discord's 3 second deadline.
+///
+/// A download that takes longer keeps going in the background, so the file is
+/// cached for the next time.
+pub async fn fetch_or_fallback_within(namespace: &str, url: &str, wait: Duration) -> Option<Media> {
+    if !is_enabled() {
+        return None;
+    }
+
+    let download = tokio::spawn({
+        let namespace = namespace.to_string();
+        let url = url.to_string();
+        async move { fetch(&namespace, &url).await }
+    });
+
+    match tokio::time::timeout(wait, download).await {
+        Ok(Ok(Ok(media))) => Some(media),
+        Ok(Ok(Err(e))) => {
+            warn!("Failed to fetch {url} through the media cache: {e}");
+            random(namespace).await
+        }
+        Ok(Err(e)) => {
+            warn!("Fetching {url} through the media cache panicked: {e}");
+            random(namespace).await
+        }
+        Err(_) => random(namespace).await,
+    }
+}
+
+/// Downloads the files that aren't cached yet in the background, so there's
+/// something to fall back to from the start.
+pub fn prefetch(namespace: String, urls: Vec<String>) {
+    if !is_enabled() {
+        return;
+    }
+
+    tokio::spawn(async move {
+        for url in urls {
+            if let Err(e) = fetch(&namespace, &url).await {
+                warn!("Failed to prefetch {url}: {e}");
+            }
+        }
+    });
+}
+
+/// Makes a namespace safe to use in a file name.
+fn sanitize(namespace: &str) -> String {
+    namespace
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '_' {
+                c.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// FNV-1a, since std's hashers aren't guaranteed to be stable across Rust
+/// versions and the hashes end up in file names.
+fn hash(url: &str) -> u64 {
+    url.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// Works out the extension of a file, which Discord needs to show it.
+fn extension(url: &str, content_type: Option<&reqwest::header::HeaderValue>) -> Option<String> {
+    const EXTENSIONS: [&str; 7] = ["gif", "png", "jpg", "jpeg", "webp", "mp4", "webm"];
+
+    let from_url = url
+        .split(['?', '#'])
+        .next()
+        .and_then(|path| path.rsplit_once('.'))
+        .map(|(_, extension)| extension.to_lowercase())
+        .filter(|extension| EXTENSIONS.contains(&extension.as_str()));
+
+    from_url.or_else(|| {
+        let content_type = content_type?.to_str().ok()?;
+        let (kind, subtype) = content_type.split(';').next()?.trim().split_once('/')?;
+
+        (matches!(kind, "image" | "video") && EXTENSIONS.contains(&subtype))
+            .then(|| subtype.to_string())
+    })
+}
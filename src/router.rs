@@ -0,0 +1,225 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::sync::RwLock;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::{commands::ERROR_EMOJI, Error};
+
+use log::{error, warn};
+use once_cell::sync::Lazy;
+use poise::serenity_prelude::{
+    self as serenity, ComponentInteraction, CreateInteractionResponse,
+    CreateInteractionResponseMessage, UserId,
+};
+use poise::BoxFuture;
+
+/// Separates the namespace and arguments of a custom ID.
+const SEPARATOR: char = ':';
+/// Separates the routing metadata (owner and expiry) from the rest of a custom ID.
+const METADATA_SEPARATOR: char = '|';
+/// Discord doesn't allow custom IDs longer than this.
+const MAX_CUSTOM_ID_LENGTH: usize = 100;
+
+/// Handles the components of a namespace.
+///
+/// It's only called once the interaction passed the owner and expiry checks.
+pub type Handler = for<'a> fn(
+    &'a serenity::Context,
+    &'a ComponentInteraction,
+    ComponentId,
+) -> BoxFuture<'a, Result<(), Error>>;
+
+static HANDLERS: Lazy<RwLock<HashMap<&'static str, Handler>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// The custom ID of a message component, in the form of
+/// `namespace:arg:arg|u<owner>|e<expiry>`.
+///
+/// Arguments can't contain `:` or `|`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ComponentId {
+    pub namespace: String,
+    pub args: Vec<String>,
+    /// The only user allowed to use the component, if any.
+    pub owner: Option<UserId>,
+    /// The UNIX timestamp after which the component stops working, if any.
+    pub expires_at: Option<u64>,
+}
+
+impl ComponentId {
+    #[must_use]
+    pub fn new(namespace: &str) -> Self {
+        Self {
+            namespace: namespace.to_string(),
+            args: Vec::new(),
+            owner: None,
+            expires_at: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_arg(mut self, arg: impl Display) -> Self {
+        let arg = arg.to_string();
+        debug_assert!(
+            !arg.contains(SEPARATOR) && !arg.contains(METADATA_SEPARATOR),
+            "Custom ID arguments can't contain separators: {arg}"
+        );
+
+        self.args.push(arg);
+        self
+    }
+
+    /// Only lets `owner` use the component.
+    #[must_use]
+    pub const fn with_owner(mut self, owner: UserId) -> Self {
+        self.owner = Some(owner);
+        self
+    }
+
+    /// Makes the component stop working after `duration`.
+    #[must_use]
+    pub fn expires_in(mut self, duration: Duration) -> Self {
+        self.expires_at = Some(now() + duration.as_secs());
+        self
+    }
+
+    /// Gets the argument at `index`.
+    #[must_use]
+    pub fn arg(&self, index: usize) -> Option<&str> {
+        self.args.get(index).map(String::as_str)
+    }
+
+    #[must_use]
+    pub fn is_expired(&self) -> bool {
+        self.expires_at.is_some_and(|expires_at| now() > expires_at)
+    }
+
+    #[must_use]
+    pub fn encode(&self) -> String {
+        let mut id = self.namespace.clone();
+
+        for arg in &self.args {
+            id.push(SEPARATOR);
+            id.push_str(arg);
+        }
+
+        if let Some(owner) = self.owner {
+            id.push(METADATA_SEPARATOR);
+            id.push('u');
+            id.push_str(&owner.to_string());
+        }
+
+        if let Some(expires_at) = self.expires_at {
+            id.push(METADATA_SEPARATOR);
+            id.push('e');
+            id.push_str(&expires_at.to_string());
+        }
+
+        debug_assert!(
+            id.len() <= MAX_CUSTOM_ID_LENGTH,
+            "Custom ID is too long: {id}"
+        );
+
+        id
+    }
+
+    /// Parses a custom ID made by [`ComponentId::encode`].
+    ///
+    /// Returns `None` if it's malformed.
+    #[must_use]
+    pub fn parse(id: &str) -> Option<Self> {
+        let mut parts = id.split(METADATA_SEPARATOR);
+
+        // .split() always returns at least one item.
+        let mut route = parts.next()?.split(SEPARATOR);
+        let namespace = route.next().filter(|namespace| !namespace.is_empty())?;
+
+        let mut parsed = Self::new(namespace);
+        parsed.args = route.map(str::to_string).collect();
+
+        for metadata in parts {
+            match metadata.split_at_checked(1)? {
+                ("u", owner) => parsed.owner = Some(UserId::new(owner.parse().ok()?)),
+                ("e", expires_at) => parsed.expires_at = Some(expires_at.parse().ok()?),
+                _ => return None,
+            }
+        }
+
+        Some(parsed)
+    }
+}
+
+impl Display for ComponentId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.encode())
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs())
+}
+
+/// Routes the components whose custom ID starts with `namespace` to `handler`.
+pub fn register(namespace: &'static str, handler: Handler) {
+    HANDLERS
+        .write()
+        .expect("Component handlers lock was poisoned")
+        .insert(namespace, handler);
+}
+
+/// Sends a component interaction to the handler of its namespace.
+///
+/// Components that can't be routed, that expired, or that belong to someone
+/// else get a private reply instead.
+pub async fn dispatch(ctx: &serenity::Context, interaction: &ComponentInteraction) {
+    let custom_id = interaction.data.custom_id.as_str();
+
+    let routed = ComponentId::parse(custom_id).and_then(|id| {
+        HANDLERS
+            .read()
+            .expect("Component handlers lock was poisoned")
+            .get(id.namespace.as_str())
+            .map(|handler| (*handler, id))
+    });
+
+    // Components from before a handler was changed or removed are treated as
+    // expired too, since there's nothing left that knows how to handle them.
+    let Some((handler, id)) = routed else {
+        warn!("Unhandled interaction: {custom_id:?}");
+        reply(ctx, interaction, "This button has expired.").await;
+        return;
+    };
+
+    if id.is_expired() {
+        reply(ctx, interaction, "This button has expired.").await;
+        return;
+    }
+
+    if id.owner.is_some_and(|owner| owner != interaction.user.id) {
+        reply(ctx, interaction, "This button isn't for you.").await;
+        return;
+    }
+
+    if let Err(e) = handler(ctx, interaction, id).await {
+        error!("Failed to handle interaction {custom_id:?}: {e}");
+        reply(ctx, interaction, "Something went wrong, please try again.").await;
+    }
+}
+
+/// Replies privately to an interaction.
+///
+/// Failures are only logged, since the interaction might have already been
+/// responded to.
+pub async fn reply(ctx: &serenity::Context, interaction: &ComponentInteraction, message: &str) {
+    let response = CreateInteractionResponse::Message(
+        CreateInteractionResponseMessage::new()
+            .content(format!("{ERROR_EMOJI} {message}"))
+            .ephemeral(true),
+    );
+
+    if let Err(e) = interaction.create_response(&ctx.http, response).await {
+        error!("Failed to reply to interaction: {e}");
+    }
+}